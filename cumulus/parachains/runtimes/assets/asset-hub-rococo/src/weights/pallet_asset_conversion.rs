@@ -16,9 +16,9 @@
 //! Autogenerated weights for `pallet_asset_conversion`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `ef4134d66388`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
 
 // Executed Command:
@@ -29,7 +29,7 @@
 // --extrinsic=*
 // --runtime=target/production/wbuild/asset-hub-rococo-runtime/asset_hub_rococo_runtime.wasm
 // --pallet=pallet_asset_conversion
-// --header=/__w/polkadot-sdk/polkadot-sdk/cumulus/file_header.txt
+// --header=cumulus/file_header.txt
 // --output=./cumulus/parachains/runtimes/assets/asset-hub-rococo/src/weights
 // --wasm-execution=compiled
 // --steps=50
@@ -68,12 +68,38 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 		// Proof Size summary in bytes:
 		//  Measured:  `365`
 		//  Estimated: `4689`
-		// Minimum execution time: 64_248_000 picoseconds.
-		Weight::from_parts(66_174_000, 0)
+		// Minimum execution time: 160_390_000 picoseconds.
+		Weight::from_parts(165_826_000, 0)
 			.saturating_add(Weight::from_parts(0, 4689))
 			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:1)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(1224), added: 3699, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::NextPoolAssetId` (r:1 w:1)
+	/// Proof: `AssetConversion::NextPoolAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Asset` (r:1 w:1)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::NextAssetId` (r:1 w:0)
+	/// Proof: `PoolAssets::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Account` (r:1 w:1)
+	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:0 w:1)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	fn create_pool_with_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `365`
+		//  Estimated: `4689`
+		// Minimum execution time: 166_269_000 picoseconds.
+		Weight::from_parts(231_217_000, 0)
+			.saturating_add(Weight::from_parts(0, 4689))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
 	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(1224), added: 3699, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
@@ -82,6 +108,10 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:1 w:1)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(2755), added: 5230, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:1 w:0)
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
@@ -92,11 +122,11 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 		// Proof Size summary in bytes:
 		//  Measured:  `929`
 		//  Estimated: `7404`
-		// Minimum execution time: 161_490_000 picoseconds.
-		Weight::from_parts(167_789_000, 0)
+		// Minimum execution time: 502_626_000 picoseconds.
+		Weight::from_parts(543_347_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
 	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(1224), added: 3699, mode: `MaxEncodedLen`)
@@ -110,19 +140,23 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
 	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:1 w:1)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(2755), added: 5230, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Account` (r:1 w:1)
 	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssetsFreezer::FrozenBalances` (r:1 w:0)
 	/// Proof: `PoolAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	fn remove_liquidity() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1141`
+		//  Measured:  `1306`
 		//  Estimated: `7404`
-		// Minimum execution time: 165_177_000 picoseconds.
-		Weight::from_parts(168_422_000, 0)
+		// Minimum execution time: 636_968_000 picoseconds.
+		Weight::from_parts(718_923_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:2 w:2)
 	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
@@ -132,18 +166,46 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:2 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:2 w:2)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(2755), added: 5230, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 3]`.
 	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (507 ±0)`
-		//  Estimated: `7404 + n * (3157 ±0)`
-		// Minimum execution time: 109_747_000 picoseconds.
-		Weight::from_parts(111_882_000, 0)
+		//  Measured:  `0 + n * (652 ±0)`
+		//  Estimated: `7404 + n * (3157 ±7)`
+		// Minimum execution time: 217_636_000 picoseconds.
+		Weight::from_parts(510_517_597, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			// Standard Error: 216_800
-			.saturating_add(Weight::from_parts(1_740_129, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(Weight::from_parts(0, 3157).saturating_mul(n.into()))
+	}
+	/// Storage: `ForeignAssets::Asset` (r:2 w:2)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:4 w:4)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:3 w:0)
+	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:2 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:2 w:2)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(2755), added: 5230, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 3]`.
+	fn swap_exact_tokens_for_tokens_stable_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (692 ±0)`
+		//  Estimated: `7404 + n * (3157 ±10)`
+		// Minimum execution time: 308_517_000 picoseconds.
+		Weight::from_parts(7_634_620, 0)
+			.saturating_add(Weight::from_parts(0, 7404))
+			// Standard Error: 11_953_699
+			.saturating_add(Weight::from_parts(305_177_626, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
 			.saturating_add(Weight::from_parts(0, 3157).saturating_mul(n.into()))
 	}
 	/// Storage: `System::Account` (r:2 w:2)
@@ -154,18 +216,48 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:3 w:0)
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:2 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:2 w:2)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(2755), added: 5230, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 3]`.
 	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (507 ±0)`
+		//  Measured:  `0 + n * (652 ±0)`
+		//  Estimated: `7404 + n * (3157 ±19)`
+		// Minimum execution time: 220_190_000 picoseconds.
+		Weight::from_parts(177_501_536, 0)
+			.saturating_add(Weight::from_parts(0, 7404))
+			// Standard Error: 6_804_201
+			.saturating_add(Weight::from_parts(117_897_987, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(Weight::from_parts(0, 3157).saturating_mul(n.into()))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:2 w:2)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:4 w:4)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:3 w:0)
+	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:2 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:2 w:2)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(2755), added: 5230, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 3]`.
+	fn swap_tokens_for_exact_tokens_stable_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (692 ±0)`
 		//  Estimated: `7404 + n * (3157 ±10)`
-		// Minimum execution time: 109_859_000 picoseconds.
-		Weight::from_parts(112_146_000, 0)
+		// Minimum execution time: 302_056_000 picoseconds.
+		Weight::from_parts(543_963_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			// Standard Error: 211_677
-			.saturating_add(Weight::from_parts(1_842_637, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			// Standard Error: 1_463_506
+			.saturating_add(Weight::from_parts(43_402_519, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
 			.saturating_add(Weight::from_parts(0, 3157).saturating_mul(n.into()))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
@@ -185,11 +277,11 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 		// Proof Size summary in bytes:
 		//  Measured:  `938`
 		//  Estimated: `4689`
-		// Minimum execution time: 43_763_000 picoseconds.
-		Weight::from_parts(49_729_164, 0)
+		// Minimum execution time: 63_375_000 picoseconds.
+		Weight::from_parts(116_969_908, 0)
 			.saturating_add(Weight::from_parts(0, 4689))
-			// Standard Error: 301_844
-			.saturating_add(Weight::from_parts(11_930_532, 0).saturating_mul(n.into()))
+			// Standard Error: 998_744
+			.saturating_add(Weight::from_parts(28_831_189, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
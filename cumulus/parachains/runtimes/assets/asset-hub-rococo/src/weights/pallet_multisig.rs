@@ -16,9 +16,9 @@
 //! Autogenerated weights for `pallet_multisig`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-25, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `c8c7296f7413`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
 
 // Executed Command:
@@ -29,7 +29,7 @@
 // --extrinsic=*
 // --runtime=target/production/wbuild/asset-hub-rococo-runtime/asset_hub_rococo_runtime.wasm
 // --pallet=pallet_multisig
-// --header=/__w/polkadot-sdk/polkadot-sdk/cumulus/file_header.txt
+// --header=cumulus/file_header.txt
 // --output=./cumulus/parachains/runtimes/assets/asset-hub-rococo/src/weights
 // --wasm-execution=compiled
 // --steps=50
@@ -51,15 +51,13 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// The range of component `z` is `[0, 10000]`.
-	fn as_multi_threshold_1(z: u32, ) -> Weight {
+	fn as_multi_threshold_1(_z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 16_387_000 picoseconds.
-		Weight::from_parts(21_205_790, 0)
+		// Minimum execution time: 25_477_000 picoseconds.
+		Weight::from_parts(91_305_747, 0)
 			.saturating_add(Weight::from_parts(0, 0))
-			// Standard Error: 32
-			.saturating_add(Weight::from_parts(122, 0).saturating_mul(z.into()))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
@@ -69,36 +67,40 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `295 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 45_977_000 picoseconds.
-		Weight::from_parts(31_074_975, 0)
+		// Minimum execution time: 106_907_000 picoseconds.
+		Weight::from_parts(105_969_315, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_279
-			.saturating_add(Weight::from_parts(167_034, 0).saturating_mul(s.into()))
-			// Standard Error: 12
-			.saturating_add(Weight::from_parts(2_016, 0).saturating_mul(z.into()))
+			// Standard Error: 62_343
+			.saturating_add(Weight::from_parts(663_120, 0).saturating_mul(s.into()))
+			// Standard Error: 610
+			.saturating_add(Weight::from_parts(4_782, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `315`
-		//  Estimated: `6811`
-		// Minimum execution time: 30_756_000 picoseconds.
-		Weight::from_parts(19_173_288, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 2_175
-			.saturating_add(Weight::from_parts(133_101, 0).saturating_mul(s.into()))
-			// Standard Error: 21
-			.saturating_add(Weight::from_parts(1_981, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+		//  Estimated: `19994`
+		// Minimum execution time: 83_668_000 picoseconds.
+		Weight::from_parts(81_266_273, 0)
+			.saturating_add(Weight::from_parts(0, 19994))
+			// Standard Error: 34_122
+			.saturating_add(Weight::from_parts(514_111, 0).saturating_mul(s.into()))
+			// Standard Error: 334
+			.saturating_add(Weight::from_parts(3_847, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
@@ -106,15 +108,15 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `418 + s * (33 ±0)`
-		//  Estimated: `6811`
-		// Minimum execution time: 52_118_000 picoseconds.
-		Weight::from_parts(33_785_281, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 2_722
-			.saturating_add(Weight::from_parts(210_321, 0).saturating_mul(s.into()))
-			// Standard Error: 26
-			.saturating_add(Weight::from_parts(2_186, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
+		//  Estimated: `19994`
+		// Minimum execution time: 94_637_000 picoseconds.
+		Weight::from_parts(95_519_172, 0)
+			.saturating_add(Weight::from_parts(0, 19994))
+			// Standard Error: 35_391
+			.saturating_add(Weight::from_parts(726_030, 0).saturating_mul(s.into()))
+			// Standard Error: 346
+			.saturating_add(Weight::from_parts(9_960, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -124,42 +126,46 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `296 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 29_905_000 picoseconds.
-		Weight::from_parts(32_174_112, 0)
+		// Minimum execution time: 69_711_000 picoseconds.
+		Weight::from_parts(106_305_846, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_811
-			.saturating_add(Weight::from_parts(157_665, 0).saturating_mul(s.into()))
+			// Standard Error: 53_856
+			.saturating_add(Weight::from_parts(1_239_490, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `315`
-		//  Estimated: `6811`
-		// Minimum execution time: 16_959_000 picoseconds.
-		Weight::from_parts(18_404_166, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_122
-			.saturating_add(Weight::from_parts(125_139, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+		//  Estimated: `19994`
+		// Minimum execution time: 55_663_000 picoseconds.
+		Weight::from_parts(79_887_825, 0)
+			.saturating_add(Weight::from_parts(0, 19994))
+			// Standard Error: 23_520
+			.saturating_add(Weight::from_parts(254_571, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `487 + s * (1 ±0)`
-		//  Estimated: `6811`
-		// Minimum execution time: 30_189_000 picoseconds.
-		Weight::from_parts(32_326_735, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_644
-			.saturating_add(Weight::from_parts(158_333, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+		//  Estimated: `19994`
+		// Minimum execution time: 84_421_000 picoseconds.
+		Weight::from_parts(99_602_360, 0)
+			.saturating_add(Weight::from_parts(0, 19994))
+			// Standard Error: 38_959
+			.saturating_add(Weight::from_parts(991_269, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -169,12 +175,48 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `487 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 29_451_000 picoseconds.
-		Weight::from_parts(31_338_818, 0)
+		// Minimum execution time: 78_445_000 picoseconds.
+		Weight::from_parts(106_587_998, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_549
-			.saturating_add(Weight::from_parts(147_032, 0).saturating_mul(s.into()))
+			// Standard Error: 50_214
+			.saturating_add(Weight::from_parts(683_483, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::Proposals` (r:1 w:1)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 16376]`.
+	fn propose_as_multi(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `296 + s * (2 ±0)`
+		//  Estimated: `19994`
+		// Minimum execution time: 150_220_000 picoseconds.
+		Weight::from_parts(149_512_583, 0)
+			.saturating_add(Weight::from_parts(0, 19994))
+			// Standard Error: 58_013
+			.saturating_add(Weight::from_parts(1_029_845, 0).saturating_mul(s.into()))
+			// Standard Error: 347
+			.saturating_add(Weight::from_parts(6_890, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:1)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn clear_expired_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `17237`
+		//  Estimated: `19994`
+		// Minimum execution time: 164_501_000 picoseconds.
+		Weight::from_parts(181_809_000, 0)
+			.saturating_add(Weight::from_parts(0, 19994))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
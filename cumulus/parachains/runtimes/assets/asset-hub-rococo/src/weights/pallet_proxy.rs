@@ -16,9 +16,9 @@
 //! Autogenerated weights for `pallet_proxy`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-03-04, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `99fc4dfa9c86`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
 
 // Executed Command:
@@ -29,7 +29,7 @@
 // --extrinsic=*
 // --runtime=target/production/wbuild/asset-hub-rococo-runtime/asset_hub_rococo_runtime.wasm
 // --pallet=pallet_proxy
-// --header=/__w/polkadot-sdk/polkadot-sdk/cumulus/file_header.txt
+// --header=cumulus/file_header.txt
 // --output=./cumulus/parachains/runtimes/assets/asset-hub-rococo/src/weights
 // --wasm-execution=compiled
 // --steps=50
@@ -51,38 +51,36 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 14_174_000 picoseconds.
-		Weight::from_parts(15_016_964, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 937
-			.saturating_add(Weight::from_parts(29_307, 0).saturating_mul(p.into()))
+		//  Measured:  `161 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 43_493_000 picoseconds.
+		Weight::from_parts(49_423_806, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 11_884
+			.saturating_add(Weight::from_parts(493_452, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `a` is `[0, 31]`.
 	/// The range of component `p` is `[1, 31]`.
-	fn proxy_announced(a: u32, p: u32, ) -> Weight {
+	fn proxy_announced(a: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `488 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
-		// Minimum execution time: 40_810_000 picoseconds.
-		Weight::from_parts(41_201_093, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
-			// Standard Error: 3_289
-			.saturating_add(Weight::from_parts(155_098, 0).saturating_mul(a.into()))
-			// Standard Error: 3_398
-			.saturating_add(Weight::from_parts(51_292, 0).saturating_mul(p.into()))
+		//  Measured:  `488 + a * (68 ±0) + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 77_239_000 picoseconds.
+		Weight::from_parts(127_454_648, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 79_897
+			.saturating_add(Weight::from_parts(687_594, 0).saturating_mul(a.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
@@ -96,13 +94,13 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `403 + a * (68 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 29_268_000 picoseconds.
-		Weight::from_parts(27_279_884, 0)
+		// Minimum execution time: 66_868_000 picoseconds.
+		Weight::from_parts(82_896_782, 0)
 			.saturating_add(Weight::from_parts(0, 5698))
-			// Standard Error: 5_927
-			.saturating_add(Weight::from_parts(118_689, 0).saturating_mul(a.into()))
-			// Standard Error: 6_124
-			.saturating_add(Weight::from_parts(112_018, 0).saturating_mul(p.into()))
+			// Standard Error: 15_594
+			.saturating_add(Weight::from_parts(587_309, 0).saturating_mul(a.into()))
+			// Standard Error: 16_111
+			.saturating_add(Weight::from_parts(328_606, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
@@ -116,18 +114,18 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `403 + a * (68 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 28_980_000 picoseconds.
-		Weight::from_parts(27_242_237, 0)
+		// Minimum execution time: 57_379_000 picoseconds.
+		Weight::from_parts(82_631_715, 0)
 			.saturating_add(Weight::from_parts(0, 5698))
-			// Standard Error: 6_279
-			.saturating_add(Weight::from_parts(121_215, 0).saturating_mul(a.into()))
-			// Standard Error: 6_488
-			.saturating_add(Weight::from_parts(116_319, 0).saturating_mul(p.into()))
+			// Standard Error: 33_441
+			.saturating_add(Weight::from_parts(760_390, 0).saturating_mul(a.into()))
+			// Standard Error: 34_551
+			.saturating_add(Weight::from_parts(287_595, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -136,107 +134,134 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	/// The range of component `p` is `[1, 31]`.
 	fn announce(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `420 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
-		// Minimum execution time: 37_394_000 picoseconds.
-		Weight::from_parts(37_283_951, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
-			// Standard Error: 2_431
-			.saturating_add(Weight::from_parts(150_859, 0).saturating_mul(a.into()))
-			// Standard Error: 2_511
-			.saturating_add(Weight::from_parts(59_816, 0).saturating_mul(p.into()))
+		//  Measured:  `420 + a * (68 ±0) + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 73_586_000 picoseconds.
+		Weight::from_parts(103_974_058, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 53_279
+			.saturating_add(Weight::from_parts(601_552, 0).saturating_mul(a.into()))
+			// Standard Error: 55_048
+			.saturating_add(Weight::from_parts(518_171, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn add_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 24_160_000 picoseconds.
-		Weight::from_parts(24_896_954, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_200
-			.saturating_add(Weight::from_parts(51_579, 0).saturating_mul(p.into()))
+		//  Measured:  `161 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 45_019_000 picoseconds.
+		Weight::from_parts(79_284_025, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 43_831
+			.saturating_add(Weight::from_parts(385_386, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 31]`.
+	fn add_limited_proxy(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `161 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 64_709_000 picoseconds.
+		Weight::from_parts(80_626_582, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 36_340
+			.saturating_add(Weight::from_parts(444_020, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 23_957_000 picoseconds.
-		Weight::from_parts(24_867_217, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_157
-			.saturating_add(Weight::from_parts(46_274, 0).saturating_mul(p.into()))
+		//  Measured:  `161 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 46_552_000 picoseconds.
+		Weight::from_parts(75_983_145, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 71_562
+			.saturating_add(Weight::from_parts(355_680, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 21_421_000 picoseconds.
-		Weight::from_parts(22_147_331, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_126
-			.saturating_add(Weight::from_parts(35_078, 0).saturating_mul(p.into()))
+		//  Measured:  `161 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 39_736_000 picoseconds.
+		Weight::from_parts(60_830_012, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 68_172
+			.saturating_add(Weight::from_parts(898_172, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn create_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `173`
-		//  Estimated: `4706`
-		// Minimum execution time: 25_009_000 picoseconds.
-		Weight::from_parts(26_272_099, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_183
-			.saturating_add(Weight::from_parts(13_796, 0).saturating_mul(p.into()))
+		//  Estimated: `6178`
+		// Minimum execution time: 45_203_000 picoseconds.
+		Weight::from_parts(69_156_973, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 71_402
+			.saturating_add(Weight::from_parts(552_956, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `198 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 22_450_000 picoseconds.
-		Weight::from_parts(23_280_004, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 958
-			.saturating_add(Weight::from_parts(31_635, 0).saturating_mul(p.into()))
+		//  Measured:  `200 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 64_511_000 picoseconds.
+		Weight::from_parts(79_260_155, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 40_122
+			.saturating_add(Weight::from_parts(324_412, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	fn poke_deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `487`
-		//  Estimated: `5698`
-		// Minimum execution time: 47_219_000 picoseconds.
-		Weight::from_parts(48_694_000, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+		//  Measured:  `489`
+		//  Estimated: `6178`
+		// Minimum execution time: 120_093_000 picoseconds.
+		Weight::from_parts(136_312_000, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Proxy::Proxies` (r:2 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1345`
+		//  Estimated: `11366`
+		// Minimum execution time: 30_131_000 picoseconds.
+		Weight::from_parts(31_580_000, 0)
+			.saturating_add(Weight::from_parts(0, 11366))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -74,7 +74,7 @@ use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	generic, impl_opaque_keys,
-	traits::{AccountIdConversion, BlakeTwo256, Block as BlockT, Saturating, Verify},
+	traits::{AccountIdConversion, BlakeTwo256, Block as BlockT, ConvertInto, Saturating, Verify},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, Perbill, Permill, RuntimeDebug,
 };
@@ -442,6 +442,8 @@ impl pallet_asset_conversion::Config for Runtime {
 	type PalletId = AssetConversionPalletId;
 	type MaxSwapPathLength = ConstU32<3>;
 	type MintMinLiquidity = ConstU128<100>;
+	type PriceObservationPeriod = ConstU32<{ HOURS }>;
+	type MaxPriceObservations = ConstU32<24>;
 	type WeightInfo = weights::pallet_asset_conversion::WeightInfo<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = assets_common::benchmarks::AssetPairFactory<
@@ -501,6 +503,34 @@ impl pallet_asset_rewards::Config for Runtime {
 	type BenchmarkHelper = PalletAssetRewardsBenchmarkHelper;
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetsVestingBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_assets_vesting::benchmarking::BenchmarkHelper<AssetIdForTrustBackedAssets>
+	for AssetsVestingBenchmarkHelper
+{
+	fn vested_asset() -> AssetIdForTrustBackedAssets {
+		100
+	}
+}
+
+// Vesting of the trust backed assets, e.g. for token launches.
+impl pallet_assets_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetIdForTrustBackedAssets;
+	type Balance = Balance;
+	type Assets = Assets;
+	type AssetsFreezer = AssetsFreezer;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type BlockNumberToBalance = ConvertInto;
+	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type MaxVestingSchedules = ConstU32<28>;
+	type WeightInfo = weights::pallet_assets_vesting::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AssetsVestingBenchmarkHelper;
+}
+
 impl pallet_asset_conversion_ops::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PriorAccountIdConverter = pallet_asset_conversion::AccountIdConverterNoSeed<
@@ -582,6 +612,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = MaxSignatories;
+	type MaxCallSize = ConstU32<{ 16 * 1024 }>;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
 }
@@ -598,6 +629,8 @@ parameter_types! {
 	pub const ProxyDepositBase: Balance = deposit(1, 40);
 	// Additional storage item size of 33 bytes.
 	pub const ProxyDepositFactor: Balance = deposit(0, 33);
+	// Additional storage item size of up to 46 bytes for the expiry and allowance of a proxy.
+	pub const LimitedProxyDepositFactor: Balance = deposit(0, 46);
 	pub const MaxProxies: u16 = 32;
 	// One storage item; key size 32, value size 16
 	pub const AnnouncementDepositBase: Balance = deposit(1, 48);
@@ -764,6 +797,7 @@ impl pallet_proxy::Config for Runtime {
 	type ProxyType = ProxyType;
 	type ProxyDepositBase = ProxyDepositBase;
 	type ProxyDepositFactor = ProxyDepositFactor;
+	type LimitedProxyDepositFactor = LimitedProxyDepositFactor;
 	type MaxProxies = MaxProxies;
 	type WeightInfo = weights::pallet_proxy::WeightInfo<Runtime>;
 	type MaxPending = MaxPending;
@@ -1102,7 +1136,10 @@ parameter_types! {
 impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = pallet_migrations::migrations::ResetPallet<Runtime, Revive>;
+	type Migrations = (
+		pallet_migrations::migrations::ResetPallet<Runtime, Revive>,
+		pallet_proxy::migrations::v1::LazyMigrationToV1<Runtime>,
+	);
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -1170,6 +1207,7 @@ construct_runtime!(
 		Revive: pallet_revive = 60,
 
 		AssetRewards: pallet_asset_rewards = 61,
+		AssetsVesting: pallet_assets_vesting = 62,
 
 		StateTrieMigration: pallet_state_trie_migration = 70,
 
@@ -1258,6 +1296,7 @@ pub type Migrations = (
 		Runtime,
 		pallet_session::migrations::v1::InitOffenceSeverity<Runtime>,
 	>,
+	// unreleased
 	// permanent
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 	cumulus_pallet_aura_ext::migration::MigrateV0ToV1<Runtime>,
@@ -1455,6 +1494,7 @@ mod benches {
 		[pallet_assets, Pool]
 		[pallet_asset_conversion, AssetConversion]
 		[pallet_asset_rewards, AssetRewards]
+		[pallet_assets_vesting, AssetsVesting]
 		[pallet_asset_conversion_tx_payment, AssetTxPayment]
 		[pallet_balances, Balances]
 		[pallet_message_queue, MessageQueue]
@@ -16,9 +16,9 @@
 //! Autogenerated weights for `pallet_asset_conversion`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `73b9817d6032`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
 
 // Executed Command:
@@ -29,7 +29,7 @@
 // --extrinsic=*
 // --runtime=target/production/wbuild/asset-hub-westend-runtime/asset_hub_westend_runtime.wasm
 // --pallet=pallet_asset_conversion
-// --header=/__w/polkadot-sdk/polkadot-sdk/cumulus/file_header.txt
+// --header=cumulus/file_header.txt
 // --output=./cumulus/parachains/runtimes/assets/asset-hub-westend/src/weights
 // --wasm-execution=compiled
 // --steps=50
@@ -68,12 +68,38 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 		// Proof Size summary in bytes:
 		//  Measured:  `365`
 		//  Estimated: `4689`
-		// Minimum execution time: 59_914_000 picoseconds.
-		Weight::from_parts(62_246_000, 0)
+		// Minimum execution time: 94_945_000 picoseconds.
+		Weight::from_parts(108_072_000, 0)
 			.saturating_add(Weight::from_parts(0, 4689))
 			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:1)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(1224), added: 3699, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:0)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::NextPoolAssetId` (r:1 w:1)
+	/// Proof: `AssetConversion::NextPoolAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Asset` (r:1 w:1)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::NextAssetId` (r:1 w:0)
+	/// Proof: `PoolAssets::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Account` (r:1 w:1)
+	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:0 w:1)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	fn create_pool_with_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `365`
+		//  Estimated: `4689`
+		// Minimum execution time: 98_542_000 picoseconds.
+		Weight::from_parts(105_005_000, 0)
+			.saturating_add(Weight::from_parts(0, 4689))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
 	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(1224), added: 3699, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
@@ -82,6 +108,10 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:1 w:1)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(2755), added: 5230, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:1 w:0)
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
@@ -92,11 +122,11 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 		// Proof Size summary in bytes:
 		//  Measured:  `929`
 		//  Estimated: `7404`
-		// Minimum execution time: 158_776_000 picoseconds.
-		Weight::from_parts(162_604_000, 0)
+		// Minimum execution time: 270_410_000 picoseconds.
+		Weight::from_parts(329_254_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
 	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(1224), added: 3699, mode: `MaxEncodedLen`)
@@ -110,19 +140,23 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
 	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:1 w:1)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(2755), added: 5230, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Account` (r:1 w:1)
 	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssetsFreezer::FrozenBalances` (r:1 w:0)
 	/// Proof: `PoolAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	fn remove_liquidity() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1175`
+		//  Measured:  `1340`
 		//  Estimated: `7404`
-		// Minimum execution time: 164_106_000 picoseconds.
-		Weight::from_parts(167_613_000, 0)
+		// Minimum execution time: 303_610_000 picoseconds.
+		Weight::from_parts(324_799_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `ForeignAssets::Asset` (r:2 w:2)
 	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
@@ -132,18 +166,48 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:2 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:2 w:2)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(2755), added: 5230, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 3]`.
 	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (507 ±0)`
+		//  Measured:  `0 + n * (652 ±0)`
 		//  Estimated: `7404 + n * (3157 ±0)`
-		// Minimum execution time: 108_139_000 picoseconds.
-		Weight::from_parts(110_553_000, 0)
+		// Minimum execution time: 187_644_000 picoseconds.
+		Weight::from_parts(204_154_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			// Standard Error: 211_449
-			.saturating_add(Weight::from_parts(1_718_841, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			// Standard Error: 488_001
+			.saturating_add(Weight::from_parts(7_726_727, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(Weight::from_parts(0, 3157).saturating_mul(n.into()))
+	}
+	/// Storage: `ForeignAssets::Asset` (r:2 w:2)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:4 w:4)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:3 w:0)
+	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:2 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:2 w:2)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(2755), added: 5230, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 3]`.
+	fn swap_exact_tokens_for_tokens_stable_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (692 ±0)`
+		//  Estimated: `7404 + n * (3157 ±10)`
+		// Minimum execution time: 262_074_000 picoseconds.
+		Weight::from_parts(298_966_000, 0)
+			.saturating_add(Weight::from_parts(0, 7404))
+			// Standard Error: 868_896
+			.saturating_add(Weight::from_parts(18_846_074, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
 			.saturating_add(Weight::from_parts(0, 3157).saturating_mul(n.into()))
 	}
 	/// Storage: `System::Account` (r:2 w:2)
@@ -154,18 +218,48 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
 	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:3 w:0)
 	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:2 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:2 w:2)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(2755), added: 5230, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 3]`.
 	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (507 ±0)`
+		//  Measured:  `0 + n * (652 ±0)`
 		//  Estimated: `7404 + n * (3157 ±19)`
-		// Minimum execution time: 108_674_000 picoseconds.
-		Weight::from_parts(111_004_000, 0)
+		// Minimum execution time: 193_108_000 picoseconds.
+		Weight::from_parts(225_436_000, 0)
 			.saturating_add(Weight::from_parts(0, 7404))
-			// Standard Error: 229_084
-			.saturating_add(Weight::from_parts(1_871_247, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			// Standard Error: 1_358_150
+			.saturating_add(Weight::from_parts(44_301_487, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(Weight::from_parts(0, 3157).saturating_mul(n.into()))
+	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:2 w:2)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:4 w:4)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:3 w:0)
+	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:2 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(1225), added: 3700, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:2 w:2)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(2755), added: 5230, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 3]`.
+	fn swap_tokens_for_exact_tokens_stable_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (692 ±0)`
+		//  Estimated: `7404 + n * (3157 ±0)`
+		// Minimum execution time: 251_222_000 picoseconds.
+		Weight::from_parts(284_598_000, 0)
+			.saturating_add(Weight::from_parts(0, 7404))
+			// Standard Error: 638_029
+			.saturating_add(Weight::from_parts(13_865_822, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
 			.saturating_add(Weight::from_parts(0, 3157).saturating_mul(n.into()))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
@@ -185,11 +279,11 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 		// Proof Size summary in bytes:
 		//  Measured:  `938`
 		//  Estimated: `4689`
-		// Minimum execution time: 43_487_000 picoseconds.
-		Weight::from_parts(49_440_869, 0)
+		// Minimum execution time: 59_514_000 picoseconds.
+		Weight::from_parts(88_311_139, 0)
 			.saturating_add(Weight::from_parts(0, 4689))
-			// Standard Error: 295_313
-			.saturating_add(Weight::from_parts(11_739_824, 0).saturating_mul(n.into()))
+			// Standard Error: 1_022_227
+			.saturating_add(Weight::from_parts(23_194_396, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 	}
@@ -0,0 +1,232 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for `pallet_assets_vesting`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
+
+// Executed Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --extrinsic=*
+// --runtime=target/production/wbuild/asset-hub-westend-runtime/asset_hub_westend_runtime.wasm
+// --pallet=pallet_assets_vesting
+// --header=cumulus/file_header.txt
+// --output=./cumulus/parachains/runtimes/assets/asset-hub-westend/src/weights
+// --wasm-execution=compiled
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --no-storage-info
+// --no-min-squares
+// --no-median-slopes
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_assets_vesting`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_assets_vesting::WeightInfo for WeightInfo<T> {
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_locked(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 49_554_000 picoseconds.
+		Weight::from_parts(103_972_198, 0)
+			.saturating_add(Weight::from_parts(0, 4682))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_unlocked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 58_335_000 picoseconds.
+		Weight::from_parts(62_683_011, 0)
+			.saturating_add(Weight::from_parts(0, 4682))
+			// Standard Error: 107_502
+			.saturating_add(Weight::from_parts(1_707_801, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_other_locked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 54_950_000 picoseconds.
+		Weight::from_parts(80_341_227, 0)
+			.saturating_add(Weight::from_parts(0, 4682))
+			// Standard Error: 65_925
+			.saturating_add(Weight::from_parts(1_318_385, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_other_unlocked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 66_819_000 picoseconds.
+		Weight::from_parts(101_814_998, 0)
+			.saturating_add(Weight::from_parts(0, 4682))
+			// Standard Error: 87_793
+			.saturating_add(Weight::from_parts(936_220, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:2 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 27]`.
+	fn vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `997 + s * (37 ±0)`
+		//  Estimated: `6208`
+		// Minimum execution time: 163_340_000 picoseconds.
+		Weight::from_parts(248_712_119, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			// Standard Error: 131_933
+			.saturating_add(Weight::from_parts(1_212_402, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:2 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 27]`.
+	fn force_vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `997 + s * (37 ±0)`
+		//  Estimated: `6208`
+		// Minimum execution time: 155_976_000 picoseconds.
+		Weight::from_parts(248_507_631, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			// Standard Error: 162_466
+			.saturating_add(Weight::from_parts(1_717_375, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 28]`.
+	fn not_unlocking_merge_schedules(_s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 56_768_000 picoseconds.
+		Weight::from_parts(103_383_170, 0)
+			.saturating_add(Weight::from_parts(0, 4682))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 28]`.
+	fn unlocking_merge_schedules(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 56_174_000 picoseconds.
+		Weight::from_parts(81_650_914, 0)
+			.saturating_add(Weight::from_parts(0, 4682))
+			// Standard Error: 126_923
+			.saturating_add(Weight::from_parts(1_331_357, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 28]`.
+	fn force_remove_vesting_schedule(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 62_937_000 picoseconds.
+		Weight::from_parts(91_324_676, 0)
+			.saturating_add(Weight::from_parts(0, 4682))
+			// Standard Error: 62_642
+			.saturating_add(Weight::from_parts(924_680, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
@@ -16,9 +16,9 @@
 //! Autogenerated weights for `pallet_multisig`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-25, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `c8c7296f7413`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
 
 // Executed Command:
@@ -29,7 +29,7 @@
 // --extrinsic=*
 // --runtime=target/production/wbuild/asset-hub-westend-runtime/asset_hub_westend_runtime.wasm
 // --pallet=pallet_multisig
-// --header=/__w/polkadot-sdk/polkadot-sdk/cumulus/file_header.txt
+// --header=cumulus/file_header.txt
 // --output=./cumulus/parachains/runtimes/assets/asset-hub-westend/src/weights
 // --wasm-execution=compiled
 // --steps=50
@@ -51,15 +51,13 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// The range of component `z` is `[0, 10000]`.
-	fn as_multi_threshold_1(z: u32, ) -> Weight {
+	fn as_multi_threshold_1(_z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 15_819_000 picoseconds.
-		Weight::from_parts(16_264_115, 0)
+		// Minimum execution time: 19_447_000 picoseconds.
+		Weight::from_parts(51_411_132, 0)
 			.saturating_add(Weight::from_parts(0, 0))
-			// Standard Error: 23
-			.saturating_add(Weight::from_parts(801, 0).saturating_mul(z.into()))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
@@ -67,54 +65,58 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_create(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `295 + s * (2 ±0)`
+		//  Measured:  `328 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 47_212_000 picoseconds.
-		Weight::from_parts(32_568_978, 0)
+		// Minimum execution time: 68_977_000 picoseconds.
+		Weight::from_parts(60_274_425, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 3_178
-			.saturating_add(Weight::from_parts(154_052, 0).saturating_mul(s.into()))
-			// Standard Error: 31
-			.saturating_add(Weight::from_parts(2_348, 0).saturating_mul(z.into()))
+			// Standard Error: 38_903
+			.saturating_add(Weight::from_parts(528_917, 0).saturating_mul(s.into()))
+			// Standard Error: 381
+			.saturating_add(Weight::from_parts(1_597, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `315`
-		//  Estimated: `6811`
-		// Minimum execution time: 32_417_000 picoseconds.
-		Weight::from_parts(17_400_688, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 3_165
-			.saturating_add(Weight::from_parts(149_105, 0).saturating_mul(s.into()))
-			// Standard Error: 30
-			.saturating_add(Weight::from_parts(2_325, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+		//  Measured:  `348`
+		//  Estimated: `19994`
+		// Minimum execution time: 48_122_000 picoseconds.
+		Weight::from_parts(6_086_411, 0)
+			.saturating_add(Weight::from_parts(0, 19994))
+			// Standard Error: 32_599
+			.saturating_add(Weight::from_parts(591_753, 0).saturating_mul(s.into()))
+			// Standard Error: 319
+			.saturating_add(Weight::from_parts(5_503, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `418 + s * (33 ±0)`
-		//  Estimated: `6811`
-		// Minimum execution time: 53_462_000 picoseconds.
-		Weight::from_parts(39_946_704, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 6_271
-			.saturating_add(Weight::from_parts(180_027, 0).saturating_mul(s.into()))
-			// Standard Error: 61
-			.saturating_add(Weight::from_parts(2_190, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
+		//  Measured:  `451 + s * (33 ±0)`
+		//  Estimated: `19994`
+		// Minimum execution time: 94_037_000 picoseconds.
+		Weight::from_parts(163_449_149, 0)
+			.saturating_add(Weight::from_parts(0, 19994))
+			// Standard Error: 50_854
+			.saturating_add(Weight::from_parts(234_002, 0).saturating_mul(s.into()))
+			// Standard Error: 498
+			.saturating_add(Weight::from_parts(536, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -122,44 +124,48 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_create(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `296 + s * (2 ±0)`
+		//  Measured:  `329 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 29_523_000 picoseconds.
-		Weight::from_parts(31_632_836, 0)
+		// Minimum execution time: 50_458_000 picoseconds.
+		Weight::from_parts(64_296_279, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 2_330
-			.saturating_add(Weight::from_parts(170_695, 0).saturating_mul(s.into()))
+			// Standard Error: 20_685
+			.saturating_add(Weight::from_parts(165_251, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `315`
-		//  Estimated: `6811`
-		// Minimum execution time: 17_471_000 picoseconds.
-		Weight::from_parts(18_501_369, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_310
-			.saturating_add(Weight::from_parts(132_081, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+		//  Measured:  `348`
+		//  Estimated: `19994`
+		// Minimum execution time: 32_659_000 picoseconds.
+		Weight::from_parts(62_397_187, 0)
+			.saturating_add(Weight::from_parts(0, 19994))
+			// Standard Error: 38_866
+			.saturating_add(Weight::from_parts(150_585, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `487 + s * (1 ±0)`
-		//  Estimated: `6811`
-		// Minimum execution time: 30_715_000 picoseconds.
-		Weight::from_parts(32_330_651, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_147
-			.saturating_add(Weight::from_parts(152_071, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+		//  Measured:  `520 + s * (1 ±0)`
+		//  Estimated: `19994`
+		// Minimum execution time: 53_357_000 picoseconds.
+		Weight::from_parts(54_657_978, 0)
+			.saturating_add(Weight::from_parts(0, 19994))
+			// Standard Error: 25_645
+			.saturating_add(Weight::from_parts(476_989, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -167,14 +173,50 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// The range of component `s` is `[2, 100]`.
 	fn poke_deposit(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `487 + s * (1 ±0)`
+		//  Measured:  `520 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 29_011_000 picoseconds.
-		Weight::from_parts(30_639_580, 0)
+		// Minimum execution time: 45_820_000 picoseconds.
+		Weight::from_parts(50_018_289, 0)
 			.saturating_add(Weight::from_parts(0, 6811))
-			// Standard Error: 1_876
-			.saturating_add(Weight::from_parts(164_361, 0).saturating_mul(s.into()))
+			// Standard Error: 24_067
+			.saturating_add(Weight::from_parts(390_239, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::Proposals` (r:1 w:1)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 16376]`.
+	fn propose_as_multi(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `329 + s * (2 ±0)`
+		//  Estimated: `19994`
+		// Minimum execution time: 119_410_000 picoseconds.
+		Weight::from_parts(141_477_922, 0)
+			.saturating_add(Weight::from_parts(0, 19994))
+			// Standard Error: 66_361
+			.saturating_add(Weight::from_parts(1_036_297, 0).saturating_mul(s.into()))
+			// Standard Error: 397
+			.saturating_add(Weight::from_parts(1_269, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:1)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn clear_expired_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `17270`
+		//  Estimated: `19994`
+		// Minimum execution time: 149_946_000 picoseconds.
+		Weight::from_parts(177_539_000, 0)
+			.saturating_add(Weight::from_parts(0, 19994))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
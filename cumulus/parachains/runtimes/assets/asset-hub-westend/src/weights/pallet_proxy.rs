@@ -16,9 +16,9 @@
 //! Autogenerated weights for `pallet_proxy`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-03-04, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `99fc4dfa9c86`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
 
 // Executed Command:
@@ -29,7 +29,7 @@
 // --extrinsic=*
 // --runtime=target/production/wbuild/asset-hub-westend-runtime/asset_hub_westend_runtime.wasm
 // --pallet=pallet_proxy
-// --header=/__w/polkadot-sdk/polkadot-sdk/cumulus/file_header.txt
+// --header=cumulus/file_header.txt
 // --output=./cumulus/parachains/runtimes/assets/asset-hub-westend/src/weights
 // --wasm-execution=compiled
 // --steps=50
@@ -51,38 +51,34 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 14_242_000 picoseconds.
-		Weight::from_parts(14_960_578, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_137
-			.saturating_add(Weight::from_parts(35_649, 0).saturating_mul(p.into()))
+		//  Measured:  `161 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 22_722_000 picoseconds.
+		Weight::from_parts(31_324_238, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 22_850
+			.saturating_add(Weight::from_parts(123_056, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `a` is `[0, 31]`.
 	/// The range of component `p` is `[1, 31]`.
-	fn proxy_announced(a: u32, p: u32, ) -> Weight {
+	fn proxy_announced(_a: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `488 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
-		// Minimum execution time: 41_637_000 picoseconds.
-		Weight::from_parts(41_169_266, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
-			// Standard Error: 2_978
-			.saturating_add(Weight::from_parts(172_008, 0).saturating_mul(a.into()))
-			// Standard Error: 3_077
-			.saturating_add(Weight::from_parts(68_505, 0).saturating_mul(p.into()))
+		//  Measured:  `488 + a * (68 ±0) + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 67_103_000 picoseconds.
+		Weight::from_parts(100_055_395, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
@@ -96,13 +92,13 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `403 + a * (68 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 26_087_000 picoseconds.
-		Weight::from_parts(26_927_415, 0)
+		// Minimum execution time: 43_382_000 picoseconds.
+		Weight::from_parts(26_845_610, 0)
 			.saturating_add(Weight::from_parts(0, 5698))
-			// Standard Error: 1_873
-			.saturating_add(Weight::from_parts(147_445, 0).saturating_mul(a.into()))
-			// Standard Error: 1_935
-			.saturating_add(Weight::from_parts(22_864, 0).saturating_mul(p.into()))
+			// Standard Error: 45_798
+			.saturating_add(Weight::from_parts(805_121, 0).saturating_mul(a.into()))
+			// Standard Error: 47_318
+			.saturating_add(Weight::from_parts(594_019, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
@@ -116,18 +112,18 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `403 + a * (68 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 26_473_000 picoseconds.
-		Weight::from_parts(26_682_318, 0)
+		// Minimum execution time: 40_538_000 picoseconds.
+		Weight::from_parts(52_074_950, 0)
 			.saturating_add(Weight::from_parts(0, 5698))
-			// Standard Error: 1_651
-			.saturating_add(Weight::from_parts(153_500, 0).saturating_mul(a.into()))
-			// Standard Error: 1_706
-			.saturating_add(Weight::from_parts(25_850, 0).saturating_mul(p.into()))
+			// Standard Error: 59_739
+			.saturating_add(Weight::from_parts(169_587, 0).saturating_mul(a.into()))
+			// Standard Error: 61_722
+			.saturating_add(Weight::from_parts(525_869, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -136,107 +132,132 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	/// The range of component `p` is `[1, 31]`.
 	fn announce(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `420 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
-		// Minimum execution time: 37_884_000 picoseconds.
-		Weight::from_parts(38_379_964, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
-			// Standard Error: 1_965
-			.saturating_add(Weight::from_parts(151_226, 0).saturating_mul(a.into()))
-			// Standard Error: 2_030
-			.saturating_add(Weight::from_parts(51_251, 0).saturating_mul(p.into()))
+		//  Measured:  `420 + a * (68 ±0) + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 59_580_000 picoseconds.
+		Weight::from_parts(74_009_887, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 68_288
+			.saturating_add(Weight::from_parts(269_553, 0).saturating_mul(a.into()))
+			// Standard Error: 70_555
+			.saturating_add(Weight::from_parts(208_973, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn add_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 24_317_000 picoseconds.
-		Weight::from_parts(25_244_966, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_330
-			.saturating_add(Weight::from_parts(50_765, 0).saturating_mul(p.into()))
+		//  Measured:  `161 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 40_700_000 picoseconds.
+		Weight::from_parts(53_183_994, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 76_119
+			.saturating_add(Weight::from_parts(118_636, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 31]`.
+	fn add_limited_proxy(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `161 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 42_342_000 picoseconds.
+		Weight::from_parts(49_321_175, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 48_272
+			.saturating_add(Weight::from_parts(393_161, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
-	fn remove_proxy(p: u32, ) -> Weight {
+	fn remove_proxy(_p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 23_865_000 picoseconds.
-		Weight::from_parts(25_111_800, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_501
-			.saturating_add(Weight::from_parts(45_002, 0).saturating_mul(p.into()))
+		//  Measured:  `161 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 45_221_000 picoseconds.
+		Weight::from_parts(78_330_755, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 21_721_000 picoseconds.
-		Weight::from_parts(22_626_669, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_314
-			.saturating_add(Weight::from_parts(27_650, 0).saturating_mul(p.into()))
+		//  Measured:  `161 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 36_964_000 picoseconds.
+		Weight::from_parts(40_414_064, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 19_558
+			.saturating_add(Weight::from_parts(278_495, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn create_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `173`
-		//  Estimated: `4706`
-		// Minimum execution time: 25_709_000 picoseconds.
-		Weight::from_parts(27_143_129, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_723
-			.saturating_add(Weight::from_parts(8_047, 0).saturating_mul(p.into()))
+		//  Estimated: `6178`
+		// Minimum execution time: 43_215_000 picoseconds.
+		Weight::from_parts(50_713_275, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 62_203
+			.saturating_add(Weight::from_parts(1_674_297, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `198 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 22_817_000 picoseconds.
-		Weight::from_parts(23_755_496, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_854
-			.saturating_add(Weight::from_parts(33_383, 0).saturating_mul(p.into()))
+		//  Measured:  `200 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 43_311_000 picoseconds.
+		Weight::from_parts(52_115_659, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 91_083
+			.saturating_add(Weight::from_parts(1_150_927, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	fn poke_deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `487`
-		//  Estimated: `5698`
-		// Minimum execution time: 43_822_000 picoseconds.
-		Weight::from_parts(45_419_000, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+		//  Measured:  `489`
+		//  Estimated: `6178`
+		// Minimum execution time: 148_705_000 picoseconds.
+		Weight::from_parts(164_010_000, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Proxy::Proxies` (r:2 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1345`
+		//  Estimated: `11366`
+		// Minimum execution time: 34_614_000 picoseconds.
+		Weight::from_parts(40_896_000, 0)
+			.saturating_add(Weight::from_parts(0, 11366))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -177,4 +177,42 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:1)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16536), added: 19011, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 16376]`.
+	fn propose_as_multi(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191 + s * (2 ±0)`
+		//  Estimated: `20001`
+		// Minimum execution time: 57_084_000 picoseconds.
+		Weight::from_parts(42_485_761, 0)
+			.saturating_add(Weight::from_parts(0, 20001))
+			// Standard Error: 1_912
+			.saturating_add(Weight::from_parts(161_720, 0).saturating_mul(s.into()))
+			// Standard Error: 12
+			.saturating_add(Weight::from_parts(2_693, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:1)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16536), added: 19011, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn clear_expired_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `16940`
+		//  Estimated: `20001`
+		// Minimum execution time: 73_250_000 picoseconds.
+		Weight::from_parts(73_871_000, 0)
+			.saturating_add(Weight::from_parts(0, 20001))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
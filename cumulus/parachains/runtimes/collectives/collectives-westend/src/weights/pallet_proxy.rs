@@ -51,21 +51,21 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 14_410_000 picoseconds.
 		Weight::from_parts(15_193_802, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 997
 			.saturating_add(Weight::from_parts(30_486, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -75,10 +75,10 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn proxy_announced(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `488 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 42_192_000 picoseconds.
 		Weight::from_parts(42_093_319, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			// Standard Error: 2_704
 			.saturating_add(Weight::from_parts(158_968, 0).saturating_mul(a.into()))
 			// Standard Error: 2_794
@@ -127,7 +127,7 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -137,10 +137,10 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn announce(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `420 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 37_999_000 picoseconds.
 		Weight::from_parts(37_895_047, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			// Standard Error: 2_480
 			.saturating_add(Weight::from_parts(165_564, 0).saturating_mul(a.into()))
 			// Standard Error: 2_562
@@ -149,82 +149,97 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn add_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 25_113_000 picoseconds.
 		Weight::from_parts(26_024_377, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_385
 			.saturating_add(Weight::from_parts(45_544, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 31]`.
+	fn add_limited_proxy(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `161 + p * (83 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 27_624_000 picoseconds.
+		Weight::from_parts(28_626_815, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 1_385
+			.saturating_add(Weight::from_parts(56_930, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 25_130_000 picoseconds.
 		Weight::from_parts(26_149_710, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_578
 			.saturating_add(Weight::from_parts(35_938, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 22_244_000 picoseconds.
 		Weight::from_parts(23_072_656, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_299
 			.saturating_add(Weight::from_parts(34_781, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn create_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `173`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 26_399_000 picoseconds.
 		Weight::from_parts(27_535_679, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_302
 			.saturating_add(Weight::from_parts(10_041, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `198 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 23_128_000 picoseconds.
 		Weight::from_parts(24_202_796, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_739
 			.saturating_add(Weight::from_parts(27_659, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
@@ -232,11 +247,23 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn poke_deposit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `487`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 49_692_000 picoseconds.
 		Weight::from_parts(51_121_000, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Proxy::Proxies` (r:2 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1456`
+		//  Estimated: `11366`
+		// Minimum execution time: 37_005_000 picoseconds.
+		Weight::from_parts(38_485_000, 0)
+			.saturating_add(Weight::from_parts(0, 11366))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
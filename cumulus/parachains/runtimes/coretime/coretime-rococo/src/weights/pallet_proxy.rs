@@ -51,21 +51,21 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 13_890_000 picoseconds.
 		Weight::from_parts(14_690_357, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_079
 			.saturating_add(Weight::from_parts(35_620, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -75,10 +75,10 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn proxy_announced(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 40_937_000 picoseconds.
 		Weight::from_parts(41_413_996, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			// Standard Error: 2_304
 			.saturating_add(Weight::from_parts(151_878, 0).saturating_mul(a.into()))
 			// Standard Error: 2_380
@@ -127,7 +127,7 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -137,10 +137,10 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn announce(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `386 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 33_900_000 picoseconds.
 		Weight::from_parts(37_483_729, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			// Standard Error: 3_283
 			.saturating_add(Weight::from_parts(166_328, 0).saturating_mul(a.into()))
 			// Standard Error: 3_392
@@ -149,82 +149,97 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn add_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 24_003_000 picoseconds.
 		Weight::from_parts(24_851_370, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_101
 			.saturating_add(Weight::from_parts(51_924, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 31]`.
+	fn add_limited_proxy(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `127 + p * (83 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 26_403_000 picoseconds.
+		Weight::from_parts(27_336_507, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 1_101
+			.saturating_add(Weight::from_parts(64_905, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 23_865_000 picoseconds.
 		Weight::from_parts(24_891_590, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_213
 			.saturating_add(Weight::from_parts(51_884, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 21_419_000 picoseconds.
 		Weight::from_parts(22_277_152, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_286
 			.saturating_add(Weight::from_parts(32_631, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn create_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `139`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 25_635_000 picoseconds.
 		Weight::from_parts(26_592_871, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_635
 			.saturating_add(Weight::from_parts(22_103, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `164 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 22_150_000 picoseconds.
 		Weight::from_parts(23_367_544, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_500
 			.saturating_add(Weight::from_parts(24_164, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
@@ -232,11 +247,23 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn poke_deposit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `453`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 43_886_000 picoseconds.
 		Weight::from_parts(45_017_000, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Proxy::Proxies` (r:2 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1456`
+		//  Estimated: `11366`
+		// Minimum execution time: 35_440_000 picoseconds.
+		Weight::from_parts(36_858_000, 0)
+			.saturating_add(Weight::from_parts(0, 11366))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
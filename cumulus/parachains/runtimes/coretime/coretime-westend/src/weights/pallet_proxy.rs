@@ -51,21 +51,21 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 13_924_000 picoseconds.
 		Weight::from_parts(14_790_514, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_262
 			.saturating_add(Weight::from_parts(24_379, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -75,10 +75,10 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn proxy_announced(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 40_753_000 picoseconds.
 		Weight::from_parts(40_824_200, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			// Standard Error: 3_314
 			.saturating_add(Weight::from_parts(156_665, 0).saturating_mul(a.into()))
 			// Standard Error: 3_424
@@ -127,7 +127,7 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -137,10 +137,10 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn announce(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `386 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 33_748_000 picoseconds.
 		Weight::from_parts(36_450_227, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			// Standard Error: 3_480
 			.saturating_add(Weight::from_parts(193_241, 0).saturating_mul(a.into()))
 			// Standard Error: 3_595
@@ -149,82 +149,97 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn add_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 24_018_000 picoseconds.
 		Weight::from_parts(25_033_148, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_434
 			.saturating_add(Weight::from_parts(53_810, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 31]`.
+	fn add_limited_proxy(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `127 + p * (83 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 26_420_000 picoseconds.
+		Weight::from_parts(27_536_463, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 1_434
+			.saturating_add(Weight::from_parts(67_262, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 23_797_000 picoseconds.
 		Weight::from_parts(24_845_997, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_369
 			.saturating_add(Weight::from_parts(49_882, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 21_311_000 picoseconds.
 		Weight::from_parts(22_123_383, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_276
 			.saturating_add(Weight::from_parts(32_718, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn create_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `139`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 25_458_000 picoseconds.
 		Weight::from_parts(26_445_470, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_375
 			.saturating_add(Weight::from_parts(19_575, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `164 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 22_087_000 picoseconds.
 		Weight::from_parts(23_104_943, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_686
 			.saturating_add(Weight::from_parts(35_333, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
@@ -232,11 +247,23 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn poke_deposit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `453`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 43_537_000 picoseconds.
 		Weight::from_parts(44_096_000, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Proxy::Proxies` (r:2 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1456`
+		//  Estimated: `11366`
+		// Minimum execution time: 35_339_000 picoseconds.
+		Weight::from_parts(36_753_000, 0)
+			.saturating_add(Weight::from_parts(0, 11366))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
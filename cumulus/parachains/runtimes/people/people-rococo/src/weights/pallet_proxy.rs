@@ -51,21 +51,21 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 14_193_000 picoseconds.
 		Weight::from_parts(14_814_540, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_163
 			.saturating_add(Weight::from_parts(25_891, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -75,10 +75,10 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn proxy_announced(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 40_717_000 picoseconds.
 		Weight::from_parts(41_406_158, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			// Standard Error: 3_363
 			.saturating_add(Weight::from_parts(149_287, 0).saturating_mul(a.into()))
 			// Standard Error: 3_475
@@ -127,7 +127,7 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -137,10 +137,10 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn announce(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `386 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 37_082_000 picoseconds.
 		Weight::from_parts(37_886_513, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			// Standard Error: 3_640
 			.saturating_add(Weight::from_parts(144_359, 0).saturating_mul(a.into()))
 			// Standard Error: 3_760
@@ -149,82 +149,97 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn add_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 23_957_000 picoseconds.
 		Weight::from_parts(24_927_975, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_758
 			.saturating_add(Weight::from_parts(43_725, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 31]`.
+	fn add_limited_proxy(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `127 + p * (83 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 26_353_000 picoseconds.
+		Weight::from_parts(27_420_773, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 1_758
+			.saturating_add(Weight::from_parts(54_656, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 23_729_000 picoseconds.
 		Weight::from_parts(24_583_323, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_400
 			.saturating_add(Weight::from_parts(45_509, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 21_192_000 picoseconds.
 		Weight::from_parts(21_995_477, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_926
 			.saturating_add(Weight::from_parts(34_525, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn create_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `139`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 25_253_000 picoseconds.
 		Weight::from_parts(26_188_295, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_659
 			.saturating_add(Weight::from_parts(22_321, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `164 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 22_278_000 picoseconds.
 		Weight::from_parts(23_226_079, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_375
 			.saturating_add(Weight::from_parts(23_729, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
@@ -232,11 +247,23 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn poke_deposit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `453`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 43_833_000 picoseconds.
 		Weight::from_parts(44_489_000, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Proxy::Proxies` (r:2 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1456`
+		//  Estimated: `11366`
+		// Minimum execution time: 35_645_000 picoseconds.
+		Weight::from_parts(37_071_000, 0)
+			.saturating_add(Weight::from_parts(0, 11366))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
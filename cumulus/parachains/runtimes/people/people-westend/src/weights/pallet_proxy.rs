@@ -51,21 +51,21 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 14_292_000 picoseconds.
 		Weight::from_parts(15_140_165, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_184
 			.saturating_add(Weight::from_parts(37_715, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -75,10 +75,10 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn proxy_announced(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 41_703_000 picoseconds.
 		Weight::from_parts(42_146_045, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			// Standard Error: 3_906
 			.saturating_add(Weight::from_parts(152_856, 0).saturating_mul(a.into()))
 			// Standard Error: 4_036
@@ -127,7 +127,7 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -137,10 +137,10 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn announce(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `386 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 37_343_000 picoseconds.
 		Weight::from_parts(37_754_114, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			// Standard Error: 2_112
 			.saturating_add(Weight::from_parts(154_206, 0).saturating_mul(a.into()))
 			// Standard Error: 2_183
@@ -149,82 +149,97 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn add_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 24_058_000 picoseconds.
 		Weight::from_parts(25_043_055, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_548
 			.saturating_add(Weight::from_parts(59_084, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 31]`.
+	fn add_limited_proxy(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `127 + p * (83 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 26_464_000 picoseconds.
+		Weight::from_parts(27_547_361, 0)
+			.saturating_add(Weight::from_parts(0, 6178))
+			// Standard Error: 1_548
+			.saturating_add(Weight::from_parts(73_855, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 24_027_000 picoseconds.
 		Weight::from_parts(25_013_643, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_194
 			.saturating_add(Weight::from_parts(52_428, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `127 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 21_487_000 picoseconds.
 		Weight::from_parts(22_407_585, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_366
 			.saturating_add(Weight::from_parts(35_206, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn create_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `139`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 25_488_000 picoseconds.
 		Weight::from_parts(26_421_963, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 1_595
 			.saturating_add(Weight::from_parts(23_770, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `164 + p * (37 ±0)`
-		//  Estimated: `4706`
+		//  Estimated: `6178`
 		// Minimum execution time: 22_402_000 picoseconds.
 		Weight::from_parts(23_547_112, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(0, 6178))
 			// Standard Error: 2_281
 			.saturating_add(Weight::from_parts(33_996, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
@@ -232,11 +247,23 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	fn poke_deposit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `453`
-		//  Estimated: `5698`
+		//  Estimated: `7170`
 		// Minimum execution time: 44_682_000 picoseconds.
 		Weight::from_parts(45_638_000, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(Weight::from_parts(0, 7170))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Proxy::Proxies` (r:2 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1456`
+		//  Estimated: `11366`
+		// Minimum execution time: 35_843_000 picoseconds.
+		Weight::from_parts(37_277_000, 0)
+			.saturating_add(Weight::from_parts(0, 11366))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -900,6 +900,8 @@ pub fn node_config(
 			rate_limit: None,
 			rate_limit_whitelisted_ips: Default::default(),
 			rate_limit_trust_proxy_headers: Default::default(),
+			rate_limit_per_ip: Default::default(),
+			rate_limit_method_costs: Default::default(),
 		},
 		prometheus_config: None,
 		telemetry_endpoints: None,
@@ -1036,6 +1038,8 @@ pub fn run_relay_chain_validator_node(
 			max_buffer_capacity_per_connection: config.rpc.message_buffer_capacity,
 			rpc_methods: config.rpc.methods,
 			rate_limit: config.rpc.rate_limit,
+			rate_limit_per_ip: config.rpc.rate_limit_per_ip,
+			rate_limit_method_costs: config.rpc.rate_limit_method_costs.clone(),
 			rate_limit_trust_proxy_headers: config.rpc.rate_limit_trust_proxy_headers,
 			rate_limit_whitelisted_ips: config.rpc.rate_limit_whitelisted_ips.clone(),
 			retry_random_port: true,
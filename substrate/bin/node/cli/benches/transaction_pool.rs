@@ -83,6 +83,8 @@ fn new_node(tokio_handle: Handle) -> node_cli::service::NewFullBase {
 			rate_limit: None,
 			rate_limit_whitelisted_ips: Default::default(),
 			rate_limit_trust_proxy_headers: Default::default(),
+			rate_limit_per_ip: Default::default(),
+			rate_limit_method_costs: Default::default(),
 		},
 		prometheus_config: None,
 		telemetry_endpoints: None,
@@ -97,6 +97,15 @@ pub enum Subcommand {
 	/// Export the state of a given block into a chain spec.
 	ExportState(sc_cli::ExportStateCmd),
 
+	/// Export the state of a finalized block into a binary snapshot.
+	ExportSnapshot(sc_cli::ExportSnapshotCmd),
+
+	/// Initialize an empty database from a binary snapshot.
+	ImportSnapshot(sc_cli::ImportSnapshotCmd),
+
+	/// Export a checkpoint of the last finalized block to start warp sync from.
+	ExportWarpSyncCheckpoint(sc_cli::ExportWarpSyncCheckpointCmd),
+
 	/// Import blocks.
 	ImportBlocks(sc_cli::ImportBlocksCmd),
 
@@ -106,6 +115,9 @@ pub enum Subcommand {
 	/// Revert the chain to a previous state.
 	Revert(sc_cli::RevertCmd),
 
+	/// Check the consistency of the database.
+	CheckDb(sc_cli::CheckDbCmd),
+
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
 }
@@ -205,6 +205,51 @@ pub fn run() -> Result<()> {
 				Ok((cmd.run(client, config.chain_spec), task_manager))
 			})
 		},
+		Some(Subcommand::ExportSnapshot(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents { client, backend, task_manager, other, .. } =
+					new_partial(&config, None)?;
+				let (_, (_, grandpa_link, _, _), ..) = other;
+				let warp_sync: Arc<dyn sc_service::WarpSyncProvider<Block>> =
+					Arc::new(sc_consensus_grandpa::warp_proof::NetworkProvider::new(
+						backend,
+						grandpa_link.shared_authority_set().clone(),
+						Vec::default(),
+					));
+				Ok((cmd.run(client, Some(warp_sync)), task_manager))
+			})
+		},
+		Some(Subcommand::ImportSnapshot(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents { client, backend, task_manager, import_queue, other, .. } =
+					new_partial(&config, None)?;
+				let (_, (_, grandpa_link, _, _), ..) = other;
+				let warp_sync: Arc<dyn sc_service::WarpSyncProvider<Block>> =
+					Arc::new(sc_consensus_grandpa::warp_proof::NetworkProvider::new(
+						backend,
+						grandpa_link.shared_authority_set().clone(),
+						Vec::default(),
+					));
+				Ok((cmd.run(client, import_queue, Some(warp_sync)), task_manager))
+			})
+		},
+		Some(Subcommand::ExportWarpSyncCheckpoint(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents { backend, task_manager, other, .. } =
+					new_partial(&config, None)?;
+				let (_, (_, grandpa_link, _, _), ..) = other;
+				let warp_sync: Arc<dyn sc_service::WarpSyncProvider<Block>> =
+					Arc::new(sc_consensus_grandpa::warp_proof::NetworkProvider::new(
+						backend,
+						grandpa_link.shared_authority_set().clone(),
+						Vec::default(),
+					));
+				Ok((cmd.run(warp_sync), task_manager))
+			})
+		},
 		Some(Subcommand::ImportBlocks(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
@@ -230,6 +275,19 @@ pub fn run() -> Result<()> {
 				Ok((cmd.run(client, backend, Some(aux_revert)), task_manager))
 			})
 		},
+		Some(Subcommand::CheckDb(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents { client, task_manager, backend, .. } =
+					new_partial(&config, None)?;
+				let aux_revert = Box::new(|client: Arc<FullClient>, backend, blocks| {
+					sc_consensus_babe::revert(client.clone(), backend, blocks)?;
+					sc_consensus_grandpa::revert(client, blocks)?;
+					Ok(())
+				});
+				Ok((cmd.run(client, backend, Some(aux_revert)), task_manager))
+			})
+		},
 		Some(Subcommand::ChainInfo(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|config| cmd.run::<Block>(&config))
@@ -110,6 +110,44 @@ impl Into<sc_tracing::TracingReceiver> for TracingReceiver {
 	}
 }
 
+/// The format of the log output.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum LogFormat {
+	/// Human readable text lines.
+	Text,
+	/// One JSON object per line.
+	Json,
+}
+
+impl Into<sc_tracing::logging::LogFormat> for LogFormat {
+	fn into(self) -> sc_tracing::logging::LogFormat {
+		match self {
+			LogFormat::Text => sc_tracing::logging::LogFormat::Text,
+			LogFormat::Json => sc_tracing::logging::LogFormat::Json,
+		}
+	}
+}
+
+/// The protocol used to export the tracing spans to the OpenTelemetry collector.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "kebab-case")]
+pub enum OtlpProtocol {
+	/// OTLP over gRPC.
+	Grpc,
+	/// OTLP over HTTP with protobuf encoded payloads.
+	Http,
+}
+
+impl Into<sc_tracing::logging::OtlpProtocol> for OtlpProtocol {
+	fn into(self) -> sc_tracing::logging::OtlpProtocol {
+		match self {
+			OtlpProtocol::Grpc => sc_tracing::logging::OtlpProtocol::Grpc,
+			OtlpProtocol::Http => sc_tracing::logging::OtlpProtocol::Http,
+		}
+	}
+}
+
 /// The type of the node key.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
 #[value(rename_all = "kebab-case")]
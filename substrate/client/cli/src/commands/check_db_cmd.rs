@@ -0,0 +1,128 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	error,
+	params::{DatabaseParams, GenericNumber, PruningParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use log::info;
+use sc_client_api::{Backend, BlockBackend, HeaderBackend, UsageProvider};
+use sc_service::chain_ops::{check_db, repair_db};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, NumberFor};
+use std::{fmt::Debug, str::FromStr, sync::Arc};
+
+/// The `check-db` command used to check the consistency of the database.
+#[derive(Debug, Parser)]
+pub struct CheckDbCmd {
+	/// Number of the first block to check.
+	#[arg(long, value_name = "NUMBER", default_value = "0")]
+	pub from: GenericNumber,
+
+	/// Number of the last block to check.
+	/// Default is the best block.
+	#[arg(long, value_name = "NUMBER")]
+	pub to: Option<GenericNumber>,
+
+	/// Walk the state of every block in the range that still has its state.
+	///
+	/// By default only the state of the last finalized block is walked.
+	#[arg(long)]
+	pub all_states: bool,
+
+	/// Revert the chain to the block below the first inconsistency if any is found.
+	///
+	/// Only unfinalized blocks are reverted. If the finalized chain is inconsistent, nothing is
+	/// reverted and the database has to be resynced.
+	#[arg(long)]
+	pub repair: bool,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+/// Revert handler for auxiliary data (e.g. consensus).
+type AuxRevertHandler<C, BA, B> =
+	Box<dyn FnOnce(Arc<C>, Arc<BA>, NumberFor<B>) -> error::Result<()>>;
+
+impl CheckDbCmd {
+	/// Run the check-db command
+	pub async fn run<B, BA, C>(
+		&self,
+		client: Arc<C>,
+		backend: Arc<BA>,
+		aux_revert: Option<AuxRevertHandler<C, BA, B>>,
+	) -> error::Result<()>
+	where
+		B: BlockT,
+		BA: Backend<B>,
+		C: HeaderBackend<B> + BlockBackend<B> + UsageProvider<B>,
+		<<<B as BlockT>::Header as HeaderT>::Number as FromStr>::Err: Debug,
+	{
+		let from = self.from.parse()?;
+		let to = match &self.to {
+			Some(to) => to.parse()?,
+			None => client.info().best_number,
+		};
+
+		let report = check_db(client.clone(), backend.clone(), from, to, self.all_states)?;
+		if report.is_consistent() {
+			info!("Database is consistent");
+			return Ok(())
+		}
+
+		if !self.repair {
+			if let Some(target) = report.repair_target(client.info().finalized_number) {
+				info!("Run with `--repair` to revert to the last consistent block #{target}");
+			}
+			return Err(format!("Found {} database inconsistencies", report.issues.len()).into())
+		}
+
+		repair_db(client.clone(), backend.clone(), &report, |blocks| match aux_revert {
+			Some(aux_revert) => aux_revert(client.clone(), backend.clone(), blocks)
+				.map_err(|e| sc_service::Error::Other(e.to_string())),
+			None => Ok(()),
+		})?;
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for CheckDbCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
@@ -0,0 +1,106 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	error,
+	params::{BlockNumberOrHash, DatabaseParams, PruningParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use sc_client_api::{BlockBackend, HeaderBackend, ProofProvider};
+use sc_service::WarpSyncProvider;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::{fmt::Debug, fs, io::BufWriter, path::PathBuf, str::FromStr, sync::Arc};
+
+/// The `export-snapshot` command used to export the state of a finalized block into a binary
+/// snapshot.
+#[derive(Debug, Clone, Parser)]
+pub struct ExportSnapshotCmd {
+	/// Output file name.
+	#[arg()]
+	pub output: PathBuf,
+
+	/// Finalized block hash or number.
+	/// Default is the last block with a finality proof, or the last finalized block if the chain
+	/// has no warp sync provider.
+	#[arg(long, value_name = "HASH or NUMBER")]
+	pub at: Option<BlockNumberOrHash>,
+
+	/// Export a snapshot without a proof of the finality of its block.
+	///
+	/// Only needed for chains without a warp sync provider. Such a snapshot can only be imported
+	/// with `--unsafe-skip-finality-check`.
+	#[arg(long)]
+	pub unsafe_skip_finality_check: bool,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl ExportSnapshotCmd {
+	/// Run the `export-snapshot` command
+	///
+	/// The `warp_sync_provider` generates the proof of finality of the snapshot block.
+	pub async fn run<B, C>(
+		&self,
+		client: Arc<C>,
+		warp_sync_provider: Option<Arc<dyn WarpSyncProvider<B>>>,
+	) -> error::Result<()>
+	where
+		B: BlockT,
+		C: HeaderBackend<B> + BlockBackend<B> + ProofProvider<B>,
+		<B::Hash as FromStr>::Err: Debug,
+		<<B::Header as HeaderT>::Number as FromStr>::Err: Debug,
+	{
+		let block_id = self.at.as_ref().map(|b| b.parse()).transpose()?;
+		let hash = block_id.map(|id| client.expect_block_hash_from_id(&id)).transpose()?;
+		let file = BufWriter::new(fs::File::create(&self.output)?);
+
+		sc_service::chain_ops::export_snapshot(
+			client,
+			hash,
+			warp_sync_provider.as_deref(),
+			self.unsafe_skip_finality_check,
+			file,
+		)
+		.map_err(Into::into)
+	}
+}
+
+impl CliConfiguration for ExportSnapshotCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
@@ -0,0 +1,67 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	error,
+	params::{DatabaseParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use sc_service::WarpSyncProvider;
+use sp_runtime::traits::Block as BlockT;
+use std::{fs, io::BufWriter, path::PathBuf, sync::Arc};
+
+/// The `export-warp-sync-checkpoint` command used to export the last finalized block and its
+/// authority set, to start warp sync from with `--warp-sync-checkpoint`.
+#[derive(Debug, Clone, Parser)]
+pub struct ExportWarpSyncCheckpointCmd {
+	/// Output file name.
+	#[arg()]
+	pub output: PathBuf,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl ExportWarpSyncCheckpointCmd {
+	/// Run the `export-warp-sync-checkpoint` command
+	pub async fn run<B: BlockT>(
+		&self,
+		warp_sync_provider: Arc<dyn WarpSyncProvider<B>>,
+	) -> error::Result<()> {
+		let file = BufWriter::new(fs::File::create(&self.output)?);
+
+		sc_service::chain_ops::export_warp_sync_checkpoint(&*warp_sync_provider, file)
+			.map_err(Into::into)
+	}
+}
+
+impl CliConfiguration for ExportWarpSyncCheckpointCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
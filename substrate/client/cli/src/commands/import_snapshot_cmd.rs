@@ -0,0 +1,90 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	error,
+	params::{ImportParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use sc_client_api::{HeaderBackend, ProofProvider};
+use sc_service::{chain_ops::import_snapshot, WarpSyncProvider};
+use sp_runtime::traits::Block as BlockT;
+use std::{fs, io::BufReader, path::PathBuf, sync::Arc};
+
+/// The `import-snapshot` command used to initialize an empty database from a binary snapshot.
+#[derive(Debug, Parser)]
+pub struct ImportSnapshotCmd {
+	/// Input file name.
+	#[arg()]
+	pub input: PathBuf,
+
+	/// Import the snapshot without verifying the finality of its block.
+	///
+	/// The snapshot block is imported as finalized, so only use this with a snapshot from a
+	/// trusted source.
+	#[arg(long)]
+	pub unsafe_skip_finality_check: bool,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+}
+
+impl ImportSnapshotCmd {
+	/// Run the `import-snapshot` command
+	///
+	/// The `warp_sync_provider` verifies the finality of the snapshot block.
+	pub async fn run<B, C, IQ>(
+		&self,
+		client: Arc<C>,
+		import_queue: IQ,
+		warp_sync_provider: Option<Arc<dyn WarpSyncProvider<B>>>,
+	) -> error::Result<()>
+	where
+		B: BlockT,
+		C: HeaderBackend<B> + ProofProvider<B> + Send + Sync + 'static,
+		IQ: sc_service::ImportQueue<B> + 'static,
+	{
+		let file = BufReader::new(fs::File::open(&self.input)?);
+
+		import_snapshot(
+			client,
+			import_queue,
+			warp_sync_provider.as_deref(),
+			self.unsafe_skip_finality_check,
+			file,
+		)
+		.await
+		.map_err(Into::into)
+	}
+}
+
+impl CliConfiguration for ImportSnapshotCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+}
@@ -21,12 +21,16 @@
 mod build_spec_cmd;
 mod chain_info_cmd;
 mod check_block_cmd;
+mod check_db_cmd;
 mod export_blocks_cmd;
 mod export_chain_spec_cmd;
+mod export_snapshot_cmd;
 mod export_state_cmd;
+mod export_warp_sync_checkpoint_cmd;
 mod generate;
 mod generate_node_key;
 mod import_blocks_cmd;
+mod import_snapshot_cmd;
 mod insert_key;
 mod inspect_key;
 mod inspect_node_key;
@@ -42,9 +46,11 @@ mod verify;
 
 pub use self::{
 	build_spec_cmd::BuildSpecCmd, chain_info_cmd::ChainInfoCmd, check_block_cmd::CheckBlockCmd,
-	export_blocks_cmd::ExportBlocksCmd, export_chain_spec_cmd::ExportChainSpecCmd,
-	export_state_cmd::ExportStateCmd, generate::GenerateCmd,
-	generate_node_key::GenerateKeyCmdCommon, import_blocks_cmd::ImportBlocksCmd,
+	check_db_cmd::CheckDbCmd, export_blocks_cmd::ExportBlocksCmd,
+	export_chain_spec_cmd::ExportChainSpecCmd, export_snapshot_cmd::ExportSnapshotCmd,
+	export_state_cmd::ExportStateCmd, export_warp_sync_checkpoint_cmd::ExportWarpSyncCheckpointCmd,
+	generate::GenerateCmd, generate_node_key::GenerateKeyCmdCommon,
+	import_blocks_cmd::ImportBlocksCmd, import_snapshot_cmd::ImportSnapshotCmd,
 	insert_key::InsertKeyCmd, inspect_key::InspectKeyCmd, inspect_node_key::InspectNodeKeyCmd,
 	key::KeySubcommand, purge_chain_cmd::PurgeChainCmd, revert_cmd::RevertCmd, run_cmd::RunCmd,
 	sign::SignCmd, vanity::VanityCmd, verify::VerifyCmd,
@@ -28,11 +28,13 @@ use clap::Parser;
 use regex::Regex;
 use sc_service::{
 	config::{
-		BasePath, IpNetwork, PrometheusConfig, RpcBatchRequestConfig, TransactionPoolOptions,
+		BasePath, IpNetwork, PrometheusConfig, RpcBatchRequestConfig, RpcMethodCost,
+		TransactionPoolOptions,
 	},
 	ChainSpec, Role,
 };
 use sc_telemetry::TelemetryEndpoints;
+use sc_tracing::logging::OtlpConfig;
 use std::num::NonZeroU32;
 
 /// The `run` command used to run a node.
@@ -229,6 +231,19 @@ impl CliConfiguration for RunCmd {
 		Ok(name)
 	}
 
+	fn otlp_config(&self) -> Result<Option<OtlpConfig>> {
+		let chain_id = self.chain_id(self.is_dev()?)?;
+		let Some(mut config) = self.shared_params().otlp_config(&chain_id) else { return Ok(None) };
+
+		// A random node name is generated again when the node is configured, so only an explicitly
+		// given name can be attached.
+		if let Some(name) = self.name.as_ref() {
+			config.resource_attributes.insert(1, ("node.name".to_string(), name.clone()));
+		}
+
+		Ok(Some(config))
+	}
+
 	fn dev_key_seed(&self, is_dev: bool) -> Result<Option<String>> {
 		Ok(self.get_keyring().map(|a| format!("//{}", a)).or_else(|| {
 			if is_dev {
@@ -322,6 +337,14 @@ impl CliConfiguration for RunCmd {
 		Ok(self.rpc_params.rpc_rate_limit)
 	}
 
+	fn rpc_rate_limit_per_ip(&self) -> Result<Option<NonZeroU32>> {
+		Ok(self.rpc_params.rpc_rate_limit_per_ip)
+	}
+
+	fn rpc_method_costs(&self) -> Result<Vec<RpcMethodCost>> {
+		Ok(self.rpc_params.rpc_method_cost.clone())
+	}
+
 	fn rpc_rate_limit_whitelisted_ips(&self) -> Result<Vec<IpNetwork>> {
 		Ok(self.rpc_params.rpc_rate_limit_whitelisted_ips.clone())
 	}
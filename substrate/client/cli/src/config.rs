@@ -29,12 +29,12 @@ use sc_service::{
 	config::{
 		BasePath, Configuration, DatabaseSource, ExecutorConfiguration, IpNetwork, KeystoreConfig,
 		NetworkConfiguration, NodeKeyConfig, OffchainWorkerConfig, PrometheusConfig, PruningMode,
-		Role, RpcBatchRequestConfig, RpcConfiguration, RpcMethods, TelemetryEndpoints,
-		TransactionPoolOptions, WasmExecutionMethod,
+		Role, RpcBatchRequestConfig, RpcConfiguration, RpcMethodCost, RpcMethods,
+		TelemetryEndpoints, TransactionPoolOptions, WasmExecutionMethod,
 	},
 	BlocksPruning, ChainSpec, TracingReceiver,
 };
-use sc_tracing::logging::LoggerBuilder;
+use sc_tracing::logging::{LogFormat, LoggerBuilder, OtlpConfig};
 use std::{num::NonZeroU32, path::PathBuf};
 
 /// The maximum number of characters for a node name.
@@ -366,6 +366,16 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(None)
 	}
 
+	/// RPC rate limit configuration for each IP address.
+	fn rpc_rate_limit_per_ip(&self) -> Result<Option<NonZeroU32>> {
+		Ok(None)
+	}
+
+	/// RPC method costs counted against the rate limits.
+	fn rpc_method_costs(&self) -> Result<Vec<RpcMethodCost>> {
+		Ok(vec![])
+	}
+
 	/// RPC rate limit whitelisted ip addresses.
 	fn rpc_rate_limit_whitelisted_ips(&self) -> Result<Vec<IpNetwork>> {
 		Ok(vec![])
@@ -515,6 +525,20 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		let telemetry_endpoints = self.telemetry_endpoints(&chain_spec)?;
 		let runtime_cache_size = self.runtime_cache_size()?;
 
+		let database = self.database_config(&config_dir, database_cache_size, database)?;
+
+		// A relative path of the transaction pool journal is resolved against the database
+		// directory.
+		let mut transaction_pool = self.transaction_pool(is_dev)?;
+		if let (Some(journal_path), Some(database_path)) =
+			(transaction_pool.journal_path(), database.path())
+		{
+			if journal_path.is_relative() {
+				let journal_path = database_path.join(journal_path);
+				transaction_pool = transaction_pool.with_journal_path(journal_path);
+			}
+		}
+
 		let rpc_addrs: Option<Vec<sc_service::config::RpcEndpoint>> = self
 			.rpc_addr(DCV::rpc_listen_port())?
 			.map(|addrs| addrs.into_iter().map(Into::into).collect());
@@ -523,7 +547,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			impl_name: C::impl_name(),
 			impl_version: C::impl_version(),
 			tokio_handle,
-			transaction_pool: self.transaction_pool(is_dev)?,
+			transaction_pool,
 			network: self.network_config(
 				&chain_spec,
 				is_dev,
@@ -535,7 +559,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 				DCV::p2p_listen_port(),
 			)?,
 			keystore,
-			database: self.database_config(&config_dir, database_cache_size, database)?,
+			database,
 			data_path: config_dir,
 			trie_cache_maximum_size: self.trie_cache_maximum_size()?,
 			warm_up_trie_cache: self.warm_up_trie_cache()?,
@@ -561,6 +585,8 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 				message_buffer_capacity: self.rpc_buffer_capacity_per_connection()?,
 				batch_config: self.rpc_batch_config()?,
 				rate_limit: self.rpc_rate_limit()?,
+				rate_limit_per_ip: self.rpc_rate_limit_per_ip()?,
+				rate_limit_method_costs: self.rpc_method_costs()?,
 				rate_limit_whitelisted_ips: self.rpc_rate_limit_whitelisted_ips()?,
 				rate_limit_trust_proxy_headers: self.rpc_rate_limit_trust_proxy_headers()?,
 			},
@@ -605,6 +631,19 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(self.shared_params().disable_log_color())
 	}
 
+	/// The format of the log output.
+	fn log_format(&self) -> Result<LogFormat> {
+		Ok(self.shared_params().log_format())
+	}
+
+	/// Get the configuration of the OpenTelemetry span exporter (`None` if disabled).
+	///
+	/// By default this is retrieved from `SharedParams`.
+	fn otlp_config(&self) -> Result<Option<OtlpConfig>> {
+		let chain_id = self.chain_id(self.is_dev()?)?;
+		Ok(self.shared_params().otlp_config(&chain_id))
+	}
+
 	/// Initialize substrate. This must be done only once per process.
 	///
 	/// This method:
@@ -642,7 +681,8 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		let mut logger = LoggerBuilder::new(self.log_filters()?);
 		logger
 			.with_log_reloading(self.enable_log_reloading()?)
-			.with_detailed_output(self.detailed_log_output()?);
+			.with_detailed_output(self.detailed_log_output()?)
+			.with_log_format(self.log_format()?);
 
 		if let Some(tracing_targets) = self.tracing_targets()? {
 			let tracing_receiver = self.tracing_receiver()?;
@@ -653,6 +693,10 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			logger.with_colors(false);
 		}
 
+		if let Some(otlp_config) = self.otlp_config()? {
+			logger.with_otlp_exporter(otlp_config);
+		}
+
 		// Call hook for custom profiling setup.
 		logger_hook(&mut logger);
 
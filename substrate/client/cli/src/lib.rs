@@ -223,6 +223,7 @@ pub trait SubstrateCli: Sized {
 		let config = command.create_configuration(self, tokio_runtime.handle().clone())?;
 
 		command.init(&Self::support_url(), &Self::impl_version(), |logger_builder| {
+			logger_builder.with_node_name(config.network.node_name.clone());
 			logger_hook(logger_builder, &config)
 		})?;
 
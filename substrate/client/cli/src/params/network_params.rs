@@ -163,6 +163,17 @@ pub struct NetworkParams {
 	)]
 	pub sync: SyncMode,
 
+	/// Start warp sync from a trusted checkpoint file instead of genesis.
+	///
+	/// The file contains a SCALE-encoded finalized header together with the GRANDPA
+	/// authority set id and authorities active after it. Only finality proofs for
+	/// authority set changes after the checkpoint are downloaded and verified.
+	///
+	/// The checkpoint is trusted as is, so it must come from a reliable source. Only valid
+	/// together with `--sync warp`.
+	#[arg(long, value_name = "PATH")]
+	pub warp_sync_checkpoint: Option<PathBuf>,
+
 	/// Maximum number of blocks per request.
 	///
 	/// Try reducing this number from the default value if you have a slow network connection
@@ -276,6 +287,7 @@ impl NetworkParams {
 			max_parallel_downloads: self.max_parallel_downloads,
 			max_blocks_per_request: self.max_blocks_per_request,
 			min_peers_to_start_warp_sync: None,
+			warp_sync_checkpoint: self.warp_sync_checkpoint.clone(),
 			enable_dht_random_walk: !self.reserved_only,
 			allow_non_globals_in_dht,
 			kademlia_disjoint_query_paths: self.kademlia_disjoint_query_paths,
@@ -337,4 +349,23 @@ mod tests {
 
 		assert_eq!(SyncMode::Warp, params.network_params.sync);
 	}
+
+	#[test]
+	fn warp_sync_checkpoint_is_parsed() {
+		let params = Cli::try_parse_from(["", "--sync", "warp"]).expect("Parses network params");
+		assert!(params.network_params.warp_sync_checkpoint.is_none());
+
+		let params = Cli::try_parse_from([
+			"",
+			"--sync",
+			"warp",
+			"--warp-sync-checkpoint",
+			"/tmp/checkpoint.scale",
+		])
+		.expect("Parses network params");
+		assert_eq!(
+			Some(PathBuf::from("/tmp/checkpoint.scale")),
+			params.network_params.warp_sync_checkpoint,
+		);
+	}
 }
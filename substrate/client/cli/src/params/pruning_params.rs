@@ -19,6 +19,7 @@
 use crate::error;
 use clap::Args;
 use sc_service::{BlocksPruning, PruningMode};
+use std::num::NonZeroU32;
 
 /// Parameters to define the pruning mode
 #[derive(Debug, Clone, Args)]
@@ -45,6 +46,15 @@ pub struct PruningParams {
 	#[arg(alias = "pruning", long, value_name = "PRUNING_MODE")]
 	pub state_pruning: Option<DatabasePruningMode>,
 
+	/// Keep the state of every block whose number is a multiple of the given period.
+	///
+	/// The state of the finalized blocks in between is pruned according to `--state-pruning`,
+	/// which must be a NUMBER when this flag is set. Only the checkpoints of the blocks imported
+	/// by this node are kept. Like the pruning mode, the period can only be set on the first
+	/// creation of the database.
+	#[arg(long, value_name = "PERIOD")]
+	pub state_checkpoint_period: Option<NonZeroU32>,
+
 	/// Specify the blocks pruning mode.
 	///
 	/// This mode specifies when the block's body (including justifications)
@@ -69,7 +79,16 @@ pub struct PruningParams {
 impl PruningParams {
 	/// Get the pruning value from the parameters
 	pub fn state_pruning(&self) -> error::Result<Option<PruningMode>> {
-		Ok(self.state_pruning.map(|v| v.into()))
+		match (self.state_checkpoint_period, self.state_pruning) {
+			(None, state_pruning) => Ok(state_pruning.map(|v| v.into())),
+			(Some(period), None) =>
+				Ok(Some(PruningMode::Checkpoints { period, constraints: Default::default() })),
+			(Some(period), Some(DatabasePruningMode::Custom(n))) =>
+				Ok(Some(PruningMode::checkpoints(period, n))),
+			(Some(_), Some(_)) => Err(error::Error::Input(
+				"`--state-checkpoint-period` requires a NUMBER `--state-pruning` mode".into(),
+			)),
+		}
 	}
 
 	/// Get the block pruning value from the parameters
@@ -162,4 +181,25 @@ mod tests {
 		assert!(matches!(dbg!(pruning.state_pruning), Some(DatabasePruningMode::ArchiveCanonical)));
 		assert!(matches!(pruning.blocks_pruning, DatabasePruningMode::ArchiveCanonical));
 	}
+
+	#[test]
+	fn state_checkpoint_period_works() {
+		let period = NonZeroU32::new(1000).unwrap();
+
+		let Cli { pruning } =
+			Cli::parse_from(["", "--state-pruning=256", "--state-checkpoint-period=1000"]);
+		assert_eq!(pruning.state_pruning().unwrap(), Some(PruningMode::checkpoints(period, 256)));
+
+		let Cli { pruning } = Cli::parse_from(["", "--state-checkpoint-period=1000"]);
+		assert_eq!(
+			pruning.state_pruning().unwrap(),
+			Some(PruningMode::Checkpoints { period, constraints: Default::default() })
+		);
+
+		let Cli { pruning } =
+			Cli::parse_from(["", "--state-pruning=archive", "--state-checkpoint-period=1000"]);
+		assert!(pruning.state_pruning().is_err());
+
+		assert!(Cli::try_parse_from(["", "--state-checkpoint-period=0"]).is_err());
+	}
 }
@@ -18,7 +18,7 @@
 
 use crate::{
 	arg_enums::{Cors, RpcMethods},
-	params::{IpNetwork, RpcBatchRequestConfig},
+	params::{IpNetwork, RpcBatchRequestConfig, RpcMethodCost},
 	RPC_DEFAULT_MAX_CONNECTIONS, RPC_DEFAULT_MAX_REQUEST_SIZE_MB, RPC_DEFAULT_MAX_RESPONSE_SIZE_MB,
 	RPC_DEFAULT_MAX_SUBS_PER_CONN, RPC_DEFAULT_MESSAGE_CAPACITY_PER_CONN,
 };
@@ -36,6 +36,8 @@ const RPC_MAX_RESPONSE_SIZE: &str = "max-response-size";
 const RPC_MAX_SUBS_PER_CONN: &str = "max-subscriptions-per-connection";
 const RPC_MAX_BUF_CAP_PER_CONN: &str = "max-buffer-capacity-per-connection";
 const RPC_RATE_LIMIT: &str = "rate-limit";
+const RPC_RATE_LIMIT_PER_IP: &str = "rate-limit-per-ip";
+const RPC_METHOD_COST: &str = "method-cost";
 const RPC_RATE_LIMIT_TRUST_PROXY_HEADERS: &str = "rate-limit-trust-proxy-headers";
 const RPC_RATE_LIMIT_WHITELISTED_IPS: &str = "rate-limit-whitelisted-ips";
 const RPC_RETRY_RANDOM_PORT: &str = "retry-random-port";
@@ -84,6 +86,25 @@ pub struct RpcParams {
 	#[arg(long)]
 	pub rpc_rate_limit: Option<NonZeroU32>,
 
+	/// RPC rate limiting (calls/minute) shared by all connections from the same IP address.
+	///
+	/// This is disabled by default.
+	///
+	/// The IP address is read from the proxy headers if
+	/// `--rpc-rate-limit-trust-proxy-headers` is enabled.
+	#[arg(long)]
+	pub rpc_rate_limit_per_ip: Option<NonZeroU32>,
+
+	/// The cost of a single call to an RPC method, counted against the RPC rate limits.
+	///
+	/// Must be in the format `<method>=<cost>` and can be passed multiple times. Methods which
+	/// are not specified cost 1.
+	///
+	/// For example `--rpc-method-cost state_queryStorage=50` makes a single
+	/// `state_queryStorage` call count as 50 calls.
+	#[arg(long, value_name = "METHOD=COST", num_args = 1..)]
+	pub rpc_method_cost: Vec<RpcMethodCost>,
+
 	/// Disable RPC rate limiting for certain ip addresses.
 	///
 	/// Each IP address must be in CIDR notation such as `1.2.3.4/24`.
@@ -143,6 +164,9 @@ pub struct RpcParams {
 	///    available For example this may be useful if some platforms doesn't support ipv6
 	///    (optional)
 	///  • rate-limit: The rate limit in calls per minute for each connection (optional)
+	///  • rate-limit-per-ip: The rate limit in calls per minute for each IP address (optional)
+	///  • method-cost: The cost of a call to an RPC method in the format `<method>=<cost>`, this
+	///    can be enabled more than once (optional)
 	///  • rate-limit-trust-proxy-headers: Trust proxy headers for disable rate limiting (optional)
 	///  • rate-limit-whitelisted-ips: Disable rate limiting for certain ip addresses, this can be
 	/// enabled more than once (optional)  • retry-random-port: If the port is already in use,
@@ -153,7 +177,7 @@ pub struct RpcParams {
 		long,
 		num_args = 1..,
 		verbatim_doc_comment,
-		conflicts_with_all = &["rpc_external", "unsafe_rpc_external", "rpc_port", "rpc_cors", "rpc_rate_limit_trust_proxy_headers", "rpc_rate_limit", "rpc_rate_limit_whitelisted_ips", "rpc_message_buffer_capacity_per_connection", "rpc_disable_batch_requests", "rpc_max_subscriptions_per_connection", "rpc_max_request_size", "rpc_max_response_size"]
+		conflicts_with_all = &["rpc_external", "unsafe_rpc_external", "rpc_port", "rpc_cors", "rpc_rate_limit_trust_proxy_headers", "rpc_rate_limit", "rpc_rate_limit_per_ip", "rpc_method_cost", "rpc_rate_limit_whitelisted_ips", "rpc_message_buffer_capacity_per_connection", "rpc_disable_batch_requests", "rpc_max_subscriptions_per_connection", "rpc_max_request_size", "rpc_max_response_size"]
 	)]
 	pub experimental_rpc_endpoint: Vec<RpcEndpoint>,
 
@@ -254,6 +278,8 @@ impl RpcParams {
 				listen_addr: SocketAddr::new(std::net::IpAddr::V4(ipv4), port),
 				rpc_methods: self.rpc_methods,
 				rate_limit: self.rpc_rate_limit,
+				rate_limit_per_ip: self.rpc_rate_limit_per_ip,
+				rate_limit_method_costs: self.rpc_method_cost.clone(),
 				rate_limit_trust_proxy_headers: self.rpc_rate_limit_trust_proxy_headers,
 				rate_limit_whitelisted_ips: self.rpc_rate_limit_whitelisted_ips.clone(),
 				max_payload_in_mb: self.rpc_max_request_size,
@@ -270,6 +296,8 @@ impl RpcParams {
 				listen_addr: SocketAddr::new(std::net::IpAddr::V6(ipv6), port),
 				rpc_methods: self.rpc_methods,
 				rate_limit: self.rpc_rate_limit,
+				rate_limit_per_ip: self.rpc_rate_limit_per_ip,
+				rate_limit_method_costs: self.rpc_method_cost.clone(),
 				rate_limit_trust_proxy_headers: self.rpc_rate_limit_trust_proxy_headers,
 				rate_limit_whitelisted_ips: self.rpc_rate_limit_whitelisted_ips.clone(),
 				max_payload_in_mb: self.rpc_max_request_size,
@@ -345,6 +373,10 @@ pub struct RpcEndpoint {
 	pub max_buffer_capacity_per_connection: u32,
 	/// Rate limit per minute.
 	pub rate_limit: Option<NonZeroU32>,
+	/// Rate limit per minute for each IP address.
+	pub rate_limit_per_ip: Option<NonZeroU32>,
+	/// Costs of the RPC methods counted against the rate limits.
+	pub rate_limit_method_costs: Vec<RpcMethodCost>,
 	/// Whether to trust proxy headers for rate limiting.
 	pub rate_limit_trust_proxy_headers: bool,
 	/// Whitelisted IPs for rate limiting.
@@ -377,6 +409,8 @@ impl std::str::FromStr for RpcEndpoint {
 		let mut disable_batch_requests = None;
 		let mut max_batch_request_len = None;
 		let mut rate_limit = None;
+		let mut rate_limit_per_ip = None;
+		let mut rate_limit_method_costs = Vec::new();
 		let mut rate_limit_trust_proxy_headers = None;
 		let mut rate_limit_whitelisted_ips = Vec::new();
 		let mut retry_random_port = None;
@@ -458,6 +492,20 @@ impl std::str::FromStr for RpcEndpoint {
 					let val = val.parse().map_err(|_| invalid_value(RPC_RATE_LIMIT, &val))?;
 					rate_limit = Some(val);
 				},
+				RPC_RATE_LIMIT_PER_IP => {
+					if rate_limit_per_ip.is_some() {
+						return Err(only_once_err(RPC_RATE_LIMIT_PER_IP));
+					}
+
+					let val =
+						val.parse().map_err(|_| invalid_value(RPC_RATE_LIMIT_PER_IP, &val))?;
+					rate_limit_per_ip = Some(val);
+				},
+				RPC_METHOD_COST => {
+					let val: RpcMethodCost =
+						val.parse().map_err(|_| invalid_value(RPC_METHOD_COST, &val))?;
+					rate_limit_method_costs.push(val);
+				},
 				RPC_RATE_LIMIT_TRUST_PROXY_HEADERS => {
 					if rate_limit_trust_proxy_headers.is_some() {
 						return Err(only_once_err(RPC_RATE_LIMIT_TRUST_PROXY_HEADERS));
@@ -541,6 +589,8 @@ impl std::str::FromStr for RpcEndpoint {
 				.unwrap_or(RPC_DEFAULT_MAX_SUBS_PER_CONN),
 			rpc_methods: rpc_methods.unwrap_or(RpcMethods::Auto),
 			rate_limit,
+			rate_limit_per_ip,
+			rate_limit_method_costs,
 			rate_limit_trust_proxy_headers: rate_limit_trust_proxy_headers.unwrap_or(false),
 			rate_limit_whitelisted_ips,
 			is_optional: is_optional.unwrap_or(false),
@@ -561,6 +611,8 @@ impl Into<sc_service::config::RpcEndpoint> for RpcEndpoint {
 			max_subscriptions_per_connection: self.max_subscriptions_per_connection,
 			rpc_methods: self.rpc_methods.into(),
 			rate_limit: self.rate_limit,
+			rate_limit_per_ip: self.rate_limit_per_ip,
+			rate_limit_method_costs: self.rate_limit_method_costs,
 			rate_limit_trust_proxy_headers: self.rate_limit_trust_proxy_headers,
 			rate_limit_whitelisted_ips: self.rate_limit_whitelisted_ips,
 			cors: self.cors,
@@ -620,7 +672,8 @@ mod tests {
 			"listen-addr=127.0.0.1:9944,methods=unsafe,cors=*,optional=true,retry-random-port=true,rate-limit=99,\
 			max-batch-request-len=100,rate-limit-trust-proxy-headers=true,max-connections=33,max-request-size=4,\
 			max-response-size=3,max-subscriptions-per-connection=7,max-buffer-capacity-per-connection=8,\
-			rate-limit-whitelisted-ips=192.168.1.0/24,rate-limit-whitelisted-ips=ff01::0/32"
+			rate-limit-whitelisted-ips=192.168.1.0/24,rate-limit-whitelisted-ips=ff01::0/32,rate-limit-per-ip=20,\
+			method-cost=state_queryStorage=50,method-cost=archive_v1_storage=10"
 		).unwrap();
 		assert_eq!(endpoint.listen_addr, ([127, 0, 0, 1], 9944).into());
 		assert_eq!(endpoint.rpc_methods, RpcMethods::Unsafe);
@@ -630,6 +683,20 @@ mod tests {
 		assert_eq!(endpoint.rate_limit, Some(NonZeroU32::new(99).unwrap()));
 		assert!(matches!(endpoint.batch_config, RpcBatchRequestConfig::Limit(l) if l == 100));
 		assert_eq!(endpoint.rate_limit_trust_proxy_headers, true);
+		assert_eq!(endpoint.rate_limit_per_ip, Some(NonZeroU32::new(20).unwrap()));
+		assert_eq!(
+			endpoint.rate_limit_method_costs,
+			vec![
+				RpcMethodCost {
+					method: "state_queryStorage".into(),
+					cost: NonZeroU32::new(50).unwrap()
+				},
+				RpcMethodCost {
+					method: "archive_v1_storage".into(),
+					cost: NonZeroU32::new(10).unwrap()
+				},
+			]
+		);
 		assert_eq!(
 			endpoint.rate_limit_whitelisted_ips,
 			vec![
@@ -16,9 +16,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::arg_enums::TracingReceiver;
+use crate::arg_enums::{LogFormat, OtlpProtocol, TracingReceiver};
 use clap::Args;
 use sc_service::config::BasePath;
+use sc_tracing::logging::OtlpConfig;
 use std::path::PathBuf;
 
 /// Shared parameters used by all `CoreParams`.
@@ -70,6 +71,14 @@ pub struct SharedParams {
 	#[arg(long)]
 	pub disable_log_color: bool,
 
+	/// The format of the log output.
+	///
+	/// With `json` every log record is printed as a single line JSON object including the
+	/// timestamp, level, target, node name, fields and spans of the record. The colors are
+	/// disabled in this format.
+	#[arg(long, value_name = "FORMAT", value_enum, ignore_case = true, default_value_t = LogFormat::Text)]
+	pub log_format: LogFormat,
+
 	/// Enable feature to dynamically update and reload the log filter.
 	///
 	/// Be aware that enabling this feature can lead to a performance decrease up to factor six or
@@ -89,6 +98,50 @@ pub struct SharedParams {
 	/// Receiver to process tracing messages.
 	#[arg(long, value_name = "RECEIVER", value_enum, ignore_case = true, default_value_t = TracingReceiver::Log)]
 	pub tracing_receiver: TracingReceiver,
+
+	/// Export the tracing spans to the OpenTelemetry collector listening at the given URL.
+	///
+	/// Only the spans enabled by `--log` and `--tracing-targets` are exported.
+	///
+	/// *Example*: `--otlp-endpoint http://localhost:4317`.
+	#[arg(long, value_name = "URL")]
+	pub otlp_endpoint: Option<String>,
+
+	/// The protocol used to export the spans to the OpenTelemetry collector.
+	#[arg(
+		long,
+		value_name = "PROTOCOL",
+		value_enum,
+		ignore_case = true,
+		default_value_t = OtlpProtocol::Grpc,
+		requires = "otlp_endpoint"
+	)]
+	pub otlp_protocol: OtlpProtocol,
+
+	/// The ratio of the traces exported to the OpenTelemetry collector [0.0 - 1.0].
+	#[arg(
+		long,
+		value_name = "RATIO",
+		default_value_t = 1.0,
+		value_parser = parse_sampling_ratio,
+		requires = "otlp_endpoint"
+	)]
+	pub otlp_sampling_ratio: f64,
+
+	/// Attach a resource attribute to the spans exported to the OpenTelemetry collector (syntax:
+	/// `<key>=<value>`).
+	///
+	/// The `chain` attribute is always attached, as well as `node.name` if `--name` is given.
+	///
+	/// *Example*: `--otlp-resource-attribute deployment.environment=staging`.
+	#[arg(
+		long,
+		value_name = "KEY=VALUE",
+		num_args = 1..,
+		value_parser = parse_resource_attribute,
+		requires = "otlp_endpoint"
+	)]
+	pub otlp_resource_attribute: Vec<(String, String)>,
 }
 
 impl SharedParams {
@@ -131,6 +184,11 @@ impl SharedParams {
 		self.disable_log_color
 	}
 
+	/// The format of the log output.
+	pub fn log_format(&self) -> sc_tracing::logging::LogFormat {
+		self.log_format.into()
+	}
+
 	/// Is log reloading enabled
 	pub fn enable_log_reloading(&self) -> bool {
 		self.enable_log_reloading
@@ -145,4 +203,40 @@ impl SharedParams {
 	pub fn tracing_targets(&self) -> Option<String> {
 		self.tracing_targets.clone()
 	}
+
+	/// The configuration of the OpenTelemetry span exporter, `None` if disabled.
+	pub fn otlp_config(&self, chain_id: &str) -> Option<OtlpConfig> {
+		let endpoint = self.otlp_endpoint.clone()?;
+		// Attributes given by the user take precedence.
+		let resource_attributes = std::iter::once(("chain".to_string(), chain_id.to_string()))
+			.chain(self.otlp_resource_attribute.iter().cloned())
+			.collect();
+
+		Some(OtlpConfig {
+			endpoint,
+			protocol: self.otlp_protocol.into(),
+			sampling_ratio: self.otlp_sampling_ratio,
+			resource_attributes,
+		})
+	}
+}
+
+fn parse_sampling_ratio(s: &str) -> Result<f64, String> {
+	let ratio: f64 =
+		s.parse().map_err(|_| format!("Illegal `--otlp-sampling-ratio` value: {s}"))?;
+
+	if (0.0..=1.0).contains(&ratio) {
+		Ok(ratio)
+	} else {
+		Err(format!("Illegal `--otlp-sampling-ratio` value: {ratio} is not between 0.0 and 1.0"))
+	}
+}
+
+fn parse_resource_attribute(s: &str) -> Result<(String, String), String> {
+	match s.split_once('=') {
+		Some((key, value)) if !key.trim().is_empty() =>
+			Ok((key.trim().to_string(), value.trim().to_string())),
+		_ =>
+			Err(format!("Illegal `--otlp-resource-attribute` value: {s}, expected `<key>=<value>`")),
+	}
 }
@@ -19,6 +19,9 @@
 use clap::{Args, ValueEnum};
 use sc_transaction_pool::TransactionPoolOptions;
 
+/// The path of the transaction pool journal, relative to the database directory.
+const TXPOOL_JOURNAL_PATH: &str = "txpool/journal";
+
 /// Type of transaction pool to be used
 #[derive(Debug, Clone, Copy, ValueEnum)]
 #[value(rename_all = "kebab-case")]
@@ -59,17 +62,30 @@ pub struct TransactionPoolParams {
 	/// The type of transaction pool to be instantiated.
 	#[arg(long, value_enum, default_value_t = TransactionPoolType::SingleState)]
 	pub pool_type: TransactionPoolType,
+
+	/// Persist the pending transactions in a journal stored in the database directory.
+	///
+	/// The transactions are restored and revalidated when the node is restarted. Only supported
+	/// by the fork-aware transaction pool.
+	#[arg(long)]
+	pub pool_journal: bool,
 }
 
 impl TransactionPoolParams {
 	/// Fill the given `PoolConfiguration` by looking at the cli parameters.
 	pub fn transaction_pool(&self, is_dev: bool) -> TransactionPoolOptions {
-		TransactionPoolOptions::new_with_params(
+		let options = TransactionPoolOptions::new_with_params(
 			self.pool_limit,
 			self.pool_kbytes * 1024,
 			self.tx_ban_seconds,
 			self.pool_type.into(),
 			is_dev,
-		)
+		);
+
+		if self.pool_journal {
+			options.with_journal_path(TXPOOL_JOURNAL_PATH)
+		} else {
+			options
+		}
 	}
 }
@@ -128,6 +128,9 @@ impl<C: SubstrateCli> Runner<C> {
 			});
 		}
 
+		// Flush the spans which were not yet exported to the OpenTelemetry collector.
+		sc_tracing::logging::shutdown_otlp_exporter();
+
 		res.map_err(Into::into)
 	}
 
@@ -283,6 +286,8 @@ mod tests {
 					rate_limit: None,
 					rate_limit_whitelisted_ips: Default::default(),
 					rate_limit_trust_proxy_headers: Default::default(),
+					rate_limit_per_ip: Default::default(),
+					rate_limit_method_costs: Default::default(),
 				},
 				prometheus_config: None,
 				telemetry_endpoints: None,
@@ -24,7 +24,9 @@ use crate::{
 	BlockNumberOps, GrandpaJustification, SharedAuthoritySet,
 };
 use sc_client_api::Backend as ClientBackend;
-use sc_network_sync::strategy::warp::{EncodedProof, VerificationResult, WarpSyncProvider};
+use sc_network_sync::strategy::warp::{
+	EncodedProof, VerificationResult, WarpSyncCheckpoint, WarpSyncProvider,
+};
 use sp_blockchain::{Backend as BlockchainBackend, HeaderBackend};
 use sp_consensus_grandpa::{AuthorityList, SetId, GRANDPA_ENGINE_ID};
 use sp_runtime::{
@@ -325,12 +327,44 @@ where
 	fn current_authorities(&self) -> AuthorityList {
 		self.authority_set.inner().current_authorities.clone()
 	}
+
+	fn proof_target(
+		&self,
+		proof: &EncodedProof,
+	) -> Result<(Block::Header, bool), Box<dyn std::error::Error + Send + Sync>> {
+		let EncodedProof(proof) = proof;
+		let proof = WarpSyncProof::<Block>::decode_all(&mut proof.as_slice())
+			.map_err(|e| format!("Proof decoding error: {:?}", e))?;
+		let last_header = proof
+			.proofs
+			.last()
+			.map(|p| p.header.clone())
+			.ok_or_else(|| "Empty proof".to_string())?;
+		Ok((last_header, proof.is_finished))
+	}
+
+	fn checkpoint(
+		&self,
+	) -> Result<WarpSyncCheckpoint<Block>, Box<dyn std::error::Error + Send + Sync>> {
+		// Finalizing a block that enacts a set change holds the authority set lock, so the set
+		// can't change while the finalized header is read.
+		let authority_set = self.authority_set.inner();
+		let blockchain = self.backend.blockchain();
+		let header = blockchain
+			.header(blockchain.info().finalized_hash)?
+			.ok_or_else(|| "Missing finalized header".to_string())?;
+		Ok(WarpSyncCheckpoint {
+			header,
+			set_id: authority_set.set_id,
+			authorities: authority_set.current_authorities.clone(),
+		})
+	}
 }
 
 #[cfg(test)]
 mod tests {
-	use super::WarpSyncProof;
-	use crate::{AuthoritySetChanges, GrandpaJustification};
+	use super::{NetworkProvider, WarpSyncProof, WarpSyncProvider};
+	use crate::{authorities::AuthoritySet, AuthoritySetChanges, GrandpaJustification};
 	use codec::Encode;
 	use rand::prelude::*;
 	use sc_block_builder::BlockBuilderBuilder;
@@ -338,6 +372,7 @@ mod tests {
 	use sp_consensus::BlockOrigin;
 	use sp_consensus_grandpa::GRANDPA_ENGINE_ID;
 	use sp_keyring::Ed25519Keyring;
+	use sp_runtime::traits::Header as HeaderT;
 	use std::sync::Arc;
 	use substrate_test_runtime_client::{
 		BlockBuilderExt, ClientBlockImportExt, ClientExt, DefaultTestClientBuilderExt,
@@ -457,4 +492,34 @@ mod tests {
 		assert_eq!(new_set_id, current_set_id);
 		assert_eq!(new_authorities, expected_authorities);
 	}
+
+	#[test]
+	fn checkpoint_is_at_latest_finalized_block() {
+		let builder = TestClientBuilder::new();
+		let backend = builder.backend();
+		let client = Arc::new(builder.build());
+
+		for _ in 0..3 {
+			let block = BlockBuilderBuilder::new(&*client)
+				.on_parent_block(client.chain_info().best_hash)
+				.with_parent_block_number(client.chain_info().best_number)
+				.build()
+				.unwrap()
+				.build()
+				.unwrap()
+				.block;
+			futures::executor::block_on(client.import(BlockOrigin::Own, block)).unwrap();
+		}
+		let finalized_hash = client.hash(2).unwrap().unwrap();
+		client.finalize_block(finalized_hash, None).unwrap();
+
+		let authorities = vec![(Ed25519Keyring::Alice.public().into(), 1)];
+		let authority_set = AuthoritySet::genesis(authorities.clone()).unwrap();
+		let provider = NetworkProvider::new(backend, authority_set.into(), Vec::new());
+
+		let checkpoint = provider.checkpoint().unwrap();
+		assert_eq!(checkpoint.header.hash(), finalized_hash);
+		assert_eq!(checkpoint.set_id, 0);
+		assert_eq!(checkpoint.authorities, authorities);
+	}
 }
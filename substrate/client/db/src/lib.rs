@@ -2542,9 +2542,16 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 					let state = RefTrackingState::new(db_state, self.storage.clone(), Some(hash));
 					Ok(RecordStatsState::new(state, Some(hash), self.state_usage.clone()))
 				} else {
-					Err(sp_blockchain::Error::UnknownBlock(format!(
-						"State already discarded for {hash:?}",
-					)))
+					let number = hdr.number.saturated_into::<u64>();
+					Err(sp_blockchain::Error::UnknownBlock(
+						match self.storage.state_db.nearest_checkpoint(number) {
+							Some(checkpoint) => format!(
+								"State already discarded for {hash:?} (#{number}), the nearest \
+								state checkpoint is #{checkpoint}",
+							),
+							None => format!("State already discarded for {hash:?}"),
+						},
+					))
 				}
 			},
 			Err(e) => Err(e),
@@ -2588,7 +2595,9 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 	fn requires_full_sync(&self) -> bool {
 		matches!(
 			self.storage.state_db.pruning_mode(),
-			PruningMode::ArchiveAll | PruningMode::ArchiveCanonical
+			PruningMode::ArchiveAll |
+				PruningMode::ArchiveCanonical |
+				PruningMode::Checkpoints { .. }
 		)
 	}
 
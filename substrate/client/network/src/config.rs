@@ -629,6 +629,9 @@ pub struct NetworkConfiguration {
 	/// Number of peers that need to be connected before warp sync is started.
 	pub min_peers_to_start_warp_sync: Option<usize>,
 
+	/// Path to a SCALE-encoded trusted checkpoint warp sync is started from instead of genesis.
+	pub warp_sync_checkpoint: Option<PathBuf>,
+
 	/// Initial syncing mode.
 	pub sync_mode: SyncMode,
 
@@ -680,6 +683,7 @@ impl NetworkConfiguration {
 			max_parallel_downloads: 5,
 			max_blocks_per_request: 64,
 			min_peers_to_start_warp_sync: None,
+			warp_sync_checkpoint: None,
 			sync_mode: SyncMode::Full,
 			enable_dht_random_walk: true,
 			allow_non_globals_in_dht: false,
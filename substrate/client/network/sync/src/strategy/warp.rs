@@ -32,7 +32,7 @@ use crate::{
 };
 use codec::{Decode, Encode};
 use futures::{channel::oneshot, FutureExt};
-use log::{debug, error, trace, warn};
+use log::{debug, error, info, trace, warn};
 use sc_network::{IfDisconnected, ProtocolName};
 use sc_network_common::sync::message::{
 	BlockAnnounce, BlockAttributes, BlockData, BlockRequest, Direction, FromBlock,
@@ -84,6 +84,21 @@ pub trait WarpSyncProvider<Block: BlockT>: Send + Sync {
 	/// Get current list of authorities. This is supposed to be genesis authorities when starting
 	/// sync.
 	fn current_authorities(&self) -> AuthorityList;
+	/// Get the last header of a warp proof and whether the proof reaches the latest finalized
+	/// block, without verifying it. Used to chain proofs when exporting them.
+	fn proof_target(
+		&self,
+		_proof: &EncodedProof,
+	) -> Result<(Block::Header, bool), Box<dyn std::error::Error + Send + Sync>> {
+		Err("Reading the target of a warp proof is not supported".into())
+	}
+	/// Get a checkpoint of the latest finalized block, to start warp sync from with
+	/// [`WarpSyncConfig::WithCheckpoint`].
+	fn checkpoint(
+		&self,
+	) -> Result<WarpSyncCheckpoint<Block>, Box<dyn std::error::Error + Send + Sync>> {
+		Err("Warp sync checkpoints are not supported".into())
+	}
 }
 
 mod rep {
@@ -151,10 +166,30 @@ pub struct WarpSyncProgress<Block: BlockT> {
 	pub total_bytes: u64,
 }
 
+/// Trusted starting point for warp sync.
+///
+/// Stored SCALE-encoded in the file passed to the node, so that warp sync in isolated
+/// environments doesn't have to prove finality all the way from the genesis authorities.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+pub struct WarpSyncCheckpoint<Block: BlockT> {
+	/// Finalized header warp proofs are requested from.
+	pub header: Block::Header,
+	/// Authority set id active after `header`.
+	pub set_id: SetId,
+	/// Authorities of the set `set_id`.
+	pub authorities: AuthorityList,
+}
+
 /// Warp sync configuration as accepted by [`WarpSync`].
 pub enum WarpSyncConfig<Block: BlockT> {
 	/// Standard warp sync for the chain.
 	WithProvider(Arc<dyn WarpSyncProvider<Block>>),
+	/// Warp sync starting from a trusted checkpoint instead of genesis.
+	///
+	/// Only proofs for authority set changes after the checkpoint are downloaded and verified
+	/// against the checkpoint authorities. It is expected that whoever supplies the checkpoint
+	/// ensures that it is trusted.
+	WithCheckpoint(Arc<dyn WarpSyncProvider<Block>>, WarpSyncCheckpoint<Block>),
 	/// Skip downloading proofs and use provided header of the state that should be downloaded.
 	///
 	/// It is expected that the header provider ensures that the header is trusted.
@@ -164,7 +199,10 @@ pub enum WarpSyncConfig<Block: BlockT> {
 /// Warp sync phase used by warp sync state machine.
 enum Phase<B: BlockT> {
 	/// Waiting for enough peers to connect.
-	WaitingForPeers { warp_sync_provider: Arc<dyn WarpSyncProvider<B>> },
+	WaitingForPeers {
+		warp_sync_provider: Arc<dyn WarpSyncProvider<B>>,
+		checkpoint: Option<WarpSyncCheckpoint<B>>,
+	},
 	/// Downloading warp proofs.
 	WarpProof {
 		set_id: SetId,
@@ -259,7 +297,17 @@ where
 
 		let phase = match warp_sync_config {
 			WarpSyncConfig::WithProvider(warp_sync_provider) =>
-				Phase::WaitingForPeers { warp_sync_provider },
+				Phase::WaitingForPeers { warp_sync_provider, checkpoint: None },
+			WarpSyncConfig::WithCheckpoint(warp_sync_provider, checkpoint) => {
+				info!(
+					target: LOG_TARGET,
+					"Warp syncing from trusted checkpoint #{} ({}), set_id={}.",
+					checkpoint.header.number(),
+					checkpoint.header.hash(),
+					checkpoint.set_id,
+				);
+				Phase::WaitingForPeers { warp_sync_provider, checkpoint: Some(checkpoint) }
+			},
 			WarpSyncConfig::WithTarget(target_header) => Phase::TargetBlock(target_header),
 		};
 
@@ -321,16 +369,24 @@ where
 
 	/// Start warp sync as soon as we have enough peers.
 	fn try_to_start_warp_sync(&mut self) {
-		let Phase::WaitingForPeers { warp_sync_provider } = &self.phase else { return };
+		let Phase::WaitingForPeers { warp_sync_provider, checkpoint } = &mut self.phase else {
+			return
+		};
 
 		if self.peers.len() < self.min_peers_to_start_warp_sync {
 			return
 		}
 
+		let (set_id, authorities, last_hash) = match checkpoint.take() {
+			Some(WarpSyncCheckpoint { header, set_id, authorities }) =>
+				(set_id, authorities, header.hash()),
+			None => (0, warp_sync_provider.current_authorities(), self.client.info().genesis_hash),
+		};
+
 		self.phase = Phase::WarpProof {
-			set_id: 0,
-			authorities: warp_sync_provider.current_authorities(),
-			last_hash: self.client.info().genesis_hash,
+			set_id,
+			authorities,
+			last_hash,
 			warp_sync_provider: Arc::clone(warp_sync_provider),
 		};
 		trace!(target: LOG_TARGET, "Started warp sync with {} peers.", self.peers.len());
@@ -1718,4 +1774,111 @@ mod test {
 		assert_eq!(result.target_body, body);
 		assert_eq!(result.target_justifications, justifications);
 	}
+
+	fn test_checkpoint() -> WarpSyncCheckpoint<Block> {
+		WarpSyncCheckpoint {
+			header: <Block as BlockT>::Header::new(
+				42,
+				Default::default(),
+				Default::default(),
+				Default::default(),
+				Default::default(),
+			),
+			set_id: 7,
+			authorities: vec![(sp_core::ed25519::Public::from_raw([1; 32]).into(), 1)],
+		}
+	}
+
+	#[test]
+	fn warp_sync_from_checkpoint_starts_at_checkpoint() {
+		let client = mock_client_without_state();
+		// Genesis authorities are not queried when starting from a checkpoint.
+		let mut provider = MockWarpSyncProvider::<Block>::new();
+		provider.expect_current_authorities().never();
+		let checkpoint = test_checkpoint();
+		let config = WarpSyncConfig::WithCheckpoint(Arc::new(provider), checkpoint.clone());
+		let mut warp_sync = WarpSync::new(
+			Arc::new(client),
+			config,
+			Some(ProtocolName::Static("")),
+			Arc::new(MockBlockDownloader::new()),
+			None,
+		);
+
+		// Warp sync is not started when there is not enough peers.
+		for best_number in 1..MIN_PEERS_TO_START_WARP_SYNC as u64 {
+			warp_sync.add_peer(PeerId::random(), Hash::random(), best_number);
+			assert!(matches!(warp_sync.phase, Phase::WaitingForPeers { .. }))
+		}
+
+		// Now we have enough peers and warp sync is started from the checkpoint.
+		warp_sync.add_peer(PeerId::random(), Hash::random(), 100);
+		match &warp_sync.phase {
+			Phase::WarpProof { set_id, authorities, last_hash, .. } => {
+				assert_eq!(*set_id, checkpoint.set_id);
+				assert_eq!(*authorities, checkpoint.authorities);
+				assert_eq!(*last_hash, checkpoint.header.hash());
+			},
+			_ => panic!("Invalid phase."),
+		}
+
+		let (_peer_id, _protocol_name, request) = warp_sync.warp_proof_request().unwrap();
+		assert_eq!(request.begin, checkpoint.header.hash());
+	}
+
+	#[test]
+	fn warp_proof_from_checkpoint_is_verified_against_checkpoint_authorities() {
+		let client = mock_client_without_state();
+		let checkpoint = test_checkpoint();
+		let mut provider = MockWarpSyncProvider::<Block>::new();
+		let expected_set_id = checkpoint.set_id;
+		let expected_authorities = checkpoint.authorities.clone();
+		provider
+			.expect_verify()
+			.withf(move |_proof, set_id, authorities| {
+				*set_id == expected_set_id && *authorities == expected_authorities
+			})
+			.return_once(|_proof, set_id, authorities| {
+				Ok(VerificationResult::Partial(set_id + 1, authorities, Hash::random()))
+			});
+		let config = WarpSyncConfig::WithCheckpoint(Arc::new(provider), checkpoint.clone());
+		let mut warp_sync = WarpSync::new(
+			Arc::new(client),
+			config,
+			Some(ProtocolName::Static("")),
+			Arc::new(MockBlockDownloader::new()),
+			None,
+		);
+
+		// Make sure we have enough peers to make a request.
+		for best_number in 1..11 {
+			warp_sync.add_peer(PeerId::random(), Hash::random(), best_number);
+		}
+
+		let network_provider = NetworkServiceProvider::new();
+		let network_handle = network_provider.handle();
+
+		// Consume `SendWarpProofRequest` action.
+		let actions = warp_sync.actions(&network_handle).collect::<Vec<_>>();
+		assert_eq!(actions.len(), 1);
+		let SyncingAction::StartRequest { peer_id: request_peer_id, .. } = actions[0] else {
+			panic!("Invalid action");
+		};
+
+		warp_sync.on_warp_proof_response(&request_peer_id, EncodedProof(Vec::new()));
+
+		// Proof is accepted and the next authority set is tracked.
+		assert!(warp_sync.actions.is_empty());
+		match &warp_sync.phase {
+			Phase::WarpProof { set_id, .. } => assert_eq!(*set_id, checkpoint.set_id + 1),
+			_ => panic!("Invalid phase."),
+		}
+	}
+
+	#[test]
+	fn warp_sync_checkpoint_encoding_roundtrip() {
+		let checkpoint = test_checkpoint();
+		let encoded = checkpoint.encode();
+		assert_eq!(WarpSyncCheckpoint::<Block>::decode(&mut &encoded[..]).unwrap(), checkpoint);
+	}
 }
@@ -0,0 +1,200 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Substrate transaction pool introspection API.
+//!
+//! The endpoints expose the internal state of the transaction pool and are meant for node
+//! operators debugging the pool. They are all marked `unsafe`.
+
+pub mod error;
+
+use error::Error;
+use jsonrpsee::proc_macros::rpc;
+use sc_transaction_pool_api::{
+	InPoolTransactionInfo, PoolContent, PoolEvent, TransactionInspection, TransactionSource,
+};
+use serde::{Deserialize, Serialize};
+use sp_core::Bytes;
+
+/// The source of the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Source {
+	/// Transaction is already included in a block.
+	InBlock,
+	/// Transaction is coming from a local source, e.g. an offchain worker.
+	Local,
+	/// Transaction has been received externally, e.g. over the network or RPC.
+	External,
+}
+
+impl From<TransactionSource> for Source {
+	fn from(source: TransactionSource) -> Self {
+		match source {
+			TransactionSource::InBlock => Self::InBlock,
+			TransactionSource::Local => Self::Local,
+			TransactionSource::External => Self::External,
+		}
+	}
+}
+
+/// Details of a transaction held in the pool.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionDetails<Hash, BlockHash> {
+	/// Hash of the transaction.
+	pub hash: Hash,
+	/// Number of bytes of the transaction encoding.
+	pub bytes: u64,
+	/// Priority of the transaction.
+	pub priority: u64,
+	/// Block number at which the transaction becomes invalid.
+	pub valid_till: u64,
+	/// Tags required by the transaction.
+	pub requires: Vec<Bytes>,
+	/// Tags provided by the transaction.
+	pub provides: Vec<Bytes>,
+	/// Whether the transaction is propagated to other peers.
+	pub propagate: bool,
+	/// The source of the transaction.
+	pub source: Source,
+	/// Views (identified by block hashes) in which the transaction is ready.
+	pub ready_in: Vec<BlockHash>,
+	/// Views (identified by block hashes) in which the transaction is in the future queue.
+	pub future_in: Vec<BlockHash>,
+}
+
+impl<Hash, BlockHash> TransactionDetails<Hash, BlockHash> {
+	/// Returns `true` if any of the transaction's tags starts with the given `sender` bytes.
+	///
+	/// FRAME based runtimes tag the signed transactions with the SCALE encoded `(sender, nonce)`
+	/// tuple (see `frame_system::CheckNonce`), so the SCALE encoded account id can be used to
+	/// find all the transactions of the given sender.
+	pub fn is_tagged_by(&self, sender: &[u8]) -> bool {
+		self.provides
+			.iter()
+			.chain(self.requires.iter())
+			.any(|tag| tag.starts_with(sender))
+	}
+}
+
+impl<Hash, BlockHash> From<InPoolTransactionInfo<Hash, BlockHash>>
+	for TransactionDetails<Hash, BlockHash>
+{
+	fn from(info: InPoolTransactionInfo<Hash, BlockHash>) -> Self {
+		Self {
+			hash: info.hash,
+			bytes: info.bytes as u64,
+			priority: info.priority,
+			valid_till: info.valid_till,
+			requires: info.requires.into_iter().map(Into::into).collect(),
+			provides: info.provides.into_iter().map(Into::into).collect(),
+			propagate: info.propagate,
+			source: info.source.into(),
+			ready_in: info.ready_in,
+			future_in: info.future_in,
+		}
+	}
+}
+
+/// The content of the ready and future queues of the pool.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxPoolContent<Hash, BlockHash> {
+	/// Block hash of the view the content was taken from.
+	///
+	/// `None` if the pool does not maintain per-fork views.
+	pub at: Option<BlockHash>,
+	/// Ready transactions, in the order they would be included in a block.
+	pub ready: Vec<TransactionDetails<Hash, BlockHash>>,
+	/// Future transactions.
+	pub future: Vec<TransactionDetails<Hash, BlockHash>>,
+}
+
+impl<Hash, BlockHash> From<PoolContent<Hash, BlockHash>> for TxPoolContent<Hash, BlockHash> {
+	fn from(content: PoolContent<Hash, BlockHash>) -> Self {
+		Self {
+			at: content.at,
+			ready: content.ready.into_iter().map(Into::into).collect(),
+			future: content.future.into_iter().map(Into::into).collect(),
+		}
+	}
+}
+
+/// Everything the pool knows about a single transaction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxPoolInspection<Hash, BlockHash> {
+	/// Details of the transaction, if it is currently held in the pool.
+	pub transaction: Option<TransactionDetails<Hash, BlockHash>>,
+	/// Whether the transaction is currently banned from entering the pool.
+	pub banned: bool,
+	/// The most recent event describing the removal of the transaction from the pool.
+	pub removal: Option<PoolEvent<Hash>>,
+}
+
+impl<Hash, BlockHash> From<TransactionInspection<Hash, BlockHash>>
+	for TxPoolInspection<Hash, BlockHash>
+{
+	fn from(inspection: TransactionInspection<Hash, BlockHash>) -> Self {
+		Self {
+			transaction: inspection.info.map(Into::into),
+			banned: inspection.banned,
+			removal: inspection.removal,
+		}
+	}
+}
+
+/// Substrate transaction pool introspection API.
+#[rpc(client, server)]
+pub trait TxPoolApi<Hash, BlockHash> {
+	/// Returns the content of the ready and future queues.
+	///
+	/// For the fork-aware pool the content of the view at `at` block is returned, the most recent
+	/// view is used if `at` is not provided.
+	#[method(name = "txpool_content", with_extensions)]
+	fn content(&self, at: Option<BlockHash>) -> Result<TxPoolContent<Hash, BlockHash>, Error>;
+
+	/// Returns the content of the ready and future queues limited to the transactions of the
+	/// given sender.
+	///
+	/// `sender` is the SCALE encoded account id, which is matched against the prefixes of the
+	/// transactions' `provides` and `requires` tags.
+	#[method(name = "txpool_contentFrom", with_extensions)]
+	fn content_from(
+		&self,
+		sender: Bytes,
+		at: Option<BlockHash>,
+	) -> Result<TxPoolContent<Hash, BlockHash>, Error>;
+
+	/// Returns everything the pool knows about the transaction with given hash.
+	#[method(name = "txpool_inspect", with_extensions)]
+	fn inspect(&self, hash: Hash) -> Result<TxPoolInspection<Hash, BlockHash>, Error>;
+
+	/// Subscribe to the events of all transactions leaving the pool.
+	///
+	/// Contrary to `author_submitAndWatchExtrinsic` the events are emitted for all transactions,
+	/// not only for the watched ones.
+	#[subscription(
+		name = "txpool_subscribeEvents" => "txpool_event",
+		unsubscribe = "txpool_unsubscribeEvents",
+		item = PoolEvent<Hash>,
+		with_extensions,
+	)]
+	fn subscribe_events(&self);
+}
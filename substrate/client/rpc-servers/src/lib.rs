@@ -42,11 +42,16 @@ pub use jsonrpsee::{
 	core::id_providers::{RandomIntegerIdProvider, RandomStringIdProvider},
 	server::{middleware::rpc::RpcServiceBuilder, BatchRequestConfig},
 };
-pub use middleware::{Metrics, MiddlewareLayer, NodeHealthProxyLayer, RpcMetrics};
+pub use middleware::{
+	Metrics, MiddlewareLayer, NodeHealthProxyLayer, PerIpRateLimit, RpcMethodCost, RpcMetrics,
+};
 pub use utils::{RpcEndpoint, RpcMethods};
 
 const MEGABYTE: u32 = 1024 * 1024;
 
+/// How often the IP addresses with fully replenished quota are removed from the per-IP rate limit.
+const PER_IP_RATE_LIMIT_CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+
 /// Type to encapsulate the server handle and listening address.
 pub struct Server {
 	/// Handle to the rpc server
@@ -157,8 +162,28 @@ where
 			host_filter,
 			cors,
 			rate_limit,
+			rate_limit_per_ip,
+			rate_limit_method_costs,
 		} = listener.rpc_settings();
 
+		// The per-IP rate limit is shared by all the connections to the endpoint.
+		let ip_rate_limit = rate_limit_per_ip.map(PerIpRateLimit::per_minute);
+
+		if let Some(ip_rate_limit) = ip_rate_limit.clone() {
+			let stop_handle = cfg.stop_handle.clone();
+
+			tokio_handle.spawn(async move {
+				let mut interval = tokio::time::interval(PER_IP_RATE_LIMIT_CLEANUP_INTERVAL);
+
+				loop {
+					tokio::select! {
+						_ = interval.tick() => ip_rate_limit.retain_recent(),
+						_ = stop_handle.clone().shutdown() => break,
+					}
+				}
+			});
+		}
+
 		let http_middleware = tower::ServiceBuilder::new()
 			.option_layer(host_filter)
 			// Proxy `GET /health, /health/readiness` requests to the internal
@@ -210,6 +235,8 @@ where
 				let cfg2 = cfg.clone();
 				let service_builder2 = service_builder.clone();
 				let rate_limit_whitelisted_ips2 = rate_limit_whitelisted_ips.clone();
+				let ip_rate_limit2 = ip_rate_limit.clone();
+				let rate_limit_method_costs2 = rate_limit_method_costs.clone();
 
 				let svc =
 					tower::service_fn(move |mut req: http::Request<hyper::body::Incoming>| {
@@ -222,35 +249,43 @@ where
 						let proxy_ip =
 							if rate_limit_trust_proxy_headers { get_proxy_ip(&req) } else { None };
 
-						let rate_limit_cfg = if rate_limit_whitelisted_ips2
+						let (rate_limit_cfg, ip_rate_limit_cfg) = if rate_limit_whitelisted_ips2
 							.iter()
 							.any(|ips| ips.contains(proxy_ip.unwrap_or(ip)))
 						{
 							log::debug!(target: "rpc", "ip={ip}, proxy_ip={:?} is trusted, disabling rate-limit", proxy_ip);
-							None
+							(None, None)
 						} else {
 							if !rate_limit_whitelisted_ips2.is_empty() {
 								log::debug!(target: "rpc", "ip={ip}, proxy_ip={:?} is not trusted, rate-limit enabled", proxy_ip);
 							}
-							rate_limit
+							(
+								rate_limit,
+								ip_rate_limit2.as_ref().map(|l| l.for_ip(proxy_ip.unwrap_or(ip))),
+							)
 						};
 
 						let is_websocket = ws::is_upgrade_request(&req);
 						let transport_label = if is_websocket { "ws" } else { "http" };
 
-						let middleware_layer = match (metrics, rate_limit_cfg) {
-							(None, None) => None,
-							(Some(metrics), None) => Some(
-								MiddlewareLayer::new()
-									.with_metrics(Metrics::new(metrics, transport_label)),
-							),
-							(None, Some(rate_limit)) =>
-								Some(MiddlewareLayer::new().with_rate_limit_per_minute(rate_limit)),
-							(Some(metrics), Some(rate_limit)) => Some(
-								MiddlewareLayer::new()
-									.with_metrics(Metrics::new(metrics, transport_label))
-									.with_rate_limit_per_minute(rate_limit),
-							),
+						let middleware_layer = if metrics.is_none() &&
+							rate_limit_cfg.is_none() &&
+							ip_rate_limit_cfg.is_none()
+						{
+							None
+						} else {
+							let mut layer = MiddlewareLayer::new()
+								.with_method_costs(rate_limit_method_costs2.clone());
+							if let Some(metrics) = metrics {
+								layer = layer.with_metrics(Metrics::new(metrics, transport_label));
+							}
+							if let Some(rate_limit) = rate_limit_cfg {
+								layer = layer.with_rate_limit_per_minute(rate_limit);
+							}
+							if let Some(ip_rate_limit) = ip_rate_limit_cfg {
+								layer = layer.with_ip_rate_limit(ip_rate_limit);
+							}
+							Some(layer)
 						};
 
 						let rpc_middleware = RpcServiceBuilder::new()
@@ -51,6 +51,8 @@ pub struct RpcMetrics {
 	calls_started: CounterVec<U64>,
 	/// Number of calls completed.
 	calls_finished: CounterVec<U64>,
+	/// Number of calls rejected by the rate limits.
+	calls_rejected: CounterVec<U64>,
 	/// Number of Websocket sessions opened.
 	ws_sessions_opened: Option<Counter<U64>>,
 	/// Number of Websocket sessions closed.
@@ -95,6 +97,16 @@ impl RpcMetrics {
 					)?,
 					metrics_registry,
 				)?,
+				calls_rejected: register(
+					CounterVec::new(
+						Opts::new(
+							"substrate_rpc_calls_rejected",
+							"Number of RPC calls rejected by the rate limits",
+						),
+						&["protocol", "method"],
+					)?,
+					metrics_registry,
+				)?,
 				ws_sessions_opened: register(
 					Counter::new(
 						"substrate_rpc_sessions_opened",
@@ -152,6 +164,18 @@ impl RpcMetrics {
 			.inc();
 	}
 
+	pub(crate) fn on_rejected(&self, req: &Request, transport_label: &'static str) {
+		log::debug!(
+			target: "rpc_metrics",
+			"[{transport_label}] {} call rejected by the rate limit",
+			req.method_name(),
+		);
+
+		self.calls_rejected
+			.with_label_values(&[transport_label, req.method_name()])
+			.inc();
+	}
+
 	pub(crate) fn on_response(
 		&self,
 		req: &Request,
@@ -215,6 +239,10 @@ impl Metrics {
 		self.inner.on_call(req, self.transport_label)
 	}
 
+	pub(crate) fn on_rejected(&self, req: &Request) {
+		self.inner.on_rejected(req, self.transport_label)
+	}
+
 	pub(crate) fn on_response(
 		&self,
 		req: &Request,
@@ -24,7 +24,7 @@ use std::{
 };
 
 use futures::future::{BoxFuture, FutureExt};
-use governor::{clock::Clock, Jitter};
+use governor::Jitter;
 use jsonrpsee::{
 	server::middleware::rpc::RpcServiceT,
 	types::{ErrorObject, Id, Request},
@@ -46,6 +46,8 @@ const MAX_RETRIES: usize = 10;
 #[derive(Debug, Clone, Default)]
 pub struct MiddlewareLayer {
 	rate_limit: Option<RateLimit>,
+	ip_rate_limit: Option<RateLimit>,
+	method_costs: MethodCosts,
 	metrics: Option<Metrics>,
 }
 
@@ -57,12 +59,25 @@ impl MiddlewareLayer {
 
 	/// Enable new rate limit middleware enforced per minute.
 	pub fn with_rate_limit_per_minute(self, n: NonZeroU32) -> Self {
-		Self { rate_limit: Some(RateLimit::per_minute(n)), metrics: self.metrics }
+		Self { rate_limit: Some(RateLimit::per_minute(n)), ..self }
+	}
+
+	/// Enable rate limit middleware keyed by the IP address of the caller.
+	///
+	/// The rate limit is obtained from [`PerIpRateLimit::for_ip`] and is shared by all the
+	/// connections from the same IP address.
+	pub fn with_ip_rate_limit(self, rate_limit: RateLimit) -> Self {
+		Self { ip_rate_limit: Some(rate_limit), ..self }
+	}
+
+	/// Use the given method costs when checking the rate limits.
+	pub fn with_method_costs(self, method_costs: MethodCosts) -> Self {
+		Self { method_costs, ..self }
 	}
 
 	/// Enable metrics middleware.
 	pub fn with_metrics(self, metrics: Metrics) -> Self {
-		Self { rate_limit: self.rate_limit, metrics: Some(metrics) }
+		Self { metrics: Some(metrics), ..self }
 	}
 
 	/// Register a new websocket connection.
@@ -80,7 +95,13 @@ impl<S> tower::Layer<S> for MiddlewareLayer {
 	type Service = Middleware<S>;
 
 	fn layer(&self, service: S) -> Self::Service {
-		Middleware { service, rate_limit: self.rate_limit.clone(), metrics: self.metrics.clone() }
+		Middleware {
+			service,
+			rate_limit: self.rate_limit.clone(),
+			ip_rate_limit: self.ip_rate_limit.clone(),
+			method_costs: self.method_costs.clone(),
+			metrics: self.metrics.clone(),
+		}
 	}
 }
 
@@ -94,6 +115,8 @@ impl<S> tower::Layer<S> for MiddlewareLayer {
 pub struct Middleware<S> {
 	service: S,
 	rate_limit: Option<RateLimit>,
+	ip_rate_limit: Option<RateLimit>,
+	method_costs: MethodCosts,
 	metrics: Option<Metrics>,
 }
 
@@ -110,25 +133,26 @@ where
 
 		let service = self.service.clone();
 		let rate_limit = self.rate_limit.clone();
+		let ip_rate_limit = self.ip_rate_limit.clone();
+		let cost = self.method_costs.cost(req.method_name());
 		let metrics = self.metrics.clone();
 
 		async move {
 			let mut is_rate_limited = false;
+			let mut attempts = 0;
+			let jitter = Jitter::up_to(MAX_JITTER);
 
-			if let Some(limit) = rate_limit.as_ref() {
-				let mut attempts = 0;
-				let jitter = Jitter::up_to(MAX_JITTER);
-
+			for limit in [rate_limit.as_ref(), ip_rate_limit.as_ref()].into_iter().flatten() {
 				loop {
-					if attempts >= MAX_RETRIES {
-						return reject_too_many_calls(req.id);
-					}
-
-					if let Err(rejected) = limit.inner.check() {
-						tokio::time::sleep(jitter + rejected.wait_time_from(limit.clock.now()))
-							.await;
-					} else {
-						break;
+					match limit.check(cost) {
+						Ok(()) => break,
+						Err(RateLimited::Wait(wait)) if attempts < MAX_RETRIES => {
+							tokio::time::sleep(jitter + wait).await;
+						},
+						Err(_) => {
+							metrics.as_ref().map(|m| m.on_rejected(&req));
+							return reject_too_many_calls(req.id);
+						},
 					}
 
 					is_rate_limited = true;
@@ -19,20 +19,39 @@
 //! RPC rate limit.
 
 use governor::{
-	clock::{DefaultClock, QuantaClock},
+	clock::{Clock, DefaultClock, QuantaClock},
 	middleware::NoOpMiddleware,
-	state::{InMemoryState, NotKeyed},
+	state::{keyed::DefaultKeyedStateStore, InMemoryState, NotKeyed},
 	Quota,
 };
-use std::{num::NonZeroU32, sync::Arc};
+use std::{
+	collections::HashMap, net::IpAddr, num::NonZeroU32, str::FromStr, sync::Arc, time::Duration,
+};
 
 type RateLimitInner = governor::RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>;
+type KeyedRateLimitInner =
+	governor::RateLimiter<IpAddr, DefaultKeyedStateStore<IpAddr>, DefaultClock, NoOpMiddleware>;
+
+/// The reason why a call was not allowed by the rate limit.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum RateLimited {
+	/// The call may be retried after the given duration.
+	Wait(Duration),
+	/// The cost of the call exceeds the quota, i.e. the call will never be allowed.
+	InsufficientCapacity,
+}
+
+#[derive(Debug, Clone)]
+enum Limiter {
+	Direct(Arc<RateLimitInner>),
+	Keyed(Arc<KeyedRateLimitInner>, IpAddr),
+}
 
 /// Rate limit.
 #[derive(Debug, Clone)]
 pub struct RateLimit {
-	pub(crate) inner: Arc<RateLimitInner>,
-	pub(crate) clock: QuantaClock,
+	inner: Limiter,
+	clock: QuantaClock,
 }
 
 impl RateLimit {
@@ -40,8 +59,158 @@ impl RateLimit {
 	pub fn per_minute(n: NonZeroU32) -> Self {
 		let clock = QuantaClock::default();
 		Self {
-			inner: Arc::new(RateLimitInner::direct_with_clock(Quota::per_minute(n), &clock)),
+			inner: Limiter::Direct(Arc::new(RateLimitInner::direct_with_clock(
+				Quota::per_minute(n),
+				&clock,
+			))),
 			clock,
 		}
 	}
+
+	/// Check whether a call of the given cost is allowed and, if so, consume the cost from the
+	/// quota.
+	pub(crate) fn check(&self, cost: NonZeroU32) -> Result<(), RateLimited> {
+		let outcome = match &self.inner {
+			Limiter::Direct(limiter) => limiter.check_n(cost),
+			Limiter::Keyed(limiter, ip) => limiter.check_key_n(ip, cost),
+		};
+
+		match outcome {
+			Ok(Ok(())) => Ok(()),
+			Ok(Err(rejected)) => Err(RateLimited::Wait(rejected.wait_time_from(self.clock.now()))),
+			Err(_) => Err(RateLimited::InsufficientCapacity),
+		}
+	}
+}
+
+/// Rate limit shared by all connections of an endpoint, keyed by the IP address of the caller.
+#[derive(Debug, Clone)]
+pub struct PerIpRateLimit {
+	inner: Arc<KeyedRateLimitInner>,
+	clock: QuantaClock,
+}
+
+impl PerIpRateLimit {
+	/// Create a new `PerIpRateLimit` per minute.
+	pub fn per_minute(n: NonZeroU32) -> Self {
+		let clock = QuantaClock::default();
+		Self {
+			inner: Arc::new(KeyedRateLimitInner::new(
+				Quota::per_minute(n),
+				DefaultKeyedStateStore::default(),
+				&clock,
+			)),
+			clock,
+		}
+	}
+
+	/// Returns the rate limit for the calls made from the given IP address.
+	pub fn for_ip(&self, ip: IpAddr) -> RateLimit {
+		RateLimit { inner: Limiter::Keyed(self.inner.clone(), ip), clock: self.clock.clone() }
+	}
+
+	/// Forget the IP addresses which have their quota fully replenished.
+	pub(crate) fn retain_recent(&self) {
+		self.inner.retain_recent();
+		self.inner.shrink_to_fit();
+	}
+}
+
+/// The cost of a single call to an RPC method, counted against the rate limits.
+///
+/// Parsed from `<method>=<cost>`, e.g. `state_queryStorage=50`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcMethodCost {
+	/// Name of the RPC method.
+	pub method: String,
+	/// Cost of a single call.
+	pub cost: NonZeroU32,
+}
+
+impl FromStr for RpcMethodCost {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (method, cost) =
+			s.split_once('=').ok_or_else(|| format!("`{s}`, expects: `<method>=<cost>`"))?;
+		let method = method.trim();
+
+		if method.is_empty() {
+			return Err(format!("`{s}`, the method name must not be empty"));
+		}
+
+		let cost = cost
+			.trim()
+			.parse()
+			.map_err(|_| format!("`{s}`, the cost must be a non-zero integer"))?;
+
+		Ok(Self { method: method.to_string(), cost })
+	}
+}
+
+/// Lookup table of the RPC method costs.
+///
+/// Methods not present in the table cost `1`.
+#[derive(Debug, Clone, Default)]
+pub struct MethodCosts(Arc<HashMap<String, NonZeroU32>>);
+
+impl MethodCosts {
+	/// Create a new `MethodCosts` table.
+	pub fn new(costs: impl IntoIterator<Item = RpcMethodCost>) -> Self {
+		Self(Arc::new(costs.into_iter().map(|c| (c.method, c.cost)).collect()))
+	}
+
+	/// Returns the cost of a call to the given method.
+	pub fn cost(&self, method: &str) -> NonZeroU32 {
+		self.0.get(method).copied().unwrap_or(NonZeroU32::MIN)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn nz(n: u32) -> NonZeroU32 {
+		NonZeroU32::new(n).unwrap()
+	}
+
+	#[test]
+	fn parse_method_cost_works() {
+		assert_eq!(
+			RpcMethodCost::from_str(" state_queryStorage = 50 "),
+			Ok(RpcMethodCost { method: "state_queryStorage".into(), cost: nz(50) })
+		);
+		assert!(RpcMethodCost::from_str("state_queryStorage").is_err());
+		assert!(RpcMethodCost::from_str("state_queryStorage=0").is_err());
+		assert!(RpcMethodCost::from_str("=10").is_err());
+	}
+
+	#[test]
+	fn method_costs_default_to_one() {
+		let costs = MethodCosts::new([RpcMethodCost { method: "foo".into(), cost: nz(5) }]);
+		assert_eq!(costs.cost("foo"), nz(5));
+		assert_eq!(costs.cost("bar"), nz(1));
+	}
+
+	#[test]
+	fn cost_is_consumed_from_quota() {
+		let limit = RateLimit::per_minute(nz(10));
+		assert_eq!(limit.check(nz(6)), Ok(()));
+		assert!(matches!(limit.check(nz(6)), Err(RateLimited::Wait(_))));
+		assert_eq!(limit.check(nz(11)), Err(RateLimited::InsufficientCapacity));
+	}
+
+	#[test]
+	fn per_ip_quotas_are_independent() {
+		let limit = PerIpRateLimit::per_minute(nz(2));
+		let alice = limit.for_ip([127, 0, 0, 1].into());
+		let bob = limit.for_ip([127, 0, 0, 2].into());
+
+		assert_eq!(alice.check(nz(2)), Ok(()));
+		assert!(matches!(alice.check(nz(1)), Err(RateLimited::Wait(_))));
+		// Another connection from the same IP shares the quota.
+		let alice2 = limit.for_ip([127, 0, 0, 1].into());
+		assert!(matches!(alice2.check(nz(1)), Err(RateLimited::Wait(_))));
+		assert_eq!(bob.check(nz(2)), Ok(()));
+	}
 }
@@ -18,7 +18,10 @@
 
 //! Substrate RPC server utils.
 
-use crate::BatchRequestConfig;
+use crate::{
+	middleware::{MethodCosts, RpcMethodCost},
+	BatchRequestConfig,
+};
 use std::{
 	error::Error as StdError,
 	net::{IpAddr, SocketAddr},
@@ -88,6 +91,8 @@ pub(crate) struct RpcSettings {
 	pub(crate) max_buffer_capacity_per_connection: u32,
 	pub(crate) rpc_methods: RpcMethods,
 	pub(crate) rate_limit: Option<NonZeroU32>,
+	pub(crate) rate_limit_per_ip: Option<NonZeroU32>,
+	pub(crate) rate_limit_method_costs: MethodCosts,
 	pub(crate) rate_limit_trust_proxy_headers: bool,
 	pub(crate) rate_limit_whitelisted_ips: Vec<IpNetwork>,
 	pub(crate) cors: CorsLayer,
@@ -113,6 +118,10 @@ pub struct RpcEndpoint {
 	pub max_buffer_capacity_per_connection: u32,
 	/// Rate limit per minute.
 	pub rate_limit: Option<NonZeroU32>,
+	/// Rate limit per minute shared by all the connections from the same IP address.
+	pub rate_limit_per_ip: Option<NonZeroU32>,
+	/// Costs of the RPC methods counted against the rate limits, other methods cost `1`.
+	pub rate_limit_method_costs: Vec<RpcMethodCost>,
 	/// Whether to trust proxy headers for rate limiting.
 	pub rate_limit_trust_proxy_headers: bool,
 	/// Whitelisted IPs for rate limiting.
@@ -158,6 +167,8 @@ impl RpcEndpoint {
 				max_buffer_capacity_per_connection: self.max_buffer_capacity_per_connection,
 				rpc_methods: self.rpc_methods,
 				rate_limit: self.rate_limit,
+				rate_limit_per_ip: self.rate_limit_per_ip,
+				rate_limit_method_costs: MethodCosts::new(self.rate_limit_method_costs),
 				rate_limit_trust_proxy_headers: self.rate_limit_trust_proxy_headers,
 				rate_limit_whitelisted_ips: self.rate_limit_whitelisted_ips,
 				host_filter,
@@ -24,7 +24,8 @@ use crate::{
 		types::MethodResult,
 	},
 	common::events::{
-		ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageEvent, StorageQuery,
+		ArchiveBlockEventsEvent, ArchiveStorageDiffEvent, ArchiveStorageDiffItem,
+		ArchiveStorageEvent, StorageQuery,
 	},
 };
 use jsonrpsee::proc_macros::rpc;
@@ -130,4 +131,21 @@ pub trait ArchiveApi<Hash> {
 		items: Vec<ArchiveStorageDiffItem<String>>,
 		previous_hash: Option<Hash>,
 	);
+
+	/// Returns the extrinsic outcomes and events of the finalized blocks in the given
+	/// inclusive height range.
+	///
+	/// Events are decoded using the runtime metadata of each block. At most 256 blocks are
+	/// reported by a single call, the height to continue from is provided by the
+	/// `blockEventsDone` event.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and can change in minor or patch releases.
+	#[subscription(
+		name = "archive_unstable_blockEvents" => "archive_unstable_blockEventsEvent",
+		unsubscribe = "archive_unstable_stopBlockEvents",
+		item = ArchiveBlockEventsEvent,
+	)]
+	fn archive_unstable_block_events(&self, from_height: u64, to_height: u64);
 }
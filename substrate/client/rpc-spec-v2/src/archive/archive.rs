@@ -20,6 +20,7 @@
 
 use crate::{
 	archive::{
+		archive_block_events::ArchiveBlockEvents,
 		archive_storage::ArchiveStorageDiff,
 		error::{Error as ArchiveError, Infallible},
 		types::MethodResult,
@@ -27,7 +28,8 @@ use crate::{
 	},
 	common::{
 		events::{
-			ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageEvent, StorageQuery,
+			ArchiveBlockEventsEvent, ArchiveStorageDiffEvent, ArchiveStorageDiffItem,
+			ArchiveStorageEvent, StorageQuery,
 		},
 		storage::{QueryResult, StorageSubscriptionClient},
 	},
@@ -63,6 +65,11 @@ pub(crate) const LOG_TARGET: &str = "rpc-spec-v2::archive";
 /// its down buffer capacity per connection as well.
 const STORAGE_QUERY_BUF: usize = 16;
 
+/// The buffer capacity for the block events query.
+///
+/// Each item holds all the events of a block, which can be large.
+const BLOCK_EVENTS_BUF: usize = 4;
+
 /// An API for archive RPC calls.
 pub struct Archive<BE: Backend<Block>, Block: BlockT, Client> {
 	/// Substrate client.
@@ -306,6 +313,63 @@ where
 
 		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
 	}
+
+	fn archive_unstable_block_events(
+		&self,
+		pending: PendingSubscriptionSink,
+		from_height: u64,
+		to_height: u64,
+	) {
+		let block_events = ArchiveBlockEvents::<Client, Block, BE>::new(self.client.clone());
+
+		log::trace!(target: LOG_TARGET, "Block events subscription started");
+
+		let fut = async move {
+			let Ok(mut sink) = pending.accept().await.map(Subscription::from) else { return };
+
+			let (tx, mut rx) = tokio::sync::mpsc::channel(BLOCK_EVENTS_BUF);
+			let events_fut = block_events.handle_range(from_height, to_height, tx);
+
+			// We don't care about the return value of this join:
+			// - process_events might encounter an error (if the client disconnected)
+			// - events_fut might encounter an error while decoding the events of a block and
+			// the error is propagated via the sink.
+			let _ =
+				futures::future::join(events_fut, process_block_events(&mut rx, &mut sink)).await;
+		};
+
+		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
+	}
+}
+
+/// Sends all the events of the block_events method to the sink.
+async fn process_block_events(
+	rx: &mut mpsc::Receiver<ArchiveBlockEventsEvent>,
+	sink: &mut Subscription,
+) {
+	loop {
+		tokio::select! {
+			_ = sink.closed() => {
+				return
+			},
+
+			maybe_event = rx.recv() => {
+				let Some(event) = maybe_event else {
+					break;
+				};
+
+				if event.is_done() {
+					log::debug!(target: LOG_TARGET, "Finished processing block events");
+				} else if event.is_err() {
+					log::debug!(target: LOG_TARGET, "Error encountered while processing block events");
+				}
+
+				if sink.send(&event).await.is_err() {
+					return
+				}
+			}
+		}
+	}
 }
 
 /// Sends all the events of the storage_diff method to the sink.
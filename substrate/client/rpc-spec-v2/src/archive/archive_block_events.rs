@@ -0,0 +1,700 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Implementation of the `archive_blockEvents` method.
+
+use std::{collections::HashMap, marker::PhantomData, sync::Arc};
+
+use codec::{Compact, Decode, Encode};
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use sc_client_api::{
+	Backend, BlockBackend, CallExecutor, ExecutorProvider, StorageKey, StorageProvider,
+};
+use scale_info::{form::PortableForm, PortableRegistry, TypeDef, TypeDefPrimitive, Variant};
+use sp_api::{CallApiAt, CallContext};
+use sp_blockchain::HeaderBackend;
+use sp_core::twox_128;
+use sp_runtime::{
+	traits::{Block as BlockT, NumberFor, One},
+	SaturatedConversion,
+};
+
+use super::error::Error as ArchiveError;
+use crate::{
+	archive::archive::LOG_TARGET,
+	common::events::{
+		ArchiveBlockEventsDone, ArchiveBlockEventsEvent, ArchiveBlockEventsResult,
+		ArchiveEventField, ArchiveEventPhase, ArchiveEventRecord, ArchiveExtrinsicOutcome,
+	},
+	hex_string,
+};
+use tokio::sync::mpsc;
+
+/// The maximum number of blocks reported by a single `archive_blockEvents` call.
+///
+/// Longer ranges are reported in multiple calls, continuing from the height provided
+/// by the `blockEventsDone` event.
+pub const MAX_BLOCKS_PER_CALL: u64 = 256;
+
+/// The version of the metadata used to decode events, if provided by the runtime.
+///
+/// Older runtimes are decoded with the V14 metadata returned by `Metadata_metadata`.
+const METADATA_VERSION: u32 = 15;
+
+/// Mirrors `frame_system::Phase`.
+#[derive(Decode)]
+enum Phase {
+	ApplyExtrinsic(u32),
+	Finalization,
+	Initialization,
+}
+
+impl From<Phase> for ArchiveEventPhase {
+	fn from(phase: Phase) -> Self {
+		match phase {
+			Phase::ApplyExtrinsic(index) => ArchiveEventPhase::ApplyExtrinsic(index),
+			Phase::Finalization => ArchiveEventPhase::Finalization,
+			Phase::Initialization => ArchiveEventPhase::Initialization,
+		}
+	}
+}
+
+/// The storage key of `frame_system::Events`.
+fn system_events_key() -> StorageKey {
+	StorageKey([twox_128(b"System"), twox_128(b"Events")].concat())
+}
+
+/// Take `len` bytes from the input.
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], String> {
+	if input.len() < len {
+		return Err("Unexpected end of input".into())
+	}
+	let (head, tail) = input.split_at(len);
+	*input = tail;
+	Ok(head)
+}
+
+/// Take a single byte from the input.
+fn take_u8(input: &mut &[u8]) -> Result<u8, String> {
+	take(input, 1).map(|byte| byte[0])
+}
+
+/// Read the length prefix of a sequence.
+fn decode_len(input: &mut &[u8]) -> Result<usize, String> {
+	Compact::<u32>::decode(input)
+		.map(|len| len.0 as usize)
+		.map_err(|e| format!("Invalid length prefix: {e}"))
+}
+
+/// Encoded size of the given primitive, `None` if the size is not fixed.
+fn primitive_size(primitive: &TypeDefPrimitive) -> Option<usize> {
+	Some(match primitive {
+		TypeDefPrimitive::Bool | TypeDefPrimitive::U8 | TypeDefPrimitive::I8 => 1,
+		TypeDefPrimitive::U16 | TypeDefPrimitive::I16 => 2,
+		TypeDefPrimitive::Char | TypeDefPrimitive::U32 | TypeDefPrimitive::I32 => 4,
+		TypeDefPrimitive::U64 | TypeDefPrimitive::I64 => 8,
+		TypeDefPrimitive::U128 | TypeDefPrimitive::I128 => 16,
+		TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => 32,
+		TypeDefPrimitive::Str => return None,
+	})
+}
+
+/// Runtime metadata needed to decode `frame_system::Events`.
+struct EventsDecoder {
+	/// The type registry of the runtime.
+	types: PortableRegistry,
+	/// Pallet name, event type and error type by pallet index.
+	pallets: HashMap<u8, (String, Option<u32>, Option<u32>)>,
+}
+
+impl EventsDecoder {
+	/// Build the decoder from the SCALE-encoded runtime metadata, V14 or V15.
+	fn new(metadata: &[u8]) -> Result<Self, String> {
+		let metadata = RuntimeMetadataPrefixed::decode(&mut &metadata[..])
+			.map_err(|e| format!("Invalid runtime metadata: {e}"))?
+			.1;
+
+		let (types, pallets) = match metadata {
+			RuntimeMetadata::V14(metadata) => (
+				metadata.types,
+				metadata
+					.pallets
+					.into_iter()
+					.map(|pallet| {
+						let event = pallet.event.map(|event| event.ty.id);
+						let error = pallet.error.map(|error| error.ty.id);
+						(pallet.index, (pallet.name, event, error))
+					})
+					.collect(),
+			),
+			RuntimeMetadata::V15(metadata) => (
+				metadata.types,
+				metadata
+					.pallets
+					.into_iter()
+					.map(|pallet| {
+						let event = pallet.event.map(|event| event.ty.id);
+						let error = pallet.error.map(|error| error.ty.id);
+						(pallet.index, (pallet.name, event, error))
+					})
+					.collect(),
+			),
+			_ => return Err("Metadata version 14 or 15 is required".into()),
+		};
+
+		Ok(Self { types, pallets })
+	}
+
+	/// Resolve the type definition of the given type id.
+	fn type_def(&self, type_id: u32) -> Result<&TypeDef<PortableForm>, String> {
+		self.types
+			.resolve(type_id)
+			.map(|ty| &ty.type_def)
+			.ok_or_else(|| format!("Type {type_id} not found in metadata"))
+	}
+
+	/// Resolve the variant of the enum `type_id` with the given index.
+	fn variant(&self, type_id: u32, index: u8) -> Result<&Variant<PortableForm>, String> {
+		let TypeDef::Variant(def) = self.type_def(type_id)? else {
+			return Err(format!("Type {type_id} is not an enum"))
+		};
+
+		def.variants
+			.iter()
+			.find(|variant| variant.index == index)
+			.ok_or_else(|| format!("Variant {index} of type {type_id} not found in metadata"))
+	}
+
+	/// Skip over a value of the given type.
+	fn skip(&self, type_id: u32, input: &mut &[u8]) -> Result<(), String> {
+		match self.type_def(type_id)? {
+			TypeDef::Composite(def) =>
+				def.fields.iter().try_for_each(|field| self.skip(field.ty.id, input)),
+			TypeDef::Variant(_) => {
+				let index = take_u8(input)?;
+				self.variant(type_id, index)?
+					.fields
+					.iter()
+					.try_for_each(|field| self.skip(field.ty.id, input))
+			},
+			TypeDef::Sequence(def) =>
+				(0..decode_len(input)?).try_for_each(|_| self.skip(def.type_param.id, input)),
+			TypeDef::Array(def) =>
+				(0..def.len).try_for_each(|_| self.skip(def.type_param.id, input)),
+			TypeDef::Tuple(def) =>
+				def.fields.iter().try_for_each(|field| self.skip(field.id, input)),
+			TypeDef::Primitive(primitive) => {
+				let len = match primitive_size(primitive) {
+					Some(len) => len,
+					None => decode_len(input)?,
+				};
+				take(input, len).map(|_| ())
+			},
+			TypeDef::Compact(_) =>
+				Compact::<u128>::skip(input).map_err(|e| format!("Invalid compact value: {e}")),
+			TypeDef::BitSequence(def) => {
+				let store_size = match self.type_def(def.bit_store_type.id)? {
+					TypeDef::Primitive(primitive) => primitive_size(primitive),
+					_ => None,
+				}
+				.ok_or_else(|| "Invalid bit sequence store type".to_string())?;
+				let bits = decode_len(input)?;
+				let store_bits = store_size * 8;
+				take(input, bits.div_ceil(store_bits) * store_size).map(|_| ())
+			},
+		}
+	}
+
+	/// Decode an unsigned integer of the given type.
+	fn decode_unsigned(&self, type_id: u32, mut input: &[u8]) -> Option<u128> {
+		match self.type_def(type_id).ok()? {
+			TypeDef::Primitive(TypeDefPrimitive::U8) => u8::decode(&mut input).ok().map(Into::into),
+			TypeDef::Primitive(TypeDefPrimitive::U16) =>
+				u16::decode(&mut input).ok().map(Into::into),
+			TypeDef::Primitive(TypeDefPrimitive::U32) =>
+				u32::decode(&mut input).ok().map(Into::into),
+			TypeDef::Primitive(TypeDefPrimitive::U64) =>
+				u64::decode(&mut input).ok().map(Into::into),
+			TypeDef::Primitive(TypeDefPrimitive::U128) => u128::decode(&mut input).ok(),
+			TypeDef::Compact(_) => Compact::<u128>::decode(&mut input).ok().map(|v| v.0),
+			// Newtype wrappers around an integer.
+			TypeDef::Composite(def) if def.fields.len() == 1 =>
+				self.decode_unsigned(def.fields[0].ty.id, input),
+			_ => None,
+		}
+	}
+
+	/// Render a `DispatchError` of the given type as a human readable string.
+	///
+	/// Module errors are resolved to `Pallet::Error` using the metadata of the pallet.
+	fn decode_dispatch_error(&self, type_id: u32, mut input: &[u8]) -> Result<String, String> {
+		let index = take_u8(&mut input)?;
+		let variant = self.variant(type_id, index)?;
+
+		if variant.name == "Module" {
+			// `ModuleError { index: u8, error: [u8; MAX_MODULE_ERROR_ENCODED_SIZE] }`.
+			let module = take(&mut input, 2)?;
+			let (pallet_index, error_index) = (module[0], module[1]);
+			let (pallet, _, error_ty) = self
+				.pallets
+				.get(&pallet_index)
+				.ok_or_else(|| format!("Pallet {pallet_index} not found in metadata"))?;
+			let error = match error_ty {
+				Some(error_ty) => self.variant(*error_ty, error_index)?.name.clone(),
+				None => format!("{error_index}"),
+			};
+			return Ok(format!("{pallet}::{error}"))
+		}
+
+		// Nested errors such as `Token(FundsUnavailable)`.
+		if let [field] = &variant.fields[..] {
+			if let Ok(TypeDef::Variant(_)) = self.type_def(field.ty.id) {
+				let inner = take_u8(&mut input)?;
+				let inner = self.variant(field.ty.id, inner)?;
+				return Ok(format!("{}::{}", variant.name, inner.name))
+			}
+		}
+
+		Ok(variant.name.clone())
+	}
+
+	/// Decode the content of `frame_system::Events`.
+	fn decode_events<Hash: Decode + AsRef<[u8]>>(
+		&self,
+		mut input: &[u8],
+	) -> Result<Vec<DecodedEvent>, String> {
+		let input = &mut input;
+		let count = decode_len(input)?;
+		let mut events = Vec::with_capacity(count);
+
+		for _ in 0..count {
+			let phase = Phase::decode(input).map_err(|e| format!("Invalid event phase: {e}"))?;
+
+			let pallet_index = take_u8(input)?;
+			let (pallet, event_ty, _) = self
+				.pallets
+				.get(&pallet_index)
+				.ok_or_else(|| format!("Pallet {pallet_index} not found in metadata"))?;
+			let event_ty = event_ty.ok_or_else(|| format!("Pallet {pallet} has no events"))?;
+
+			let event_index = take_u8(input)?;
+			let variant = self.variant(event_ty, event_index)?;
+
+			let mut fields = Vec::with_capacity(variant.fields.len());
+			for field in &variant.fields {
+				let start = *input;
+				self.skip(field.ty.id, input)?;
+				let value = &start[..start.len() - input.len()];
+				fields.push(DecodedField {
+					name: field.name.clone(),
+					type_name: field.type_name.clone(),
+					type_id: field.ty.id,
+					value: value.to_vec(),
+				});
+			}
+
+			let topics = Vec::<Hash>::decode(input)
+				.map_err(|e| format!("Invalid event topics: {e}"))?
+				.iter()
+				.map(|topic| hex_string(&topic.as_ref()))
+				.collect();
+
+			events.push(DecodedEvent {
+				phase: phase.into(),
+				pallet: pallet.clone(),
+				name: variant.name.clone(),
+				fields,
+				topics,
+			});
+		}
+
+		Ok(events)
+	}
+}
+
+/// A single field of a decoded event.
+struct DecodedField {
+	name: Option<String>,
+	type_name: Option<String>,
+	type_id: u32,
+	value: Vec<u8>,
+}
+
+/// An event decoded from `frame_system::Events`.
+struct DecodedEvent {
+	phase: ArchiveEventPhase,
+	pallet: String,
+	name: String,
+	fields: Vec<DecodedField>,
+	topics: Vec<String>,
+}
+
+impl DecodedEvent {
+	/// Returns true if the event is `pallet::name`.
+	fn is(&self, pallet: &str, name: &str) -> bool {
+		self.pallet == pallet && self.name == name
+	}
+
+	/// Find the field with the given name.
+	fn field(&self, name: &str) -> Option<&DecodedField> {
+		self.fields.iter().find(|field| field.name.as_deref() == Some(name))
+	}
+
+	/// Convert into the RPC representation.
+	fn into_record(self) -> ArchiveEventRecord {
+		ArchiveEventRecord {
+			phase: self.phase,
+			pallet: self.pallet,
+			name: self.name,
+			fields: self
+				.fields
+				.into_iter()
+				.map(|field| ArchiveEventField {
+					name: field.name,
+					type_name: field.type_name,
+					value: hex_string(&field.value),
+				})
+				.collect(),
+			topics: self.topics,
+		}
+	}
+}
+
+/// Reports the extrinsic outcomes and events of a range of finalized blocks.
+pub struct ArchiveBlockEvents<Client, Block, BE> {
+	client: Arc<Client>,
+	_phantom: PhantomData<(Block, BE)>,
+}
+
+impl<Client, Block, BE> ArchiveBlockEvents<Client, Block, BE> {
+	pub fn new(client: Arc<Client>) -> Self {
+		Self { client, _phantom: PhantomData }
+	}
+}
+
+impl<Client, Block, BE> ArchiveBlockEvents<Client, Block, BE>
+where
+	Block: BlockT + 'static,
+	BE: Backend<Block> + 'static,
+	Client: BlockBackend<Block>
+		+ ExecutorProvider<Block>
+		+ HeaderBackend<Block>
+		+ CallApiAt<Block>
+		+ StorageProvider<Block, BE>
+		+ Send
+		+ Sync
+		+ 'static,
+{
+	/// Fetch the runtime metadata at the given block.
+	fn fetch_decoder(&self, hash: Block::Hash) -> Result<EventsDecoder, String> {
+		let call = |method: &str, data: &[u8]| {
+			self.client
+				.executor()
+				.call(hash, method, data, CallContext::Offchain)
+				.map_err(|e| ArchiveError::RuntimeCall(e.to_string()).to_string())
+		};
+		let invalid = |e: codec::Error| format!("Invalid runtime metadata: {e}");
+
+		// `Metadata_metadata_at_version` is not implemented by runtimes older than V15.
+		let metadata = match call("Metadata_metadata_at_version", &METADATA_VERSION.encode()) {
+			Ok(metadata) => Option::<Vec<u8>>::decode(&mut &metadata[..]).map_err(invalid)?,
+			Err(_) => None,
+		};
+		let metadata = match metadata {
+			Some(metadata) => metadata,
+			None => Vec::<u8>::decode(&mut &call("Metadata_metadata", &[])?[..]).map_err(invalid)?,
+		};
+
+		EventsDecoder::new(&metadata)
+	}
+
+	/// Build the report of a single block.
+	fn block_events(
+		&self,
+		hash: Block::Hash,
+		number: NumberFor<Block>,
+		decoder: &EventsDecoder,
+	) -> Result<ArchiveBlockEventsResult, String> {
+		let extrinsics = self
+			.client
+			.block_body(hash)
+			.map_err(|e| e.to_string())?
+			.ok_or_else(|| format!("Block body is not present: {hash}"))?;
+
+		let events = match self
+			.client
+			.storage(hash, &system_events_key())
+			.map_err(|e| e.to_string())?
+		{
+			Some(events) => decoder.decode_events::<Block::Hash>(&events.0)?,
+			None => Vec::new(),
+		};
+
+		let mut outcomes: Vec<_> = extrinsics
+			.iter()
+			.enumerate()
+			.map(|(index, extrinsic)| ArchiveExtrinsicOutcome {
+				index: index as u32,
+				extrinsic: hex_string(&extrinsic.encode()),
+				success: false,
+				error: None,
+				fee_paid: None,
+				events: Vec::new(),
+			})
+			.collect();
+		let mut block_events = Vec::new();
+
+		for event in events {
+			let ArchiveEventPhase::ApplyExtrinsic(index) = event.phase else {
+				block_events.push(event.into_record());
+				continue
+			};
+			let Some(outcome) = outcomes.get_mut(index as usize) else {
+				log::debug!(
+					target: LOG_TARGET,
+					"Event {}::{} references unknown extrinsic {index} in block {hash}",
+					event.pallet,
+					event.name,
+				);
+				continue
+			};
+
+			if event.is("System", "ExtrinsicSuccess") {
+				outcome.success = true;
+			} else if event.is("System", "ExtrinsicFailed") {
+				outcome.success = false;
+				outcome.error = event.field("dispatch_error").map(|field| {
+					decoder
+						.decode_dispatch_error(field.type_id, &field.value)
+						.unwrap_or_else(|_| hex_string(&field.value))
+				});
+			} else if event.is("TransactionPayment", "TransactionFeePaid") {
+				outcome.fee_paid = event
+					.field("actual_fee")
+					.and_then(|field| decoder.decode_unsigned(field.type_id, &field.value))
+					.map(|fee| fee.to_string());
+			}
+
+			outcome.events.push(event.into_record());
+		}
+
+		Ok(ArchiveBlockEventsResult {
+			hash: hex_string(&hash.as_ref()),
+			number: number.saturated_into(),
+			extrinsics: outcomes,
+			events: block_events,
+		})
+	}
+
+	fn handle_range_inner(
+		&self,
+		from: NumberFor<Block>,
+		to: NumberFor<Block>,
+		tx: &mpsc::Sender<ArchiveBlockEventsEvent>,
+	) -> Result<(), String> {
+		// The decoder is reused for as long as the runtime doesn't change.
+		let mut decoder: Option<(u32, EventsDecoder)> = None;
+		let mut number = from;
+
+		while number <= to {
+			let hash = self
+				.client
+				.block_hash(number)
+				.map_err(|e| e.to_string())?
+				.ok_or_else(|| format!("Block #{number} is not present"))?;
+
+			let spec_version =
+				self.client.runtime_version_at(hash).map_err(|e| e.to_string())?.spec_version;
+			if decoder.as_ref().map_or(true, |(version, _)| *version != spec_version) {
+				decoder = Some((spec_version, self.fetch_decoder(hash)?));
+			}
+			let (_, events_decoder) = decoder.as_ref().expect("decoder is set above; qed");
+
+			let result = self.block_events(hash, number, events_decoder)?;
+			if tx.blocking_send(ArchiveBlockEventsEvent::BlockEvents(result)).is_err() {
+				return Ok(())
+			}
+
+			number += One::one();
+		}
+
+		Ok(())
+	}
+
+	/// Report the extrinsic outcomes and events of the finalized blocks in the `from..=to`
+	/// height range. At most [`MAX_BLOCKS_PER_CALL`] blocks are reported, the height to
+	/// continue from is provided by the `blockEventsDone` event.
+	///
+	/// The results are sent to the provided `tx` sender to leverage the backpressure
+	/// mechanism.
+	pub async fn handle_range(
+		&self,
+		from: u64,
+		to: u64,
+		tx: mpsc::Sender<ArchiveBlockEventsEvent>,
+	) -> Result<(), tokio::task::JoinError> {
+		let this = ArchiveBlockEvents { client: self.client.clone(), _phantom: PhantomData };
+
+		tokio::task::spawn_blocking(move || {
+			let finalized: u64 = this.client.info().finalized_number.saturated_into();
+
+			if from > to {
+				let error =
+					ArchiveError::InvalidParam(format!("Invalid block range: {from}..{to}"));
+				let _ = tx.blocking_send(ArchiveBlockEventsEvent::err(error.to_string()));
+				return
+			}
+			if to > finalized {
+				let error = ArchiveError::InvalidParam(format!(
+					"Block #{to} is above the finalized block #{finalized}"
+				));
+				let _ = tx.blocking_send(ArchiveBlockEventsEvent::err(error.to_string()));
+				return
+			}
+
+			let last = to.min(from.saturating_add(MAX_BLOCKS_PER_CALL - 1));
+			let next_height = (last < to).then(|| last + 1);
+
+			log::trace!(
+				target: LOG_TARGET,
+				"handle_range: from={from}, to={to}, last={last}, next_height={next_height:?}",
+			);
+
+			let result = this.handle_range_inner(from.saturated_into(), last.saturated_into(), &tx);
+
+			if let Err(error) = result {
+				log::trace!(target: LOG_TARGET, "handle_range: sending error={:?}", error);
+
+				let _ = tx.blocking_send(ArchiveBlockEventsEvent::err(error));
+				return
+			}
+
+			let _ = tx.blocking_send(ArchiveBlockEventsEvent::BlockEventsDone(
+				ArchiveBlockEventsDone { next_height },
+			));
+		})
+		.await?;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_metadata::v14::{
+		ExtrinsicMetadata, PalletErrorMetadata, PalletEventMetadata, PalletMetadata,
+		RuntimeMetadataV14,
+	};
+	use scale_info::{meta_type, MetaType, TypeInfo};
+	use sp_core::H256;
+	use sp_runtime::{DispatchError, ModuleError};
+
+	#[derive(Encode, TypeInfo)]
+	enum SystemEvent {
+		#[codec(index = 1)]
+		ExtrinsicFailed { dispatch_error: DispatchError },
+	}
+
+	#[derive(Encode, TypeInfo)]
+	enum PaymentEvent {
+		TransactionFeePaid { who: u64, actual_fee: u128, tip: u128 },
+	}
+
+	#[derive(Encode, TypeInfo)]
+	enum PaymentError {
+		#[codec(index = 1)]
+		HighFee,
+	}
+
+	#[derive(Encode)]
+	enum RuntimeEvent {
+		#[codec(index = 0)]
+		System(SystemEvent),
+		#[codec(index = 5)]
+		TransactionPayment(PaymentEvent),
+	}
+
+	/// An event record of the first extrinsic, without topics.
+	fn record(event: RuntimeEvent) -> (u8, u32, RuntimeEvent, Vec<H256>) {
+		(0, 0, event, Vec::new())
+	}
+
+	fn pallet(
+		name: &'static str,
+		index: u8,
+		event: MetaType,
+		error: Option<MetaType>,
+	) -> PalletMetadata {
+		PalletMetadata {
+			name,
+			storage: None,
+			calls: None,
+			event: Some(PalletEventMetadata { ty: event }),
+			constants: Vec::new(),
+			error: error.map(|ty| PalletErrorMetadata { ty }),
+			index,
+		}
+	}
+
+	#[test]
+	fn events_are_decoded_with_metadata_v14() {
+		let metadata = RuntimeMetadataV14::new(
+			vec![
+				pallet("System", 0, meta_type::<SystemEvent>(), None),
+				pallet(
+					"TransactionPayment",
+					5,
+					meta_type::<PaymentEvent>(),
+					Some(meta_type::<PaymentError>()),
+				),
+			],
+			ExtrinsicMetadata { ty: meta_type::<()>(), version: 4, signed_extensions: Vec::new() },
+			meta_type::<()>(),
+		);
+		let metadata = RuntimeMetadataPrefixed::from(metadata).encode();
+		let decoder = EventsDecoder::new(&metadata).unwrap();
+
+		let dispatch_error =
+			DispatchError::Module(ModuleError { index: 5, error: [1, 0, 0, 0], message: None });
+		let events = vec![
+			record(RuntimeEvent::TransactionPayment(PaymentEvent::TransactionFeePaid {
+				who: 1,
+				actual_fee: 1_000,
+				tip: 0,
+			})),
+			record(RuntimeEvent::System(SystemEvent::ExtrinsicFailed { dispatch_error })),
+		];
+		let events = decoder.decode_events::<H256>(&events.encode()).unwrap();
+		assert_eq!(events.len(), 2);
+
+		let fee_paid = &events[0];
+		assert!(fee_paid.is("TransactionPayment", "TransactionFeePaid"));
+		assert_eq!(fee_paid.phase, ArchiveEventPhase::ApplyExtrinsic(0));
+		let fee = fee_paid.field("actual_fee").unwrap();
+		assert_eq!(decoder.decode_unsigned(fee.type_id, &fee.value), Some(1_000));
+
+		let failed = &events[1];
+		assert!(failed.is("System", "ExtrinsicFailed"));
+		let error = failed.field("dispatch_error").unwrap();
+		assert_eq!(
+			decoder.decode_dispatch_error(error.type_id, &error.value).unwrap(),
+			"TransactionPayment::HighFee",
+		);
+	}
+}
@@ -25,6 +25,7 @@
 #[cfg(test)]
 mod tests;
 
+mod archive_block_events;
 mod archive_storage;
 mod types;
 
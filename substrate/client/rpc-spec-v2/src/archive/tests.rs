@@ -19,6 +19,7 @@
 use crate::{
 	archive::MethodResult,
 	common::events::{
+		ArchiveBlockEventsDone, ArchiveBlockEventsEvent, ArchiveEventPhase,
 		ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageDiffOperationType,
 		ArchiveStorageDiffResult, ArchiveStorageDiffType, ArchiveStorageEvent, StorageQuery,
 		StorageQueryType, StorageResult, StorageResultType,
@@ -26,7 +27,7 @@ use crate::{
 	hex_string,
 };
 
-use super::{archive::Archive, *};
+use super::{archive::Archive, archive_block_events::MAX_BLOCKS_PER_CALL, *};
 
 use assert_matches::assert_matches;
 use codec::{Decode, Encode};
@@ -999,3 +1000,204 @@ async fn archive_storage_diff_invalid_params() {
 		ArchiveStorageDiffEvent::StorageDiffError(ref err) if err.error.contains("Header was not found")
 	);
 }
+
+#[tokio::test]
+async fn archive_block_events() {
+	let (client, api) = setup_api();
+
+	// Block 1 contains a transfer, block 2 is empty.
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap();
+	builder
+		.push_transfer(runtime::Transfer {
+			from: Sr25519Keyring::Alice.into(),
+			to: Sr25519Keyring::Ferdie.into(),
+			amount: 42,
+			nonce: 0,
+		})
+		.unwrap();
+	let block_1 = builder.build().unwrap().block;
+	client.import(BlockOrigin::Own, block_1.clone()).await.unwrap();
+
+	let block_2 = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(block_1.hash())
+		.with_parent_block_number(1)
+		.build()
+		.unwrap()
+		.build()
+		.unwrap()
+		.block;
+	client.import(BlockOrigin::Own, block_2.clone()).await.unwrap();
+	client.finalize_block(block_2.hash(), None).unwrap();
+
+	let mut sub = api
+		.subscribe_unbounded("archive_unstable_blockEvents", rpc_params![1, 2])
+		.await
+		.unwrap();
+
+	// Block 1 reports the outcome and events of the transfer.
+	let event = get_next_event::<ArchiveBlockEventsEvent>(&mut sub).await;
+	let ArchiveBlockEventsEvent::BlockEvents(result) = event else {
+		panic!("Expected block events, got {event:?}");
+	};
+	assert_eq!(result.hash, format!("{:?}", block_1.hash()));
+	assert_eq!(result.number, 1);
+	assert_eq!(result.extrinsics.len(), 1);
+	let extrinsic = &result.extrinsics[0];
+	assert_eq!(extrinsic.index, 0);
+	assert_eq!(extrinsic.extrinsic, hex_string(&block_1.extrinsics[0].encode()));
+	assert!(extrinsic.success);
+	assert!(extrinsic.error.is_none());
+	assert!(extrinsic
+		.events
+		.iter()
+		.all(|event| event.phase == ArchiveEventPhase::ApplyExtrinsic(0)));
+	assert!(extrinsic
+		.events
+		.iter()
+		.any(|event| event.pallet == "Balances" && event.name == "Transfer"));
+	let success = extrinsic.events.last().unwrap();
+	assert_eq!((success.pallet.as_str(), success.name.as_str()), ("System", "ExtrinsicSuccess"));
+	assert_eq!(success.fields[0].name.as_deref(), Some("dispatch_info"));
+
+	// Block 2 has no extrinsics.
+	let event = get_next_event::<ArchiveBlockEventsEvent>(&mut sub).await;
+	let ArchiveBlockEventsEvent::BlockEvents(result) = event else {
+		panic!("Expected block events, got {event:?}");
+	};
+	assert_eq!(result.hash, format!("{:?}", block_2.hash()));
+	assert_eq!(result.number, 2);
+	assert!(result.extrinsics.is_empty());
+
+	// The whole range is covered.
+	assert_eq!(
+		get_next_event::<ArchiveBlockEventsEvent>(&mut sub).await,
+		ArchiveBlockEventsEvent::BlockEventsDone(ArchiveBlockEventsDone { next_height: None }),
+	);
+}
+
+#[tokio::test]
+async fn archive_block_events_failed_extrinsic() {
+	let (client, api) = setup_api();
+
+	// The transfer exceeds the balance of Alice, so the extrinsic is included but fails.
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap();
+	builder
+		.push_transfer(runtime::Transfer {
+			from: Sr25519Keyring::Alice.into(),
+			to: Sr25519Keyring::Ferdie.into(),
+			amount: u64::MAX,
+			nonce: 0,
+		})
+		.unwrap();
+	let block = builder.build().unwrap().block;
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+	client.finalize_block(block.hash(), None).unwrap();
+
+	let mut sub = api
+		.subscribe_unbounded("archive_unstable_blockEvents", rpc_params![1, 1])
+		.await
+		.unwrap();
+
+	let event = get_next_event::<ArchiveBlockEventsEvent>(&mut sub).await;
+	let ArchiveBlockEventsEvent::BlockEvents(result) = event else {
+		panic!("Expected block events, got {event:?}");
+	};
+	let extrinsic = &result.extrinsics[0];
+	assert!(!extrinsic.success);
+	assert_eq!(extrinsic.error.as_deref(), Some("Token::FundsUnavailable"));
+	// The test runtime doesn't charge fees.
+	assert!(extrinsic.fee_paid.is_none());
+	let failed = extrinsic.events.last().unwrap();
+	assert_eq!((failed.pallet.as_str(), failed.name.as_str()), ("System", "ExtrinsicFailed"));
+}
+
+#[tokio::test]
+async fn archive_block_events_pagination() {
+	let (client, api) = setup_api();
+
+	let last = MAX_BLOCKS_PER_CALL + 2;
+	let mut parent = client.chain_info().genesis_hash;
+	for number in 0..last {
+		let block = BlockBuilderBuilder::new(&*client)
+			.on_parent_block(parent)
+			.with_parent_block_number(number)
+			.build()
+			.unwrap()
+			.build()
+			.unwrap()
+			.block;
+		parent = block.hash();
+		client.import(BlockOrigin::Own, block).await.unwrap();
+	}
+	client.finalize_block(parent, None).unwrap();
+
+	// The first call stops after `MAX_BLOCKS_PER_CALL` blocks.
+	let mut sub = api
+		.subscribe_unbounded("archive_unstable_blockEvents", rpc_params![1, last])
+		.await
+		.unwrap();
+	for number in 1..=MAX_BLOCKS_PER_CALL {
+		let event = get_next_event::<ArchiveBlockEventsEvent>(&mut sub).await;
+		let ArchiveBlockEventsEvent::BlockEvents(result) = event else {
+			panic!("Expected block events, got {event:?}");
+		};
+		assert_eq!(result.number, number);
+	}
+	let next_height = MAX_BLOCKS_PER_CALL + 1;
+	assert_eq!(
+		get_next_event::<ArchiveBlockEventsEvent>(&mut sub).await,
+		ArchiveBlockEventsEvent::BlockEventsDone(ArchiveBlockEventsDone {
+			next_height: Some(next_height)
+		}),
+	);
+
+	// The second call continues from the reported height and covers the rest of the range.
+	let mut sub = api
+		.subscribe_unbounded("archive_unstable_blockEvents", rpc_params![next_height, last])
+		.await
+		.unwrap();
+	for number in next_height..=last {
+		let event = get_next_event::<ArchiveBlockEventsEvent>(&mut sub).await;
+		let ArchiveBlockEventsEvent::BlockEvents(result) = event else {
+			panic!("Expected block events, got {event:?}");
+		};
+		assert_eq!(result.number, number);
+	}
+	assert_eq!(
+		get_next_event::<ArchiveBlockEventsEvent>(&mut sub).await,
+		ArchiveBlockEventsEvent::BlockEventsDone(ArchiveBlockEventsDone { next_height: None }),
+	);
+}
+
+#[tokio::test]
+async fn archive_block_events_invalid_range() {
+	let (_client, api) = setup_api();
+
+	// Start of the range is above its end.
+	let mut sub = api
+		.subscribe_unbounded("archive_unstable_blockEvents", rpc_params![1, 0])
+		.await
+		.unwrap();
+	let event = get_next_event::<ArchiveBlockEventsEvent>(&mut sub).await;
+	assert_matches!(event,
+		ArchiveBlockEventsEvent::BlockEventsError(ref err) if err.error.contains("Invalid block range")
+	);
+
+	// The range is not finalized.
+	let mut sub = api
+		.subscribe_unbounded("archive_unstable_blockEvents", rpc_params![0, 1])
+		.await
+		.unwrap();
+	let event = get_next_event::<ArchiveBlockEventsEvent>(&mut sub).await;
+	assert_matches!(event,
+		ArchiveBlockEventsEvent::BlockEventsError(ref err) if err.error.contains("above the finalized block")
+	);
+}
@@ -262,6 +262,132 @@ impl ArchiveStorageDiffEvent {
 	}
 }
 
+/// The phase of block execution an event was emitted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ArchiveEventPhase {
+	/// Emitted while applying the extrinsic at the given index.
+	ApplyExtrinsic(u32),
+	/// Emitted while finalizing the block.
+	Finalization,
+	/// Emitted while initializing the block.
+	Initialization,
+}
+
+/// A single field of a decoded runtime event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveEventField {
+	/// The name of the field, if the event has named fields.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub name: Option<String>,
+	/// The name of the field type as declared in the runtime.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub type_name: Option<String>,
+	/// The hex-encoded SCALE-encoded value of the field.
+	pub value: String,
+}
+
+/// A runtime event decoded using the runtime metadata.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveEventRecord {
+	/// The phase the event was emitted in.
+	pub phase: ArchiveEventPhase,
+	/// The name of the pallet that emitted the event.
+	pub pallet: String,
+	/// The name of the event.
+	pub name: String,
+	/// The fields of the event.
+	pub fields: Vec<ArchiveEventField>,
+	/// The hex-encoded topics of the event.
+	pub topics: Vec<String>,
+}
+
+/// The outcome of an extrinsic included in a block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveExtrinsicOutcome {
+	/// The index of the extrinsic in the block body.
+	pub index: u32,
+	/// The hex-encoded SCALE-encoded extrinsic.
+	pub extrinsic: String,
+	/// True if the extrinsic was dispatched successfully.
+	pub success: bool,
+	/// The decoded dispatch error if the dispatch failed.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub error: Option<String>,
+	/// The decimal-encoded fee paid for the extrinsic, if reported by the runtime.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub fee_paid: Option<String>,
+	/// The events emitted while applying the extrinsic.
+	pub events: Vec<ArchiveEventRecord>,
+}
+
+/// The extrinsic outcomes and events of a single block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveBlockEventsResult {
+	/// The hex-encoded hash of the block.
+	pub hash: String,
+	/// The height of the block.
+	pub number: u64,
+	/// The outcomes of the extrinsics of the block.
+	pub extrinsics: Vec<ArchiveExtrinsicOutcome>,
+	/// The events emitted during block initialization and finalization.
+	pub events: Vec<ArchiveEventRecord>,
+}
+
+/// The `blockEventsDone` event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveBlockEventsDone {
+	/// The height the next call should start from when the requested range was not fully
+	/// covered by this call.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub next_height: Option<u64>,
+}
+
+/// The event generated by the `archive_blockEvents` method.
+///
+/// The `archive_blockEvents` can generate the following events:
+///  - `blockEvents` event - generated for every block of the range in ascending order.
+///  - `blockEventsError` event - generated when an error is produced.
+///  - `blockEventsDone` event - generated when the `archive_blockEvents` method completed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "event")]
+pub enum ArchiveBlockEventsEvent {
+	/// The `blockEvents` event.
+	BlockEvents(ArchiveBlockEventsResult),
+	/// The `blockEventsError` event.
+	BlockEventsError(ArchiveStorageMethodErr),
+	/// The `blockEventsDone` event.
+	BlockEventsDone(ArchiveBlockEventsDone),
+}
+
+impl ArchiveBlockEventsEvent {
+	/// Create a new `ArchiveBlockEventsEvent::BlockEventsError` event.
+	pub fn err(error: String) -> Self {
+		Self::BlockEventsError(ArchiveStorageMethodErr { error })
+	}
+
+	/// Checks if the event is a `BlockEventsDone` event.
+	pub fn is_done(&self) -> bool {
+		matches!(self, Self::BlockEventsDone(_))
+	}
+
+	/// Checks if the event is a `BlockEventsError` event.
+	pub fn is_err(&self) -> bool {
+		matches!(self, Self::BlockEventsError(_))
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -503,4 +629,51 @@ mod tests {
 		let dec: PaginatedStorageQuery<&str> = serde_json::from_str(exp).unwrap();
 		assert_eq!(dec, item);
 	}
+
+	#[test]
+	fn archive_block_events_event() {
+		// Done event with a continuation height.
+		let event = ArchiveBlockEventsEvent::BlockEventsDone(ArchiveBlockEventsDone {
+			next_height: Some(5),
+		});
+		// Encode
+		let ser = serde_json::to_string(&event).unwrap();
+		let exp = r#"{"event":"blockEventsDone","nextHeight":5}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveBlockEventsEvent = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, event);
+
+		// Block events.
+		let event = ArchiveBlockEventsEvent::BlockEvents(ArchiveBlockEventsResult {
+			hash: "0x1".into(),
+			number: 1,
+			extrinsics: vec![ArchiveExtrinsicOutcome {
+				index: 0,
+				extrinsic: "0x2".into(),
+				success: false,
+				error: Some("Balances::InsufficientBalance".into()),
+				fee_paid: Some("100".into()),
+				events: vec![],
+			}],
+			events: vec![ArchiveEventRecord {
+				phase: ArchiveEventPhase::Finalization,
+				pallet: "System".into(),
+				name: "Remarked".into(),
+				fields: vec![ArchiveEventField {
+					name: Some("sender".into()),
+					type_name: None,
+					value: "0x3".into(),
+				}],
+				topics: vec![],
+			}],
+		});
+		// Encode
+		let ser = serde_json::to_string(&event).unwrap();
+		let exp = r#"{"event":"blockEvents","hash":"0x1","number":1,"extrinsics":[{"index":0,"extrinsic":"0x2","success":false,"error":"Balances::InsufficientBalance","feePaid":"100","events":[]}],"events":[{"phase":"finalization","pallet":"System","name":"Remarked","fields":[{"name":"sender","value":"0x3"}],"topics":[]}]}"#;
+		assert_eq!(ser, exp);
+		// Decode
+		let dec: ArchiveBlockEventsEvent = serde_json::from_str(exp).unwrap();
+		assert_eq!(dec, event);
+	}
 }
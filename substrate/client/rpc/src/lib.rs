@@ -36,6 +36,7 @@ pub mod offchain;
 pub mod state;
 pub mod statement;
 pub mod system;
+pub mod txpool;
 pub mod utils;
 
 #[cfg(any(test, feature = "test-helpers"))]
@@ -0,0 +1,104 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Implementation of the [`TxPoolApiServer`] trait providing transaction pool introspection.
+
+#[cfg(test)]
+mod tests;
+
+use self::error::Error;
+use crate::{
+	utils::{spawn_subscription_task, BoundedVecDeque, PendingSubscription},
+	SubscriptionTaskExecutor,
+};
+use jsonrpsee::{types::ErrorObject, Extensions, PendingSubscriptionSink};
+use sc_rpc_api::{check_if_safe, DenyUnsafe};
+use sc_transaction_pool_api::{BlockHash, TransactionPoolIntrospection, TxHash};
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+pub use sc_rpc_api::txpool::*;
+
+/// Transaction pool introspection API. All methods are unsafe.
+pub struct TxPool<P> {
+	/// Transactions pool.
+	pool: Arc<P>,
+	/// Executor to spawn subscriptions.
+	executor: SubscriptionTaskExecutor,
+}
+
+impl<P> TxPool<P> {
+	/// Create new instance of transaction pool introspection API.
+	pub fn new(pool: Arc<P>, executor: SubscriptionTaskExecutor) -> Self {
+		Self { pool, executor }
+	}
+}
+
+impl<P> TxPoolApiServer<TxHash<P>, BlockHash<P>> for TxPool<P>
+where
+	P: TransactionPoolIntrospection + Sync + Send + 'static,
+	P::Hash: Unpin,
+	<P::Block as BlockT>::Hash: Unpin,
+{
+	fn content(
+		&self,
+		ext: &Extensions,
+		at: Option<BlockHash<P>>,
+	) -> Result<TxPoolContent<TxHash<P>, BlockHash<P>>, Error> {
+		check_if_safe(ext)?;
+		self.pool.content_at(at).map(Into::into).ok_or(Error::UnknownView)
+	}
+
+	fn content_from(
+		&self,
+		ext: &Extensions,
+		sender: Bytes,
+		at: Option<BlockHash<P>>,
+	) -> Result<TxPoolContent<TxHash<P>, BlockHash<P>>, Error> {
+		let mut content = self.content(ext, at)?;
+		content.ready.retain(|tx| tx.is_tagged_by(&sender));
+		content.future.retain(|tx| tx.is_tagged_by(&sender));
+		Ok(content)
+	}
+
+	fn inspect(
+		&self,
+		ext: &Extensions,
+		hash: TxHash<P>,
+	) -> Result<TxPoolInspection<TxHash<P>, BlockHash<P>>, Error> {
+		check_if_safe(ext)?;
+		Ok(self.pool.inspect_transaction(&hash).into())
+	}
+
+	fn subscribe_events(&self, pending: PendingSubscriptionSink, ext: &Extensions) {
+		let deny_unsafe = ext
+			.get::<DenyUnsafe>()
+			.cloned()
+			.expect("DenyUnsafe extension is always set by the substrate rpc server; qed");
+		if let Err(err) = deny_unsafe.check_if_safe() {
+			spawn_subscription_task(&self.executor, pending.reject(ErrorObject::from(err)));
+			return
+		}
+
+		let stream = self.pool.pool_event_stream();
+		let fut =
+			PendingSubscription::from(pending).pipe_from_stream(stream, BoundedVecDeque::default());
+		spawn_subscription_task(&self.executor, fut);
+	}
+}
@@ -0,0 +1,174 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::testing::{test_executor, timeout_secs};
+use assert_matches::assert_matches;
+use codec::Encode;
+use jsonrpsee::{core::EmptyServerParams as EmptyParams, MethodsError as RpcError, RpcModule};
+use sc_transaction_pool::{BasicPool, FullChainApi};
+use sc_transaction_pool_api::{PoolEvent, TransactionPool, TransactionSource};
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use substrate_test_runtime_client::{
+	self,
+	runtime::{AccountId, Block, Extrinsic, ExtrinsicBuilder, Transfer},
+	Backend, Client, Sr25519Keyring,
+};
+
+fn uxt(sender: Sr25519Keyring, nonce: u64) -> Extrinsic {
+	let tx = Transfer {
+		amount: Default::default(),
+		nonce,
+		from: sender.into(),
+		to: Sr25519Keyring::Bob.into(),
+	};
+	ExtrinsicBuilder::new_transfer(tx).build()
+}
+
+type FullTransactionPool = BasicPool<FullChainApi<Client<Backend>, Block>, Block>;
+
+struct TestSetup {
+	pub client: Arc<Client<Backend>>,
+	pub pool: Arc<FullTransactionPool>,
+}
+
+impl Default for TestSetup {
+	fn default() -> Self {
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let pool = Arc::from(BasicPool::new_full(
+			Default::default(),
+			true.into(),
+			None,
+			spawner,
+			client.clone(),
+		));
+		TestSetup { client, pool }
+	}
+}
+
+impl TestSetup {
+	fn to_rpc(&self, deny_unsafe: DenyUnsafe) -> RpcModule<TxPool<FullTransactionPool>> {
+		let mut module = TxPool::new(self.pool.clone(), test_executor()).into_rpc();
+		module.extensions_mut().insert(deny_unsafe);
+		module
+	}
+
+	async fn submit(&self, xt: Extrinsic) -> H256 {
+		let best_hash = self.client.info().best_hash;
+		self.pool.submit_one(best_hash, TransactionSource::External, xt).await.unwrap()
+	}
+}
+
+#[tokio::test]
+async fn txpool_content_should_return_ready_and_future() {
+	let setup = TestSetup::default();
+	let api = setup.to_rpc(DenyUnsafe::No);
+
+	let ready_hash = setup.submit(uxt(Sr25519Keyring::Alice, 0)).await;
+	let future_hash = setup.submit(uxt(Sr25519Keyring::Alice, 2)).await;
+
+	let content: TxPoolContent<H256, H256> =
+		api.call("txpool_content", [None::<H256>]).await.unwrap();
+
+	assert_eq!(content.at, None);
+	assert_eq!(content.ready.iter().map(|tx| tx.hash).collect::<Vec<_>>(), vec![ready_hash]);
+	assert_eq!(content.future.iter().map(|tx| tx.hash).collect::<Vec<_>>(), vec![future_hash]);
+
+	let alice = AccountId::from(Sr25519Keyring::Alice);
+	assert_eq!(content.ready[0].provides, vec![Bytes::from((alice, 0u64).encode())]);
+	assert_eq!(content.future[0].requires, vec![Bytes::from((alice, 1u64).encode())]);
+	assert_eq!(content.ready[0].source, Source::External);
+}
+
+#[tokio::test]
+async fn txpool_content_from_should_filter_by_sender() {
+	let setup = TestSetup::default();
+	let api = setup.to_rpc(DenyUnsafe::No);
+
+	let alice_hash = setup.submit(uxt(Sr25519Keyring::Alice, 0)).await;
+	let _ = setup.submit(uxt(Sr25519Keyring::Bob, 0)).await;
+
+	let alice: Bytes = AccountId::from(Sr25519Keyring::Alice).encode().into();
+	let content: TxPoolContent<H256, H256> =
+		api.call("txpool_contentFrom", (alice, None::<H256>)).await.unwrap();
+
+	assert_eq!(content.ready.iter().map(|tx| tx.hash).collect::<Vec<_>>(), vec![alice_hash]);
+	assert!(content.future.is_empty());
+}
+
+#[tokio::test]
+async fn txpool_inspect_should_report_removal() {
+	let setup = TestSetup::default();
+	let api = setup.to_rpc(DenyUnsafe::No);
+
+	let hash = setup.submit(uxt(Sr25519Keyring::Alice, 0)).await;
+
+	let inspection: TxPoolInspection<H256, H256> =
+		api.call("txpool_inspect", [hash]).await.unwrap();
+	assert_eq!(inspection.transaction.map(|tx| tx.hash), Some(hash));
+	assert!(!inspection.banned);
+	assert_eq!(inspection.removal, None);
+
+	setup.pool.report_invalid(None, [(hash, None)].into_iter().collect());
+
+	let inspection: TxPoolInspection<H256, H256> =
+		api.call("txpool_inspect", [hash]).await.unwrap();
+	assert_eq!(inspection.transaction, None);
+	assert!(inspection.banned);
+	assert_eq!(inspection.removal, Some(PoolEvent::Invalid { hash }));
+}
+
+#[tokio::test]
+async fn txpool_subscribe_events_should_notify_about_all_transactions() {
+	let setup = TestSetup::default();
+	let api = setup.to_rpc(DenyUnsafe::No);
+
+	let mut sub = api
+		.subscribe_unbounded("txpool_subscribeEvents", EmptyParams::new())
+		.await
+		.unwrap();
+
+	let hash = setup.submit(uxt(Sr25519Keyring::Alice, 0)).await;
+	setup.pool.report_invalid(None, [(hash, None)].into_iter().collect());
+
+	let (event, _) =
+		timeout_secs(10, sub.next::<PoolEvent<H256>>()).await.unwrap().unwrap().unwrap();
+	assert_eq!(event, PoolEvent::Invalid { hash });
+}
+
+#[tokio::test]
+async fn txpool_methods_should_be_unsafe() {
+	let setup = TestSetup::default();
+	let api = setup.to_rpc(DenyUnsafe::Yes);
+
+	assert_matches!(
+		api.call::<_, TxPoolContent<H256, H256>>("txpool_content", [None::<H256>]).await,
+		Err(RpcError::JsonRpc(e)) if e.message() == "RPC call is unsafe to be called externally"
+	);
+	assert_matches!(
+		api.call::<_, TxPoolInspection<H256, H256>>("txpool_inspect", [H256::zero()]).await,
+		Err(RpcError::JsonRpc(e)) if e.message() == "RPC call is unsafe to be called externally"
+	);
+	assert_matches!(
+		api.subscribe_unbounded("txpool_subscribeEvents", EmptyParams::new()).await,
+		Err(RpcError::JsonRpc(e)) if e.message() == "RPC call is unsafe to be called externally"
+	);
+}
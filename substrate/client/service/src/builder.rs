@@ -25,6 +25,7 @@ use crate::{
 	start_rpc_servers, BuildGenesisBlock, GenesisBlockBuilder, RpcHandlers, SpawnTaskHandle,
 	TaskManager, TransactionPoolAdapter,
 };
+use codec::DecodeAll;
 use futures::{select, FutureExt, StreamExt};
 use jsonrpsee::RpcModule;
 use log::{debug, error, info};
@@ -64,7 +65,7 @@ use sc_network_sync::{
 		SyncingStrategy,
 	},
 	warp_request_handler::RequestHandler as WarpSyncRequestHandler,
-	SyncingService, WarpSyncConfig,
+	SyncingService, WarpSyncCheckpoint, WarpSyncConfig,
 };
 use sc_rpc::{
 	author::AuthorApiServer,
@@ -72,6 +73,7 @@ use sc_rpc::{
 	offchain::OffchainApiServer,
 	state::{ChildStateApiServer, StateApiServer},
 	system::SystemApiServer,
+	txpool::TxPoolApiServer,
 	DenyUnsafe, SubscriptionTaskExecutor,
 };
 use sc_rpc_spec_v2::{
@@ -81,7 +83,9 @@ use sc_rpc_spec_v2::{
 	transaction::{TransactionApiServer, TransactionBroadcastApiServer},
 };
 use sc_telemetry::{telemetry, ConnectionMessage, Telemetry, TelemetryHandle, SUBSTRATE_INFO};
-use sc_transaction_pool_api::{MaintainedTransactionPool, TransactionPool};
+use sc_transaction_pool_api::{
+	MaintainedTransactionPool, TransactionPool, TransactionPoolIntrospection,
+};
 use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedSender};
 use sp_api::{CallApiAt, ProvideRuntimeApi};
 use sp_blockchain::{HeaderBackend, HeaderMetadata};
@@ -490,7 +494,9 @@ where
 	TBl::Hash: Unpin,
 	TBl::Header: Unpin,
 	TBackend: 'static + sc_client_api::backend::Backend<TBl> + Send,
-	TExPool: MaintainedTransactionPool<Block = TBl, Hash = <TBl as BlockT>::Hash> + 'static,
+	TExPool: MaintainedTransactionPool<Block = TBl, Hash = <TBl as BlockT>::Hash>
+		+ TransactionPoolIntrospection
+		+ 'static,
 {
 	let SpawnTasksParams {
 		mut config,
@@ -782,7 +788,9 @@ where
 		+ 'static,
 	TBackend: sc_client_api::backend::Backend<TBl> + 'static,
 	<TCl as ProvideRuntimeApi<TBl>>::Api: sp_session::SessionKeys<TBl> + sp_api::Metadata<TBl>,
-	TExPool: MaintainedTransactionPool<Block = TBl, Hash = <TBl as BlockT>::Hash> + 'static,
+	TExPool: MaintainedTransactionPool<Block = TBl, Hash = <TBl as BlockT>::Hash>
+		+ TransactionPoolIntrospection
+		+ 'static,
 	TBl::Hash: Unpin,
 	TBl::Header: Unpin,
 {
@@ -859,6 +867,9 @@ where
 	)
 	.into_rpc();
 
+	let txpool =
+		sc_rpc::txpool::TxPool::new(transaction_pool.clone(), task_executor.clone()).into_rpc();
+
 	let author = sc_rpc::author::Author::new(
 		client.clone(),
 		transaction_pool,
@@ -886,6 +897,7 @@ where
 	// Part of the old RPC spec.
 	rpc_api.merge(chain).map_err(|e| Error::Application(e.into()))?;
 	rpc_api.merge(author).map_err(|e| Error::Application(e.into()))?;
+	rpc_api.merge(txpool).map_err(|e| Error::Application(e.into()))?;
 	rpc_api.merge(system).map_err(|e| Error::Application(e.into()))?;
 	rpc_api.merge(state).map_err(|e| Error::Application(e.into()))?;
 	rpc_api.merge(child_state).map_err(|e| Error::Application(e.into()))?;
@@ -1389,6 +1401,16 @@ where
 	downloader
 }
 
+/// Read a SCALE-encoded [`WarpSyncCheckpoint`] from the given file.
+fn read_warp_sync_checkpoint<Block: BlockT>(
+	path: &std::path::Path,
+) -> Result<WarpSyncCheckpoint<Block>, Error> {
+	let encoded = std::fs::read(path)?;
+	WarpSyncCheckpoint::<Block>::decode_all(&mut &encoded[..]).map_err(|e| {
+		Error::Other(format!("Failed to decode warp sync checkpoint {}: {e}", path.display()))
+	})
+}
+
 /// Build standard polkadot syncing strategy
 pub fn build_polkadot_syncing_strategy<Block, Client, Net>(
 	protocol_id: ProtocolId,
@@ -1424,6 +1446,19 @@ where
 		}
 	}
 
+	let warp_sync_config = match net_config.network_config.warp_sync_checkpoint.as_ref() {
+		Some(_) if !net_config.network_config.sync_mode.is_warp() =>
+			return Err("Warp sync checkpoint is only supported in warp sync mode".into()),
+		Some(path) => match warp_sync_config {
+			Some(WarpSyncConfig::WithProvider(warp_sync_provider)) => {
+				let checkpoint = read_warp_sync_checkpoint::<Block>(path)?;
+				Some(WarpSyncConfig::WithCheckpoint(warp_sync_provider, checkpoint))
+			},
+			_ => return Err("Warp sync checkpoint requires a warp sync provider".into()),
+		},
+		None => warp_sync_config,
+	};
+
 	let genesis_hash = client.info().genesis_hash;
 
 	let (state_request_protocol_config, state_request_protocol_name) = {
@@ -1440,7 +1475,8 @@ where
 	net_config.add_request_response_protocol(state_request_protocol_config);
 
 	let (warp_sync_protocol_config, warp_sync_protocol_name) = match warp_sync_config.as_ref() {
-		Some(WarpSyncConfig::WithProvider(warp_with_provider)) => {
+		Some(WarpSyncConfig::WithProvider(warp_with_provider)) |
+		Some(WarpSyncConfig::WithCheckpoint(warp_with_provider, _)) => {
 			// Allow both outgoing and incoming requests.
 			let (handler, protocol_config) = WarpSyncRequestHandler::new::<_, Net>(
 				protocol_id,
@@ -0,0 +1,495 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Database consistency checks.
+
+use crate::{chain_ops::revert_chain, error::Error};
+use codec::Encode;
+use log::{info, warn};
+use sc_client_api::{Backend, BlockBackend, HeaderBackend, TrieCacheContext, UsageProvider};
+use sp_blockchain::Backend as BlockchainBackend;
+use sp_core::storage::{well_known_keys, ChildInfo, StateVersion};
+use sp_runtime::traits::{
+	Block as BlockT, Hash as HashT, HashingFor, Header as HeaderT, NumberFor, One, Zero,
+};
+use sp_state_machine::{Backend as StateBackend, IterArgs};
+use std::{fmt, sync::Arc};
+
+/// An inconsistency found in the database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DbIssue<B: BlockT> {
+	/// No canonical block is known at the given height.
+	MissingHash(NumberFor<B>),
+	/// The header of a canonical block is missing.
+	MissingHeader(NumberFor<B>, B::Hash),
+	/// The header is stored under a hash it doesn't hash to.
+	HeaderHashMismatch(NumberFor<B>, B::Hash),
+	/// The parent hash of the header doesn't match the canonical block below it.
+	ParentMismatch(NumberFor<B>, B::Hash),
+	/// The block is not registered as a child of its parent.
+	NotAChild(NumberFor<B>, B::Hash),
+	/// The body of the block doesn't match the extrinsics root of its header.
+	ExtrinsicsRootMismatch(NumberFor<B>, B::Hash),
+	/// The state of the last finalized block is missing.
+	MissingState(NumberFor<B>, B::Hash),
+	/// Reading the block data or state failed.
+	Corrupted(NumberFor<B>, B::Hash, String),
+	/// The last finalized block or a leaf, both pinned by RPC subscriptions, can't be pinned or
+	/// its state can't be read.
+	Unpinnable(NumberFor<B>, B::Hash, String),
+	/// The header of a leaf is missing.
+	MissingLeaf(B::Hash),
+}
+
+impl<B: BlockT> DbIssue<B> {
+	/// The height of the block the issue was found at, if known.
+	pub fn number(&self) -> Option<NumberFor<B>> {
+		match self {
+			Self::MissingHash(number) |
+			Self::MissingHeader(number, _) |
+			Self::HeaderHashMismatch(number, _) |
+			Self::ParentMismatch(number, _) |
+			Self::NotAChild(number, _) |
+			Self::ExtrinsicsRootMismatch(number, _) |
+			Self::MissingState(number, _) |
+			Self::Corrupted(number, _, _) |
+			Self::Unpinnable(number, _, _) => Some(*number),
+			Self::MissingLeaf(_) => None,
+		}
+	}
+}
+
+impl<B: BlockT> fmt::Display for DbIssue<B> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::MissingHash(n) => write!(f, "#{n}: no canonical block hash"),
+			Self::MissingHeader(n, hash) => write!(f, "#{n} ({hash}): missing header"),
+			Self::HeaderHashMismatch(n, hash) =>
+				write!(f, "#{n} ({hash}): header hash doesn't match its key"),
+			Self::ParentMismatch(n, hash) =>
+				write!(f, "#{n} ({hash}): parent hash doesn't match the canonical chain"),
+			Self::NotAChild(n, hash) => write!(f, "#{n} ({hash}): missing from the children tree"),
+			Self::ExtrinsicsRootMismatch(n, hash) =>
+				write!(f, "#{n} ({hash}): body doesn't match the extrinsics root"),
+			Self::MissingState(n, hash) => write!(f, "#{n} ({hash}): missing finalized state"),
+			Self::Corrupted(n, hash, e) => write!(f, "#{n} ({hash}): {e}"),
+			Self::Unpinnable(n, hash, e) => write!(f, "#{n} ({hash}): can't be pinned: {e}"),
+			Self::MissingLeaf(hash) => write!(f, "leaf {hash}: missing header"),
+		}
+	}
+}
+
+/// The result of [`check_db`].
+#[derive(Debug)]
+pub struct DbCheckReport<B: BlockT> {
+	/// Number of canonical blocks checked.
+	pub checked_blocks: u64,
+	/// Number of states walked.
+	pub checked_states: u64,
+	/// Inconsistencies found.
+	pub issues: Vec<DbIssue<B>>,
+}
+
+impl<B: BlockT> DbCheckReport<B> {
+	/// Returns `true` if no inconsistency was found.
+	pub fn is_consistent(&self) -> bool {
+		self.issues.is_empty()
+	}
+
+	/// The block below the first inconsistency, which the chain can be reverted to.
+	///
+	/// `None` if there is no inconsistency at a known height, or if the first one is at or below
+	/// the `finalized` block: consensus data such as authority sets can't be reverted past
+	/// finality, so reverting finalized blocks would leave the database inconsistent.
+	pub fn repair_target(&self, finalized: NumberFor<B>) -> Option<NumberFor<B>> {
+		let first = self.issues.iter().filter_map(DbIssue::number).min()?;
+		(first > finalized).then(|| first - One::one())
+	}
+}
+
+/// Walk the whole state of the given block, including child tries.
+///
+/// Every trie node is read from the database, so that missing or undecodable nodes are
+/// reported.
+pub fn check_state<B, BA>(backend: &BA, hash: B::Hash) -> Result<(), String>
+where
+	B: BlockT,
+	BA: Backend<B>,
+{
+	let state = backend.state_at(hash, TrieCacheContext::Untrusted).map_err(|e| e.to_string())?;
+
+	let mut child_tries = Vec::new();
+	for pair in state.pairs(IterArgs::default()).map_err(|e| e.to_string())? {
+		let (key, _) = pair.map_err(|e| format!("Corrupted state: {e}"))?;
+		if let Some(child) = key.strip_prefix(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX) {
+			child_tries.push(ChildInfo::new_default(child));
+		}
+	}
+
+	for child_info in child_tries {
+		let mut args = IterArgs::default();
+		args.child_info = Some(child_info);
+		for pair in state.pairs(args).map_err(|e| e.to_string())? {
+			pair.map_err(|e| format!("Corrupted child state: {e}"))?;
+		}
+	}
+
+	Ok(())
+}
+
+/// Pin the block like RPC subscriptions do, and read the runtime code from its state.
+fn check_pinnable<B, BA>(backend: &BA, hash: B::Hash) -> Result<(), String>
+where
+	B: BlockT,
+	BA: Backend<B>,
+{
+	backend.pin_block(hash).map_err(|e| e.to_string())?;
+	let code = backend
+		.state_at(hash, TrieCacheContext::Untrusted)
+		.map_err(|e| e.to_string())
+		.and_then(|state| {
+			state.storage(well_known_keys::CODE).map_err(|e| format!("Corrupted state: {e}"))
+		});
+	backend.unpin_block(hash);
+
+	code?.map(drop).ok_or_else(|| "Missing runtime code".into())
+}
+
+/// Check that the body matches the extrinsics root of the header.
+///
+/// The state version used to build the extrinsics root is defined by the runtime, so both
+/// versions are accepted.
+fn extrinsics_root_matches<B: BlockT>(header: &B::Header, body: &[B::Extrinsic]) -> bool {
+	let extrinsics: Vec<_> = body.iter().map(Encode::encode).collect();
+	[StateVersion::V0, StateVersion::V1].into_iter().any(|version| {
+		HashingFor::<B>::ordered_trie_root(extrinsics.clone(), version) ==
+			*header.extrinsics_root()
+	})
+}
+
+/// Check the consistency of the canonical chain in the `from..=to` height range.
+///
+/// For every block the header, the link to its parent, the children tree, the body and the
+/// justifications are checked. The state of the last finalized block in the range is walked,
+/// as well as the state of every other block in the range if `all_states` is set. The last
+/// finalized block and all leaves are checked to be pinnable.
+pub fn check_db<B, BA, C>(
+	client: Arc<C>,
+	backend: Arc<BA>,
+	from: NumberFor<B>,
+	to: NumberFor<B>,
+	all_states: bool,
+) -> Result<DbCheckReport<B>, Error>
+where
+	B: BlockT,
+	BA: Backend<B>,
+	C: HeaderBackend<B> + BlockBackend<B>,
+{
+	let info = client.info();
+	let to = to.min(info.best_number);
+	let blockchain = backend.blockchain();
+
+	let mut report = DbCheckReport { checked_blocks: 0, checked_states: 0, issues: Vec::new() };
+	let mut parent_hash = None;
+	let mut number = from;
+
+	info!("Checking database from #{from} to #{to}");
+
+	while number <= to {
+		report.checked_blocks += 1;
+		let current = number;
+		number += One::one();
+
+		let hash = match client.hash(current) {
+			Ok(Some(hash)) => hash,
+			Ok(None) => {
+				report.issues.push(DbIssue::MissingHash(current));
+				parent_hash = None;
+				continue
+			},
+			Err(e) => return Err(e.into()),
+		};
+		let expected_parent = parent_hash.replace(hash);
+
+		let header = match client.header(hash) {
+			Ok(Some(header)) => header,
+			Ok(None) => {
+				report.issues.push(DbIssue::MissingHeader(current, hash));
+				continue
+			},
+			Err(e) => {
+				report.issues.push(DbIssue::Corrupted(current, hash, e.to_string()));
+				continue
+			},
+		};
+
+		if header.hash() != hash {
+			report.issues.push(DbIssue::HeaderHashMismatch(current, hash));
+		}
+
+		if expected_parent.is_some_and(|parent| parent != *header.parent_hash()) {
+			report.issues.push(DbIssue::ParentMismatch(current, hash));
+		}
+
+		if !current.is_zero() {
+			match blockchain.children(*header.parent_hash()) {
+				Ok(children) if !children.contains(&hash) =>
+					report.issues.push(DbIssue::NotAChild(current, hash)),
+				Ok(_) => {},
+				Err(e) => report.issues.push(DbIssue::Corrupted(current, hash, e.to_string())),
+			}
+		}
+
+		// Bodies might be pruned, only the present ones are checked.
+		match client.block_body(hash) {
+			Ok(Some(body)) if !extrinsics_root_matches::<B>(&header, &body) =>
+				report.issues.push(DbIssue::ExtrinsicsRootMismatch(current, hash)),
+			Ok(_) => {},
+			Err(e) => report.issues.push(DbIssue::Corrupted(current, hash, e.to_string())),
+		}
+
+		if let Err(e) = client.justifications(hash) {
+			report.issues.push(DbIssue::Corrupted(current, hash, e.to_string()));
+		}
+
+		// State is only guaranteed to be kept for the last finalized block.
+		let is_finalized = hash == info.finalized_hash;
+		let has_state = backend.have_state_at(hash, current);
+		if is_finalized && !has_state {
+			report.issues.push(DbIssue::MissingState(current, hash));
+		} else if has_state && (is_finalized || all_states) {
+			report.checked_states += 1;
+			if let Err(e) = check_state::<B, _>(&*backend, hash) {
+				report.issues.push(DbIssue::Corrupted(current, hash, e));
+			}
+		}
+
+		if report.checked_blocks % 10_000 == 0 {
+			info!("Checked {} blocks, #{current}", report.checked_blocks);
+		}
+	}
+
+	if let Err(e) = check_pinnable::<B, _>(&*backend, info.finalized_hash) {
+		report.issues.push(DbIssue::Unpinnable(info.finalized_number, info.finalized_hash, e));
+	}
+	for leaf in blockchain.leaves()? {
+		let Ok(Some(header)) = client.header(leaf) else {
+			report.issues.push(DbIssue::MissingLeaf(leaf));
+			continue
+		};
+		if let Err(e) = check_pinnable::<B, _>(&*backend, leaf) {
+			report.issues.push(DbIssue::Unpinnable(*header.number(), leaf, e));
+		}
+	}
+
+	for issue in &report.issues {
+		warn!("Database inconsistency: {issue}");
+	}
+	info!(
+		"Checked {} blocks and {} states, found {} inconsistencies",
+		report.checked_blocks,
+		report.checked_states,
+		report.issues.len(),
+	);
+
+	Ok(report)
+}
+
+/// Revert the chain to the block below the first inconsistency found by [`check_db`].
+///
+/// Only unfinalized blocks are reverted, see [`DbCheckReport::repair_target`]. The state of the
+/// target block is walked before reverting to it. `aux_revert` reverts the auxiliary data, e.g.
+/// consensus, of the given number of blocks.
+pub fn repair_db<B, BA, C>(
+	client: Arc<C>,
+	backend: Arc<BA>,
+	report: &DbCheckReport<B>,
+	aux_revert: impl FnOnce(NumberFor<B>) -> Result<(), Error>,
+) -> Result<(), Error>
+where
+	B: BlockT,
+	BA: Backend<B>,
+	C: HeaderBackend<B> + UsageProvider<B>,
+{
+	let info = client.info();
+	let Some(target) = report.repair_target(info.finalized_number) else {
+		return Err(Error::Other(
+			"The finalized chain is inconsistent, the database has to be resynced".into(),
+		))
+	};
+
+	let hash = client
+		.hash(target)?
+		.ok_or_else(|| Error::Other(format!("Block #{target} is missing")))?;
+	check_state::<B, _>(&*backend, hash)
+		.map_err(|e| Error::Other(format!("State of block #{target} is corrupted: {e}")))?;
+
+	let blocks = info.best_number - target;
+	if blocks.is_zero() {
+		return Ok(())
+	}
+
+	info!("Reverting to the last consistent block #{target}");
+	aux_revert(blocks)?;
+	revert_chain(client, backend, blocks)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sc_block_builder::BlockBuilderBuilder;
+	use sc_client_api::backend::{BlockImportOperation, NewBlockState};
+	use substrate_test_runtime_client::{
+		prelude::*,
+		runtime::{Block, Transfer},
+		Backend, BlockOrigin, Client,
+	};
+
+	/// Build a block on top of `parent` with a transfer, without importing it.
+	fn build_block(
+		client: &Client<Backend>,
+		parent: <Block as BlockT>::Hash,
+		number: u64,
+	) -> Block {
+		let mut builder = BlockBuilderBuilder::new(client)
+			.on_parent_block(parent)
+			.with_parent_block_number(number)
+			.build()
+			.unwrap();
+		builder
+			.push_transfer(Transfer {
+				from: Sr25519Keyring::Alice.into(),
+				to: Sr25519Keyring::Ferdie.into(),
+				amount: 1,
+				nonce: number,
+			})
+			.unwrap();
+		builder.build().unwrap().block
+	}
+
+	/// Import three blocks and finalize block `finalized`.
+	fn setup_chain(finalized: u64) -> (Arc<Client<Backend>>, Arc<Backend>) {
+		let builder = TestClientBuilder::new();
+		let backend = builder.backend();
+		let client = Arc::new(builder.build());
+
+		let mut parent = client.chain_info().genesis_hash;
+		for number in 0..3u64 {
+			let block = build_block(&client, parent, number);
+			parent = block.header.hash();
+			futures::executor::block_on(client.import(BlockOrigin::Own, block)).unwrap();
+		}
+		client.finalize_block(client.hash(finalized).unwrap().unwrap(), None).unwrap();
+		(client, backend)
+	}
+
+	/// Write block #4 directly to the database, with an empty body and without its state.
+	fn write_corrupted_block(
+		client: &Client<Backend>,
+		backend: &Backend,
+		state: NewBlockState,
+	) -> <Block as BlockT>::Hash {
+		let parent = client.chain_info().best_hash;
+		let header = build_block(client, parent, 3).header;
+		let hash = header.hash();
+		let mut op = backend.begin_operation().unwrap();
+		backend.begin_state_operation(&mut op, parent).unwrap();
+		op.set_block_data(header, Some(Vec::new()), None, None, state).unwrap();
+		backend.commit_operation(op).unwrap();
+		hash
+	}
+
+	#[test]
+	fn consistent_database_passes() {
+		let (client, backend) = setup_chain(3);
+
+		let report = check_db::<Block, _, _>(client.clone(), backend, 0, 3, true).unwrap();
+		assert!(report.is_consistent(), "{:?}", report.issues);
+		assert_eq!(report.checked_blocks, 4);
+		assert!(report.checked_states >= 1);
+		assert_eq!(report.repair_target(3), None);
+	}
+
+	#[test]
+	fn corrupted_block_is_detected_and_repaired() {
+		let (client, backend) = setup_chain(2);
+		let hash = write_corrupted_block(&client, &backend, NewBlockState::Best);
+		assert_eq!(client.chain_info().best_hash, hash);
+
+		let report = check_db::<Block, _, _>(client.clone(), backend.clone(), 0, 4, false).unwrap();
+		assert!(report.issues.contains(&DbIssue::ExtrinsicsRootMismatch(4, hash)));
+		assert!(report
+			.issues
+			.iter()
+			.any(|issue| matches!(issue, DbIssue::Unpinnable(4, leaf, _) if *leaf == hash)));
+		assert_eq!(report.repair_target(2), Some(3));
+
+		repair_db(client.clone(), backend.clone(), &report, |blocks| {
+			assert_eq!(blocks, 1);
+			Ok(())
+		})
+		.unwrap();
+		assert_eq!(client.chain_info().best_number, 3);
+
+		let report = check_db::<Block, _, _>(client.clone(), backend, 0, 3, false).unwrap();
+		assert!(report.is_consistent(), "{:?}", report.issues);
+	}
+
+	#[test]
+	fn corrupted_finalized_block_is_not_repaired() {
+		let (client, backend) = setup_chain(3);
+		let hash = write_corrupted_block(&client, &backend, NewBlockState::Final);
+		assert_eq!(client.chain_info().finalized_hash, hash);
+
+		let report = check_db::<Block, _, _>(client.clone(), backend.clone(), 0, 4, false).unwrap();
+		assert!(report.issues.contains(&DbIssue::ExtrinsicsRootMismatch(4, hash)));
+		assert!(report
+			.issues
+			.iter()
+			.any(|issue| matches!(issue, DbIssue::Corrupted(4, block, _) if *block == hash)));
+		assert_eq!(report.repair_target(4), None);
+
+		assert!(repair_db(client.clone(), backend, &report, |_| Ok(())).is_err());
+		assert_eq!(client.chain_info().best_hash, hash);
+	}
+
+	#[test]
+	fn repair_target_is_below_first_issue() {
+		let hash = Default::default();
+		let report = DbCheckReport::<Block> {
+			checked_blocks: 10,
+			checked_states: 1,
+			issues: vec![
+				DbIssue::MissingLeaf(hash),
+				DbIssue::ExtrinsicsRootMismatch(7, hash),
+				DbIssue::MissingHeader(5, hash),
+			],
+		};
+
+		// Finalized blocks are never reverted.
+		assert_eq!(report.repair_target(8), None);
+		assert_eq!(report.repair_target(3), Some(4));
+
+		let report = DbCheckReport::<Block> {
+			checked_blocks: 1,
+			checked_states: 0,
+			issues: vec![DbIssue::MissingHash(0)],
+		};
+		assert_eq!(report.repair_target(0), None);
+	}
+}
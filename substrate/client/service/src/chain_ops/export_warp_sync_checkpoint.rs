@@ -0,0 +1,44 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::Error;
+use codec::Encode;
+use log::info;
+use sc_network_sync::strategy::warp::WarpSyncProvider;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::io::Write;
+
+/// Export a warp sync checkpoint of the last finalized block to `output`, SCALE-encoded as read
+/// by `--warp-sync-checkpoint`.
+pub fn export_warp_sync_checkpoint<B: BlockT>(
+	warp_sync_provider: &dyn WarpSyncProvider<B>,
+	mut output: impl Write,
+) -> Result<(), Error> {
+	let checkpoint = warp_sync_provider
+		.checkpoint()
+		.map_err(|e| Error::Other(format!("Failed to create the warp sync checkpoint: {e}")))?;
+	info!(
+		"Exporting warp sync checkpoint of block #{} ({:?}), authority set {}",
+		checkpoint.header.number(),
+		checkpoint.header.hash(),
+		checkpoint.set_id,
+	);
+	output.write_all(&checkpoint.encode())?;
+	output.flush()?;
+	Ok(())
+}
@@ -19,13 +19,19 @@
 //! Chain utilities.
 
 mod check_block;
+mod check_db;
 mod export_blocks;
 mod export_raw_state;
+mod export_warp_sync_checkpoint;
 mod import_blocks;
 mod revert_chain;
+mod snapshot;
 
 pub use check_block::*;
+pub use check_db::*;
 pub use export_blocks::*;
 pub use export_raw_state::*;
+pub use export_warp_sync_checkpoint::*;
 pub use import_blocks::*;
 pub use revert_chain::*;
+pub use snapshot::*;
@@ -0,0 +1,607 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Binary state snapshots.
+//!
+//! A snapshot holds the header, justifications and state of a finalized block. The finality of
+//! the block is proven by the warp sync proof from genesis, verified on import by the consensus
+//! warp sync provider. The state is stored as a sequence of compact range proofs, the same that
+//! are exchanged during state sync, so that importing a snapshot verifies every trie node against
+//! the state root of the header.
+//!
+//! File layout:
+//! - `SNAPSHOT_MAGIC`;
+//! - SCALE-encoded `SnapshotHeader`;
+//! - SCALE-encoded `Option<Vec<u8>>` records, each holding an encoded `CompactProof`, terminated by
+//!   `None`;
+//! - 32-byte checksum chaining the blake2-256 hashes of the header and of every record.
+
+use crate::error::Error;
+use codec::{Decode, Encode, IoReader as CodecIoReader};
+use futures::future;
+use log::{info, warn};
+use parking_lot::Mutex;
+use sc_client_api::{BlockBackend, HeaderBackend, ProofProvider};
+use sc_consensus::import_queue::{
+	BlockImportError, BlockImportStatus, ImportQueue, IncomingBlock, Link,
+};
+use sc_network_sync::{
+	strategy::{
+		state_sync::{ImportResult, StateSync, StateSyncProvider},
+		warp::{EncodedProof, VerificationResult, WarpSyncProvider},
+	},
+	StateResponse,
+};
+use smallvec::SmallVec;
+use sp_consensus::BlockOrigin;
+use sp_core::hashing::blake2_256;
+use sp_runtime::{
+	traits::{Block as BlockT, Header as HeaderT, NumberFor, Zero},
+	Justifications,
+};
+use std::{
+	io::{Read, Write},
+	sync::Arc,
+	task::Poll,
+};
+
+/// Magic bytes every snapshot file starts with.
+const SNAPSHOT_MAGIC: &[u8; 8] = b"SUBSNAPS";
+
+/// Version of the snapshot format.
+const SNAPSHOT_VERSION: u32 = 1;
+
+/// Maximum size of the state proven by a single record.
+const MAX_RECORD_BYTES: usize = 8 * 1024 * 1024;
+
+/// Snapshot metadata, written before the state records.
+#[derive(Encode, Decode)]
+struct SnapshotHeader<B: BlockT> {
+	version: u32,
+	genesis_hash: B::Hash,
+	header: B::Header,
+	justifications: Option<Justifications>,
+	/// Encoded warp sync proofs chaining the genesis authorities to the finality of `header`.
+	finality_proof: Vec<Vec<u8>>,
+}
+
+/// Running checksum of the snapshot content.
+#[derive(Default)]
+struct Checksum([u8; 32]);
+
+impl Checksum {
+	fn update(&mut self, data: &[u8]) {
+		self.0 = blake2_256(&[&self.0[..], &blake2_256(data)[..]].concat());
+	}
+}
+
+/// Generate the warp sync proofs from genesis, returning the block they finalize.
+fn generate_finality_proof<B: BlockT>(
+	warp_sync_provider: &dyn WarpSyncProvider<B>,
+	genesis_hash: B::Hash,
+) -> Result<(B::Hash, Vec<Vec<u8>>), Error> {
+	let mut begin = genesis_hash;
+	let mut parts = Vec::new();
+	loop {
+		let proof = warp_sync_provider
+			.generate(begin)
+			.map_err(|e| Error::Other(format!("Failed to generate the finality proof: {e}")))?;
+		let (header, is_finished) = warp_sync_provider
+			.proof_target(&proof)
+			.map_err(|e| Error::Other(format!("Failed to read the finality proof: {e}")))?;
+		parts.push(proof.0);
+		if is_finished {
+			return Ok((header.hash(), parts))
+		}
+		if header.hash() == begin {
+			return Err(Error::Other(format!("Finality proof does not advance past {begin:?}")))
+		}
+		begin = header.hash();
+	}
+}
+
+/// Verify that the warp sync proofs `parts` finalize `header`, starting from the genesis
+/// authorities.
+fn verify_finality_proof<B: BlockT>(
+	warp_sync_provider: &dyn WarpSyncProvider<B>,
+	parts: Vec<Vec<u8>>,
+	header: &B::Header,
+) -> Result<(), Error> {
+	let count = parts.len();
+	let mut set_id = 0;
+	let mut authorities = warp_sync_provider.current_authorities();
+	for (index, part) in parts.into_iter().enumerate() {
+		let result = warp_sync_provider
+			.verify(&EncodedProof(part), set_id, authorities)
+			.map_err(|e| Error::Other(format!("Invalid finality proof: {e}")))?;
+		match result {
+			VerificationResult::Partial(next_set_id, next_authorities, _) if index + 1 < count => {
+				set_id = next_set_id;
+				authorities = next_authorities;
+			},
+			VerificationResult::Complete(_, _, target) if index + 1 == count =>
+				return if target.hash() == header.hash() {
+					Ok(())
+				} else {
+					Err(Error::Other(format!(
+						"Finality proof is for block {:?}, not the snapshot block",
+						target.hash(),
+					)))
+				},
+			_ => return Err(Error::Other(format!("Unexpected end of finality proof #{index}"))),
+		}
+	}
+	Err(Error::Other("Snapshot has no finality proof".into()))
+}
+
+/// Export a snapshot of a finalized block to `output`.
+///
+/// With a `warp_sync_provider` the snapshot block is the one finalized by its warp sync proof, and
+/// `at` must match it if given. Without one, the snapshot of `at`, or of the last finalized block,
+/// carries no finality proof, which is only allowed with `skip_finality_check`.
+pub fn export_snapshot<B, C>(
+	client: Arc<C>,
+	at: Option<B::Hash>,
+	warp_sync_provider: Option<&dyn WarpSyncProvider<B>>,
+	skip_finality_check: bool,
+	mut output: impl Write,
+) -> Result<(), Error>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + BlockBackend<B> + ProofProvider<B>,
+{
+	let info = client.info();
+	let (hash, finality_proof) = match warp_sync_provider {
+		Some(warp_sync_provider) => {
+			let (hash, finality_proof) =
+				generate_finality_proof(warp_sync_provider, info.genesis_hash)?;
+			if at.is_some_and(|at| at != hash) {
+				return Err(Error::Other(format!(
+					"The latest finality proof is for block {hash:?}, not the requested block",
+				)))
+			}
+			(hash, finality_proof)
+		},
+		None if skip_finality_check => {
+			warn!("No warp sync provider, the snapshot will not prove the finality of its block");
+			(at.unwrap_or(info.finalized_hash), Vec::new())
+		},
+		None =>
+			return Err(Error::Other(
+				"No warp sync provider to prove the finality of the snapshot block".into(),
+			)),
+	};
+	let header = client
+		.header(hash)?
+		.ok_or_else(|| Error::Other(format!("Unknown block {hash:?}")))?;
+	let number = *header.number();
+	if number > info.finalized_number || client.hash(number)? != Some(hash) {
+		return Err(Error::Other(format!("Block {hash:?} (#{number}) is not finalized")))
+	}
+	let justifications = client.justifications(hash)?;
+
+	info!("Exporting snapshot of block #{number} ({hash:?})");
+	let state_root = *header.state_root();
+	let snapshot_header = SnapshotHeader::<B> {
+		version: SNAPSHOT_VERSION,
+		genesis_hash: info.genesis_hash,
+		header,
+		justifications,
+		finality_proof,
+	};
+	let mut checksum = Checksum::default();
+	let encoded = snapshot_header.encode();
+	checksum.update(&encoded);
+	output.write_all(SNAPSHOT_MAGIC)?;
+	output.write_all(&encoded)?;
+
+	let mut last_key = SmallVec::<[Vec<u8>; 2]>::new();
+	let mut records = 0u64;
+	let mut size = 0u64;
+	loop {
+		let (proof, _) =
+			client.read_proof_collection(hash, last_key.as_slice(), MAX_RECORD_BYTES)?;
+		// Verifying the proof is the only way to know where the next record starts.
+		let (values, completed) =
+			client.verify_range_proof(state_root, proof.clone(), last_key.as_slice())?;
+		let record = proof.encode();
+		checksum.update(&record);
+		output.write_all(&Some(&record).encode())?;
+		records += 1;
+		size += record.len() as u64;
+		if completed == 0 {
+			break
+		}
+		if !values.update_last_key(completed, &mut last_key) {
+			return Err(Error::Other(format!("Error updating key cursor, depth: {completed}")))
+		}
+	}
+	output.write_all(&None::<Vec<u8>>.encode())?;
+	output.write_all(&checksum.0)?;
+	output.flush()?;
+
+	info!("🎉 Exported {records} state records ({size} bytes) of block #{number}");
+	Ok(())
+}
+
+/// Read and verify a snapshot from `input`, returning the snapshot block ready to be imported
+/// with its state.
+///
+/// The finality of the snapshot block is verified with `warp_sync_provider`, which is required
+/// unless `skip_finality_check` is set.
+pub fn read_snapshot<B, C>(
+	client: Arc<C>,
+	warp_sync_provider: Option<&dyn WarpSyncProvider<B>>,
+	skip_finality_check: bool,
+	mut input: impl Read,
+) -> Result<IncomingBlock<B>, Error>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + ProofProvider<B> + Send + Sync + 'static,
+{
+	let mut magic = [0u8; 8];
+	input.read_exact(&mut magic)?;
+	if &magic != SNAPSHOT_MAGIC {
+		return Err(Error::Other("Not a snapshot file".into()))
+	}
+	let mut reader = CodecIoReader(&mut input);
+	let snapshot_header = SnapshotHeader::<B>::decode(&mut reader)
+		.map_err(|e| Error::Other(format!("Failed to decode the snapshot header: {e}")))?;
+	if snapshot_header.version != SNAPSHOT_VERSION {
+		return Err(Error::Other(format!(
+			"Unsupported snapshot version {}, expected {SNAPSHOT_VERSION}",
+			snapshot_header.version,
+		)))
+	}
+	let genesis_hash = client.info().genesis_hash;
+	if snapshot_header.genesis_hash != genesis_hash {
+		return Err(Error::Other(format!(
+			"Snapshot genesis {:?} does not match the chain genesis {genesis_hash:?}",
+			snapshot_header.genesis_hash,
+		)))
+	}
+
+	let mut checksum = Checksum::default();
+	checksum.update(&snapshot_header.encode());
+	let SnapshotHeader { header, justifications, finality_proof, .. } = snapshot_header;
+	info!("Reading snapshot of block #{} ({:?})", header.number(), header.hash());
+	match warp_sync_provider {
+		_ if skip_finality_check =>
+			warn!("Skipping the finality check, the snapshot block is imported as finalized"),
+		Some(warp_sync_provider) =>
+			verify_finality_proof(warp_sync_provider, finality_proof, &header)?,
+		None =>
+			return Err(Error::Other(
+				"No warp sync provider to verify the finality of the snapshot block".into(),
+			)),
+	}
+
+	let mut state_sync = StateSync::new(client, header, None, justifications, false);
+	let mut records = 0u64;
+	let mut block = None;
+	while let Some(record) = Option::<Vec<u8>>::decode(&mut reader)
+		.map_err(|e| Error::Other(format!("Failed to decode state record #{records}: {e}")))?
+	{
+		checksum.update(&record);
+		if block.is_some() {
+			return Err(Error::Other("Unexpected state record after the complete state".into()))
+		}
+		match state_sync.import(StateResponse { entries: Vec::new(), proof: record }) {
+			ImportResult::Import(hash, header, state, body, justifications) =>
+				block = Some(IncomingBlock {
+					hash,
+					header: Some(header),
+					body,
+					indexed_body: None,
+					justifications,
+					origin: None,
+					allow_missing_state: true,
+					import_existing: true,
+					skip_execution: true,
+					state: Some(state),
+				}),
+			ImportResult::Continue => {},
+			ImportResult::BadResponse =>
+				return Err(Error::Other(format!("Invalid state record #{records}"))),
+		}
+		records += 1;
+	}
+	let mut expected = [0u8; 32];
+	input.read_exact(&mut expected)?;
+	if expected != checksum.0 {
+		return Err(Error::Other("Snapshot checksum mismatch".into()))
+	}
+	info!("Verified {records} state records");
+	block.ok_or_else(|| Error::Other("Snapshot state is incomplete".into()))
+}
+
+/// Import a snapshot from `input` into an empty database, as if state sync had completed for the
+/// snapshot block.
+///
+/// See [`read_snapshot`] for the finality check.
+pub async fn import_snapshot<B, IQ, C>(
+	client: Arc<C>,
+	mut import_queue: IQ,
+	warp_sync_provider: Option<&dyn WarpSyncProvider<B>>,
+	skip_finality_check: bool,
+	input: impl Read,
+) -> Result<(), Error>
+where
+	B: BlockT,
+	C: HeaderBackend<B> + ProofProvider<B> + Send + Sync + 'static,
+	IQ: ImportQueue<B> + 'static,
+{
+	if !client.info().finalized_number.is_zero() {
+		return Err(Error::Other("Snapshots can only be imported into an empty database".into()))
+	}
+	let block = read_snapshot(client.clone(), warp_sync_provider, skip_finality_check, input)?;
+	info!("Importing snapshot block {:?}", block.hash);
+	import_queue
+		.service_ref()
+		.import_blocks(BlockOrigin::NetworkInitialSync, vec![block]);
+
+	#[derive(Default)]
+	struct WaitLink {
+		result: Mutex<Option<Result<(), String>>>,
+	}
+
+	impl<B: BlockT> Link<B> for WaitLink {
+		fn blocks_processed(
+			&self,
+			_imported: usize,
+			_count: usize,
+			results: Vec<(Result<BlockImportStatus<NumberFor<B>>, BlockImportError>, B::Hash)>,
+		) {
+			for (result, hash) in results {
+				*self.result.lock() = Some(
+					result.map(|_| ()).map_err(|e| format!("Error importing block {hash:?}: {e}")),
+				);
+			}
+		}
+	}
+
+	let link = WaitLink::default();
+	future::poll_fn(|cx| {
+		import_queue.poll_actions(cx, &link);
+		match link.result.lock().take() {
+			Some(result) => Poll::Ready(result),
+			None => Poll::Pending,
+		}
+	})
+	.await?;
+
+	info!("🎉 Imported snapshot. Best: #{}", client.info().best_number);
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sc_block_builder::BlockBuilderBuilder;
+	use sc_client_api::StorageProvider;
+	use sc_consensus::{BasicQueue, BlockImportParams, ForkChoiceStrategy, Verifier};
+	use sc_network_sync::strategy::warp::{AuthorityList, SetId};
+	use sp_core::storage::well_known_keys;
+	use substrate_test_runtime_client::{
+		prelude::*,
+		runtime::{Block, Header, Transfer},
+		Backend, Client,
+	};
+
+	/// Proves the finality of a single trusted header, encoded as the whole proof.
+	struct TestWarpSyncProvider(Header);
+
+	impl WarpSyncProvider<Block> for TestWarpSyncProvider {
+		fn generate(
+			&self,
+			_start: <Block as BlockT>::Hash,
+		) -> Result<EncodedProof, Box<dyn std::error::Error + Send + Sync>> {
+			Ok(EncodedProof(self.0.encode()))
+		}
+
+		fn verify(
+			&self,
+			proof: &EncodedProof,
+			set_id: SetId,
+			authorities: AuthorityList,
+		) -> Result<VerificationResult<Block>, Box<dyn std::error::Error + Send + Sync>> {
+			let header = Header::decode(&mut &proof.0[..])?;
+			if header != self.0 {
+				return Err("Untrusted header".into())
+			}
+			Ok(VerificationResult::Complete(set_id, authorities, header))
+		}
+
+		fn current_authorities(&self) -> AuthorityList {
+			Vec::new()
+		}
+
+		fn proof_target(
+			&self,
+			proof: &EncodedProof,
+		) -> Result<(Header, bool), Box<dyn std::error::Error + Send + Sync>> {
+			Ok((Header::decode(&mut &proof.0[..])?, true))
+		}
+	}
+
+	struct PassThroughVerifier;
+
+	#[async_trait::async_trait]
+	impl Verifier<Block> for PassThroughVerifier {
+		async fn verify(
+			&self,
+			mut block: BlockImportParams<Block>,
+		) -> Result<BlockImportParams<Block>, String> {
+			block.fork_choice = Some(ForkChoiceStrategy::LongestChain);
+			Ok(block)
+		}
+	}
+
+	fn export_genesis() -> (Arc<Client<Backend>>, Vec<u8>) {
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let mut snapshot = Vec::new();
+		export_snapshot::<Block, _>(client.clone(), None, None, true, &mut snapshot).unwrap();
+		(client, snapshot)
+	}
+
+	/// Build a client with three blocks, all finalized, returning their headers.
+	fn finalized_chain() -> (Arc<Client<Backend>>, Vec<Header>) {
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let mut headers = Vec::new();
+		let mut parent = client.chain_info().genesis_hash;
+		for number in 0..3u64 {
+			let mut builder = BlockBuilderBuilder::new(&*client)
+				.on_parent_block(parent)
+				.with_parent_block_number(number)
+				.build()
+				.unwrap();
+			builder
+				.push_transfer(Transfer {
+					from: Sr25519Keyring::Alice.into(),
+					to: Sr25519Keyring::Ferdie.into(),
+					amount: 1,
+					nonce: number,
+				})
+				.unwrap();
+			let block = builder.build().unwrap().block;
+			parent = block.header.hash();
+			headers.push(block.header.clone());
+			futures::executor::block_on(client.import(BlockOrigin::Own, block)).unwrap();
+		}
+		client.finalize_block(parent, None).unwrap();
+		(client, headers)
+	}
+
+	#[test]
+	fn snapshot_roundtrip_works() {
+		let (client, snapshot) = export_genesis();
+		let block = read_snapshot::<Block, _>(client.clone(), None, true, &snapshot[..]).unwrap();
+		assert_eq!(block.hash, client.info().genesis_hash);
+		assert!(block.skip_execution);
+		let state = block.state.unwrap();
+		assert!(state
+			.state
+			.0
+			.iter()
+			.flat_map(|level| level.key_values.iter())
+			.any(|(key, _)| key == well_known_keys::CODE));
+	}
+
+	#[test]
+	fn corrupted_snapshot_is_rejected() {
+		let (client, mut snapshot) = export_genesis();
+		let truncated = &snapshot[..snapshot.len() - 1];
+		assert!(read_snapshot::<Block, _>(client.clone(), None, true, truncated).is_err());
+		let last = snapshot.len() - 1;
+		snapshot[last] ^= 1;
+		assert!(read_snapshot::<Block, _>(client.clone(), None, true, &snapshot[..]).is_err());
+		snapshot[0] = 0;
+		assert!(read_snapshot::<Block, _>(client, None, true, &snapshot[..]).is_err());
+	}
+
+	#[test]
+	fn snapshot_of_finalized_block_imports_into_empty_database() {
+		let (client, headers) = finalized_chain();
+		let target = headers[2].clone();
+		let warp_sync_provider = TestWarpSyncProvider(target.clone());
+		let mut snapshot = Vec::new();
+		export_snapshot::<Block, _>(
+			client.clone(),
+			None,
+			Some(&warp_sync_provider),
+			false,
+			&mut snapshot,
+		)
+		.unwrap();
+
+		let empty = Arc::new(substrate_test_runtime_client::new());
+		let import_queue = BasicQueue::new(
+			PassThroughVerifier,
+			Box::new(empty.clone()),
+			None,
+			&sp_core::testing::TaskExecutor::new(),
+			None,
+		);
+		futures::executor::block_on(import_snapshot::<Block, _, _>(
+			empty.clone(),
+			import_queue,
+			Some(&warp_sync_provider),
+			false,
+			&snapshot[..],
+		))
+		.unwrap();
+
+		assert_eq!(empty.info().best_hash, target.hash());
+		let pairs = |client: &Client<Backend>| {
+			client.storage_pairs(target.hash(), None, None).unwrap().collect::<Vec<_>>()
+		};
+		assert_eq!(pairs(&empty), pairs(&client));
+	}
+
+	#[test]
+	fn snapshot_with_invalid_finality_proof_is_rejected() {
+		let (client, headers) = finalized_chain();
+		let warp_sync_provider = TestWarpSyncProvider(headers[2].clone());
+		let mut snapshot = Vec::new();
+		export_snapshot::<Block, _>(
+			client.clone(),
+			None,
+			Some(&warp_sync_provider),
+			false,
+			&mut snapshot,
+		)
+		.unwrap();
+
+		// The proof must chain to a header trusted by the verifier.
+		let untrusted = TestWarpSyncProvider(headers[1].clone());
+		let result =
+			read_snapshot::<Block, _>(client.clone(), Some(&untrusted), false, &snapshot[..]);
+		assert!(result.is_err());
+
+		// The proof must finalize the snapshot block.
+		let mut other = Vec::new();
+		let at = Some(headers[1].hash());
+		assert!(export_snapshot::<Block, _>(
+			client.clone(),
+			at,
+			Some(&warp_sync_provider),
+			false,
+			&mut other
+		)
+		.is_err());
+
+		// A snapshot without a proof is rejected when finality is verified.
+		export_snapshot::<Block, _>(client.clone(), at, None, true, &mut other).unwrap();
+		assert!(read_snapshot::<Block, _>(client.clone(), None, true, &other[..]).is_ok());
+		let result =
+			read_snapshot::<Block, _>(client.clone(), Some(&warp_sync_provider), false, &other[..]);
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn finality_check_is_only_skipped_explicitly() {
+		let (client, headers) = finalized_chain();
+		let mut snapshot = Vec::new();
+		let at = Some(headers[1].hash());
+		let result = export_snapshot::<Block, _>(client.clone(), at, None, false, &mut snapshot);
+		assert!(result.is_err());
+		export_snapshot::<Block, _>(client.clone(), at, None, true, &mut snapshot).unwrap();
+		assert!(read_snapshot::<Block, _>(client.clone(), None, false, &snapshot[..]).is_err());
+		assert!(read_snapshot::<Block, _>(client, None, true, &snapshot[..]).is_ok());
+	}
+}
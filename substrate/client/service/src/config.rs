@@ -34,7 +34,8 @@ pub use sc_network::{
 	Multiaddr,
 };
 pub use sc_rpc_server::{
-	IpNetwork, RpcEndpoint, RpcMethods, SubscriptionIdProvider as RpcSubscriptionIdProvider,
+	IpNetwork, RpcEndpoint, RpcMethodCost, RpcMethods,
+	SubscriptionIdProvider as RpcSubscriptionIdProvider,
 };
 pub use sc_telemetry::TelemetryEndpoints;
 pub use sc_transaction_pool::TransactionPoolOptions;
@@ -336,6 +337,10 @@ pub struct RpcConfiguration {
 	pub batch_config: RpcBatchRequestConfig,
 	/// RPC rate limit per minute.
 	pub rate_limit: Option<NonZeroU32>,
+	/// RPC rate limit per minute for each IP address.
+	pub rate_limit_per_ip: Option<NonZeroU32>,
+	/// RPC method costs counted against the rate limits.
+	pub rate_limit_method_costs: Vec<RpcMethodCost>,
 	/// RPC rate limit whitelisted ip addresses.
 	pub rate_limit_whitelisted_ips: Vec<IpNetwork>,
 	/// RPC rate limit trust proxy headers.
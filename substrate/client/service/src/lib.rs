@@ -88,7 +88,7 @@ use crate::config::RpcConfiguration;
 use prometheus_endpoint::Registry;
 pub use sc_consensus::ImportQueue;
 pub use sc_executor::NativeExecutionDispatch;
-pub use sc_network_sync::WarpSyncConfig;
+pub use sc_network_sync::{strategy::warp::WarpSyncProvider, WarpSyncCheckpoint, WarpSyncConfig};
 #[doc(hidden)]
 pub use sc_network_transactions::config::{TransactionImport, TransactionImportFuture};
 pub use sc_rpc::{RandomIntegerSubscriptionId, RandomStringSubscriptionId};
@@ -411,6 +411,8 @@ where
 				max_subscriptions_per_connection: rpc_configuration.max_subs_per_conn,
 				rpc_methods: rpc_configuration.methods.into(),
 				rate_limit: rpc_configuration.rate_limit,
+				rate_limit_per_ip: rpc_configuration.rate_limit_per_ip,
+				rate_limit_method_costs: rpc_configuration.rate_limit_method_costs.clone(),
 				rate_limit_trust_proxy_headers: rpc_configuration.rate_limit_trust_proxy_headers,
 				rate_limit_whitelisted_ips: rpc_configuration.rate_limit_whitelisted_ips.clone(),
 				retry_random_port: true,
@@ -427,6 +429,8 @@ where
 				max_subscriptions_per_connection: rpc_configuration.max_subs_per_conn,
 				rpc_methods: rpc_configuration.methods.into(),
 				rate_limit: rpc_configuration.rate_limit,
+				rate_limit_per_ip: rpc_configuration.rate_limit_per_ip,
+				rate_limit_method_costs: rpc_configuration.rate_limit_method_costs.clone(),
 				rate_limit_trust_proxy_headers: rpc_configuration.rate_limit_trust_proxy_headers,
 				rate_limit_whitelisted_ips: rpc_configuration.rate_limit_whitelisted_ips.clone(),
 				retry_random_port: true,
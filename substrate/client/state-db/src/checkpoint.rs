@@ -0,0 +1,256 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! State checkpoints.
+//!
+//! In the checkpoint pruning mode the state of every canonical block whose number is a multiple
+//! of the checkpoint period is kept in the database, while the states in between are pruned by
+//! the regular pruning window.
+//!
+//! To do so we track the nodes inserted by the canonical blocks following the last checkpoint.
+//! When a canonical block deletes a node that is not one of them, the node is still referenced by
+//! the checkpoint state and the deletion is dropped before it reaches the pruning window. The
+//! changes of every block since the last checkpoint are journaled in the DB, so the tracked sets
+//! can be rebuilt on startup. The journal is discarded once the next checkpoint is canonicalized.
+//!
+//! The tracking is conservative: a node that was retained for a checkpoint is never deleted
+//! again, even if it is re-inserted and deleted later on.
+
+use crate::{to_meta_key, CommitSet, Error, Hash, MetaDb, LOG_TARGET};
+use codec::{Decode, Encode};
+use log::trace;
+use std::collections::HashSet;
+
+pub(crate) const CHECKPOINT_RANGE: &[u8] = b"checkpoint_range";
+const CHECKPOINT_JOURNAL: &[u8] = b"checkpoint_journal";
+
+/// See module documentation.
+pub struct CheckpointTracker<Key: Hash> {
+	/// Number of blocks between two checkpoints.
+	period: u64,
+	/// Numbers of the first and the last canonicalized checkpoint blocks.
+	range: Option<(u64, u64)>,
+	/// Nodes inserted since the last checkpoint.
+	inserted: HashSet<Key>,
+	/// Nodes deleted since the last checkpoint that are kept for it.
+	retained: HashSet<Key>,
+}
+
+#[derive(Encode, Decode)]
+struct JournalRecord<Key: Hash> {
+	inserted: Vec<Key>,
+	deleted: Vec<Key>,
+}
+
+fn to_journal_key(block: u64) -> Vec<u8> {
+	to_meta_key(CHECKPOINT_JOURNAL, &block)
+}
+
+impl<Key: Hash> CheckpointTracker<Key> {
+	pub fn new<D: MetaDb>(db: &D, period: u64) -> Result<CheckpointTracker<Key>, Error<D::Error>> {
+		let range = match db.get_meta(&to_meta_key(CHECKPOINT_RANGE, &())).map_err(Error::Db)? {
+			Some(buffer) => Some(<(u64, u64)>::decode(&mut buffer.as_slice())?),
+			None => None,
+		};
+		let mut tracker = CheckpointTracker {
+			period,
+			range,
+			inserted: Default::default(),
+			retained: Default::default(),
+		};
+		if let Some((_, last)) = range {
+			trace!(target: LOG_TARGET, "Reading checkpoint journal. Last checkpoint #{}", last);
+			let mut block = last + 1;
+			while let Some(record) = db.get_meta(&to_journal_key(block)).map_err(Error::Db)? {
+				let record: JournalRecord<Key> = Decode::decode(&mut record.as_slice())?;
+				tracker.track(&record.inserted, &record.deleted);
+				block += 1;
+			}
+		}
+		Ok(tracker)
+	}
+
+	/// Check if the state of the canonical block with the given number is kept as a checkpoint.
+	pub fn is_checkpoint(&self, number: u64) -> bool {
+		number % self.period == 0 &&
+			self.range.map_or(false, |(first, last)| number >= first && number <= last)
+	}
+
+	/// Returns the kept checkpoint closest to the given block number, if any.
+	pub fn nearest(&self, number: u64) -> Option<u64> {
+		let (first, last) = self.range?;
+		let below = number - number % self.period;
+		let above = below.saturating_add(self.period);
+		if below < first {
+			Some(first)
+		} else if above > last {
+			Some(below.min(last))
+		} else if above - number < number - below {
+			Some(above)
+		} else {
+			Some(below)
+		}
+	}
+
+	/// Filter the deletions of a newly canonicalized block. Must be called for every canonical
+	/// block in order, with `commit` holding the changes of the block.
+	pub fn note_canonical(&mut self, number: u64, commit: &mut CommitSet<Key>) {
+		if let Some((_, last)) = self.range {
+			let inserted: Vec<Key> = commit.data.inserted.iter().map(|(k, _)| k.clone()).collect();
+			let deleted = std::mem::take(&mut commit.data.deleted);
+			commit.data.deleted = self.track(&inserted, &deleted);
+			trace!(
+				target: LOG_TARGET,
+				"Retaining {} nodes of #{} for checkpoint #{}",
+				deleted.len() - commit.data.deleted.len(),
+				number,
+				last,
+			);
+			if number % self.period != 0 {
+				let record = JournalRecord { inserted, deleted };
+				commit.meta.inserted.push((to_journal_key(number), record.encode()));
+			} else {
+				for block in last + 1..number {
+					commit.meta.deleted.push(to_journal_key(block));
+				}
+			}
+		}
+		if number % self.period == 0 {
+			trace!(target: LOG_TARGET, "New state checkpoint #{}", number);
+			let range = (self.range.map_or(number, |(first, _)| first), number);
+			commit.meta.inserted.push((to_meta_key(CHECKPOINT_RANGE, &()), range.encode()));
+			self.range = Some(range);
+			self.inserted.clear();
+			self.retained.clear();
+		}
+	}
+
+	/// Track the changes of a block following the last checkpoint. Returns the deleted nodes that
+	/// are not referenced by the checkpoint state.
+	fn track(&mut self, inserted: &[Key], deleted: &[Key]) -> Vec<Key> {
+		let mut prunable = Vec::new();
+		for k in deleted {
+			if self.inserted.remove(k) {
+				prunable.push(k.clone());
+			} else {
+				self.retained.insert(k.clone());
+			}
+		}
+		for k in inserted {
+			if !self.retained.contains(k) {
+				self.inserted.insert(k.clone());
+			}
+		}
+		prunable
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{to_journal_key, CheckpointTracker, CHECKPOINT_RANGE};
+	use crate::{
+		test::{make_commit, make_db, TestDb},
+		to_meta_key, MetaDb,
+	};
+	use sp_core::H256;
+
+	fn check_journal(tracker: &CheckpointTracker<H256>, db: &TestDb) {
+		let restored: CheckpointTracker<H256> = CheckpointTracker::new(db, tracker.period).unwrap();
+		assert_eq!(tracker.range, restored.range);
+		assert_eq!(tracker.inserted, restored.inserted);
+		assert_eq!(tracker.retained, restored.retained);
+	}
+
+	#[test]
+	fn nothing_is_retained_before_first_checkpoint() {
+		let mut db = make_db(&[1, 2]);
+		let mut tracker: CheckpointTracker<H256> = CheckpointTracker::new(&db, 10).unwrap();
+		let mut commit = make_commit(&[3], &[1]);
+		tracker.note_canonical(5, &mut commit);
+		db.commit(&commit);
+		assert_eq!(commit.data.deleted, vec![H256::from_low_u64_be(1)]);
+		assert_eq!(db.meta_len(), 0);
+		assert_eq!(tracker.range, None);
+		assert!(!tracker.is_checkpoint(0));
+	}
+
+	#[test]
+	fn retains_checkpoint_nodes() {
+		let mut db = make_db(&[1, 2]);
+		let mut tracker: CheckpointTracker<H256> = CheckpointTracker::new(&db, 10).unwrap();
+
+		let mut commit = make_commit(&[3], &[]);
+		tracker.note_canonical(10, &mut commit);
+		db.commit(&commit);
+		assert!(db.get_meta(&to_meta_key(CHECKPOINT_RANGE, &())).unwrap().is_some());
+		assert!(tracker.is_checkpoint(10));
+		check_journal(&tracker, &db);
+
+		// Node 1 belongs to the checkpoint state, node 4 does not.
+		let mut commit = make_commit(&[4, 5], &[1]);
+		tracker.note_canonical(11, &mut commit);
+		db.commit(&commit);
+		assert!(commit.data.deleted.is_empty());
+		check_journal(&tracker, &db);
+
+		let mut commit = make_commit(&[1], &[4, 3]);
+		tracker.note_canonical(12, &mut commit);
+		db.commit(&commit);
+		assert_eq!(commit.data.deleted, vec![H256::from_low_u64_be(4)]);
+		check_journal(&tracker, &db);
+
+		// Re-inserted checkpoint nodes are still kept.
+		let mut commit = make_commit(&[], &[1, 5]);
+		tracker.note_canonical(13, &mut commit);
+		db.commit(&commit);
+		assert_eq!(commit.data.deleted, vec![H256::from_low_u64_be(5)]);
+		check_journal(&tracker, &db);
+
+		for number in 14..20 {
+			let mut commit = make_commit(&[], &[]);
+			tracker.note_canonical(number, &mut commit);
+			db.commit(&commit);
+		}
+		let mut commit = make_commit(&[], &[2]);
+		tracker.note_canonical(20, &mut commit);
+		db.commit(&commit);
+		assert!(commit.data.deleted.is_empty());
+		assert_eq!(tracker.range, Some((10, 20)));
+		assert!(tracker.inserted.is_empty());
+		assert!(tracker.retained.is_empty());
+		assert!((11..20).all(|n| db.get_meta(&to_journal_key(n)).unwrap().is_none()));
+		check_journal(&tracker, &db);
+	}
+
+	#[test]
+	fn nearest_checkpoint() {
+		let mut tracker: CheckpointTracker<H256> =
+			CheckpointTracker::new(&make_db(&[]), 1000).unwrap();
+		assert_eq!(tracker.nearest(1200), None);
+		for number in [1000, 2000, 3000] {
+			tracker.note_canonical(number, &mut make_commit(&[], &[]));
+		}
+		assert_eq!(tracker.nearest(1200), Some(1000));
+		assert_eq!(tracker.nearest(1500), Some(1000));
+		assert_eq!(tracker.nearest(1501), Some(2000));
+		assert_eq!(tracker.nearest(3700), Some(3000));
+		assert_eq!(tracker.nearest(5000), Some(3000));
+		assert_eq!(tracker.nearest(200), Some(1000));
+		assert!(!tracker.is_checkpoint(0));
+	}
+}
@@ -40,13 +40,19 @@
 //! # Pruning.
 //! See `RefWindow` for pruning algorithm details. `StateDb` prunes on each canonicalization until
 //! pruning constraints are satisfied.
+//!
+//! # Checkpoints.
+//! See `CheckpointTracker` for details. In the checkpoint mode the state of every canonical block
+//! whose number is a multiple of the checkpoint period is excluded from pruning.
 
+mod checkpoint;
 mod noncanonical;
 mod pruning;
 #[cfg(test)]
 mod test;
 
-use codec::Codec;
+use checkpoint::CheckpointTracker;
+use codec::{Codec, Decode, Encode};
 use log::trace;
 use noncanonical::NonCanonicalOverlay;
 use parking_lot::RwLock;
@@ -54,6 +60,7 @@ use pruning::{HaveBlock, RefWindow};
 use std::{
 	collections::{hash_map::Entry, HashMap},
 	fmt,
+	num::NonZeroU32,
 };
 
 const LOG_TARGET: &str = "state-db";
@@ -62,6 +69,8 @@ const PRUNING_MODE: &[u8] = b"mode";
 const PRUNING_MODE_ARCHIVE: &[u8] = b"archive";
 const PRUNING_MODE_ARCHIVE_CANON: &[u8] = b"archive_canonical";
 const PRUNING_MODE_CONSTRAINED: &[u8] = b"constrained";
+const PRUNING_MODE_CHECKPOINTS: &[u8] = b"checkpoints";
+const CHECKPOINT_PERIOD: &[u8] = b"checkpoint_period";
 pub(crate) const DEFAULT_MAX_BLOCK_CONSTRAINT: u32 = 256;
 
 /// Database value type.
@@ -235,6 +244,14 @@ pub enum PruningMode {
 	ArchiveAll,
 	/// Canonicalization discards non-canonical nodes. All the canonical nodes are kept in the DB.
 	ArchiveCanonical,
+	/// Maintain a pruning window, but keep the state of every canonical block whose number is a
+	/// multiple of `period`.
+	Checkpoints {
+		/// Number of blocks between two state checkpoints.
+		period: NonZeroU32,
+		/// Constraints of the pruning window.
+		constraints: Constraints,
+	},
 }
 
 impl PruningMode {
@@ -243,11 +260,16 @@ impl PruningMode {
 		PruningMode::Constrained(Constraints { max_blocks: Some(n) })
 	}
 
+	/// Create a mode that keeps given number of blocks and the state of every `period`-th block.
+	pub fn checkpoints(period: NonZeroU32, n: u32) -> PruningMode {
+		PruningMode::Checkpoints { period, constraints: Constraints { max_blocks: Some(n) } }
+	}
+
 	/// Is this an archive (either ArchiveAll or ArchiveCanonical) pruning mode?
 	pub fn is_archive(&self) -> bool {
 		match *self {
 			PruningMode::ArchiveAll | PruningMode::ArchiveCanonical => true,
-			PruningMode::Constrained(_) => false,
+			PruningMode::Constrained(_) | PruningMode::Checkpoints { .. } => false,
 		}
 	}
 
@@ -257,9 +279,12 @@ impl PruningMode {
 			PruningMode::ArchiveAll => PRUNING_MODE_ARCHIVE,
 			PruningMode::ArchiveCanonical => PRUNING_MODE_ARCHIVE_CANON,
 			PruningMode::Constrained(_) => PRUNING_MODE_CONSTRAINED,
+			PruningMode::Checkpoints { .. } => PRUNING_MODE_CHECKPOINTS,
 		}
 	}
 
+	/// Returns the pruning mode for the given id. The checkpoint mode is not returned, as its
+	/// period is stored separately.
 	pub fn from_id(id: &[u8]) -> Option<Self> {
 		match id {
 			PRUNING_MODE_ARCHIVE => Some(Self::ArchiveAll),
@@ -268,6 +293,15 @@ impl PruningMode {
 			_ => None,
 		}
 	}
+
+	/// Returns the constraints of the pruning window, if any.
+	fn constraints(&self) -> Option<&Constraints> {
+		match self {
+			PruningMode::Constrained(constraints) |
+			PruningMode::Checkpoints { constraints, .. } => Some(constraints),
+			PruningMode::ArchiveAll | PruningMode::ArchiveCanonical => None,
+		}
+	}
 }
 
 impl Default for PruningMode {
@@ -303,6 +337,7 @@ pub struct StateDbSync<BlockHash: Hash, Key: Hash, D: MetaDb> {
 	mode: PruningMode,
 	non_canonical: NonCanonicalOverlay<BlockHash, Key>,
 	pruning: Option<RefWindow<BlockHash, Key, D>>,
+	checkpoints: Option<CheckpointTracker<Key>>,
 	pinned: HashMap<BlockHash, u32>,
 	ref_counting: bool,
 }
@@ -316,13 +351,25 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 		trace!(target: LOG_TARGET, "StateDb settings: {:?}. Ref-counting: {}", mode, ref_counting);
 
 		let non_canonical: NonCanonicalOverlay<BlockHash, Key> = NonCanonicalOverlay::new(&db)?;
-		let pruning: Option<RefWindow<BlockHash, Key, D>> = match mode {
-			PruningMode::Constrained(Constraints { max_blocks }) =>
+		let checkpoints = match mode {
+			PruningMode::Checkpoints { period, .. } =>
+				Some(CheckpointTracker::new(&db, period.get() as u64)?),
+			_ => None,
+		};
+		let pruning: Option<RefWindow<BlockHash, Key, D>> = match mode.constraints() {
+			Some(Constraints { max_blocks }) =>
 				Some(RefWindow::new(db, max_blocks.unwrap_or(0), ref_counting)?),
-			PruningMode::ArchiveAll | PruningMode::ArchiveCanonical => None,
+			None => None,
 		};
 
-		Ok(StateDbSync { mode, non_canonical, pruning, pinned: Default::default(), ref_counting })
+		Ok(StateDbSync {
+			mode,
+			non_canonical,
+			pruning,
+			checkpoints,
+			pinned: Default::default(),
+			ref_counting,
+		})
 	}
 
 	fn insert_block(
@@ -338,7 +385,9 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 				// write changes immediately
 				Ok(CommitSet { data: changeset, meta: Default::default() })
 			},
-			PruningMode::Constrained(_) | PruningMode::ArchiveCanonical => self
+			PruningMode::Constrained(_) |
+			PruningMode::ArchiveCanonical |
+			PruningMode::Checkpoints { .. } => self
 				.non_canonical
 				.insert(hash, number, parent_hash, changeset)
 				.map_err(Into::into),
@@ -358,6 +407,9 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 		if self.mode == PruningMode::ArchiveCanonical {
 			commit.data.deleted.clear();
 		}
+		if let Some(ref mut checkpoints) = self.checkpoints {
+			checkpoints.note_canonical(number, &mut commit);
+		}
 		if let Some(ref mut pruning) = self.pruning {
 			pruning.note_canonical(hash, number, &mut commit)?;
 		}
@@ -380,7 +432,9 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 	fn is_pruned(&self, hash: &BlockHash, number: u64) -> IsPruned {
 		match self.mode {
 			PruningMode::ArchiveAll => IsPruned::NotPruned,
-			PruningMode::ArchiveCanonical | PruningMode::Constrained(_) => {
+			PruningMode::ArchiveCanonical |
+			PruningMode::Constrained(_) |
+			PruningMode::Checkpoints { .. } => {
 				if self
 					.non_canonical
 					.last_canonicalized_block_number()
@@ -397,6 +451,8 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 						// We don't know for sure.
 						None => IsPruned::MaybePruned,
 						Some(pruning) => match pruning.have_block(hash, number) {
+							// The number matches a checkpoint, but the block may be non-canonical.
+							HaveBlock::No if self.is_checkpoint(number) => IsPruned::MaybePruned,
 							HaveBlock::No => IsPruned::Pruned,
 							HaveBlock::Yes => IsPruned::NotPruned,
 							HaveBlock::Maybe => IsPruned::MaybePruned,
@@ -407,9 +463,17 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 		}
 	}
 
+	fn is_checkpoint(&self, number: u64) -> bool {
+		self.checkpoints.as_ref().map_or(false, |c| c.is_checkpoint(number))
+	}
+
+	fn nearest_checkpoint(&self, number: u64) -> Option<u64> {
+		self.checkpoints.as_ref().and_then(|c| c.nearest(number))
+	}
+
 	fn prune(&mut self, commit: &mut CommitSet<Key>) -> Result<(), Error<D::Error>> {
-		if let (&mut Some(ref mut pruning), PruningMode::Constrained(constraints)) =
-			(&mut self.pruning, &self.mode)
+		if let (&mut Some(ref mut pruning), Some(constraints)) =
+			(&mut self.pruning, self.mode.constraints())
 		{
 			loop {
 				if pruning.window_size() <= constraints.max_blocks.unwrap_or(0) as u64 {
@@ -442,16 +506,18 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 	fn revert_one(&mut self) -> Option<CommitSet<Key>> {
 		match self.mode {
 			PruningMode::ArchiveAll => Some(CommitSet::default()),
-			PruningMode::ArchiveCanonical | PruningMode::Constrained(_) =>
-				self.non_canonical.revert_one(),
+			PruningMode::ArchiveCanonical |
+			PruningMode::Constrained(_) |
+			PruningMode::Checkpoints { .. } => self.non_canonical.revert_one(),
 		}
 	}
 
 	fn remove(&mut self, hash: &BlockHash) -> Option<CommitSet<Key>> {
 		match self.mode {
 			PruningMode::ArchiveAll => Some(CommitSet::default()),
-			PruningMode::ArchiveCanonical | PruningMode::Constrained(_) =>
-				self.non_canonical.remove(hash),
+			PruningMode::ArchiveCanonical |
+			PruningMode::Constrained(_) |
+			PruningMode::Checkpoints { .. } => self.non_canonical.remove(hash),
 		}
 	}
 
@@ -461,11 +527,14 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDbSync<BlockHash, Key, D> {
 	{
 		match self.mode {
 			PruningMode::ArchiveAll => Ok(()),
-			PruningMode::ArchiveCanonical | PruningMode::Constrained(_) => {
+			PruningMode::ArchiveCanonical |
+			PruningMode::Constrained(_) |
+			PruningMode::Checkpoints { .. } => {
 				let have_block = self.non_canonical.have_block(hash) ||
 					self.pruning.as_ref().map_or_else(
 						|| hint(),
 						|pruning| match pruning.have_block(hash, number) {
+							HaveBlock::No if self.is_checkpoint(number) => hint(),
 							HaveBlock::No => false,
 							HaveBlock::Yes => true,
 							HaveBlock::Maybe => hint(),
@@ -564,6 +633,12 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDb<BlockHash, Key, D> {
 
 			cs.meta.inserted.push((key, value));
 
+			if let PruningMode::Checkpoints { period, .. } = selected_mode {
+				cs.meta
+					.inserted
+					.push((to_meta_key(CHECKPOINT_PERIOD, &()), period.get().encode()));
+			}
+
 			cs
 		} else {
 			Default::default()
@@ -652,6 +727,12 @@ impl<BlockHash: Hash, Key: Hash, D: MetaDb> StateDb<BlockHash, Key, D> {
 		self.db.read().is_pruned(hash, number)
 	}
 
+	/// Returns the number of the state checkpoint closest to the given block number.
+	/// Always `None` unless the pruning mode is `Checkpoints`.
+	pub fn nearest_checkpoint(&self, number: u64) -> Option<u64> {
+		self.db.read().nearest_checkpoint(number)
+	}
+
 	/// Reset in-memory changes to the last disk-backed state.
 	pub fn reset(&self, db: D) -> Result<(), Error<D::Error>> {
 		let mut state_db = self.db.write();
@@ -674,7 +755,18 @@ pub enum IsPruned {
 fn fetch_stored_pruning_mode<D: MetaDb>(db: &D) -> Result<Option<PruningMode>, Error<D::Error>> {
 	let meta_key_mode = to_meta_key(PRUNING_MODE, &());
 	if let Some(stored_mode) = db.get_meta(&meta_key_mode).map_err(Error::Db)? {
-		if let Some(mode) = PruningMode::from_id(&stored_mode) {
+		if stored_mode == PRUNING_MODE_CHECKPOINTS {
+			let period = db
+				.get_meta(&to_meta_key(CHECKPOINT_PERIOD, &()))
+				.map_err(Error::Db)?
+				.map(|buffer| u32::decode(&mut buffer.as_slice()))
+				.transpose()?
+				.and_then(NonZeroU32::new)
+				.ok_or_else(|| {
+					StateDbError::Metadata("Invalid or missing CHECKPOINT_PERIOD".into())
+				})?;
+			Ok(Some(PruningMode::Checkpoints { period, constraints: Default::default() }))
+		} else if let Some(mode) = PruningMode::from_id(&stored_mode) {
 			Ok(Some(mode))
 		} else {
 			Err(StateDbError::Metadata(format!(
@@ -698,6 +790,10 @@ fn choose_pruning_mode(
 			Ok(PruningMode::ArchiveCanonical),
 		(PruningMode::Constrained(_), PruningMode::Constrained(requested)) =>
 			Ok(PruningMode::Constrained(requested)),
+		(
+			PruningMode::Checkpoints { period: stored, .. },
+			PruningMode::Checkpoints { period, constraints },
+		) if stored == period => Ok(PruningMode::Checkpoints { period, constraints }),
 		(stored, requested) => Err(StateDbError::IncompatiblePruningModes { requested, stored }),
 	}
 }
@@ -707,8 +803,10 @@ mod tests {
 	use crate::{
 		test::{make_changeset, make_db, TestDb},
 		Constraints, Error, IsPruned, PruningMode, StateDb, StateDbError,
+		DEFAULT_MAX_BLOCK_CONSTRAINT,
 	};
 	use sp_core::H256;
+	use std::num::NonZeroU32;
 
 	fn make_test_db(settings: PruningMode) -> (TestDb, StateDb<H256, H256, TestDb>) {
 		let mut db = make_db(&[91, 921, 922, 93, 94]);
@@ -847,6 +945,47 @@ mod tests {
 		assert!(db.data_eq(&make_db(&[1, 21, 3, 921, 922, 93, 94])));
 	}
 
+	#[test]
+	fn checkpoints_keep_checkpoint_state() {
+		let period = NonZeroU32::new(2).unwrap();
+		let (db, sdb) = make_test_db(PruningMode::checkpoints(period, 0));
+		assert!(db.data_eq(&make_db(&[21, 3, 922, 93, 94])));
+		assert_eq!(sdb.is_pruned(&H256::from_low_u64_be(1), 1), IsPruned::Pruned);
+		assert_eq!(sdb.is_pruned(&H256::from_low_u64_be(21), 2), IsPruned::MaybePruned);
+		assert_eq!(sdb.nearest_checkpoint(1), Some(2));
+		assert_eq!(sdb.nearest_checkpoint(3), Some(2));
+	}
+
+	#[test]
+	fn checkpoints_survive_restart() {
+		let period = NonZeroU32::new(2).unwrap();
+		let (mut db, _) = make_test_db(PruningMode::checkpoints(period, 0));
+		let (_, sdb): (_, StateDb<H256, H256, TestDb>) =
+			StateDb::open(db.clone(), None, false, false).unwrap();
+		assert_eq!(
+			sdb.pruning_mode(),
+			PruningMode::checkpoints(period, DEFAULT_MAX_BLOCK_CONSTRAINT)
+		);
+		let sdb: StateDb<H256, H256, TestDb> =
+			StateDb::open(db.clone(), Some(PruningMode::checkpoints(period, 0)), false, false)
+				.unwrap()
+				.1;
+		// Node 94 belongs to checkpoint #2, node 3 was inserted after it.
+		db.commit(
+			&sdb.insert_block(
+				&H256::from_low_u64_be(44),
+				4,
+				&H256::from_low_u64_be(3),
+				make_changeset(&[44], &[94, 3]),
+			)
+			.unwrap(),
+		);
+		db.commit(&sdb.canonicalize_block(&H256::from_low_u64_be(44)).unwrap());
+		assert!(db.data_eq(&make_db(&[21, 44, 922, 93, 94])));
+		assert_eq!(sdb.nearest_checkpoint(3), Some(2));
+		assert_eq!(sdb.nearest_checkpoint(5), Some(4));
+	}
+
 	#[test]
 	fn detects_incompatible_mode() {
 		let mut db = make_db(&[]);
@@ -897,6 +1036,7 @@ mod tests {
 
 	#[test]
 	fn pruning_mode_compatibility() {
+		let period = NonZeroU32::new(1000).unwrap();
 		for (created, reopened, expected) in [
 			(None, None, Ok(PruningMode::blocks_pruning(256))),
 			(None, Some(PruningMode::blocks_pruning(256)), Ok(PruningMode::blocks_pruning(256))),
@@ -942,6 +1082,32 @@ mod tests {
 				Some(PruningMode::ArchiveCanonical),
 				Ok(PruningMode::ArchiveCanonical),
 			),
+			(
+				Some(PruningMode::checkpoints(period, 256)),
+				None,
+				Ok(PruningMode::checkpoints(period, 256)),
+			),
+			(
+				Some(PruningMode::checkpoints(period, 256)),
+				Some(PruningMode::checkpoints(period, 128)),
+				Ok(PruningMode::checkpoints(period, 128)),
+			),
+			(
+				Some(PruningMode::checkpoints(period, 256)),
+				Some(PruningMode::checkpoints(NonZeroU32::new(500).unwrap(), 256)),
+				Err(()),
+			),
+			(
+				Some(PruningMode::checkpoints(period, 256)),
+				Some(PruningMode::blocks_pruning(256)),
+				Err(()),
+			),
+			(
+				Some(PruningMode::blocks_pruning(256)),
+				Some(PruningMode::checkpoints(period, 256)),
+				Err(()),
+			),
+			(Some(PruningMode::ArchiveAll), Some(PruningMode::checkpoints(period, 256)), Err(())),
 		] {
 			check_stored_and_requested_mode_compatibility(created, reopened, expected);
 		}
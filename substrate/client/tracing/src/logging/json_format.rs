@@ -0,0 +1,190 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	layers::{Prefix, SpanFields, PREFIX_LOG_SPAN},
+	EventFormat,
+};
+use chrono::SecondsFormat;
+use serde_json::{Map, Value};
+use std::fmt;
+use tracing::{field::Field, Event, Subscriber};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::{
+	fmt::{format, FmtContext, FormatEvent, FormatFields},
+	registry::LookupSpan,
+};
+
+/// The format of the log output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+	/// Human readable text lines.
+	#[default]
+	Text,
+	/// One JSON object per line.
+	Json,
+}
+
+/// An event formatter writing every event as a single line JSON object.
+///
+/// The object contains the `timestamp` (RFC 3339, UTC), `level`, `target`, `thread`, `message`,
+/// the `node` name and the log `prefix` if available, the other `fields` of the event and the
+/// `spans` the event was emitted in, starting from the root span.
+#[derive(Debug, Clone, Default)]
+pub struct JsonEventFormat {
+	/// The name of the node added to every event.
+	pub node_name: Option<String>,
+}
+
+impl<S, N> FormatEvent<S, N> for JsonEventFormat
+where
+	S: Subscriber + for<'a> LookupSpan<'a>,
+	N: for<'a> FormatFields<'a> + 'static,
+{
+	fn format_event(
+		&self,
+		ctx: &FmtContext<S, N>,
+		mut writer: format::Writer<'_>,
+		event: &Event,
+	) -> fmt::Result {
+		let normalized_meta = event.normalized_metadata();
+		let meta = normalized_meta.as_ref().unwrap_or_else(|| event.metadata());
+
+		let mut record = Map::new();
+		record.insert(
+			"timestamp".into(),
+			chrono::Utc::now().to_rfc3339_opts(SecondsFormat::Micros, true).into(),
+		);
+		record.insert("level".into(), meta.level().as_str().into());
+		record.insert("target".into(), meta.target().into());
+
+		if let Some(node_name) = self.node_name.as_ref() {
+			record.insert("node".into(), node_name.clone().into());
+		}
+
+		if let Some(thread_name) = std::thread::current().name() {
+			record.insert("thread".into(), thread_name.into());
+		}
+
+		let mut fields = Map::new();
+		event.record(&mut JsonVisitor(&mut fields));
+
+		if let Some(message) = fields.remove("message") {
+			record.insert("message".into(), message);
+		}
+
+		if !fields.is_empty() {
+			record.insert("fields".into(), fields.into());
+		}
+
+		let mut spans = Vec::new();
+		if let Some(span) = ctx.lookup_current() {
+			for span in span.scope().from_root() {
+				let exts = span.extensions();
+
+				if span.name() == PREFIX_LOG_SPAN {
+					if let Some(prefix) = exts.get::<Prefix>() {
+						record.insert("prefix".into(), prefix.name().into());
+					}
+					continue
+				}
+
+				let mut entry = Map::new();
+				entry.insert("name".into(), span.name().into());
+				if let Some(fields) = exts.get::<SpanFields>().filter(|f| !f.0.is_empty()) {
+					entry.insert("fields".into(), fields.0.clone().into());
+				}
+				spans.push(Value::Object(entry));
+			}
+		}
+
+		if !spans.is_empty() {
+			record.insert("spans".into(), spans.into());
+		}
+
+		let json = serde_json::to_string(&record).map_err(|_| fmt::Error)?;
+		writeln!(writer, "{json}")
+	}
+}
+
+/// Event formatter of the selected [`LogFormat`].
+pub(crate) enum LogEventFormat {
+	Text(EventFormat),
+	Json(JsonEventFormat),
+}
+
+impl<S, N> FormatEvent<S, N> for LogEventFormat
+where
+	S: Subscriber + for<'a> LookupSpan<'a>,
+	N: for<'a> FormatFields<'a> + 'static,
+{
+	fn format_event(
+		&self,
+		ctx: &FmtContext<S, N>,
+		writer: format::Writer<'_>,
+		event: &Event,
+	) -> fmt::Result {
+		match self {
+			Self::Text(format) => format.format_event(ctx, writer, event),
+			Self::Json(format) => format.format_event(ctx, writer, event),
+		}
+	}
+}
+
+/// Records the fields as JSON values.
+///
+/// The fields added by `tracing-log` for the events coming from the `log` crate are skipped.
+pub(crate) struct JsonVisitor<'a>(pub(crate) &'a mut Map<String, Value>);
+
+impl<'a> JsonVisitor<'a> {
+	fn insert(&mut self, field: &Field, value: Value) {
+		if !field.name().starts_with("log.") {
+			self.0.insert(field.name().into(), value);
+		}
+	}
+}
+
+impl<'a> tracing::field::Visit for JsonVisitor<'a> {
+	fn record_f64(&mut self, field: &Field, value: f64) {
+		self.insert(field, value.into());
+	}
+
+	fn record_i64(&mut self, field: &Field, value: i64) {
+		self.insert(field, value.into());
+	}
+
+	fn record_u64(&mut self, field: &Field, value: u64) {
+		self.insert(field, value.into());
+	}
+
+	fn record_bool(&mut self, field: &Field, value: bool) {
+		self.insert(field, value.into());
+	}
+
+	fn record_str(&mut self, field: &Field, value: &str) {
+		self.insert(field, value.into());
+	}
+
+	fn record_error(&mut self, field: &Field, value: &(dyn std::error::Error + 'static)) {
+		self.insert(field, value.to_string().into());
+	}
+
+	fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+		self.insert(field, format!("{:?}", value).into());
+	}
+}
@@ -92,4 +92,9 @@ impl Prefix {
 	pub(crate) fn as_str(&self) -> &str {
 		self.0.as_str()
 	}
+
+	/// Returns the prefix without the surrounding brackets.
+	pub(crate) fn name(&self) -> &str {
+		self.0.trim_end().trim_start_matches('[').trim_end_matches(']')
+	}
 }
@@ -0,0 +1,62 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::logging::json_format::JsonVisitor;
+use serde_json::{Map, Value};
+use tracing::{
+	span::{Attributes, Record},
+	Id, Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+/// A `Layer` that records the fields of the spans, which are then used by
+/// [`crate::logging::JsonEventFormat`] to add the span context to the log records.
+pub struct SpanFieldsLayer;
+
+impl<S> Layer<S> for SpanFieldsLayer
+where
+	S: Subscriber + for<'a> LookupSpan<'a>,
+{
+	fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+		let Some(span) = ctx.span(id) else {
+			// this shouldn't happen!
+			debug_assert!(
+				false,
+				"newly created span with ID {:?} did not exist in the registry; this is a bug!",
+				id
+			);
+			return
+		};
+
+		let mut fields = Map::new();
+		attrs.record(&mut JsonVisitor(&mut fields));
+		span.extensions_mut().insert(SpanFields(fields));
+	}
+
+	fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+		if let Some(span) = ctx.span(id) {
+			if let Some(fields) = span.extensions_mut().get_mut::<SpanFields>() {
+				values.record(&mut JsonVisitor(&mut fields.0));
+			}
+		}
+	}
+}
+
+/// The fields of a span, as JSON values.
+#[derive(Debug)]
+pub(crate) struct SpanFields(pub(crate) Map<String, Value>);
@@ -25,7 +25,9 @@
 mod directives;
 mod event_format;
 mod fast_local_time;
+mod json_format;
 mod layers;
+mod otlp;
 mod stderr_writer;
 
 pub(crate) type DefaultLogger = stderr_writer::MakeStderrWriter;
@@ -49,8 +51,11 @@ use tracing_subscriber::{
 
 pub use event_format::*;
 pub use fast_local_time::FastLocalTime;
+pub use json_format::{JsonEventFormat, LogFormat};
 pub use layers::*;
+pub use otlp::{shutdown_otlp_exporter, OtlpConfig, OtlpProtocol};
 
+use json_format::LogEventFormat;
 use stderr_writer::MakeStderrWriter;
 
 /// Logging Result typedef.
@@ -66,6 +71,7 @@ pub enum Error {
 	SetGlobalDefaultError(#[from] tracing::subscriber::SetGlobalDefaultError),
 	DirectiveParseError(#[from] tracing_subscriber::filter::ParseError),
 	SetLoggerError(#[from] tracing_log::log_tracer::SetLoggerError),
+	OtlpError(#[from] opentelemetry::trace::TraceError),
 }
 
 macro_rules! enable_log_reloading {
@@ -97,8 +103,10 @@ fn prepare_subscriber<N, E, F, W>(
 	profiling_targets: Option<&str>,
 	force_colors: Option<bool>,
 	detailed_output: bool,
+	log_format: LogFormat,
+	node_name: Option<&str>,
 	builder_hook: impl Fn(
-		SubscriberBuilder<format::DefaultFields, EventFormat, EnvFilter, DefaultLogger>,
+		SubscriberBuilder<format::DefaultFields, LogEventFormat, EnvFilter, DefaultLogger>,
 	) -> SubscriberBuilder<N, E, F, W>,
 ) -> Result<impl Subscriber + for<'a> LookupSpan<'a>>
 where
@@ -182,19 +190,25 @@ where
 		_ => true,
 	} || detailed_output;
 
-	let enable_color = force_colors.unwrap_or_else(|| io::stderr().is_terminal());
+	// The JSON output is meant to be processed by machines, so it is never colored.
+	let enable_color =
+		log_format == LogFormat::Text && force_colors.unwrap_or_else(|| io::stderr().is_terminal());
 	let timer = fast_local_time::FastLocalTime { with_fractional: detailed_output };
 
 	// We need to set both together, because we are may printing to `stdout` and `stderr`.
 	console::set_colors_enabled(enable_color);
 	console::set_colors_enabled_stderr(enable_color);
 
-	let event_format = EventFormat {
-		timer,
-		display_target: detailed_output,
-		display_level: detailed_output,
-		display_thread_name: detailed_output,
-		dup_to_stdout: !io::stderr().is_terminal() && io::stdout().is_terminal(),
+	let event_format = match log_format {
+		LogFormat::Text => LogEventFormat::Text(EventFormat {
+			timer,
+			display_target: detailed_output,
+			display_level: detailed_output,
+			display_thread_name: detailed_output,
+			dup_to_stdout: !io::stderr().is_terminal() && io::stdout().is_terminal(),
+		}),
+		LogFormat::Json =>
+			LogEventFormat::Json(JsonEventFormat { node_name: node_name.map(Into::into) }),
 	};
 	let builder = FmtSubscriber::builder().with_env_filter(env_filter);
 
@@ -206,7 +220,10 @@ where
 
 	let builder = builder_hook(builder);
 
-	let subscriber = builder.finish().with(PrefixLayer);
+	let subscriber = builder
+		.finish()
+		.with(PrefixLayer)
+		.with((log_format == LogFormat::Json).then_some(SpanFieldsLayer));
 
 	Ok(subscriber)
 }
@@ -219,6 +236,9 @@ pub struct LoggerBuilder {
 	log_reloading: bool,
 	force_colors: Option<bool>,
 	detailed_output: bool,
+	log_format: LogFormat,
+	node_name: Option<String>,
+	otlp: Option<OtlpConfig>,
 }
 
 impl LoggerBuilder {
@@ -231,6 +251,9 @@ impl LoggerBuilder {
 			log_reloading: false,
 			force_colors: None,
 			detailed_output: false,
+			log_format: LogFormat::Text,
+			node_name: None,
+			otlp: None,
 		}
 	}
 
@@ -270,12 +293,34 @@ impl LoggerBuilder {
 		self
 	}
 
+	/// Set the format of the log output.
+	///
+	/// The colors are always disabled for [`LogFormat::Json`].
+	pub fn with_log_format(&mut self, log_format: LogFormat) -> &mut Self {
+		self.log_format = log_format;
+		self
+	}
+
+	/// Set the name of the node, added to every log record with [`LogFormat::Json`].
+	pub fn with_node_name<S: Into<String>>(&mut self, node_name: S) -> &mut Self {
+		self.node_name = Some(node_name.into());
+		self
+	}
+
 	/// Force enable/disable colors.
 	pub fn with_colors(&mut self, enable: bool) -> &mut Self {
 		self.force_colors = Some(enable);
 		self
 	}
 
+	/// Export the tracing spans to an OpenTelemetry collector.
+	///
+	/// Only the spans enabled by the log directives and the profiling targets are exported.
+	pub fn with_otlp_exporter(&mut self, config: OtlpConfig) -> &mut Self {
+		self.otlp = Some(config);
+		self
+	}
+
 	/// Initialize the global logger
 	///
 	/// This sets various global logging and tracing instances and thus may only be called once.
@@ -287,6 +332,8 @@ impl LoggerBuilder {
 					Some(&profiling_targets),
 					self.force_colors,
 					self.detailed_output,
+					self.log_format,
+					self.node_name.as_deref(),
 					|builder| enable_log_reloading!(builder),
 				)?;
 				let mut profiling =
@@ -296,7 +343,9 @@ impl LoggerBuilder {
 					.into_iter()
 					.for_each(|profiler| profiling.add_handler(profiler));
 
-				tracing::subscriber::set_global_default(subscriber.with(profiling))?;
+				let otlp = self.otlp.as_ref().map(otlp::otlp_layer).transpose()?;
+
+				tracing::subscriber::set_global_default(subscriber.with(profiling).with(otlp))?;
 
 				Ok(())
 			} else {
@@ -305,6 +354,8 @@ impl LoggerBuilder {
 					Some(&profiling_targets),
 					self.force_colors,
 					self.detailed_output,
+					self.log_format,
+					self.node_name.as_deref(),
 					|builder| builder,
 				)?;
 				let mut profiling =
@@ -314,7 +365,9 @@ impl LoggerBuilder {
 					.into_iter()
 					.for_each(|profiler| profiling.add_handler(profiler));
 
-				tracing::subscriber::set_global_default(subscriber.with(profiling))?;
+				let otlp = self.otlp.as_ref().map(otlp::otlp_layer).transpose()?;
+
+				tracing::subscriber::set_global_default(subscriber.with(profiling).with(otlp))?;
 
 				Ok(())
 			}
@@ -324,10 +377,14 @@ impl LoggerBuilder {
 				None,
 				self.force_colors,
 				self.detailed_output,
+				self.log_format,
+				self.node_name.as_deref(),
 				|builder| enable_log_reloading!(builder),
 			)?;
 
-			tracing::subscriber::set_global_default(subscriber)?;
+			let otlp = self.otlp.as_ref().map(otlp::otlp_layer).transpose()?;
+
+			tracing::subscriber::set_global_default(subscriber.with(otlp))?;
 
 			Ok(())
 		} else {
@@ -336,10 +393,14 @@ impl LoggerBuilder {
 				None,
 				self.force_colors,
 				self.detailed_output,
+				self.log_format,
+				self.node_name.as_deref(),
 				|builder| builder,
 			)?;
 
-			tracing::subscriber::set_global_default(subscriber)?;
+			let otlp = self.otlp.as_ref().map(otlp::otlp_layer).transpose()?;
+
+			tracing::subscriber::set_global_default(subscriber.with(otlp))?;
 
 			Ok(())
 		}
@@ -522,6 +583,70 @@ mod tests {
 		assert!(re.is_match(output.trim()), "Expected:\n{}\nGot:\n{}", re, output);
 	}
 
+	/// This is not an actual test, it is used by the `json_log_format` test.
+	/// The given test will call the test executable and only execute this one test that logs
+	/// with the JSON format, before and after reloading the log filter.
+	#[test]
+	fn json_log_format_entrypoint() {
+		if env::var("ENABLE_LOGGING").is_ok() {
+			let mut builder = LoggerBuilder::new("test-target=info");
+			builder
+				.with_log_format(LogFormat::Json)
+				.with_node_name(EXPECTED_NODE_NAME)
+				.with_log_reloading(true);
+			builder.init().unwrap();
+
+			json_log_format_process();
+
+			add_directives("reloaded-target=debug");
+			reload_filter().unwrap();
+			log::debug!(target: "reloaded-target", "after reload");
+		}
+	}
+
+	#[crate::logging::prefix_logs_with("prefix")]
+	fn json_log_format_process() {
+		let span = tracing::info_span!(target: "test-target", "import", number = 5u64);
+		let _enter = span.enter();
+		tracing::info!(target: "test-target", answer = 42u64, "{}", EXPECTED_LOG_MESSAGE);
+		log::debug!(target: "reloaded-target", "filtered out before reload");
+	}
+
+	#[test]
+	fn json_log_format() {
+		let executable = env::current_exe().unwrap();
+		let output = Command::new(executable)
+			.env("ENABLE_LOGGING", "1")
+			.args(&["--nocapture", "json_log_format_entrypoint"])
+			.output()
+			.unwrap();
+
+		let output = String::from_utf8(output.stderr).unwrap();
+		let records = output
+			.lines()
+			.map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+			.collect::<Vec<_>>();
+
+		assert_eq!(records.len(), 2, "Got:\n{}", output);
+
+		let record = &records[0];
+		assert!(record["timestamp"].is_string());
+		assert_eq!(record["level"], "INFO");
+		assert_eq!(record["target"], "test-target");
+		assert_eq!(record["node"], EXPECTED_NODE_NAME);
+		assert_eq!(record["prefix"], "prefix");
+		assert_eq!(record["message"], EXPECTED_LOG_MESSAGE);
+		assert_eq!(record["fields"], serde_json::json!({ "answer": 42 }));
+		assert_eq!(
+			record["spans"],
+			serde_json::json!([{ "name": "import", "fields": { "number": 5 } }])
+		);
+
+		assert_eq!(records[1]["level"], "DEBUG");
+		assert_eq!(records[1]["target"], "reloaded-target");
+		assert_eq!(records[1]["message"], "after reload");
+	}
+
 	#[test]
 	fn log_max_level_is_set_properly() {
 		fn run_test(rust_log: Option<String>, tracing_targets: Option<String>) -> String {
@@ -0,0 +1,268 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Export of tracing spans to an OpenTelemetry collector using the OTLP protocol.
+
+use opentelemetry::{global, trace::TracerProvider as _, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+	runtime,
+	trace::{Config, Sampler, Tracer, TracerProvider},
+	Resource,
+};
+use std::sync::OnceLock;
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Name of the instrumentation library reported with the exported spans.
+const TRACER_NAME: &str = "substrate";
+
+/// Default value of the `service.name` resource attribute.
+const DEFAULT_SERVICE_NAME: &str = "substrate-node";
+
+/// Path under which the OTLP/HTTP collectors accept the spans.
+const HTTP_TRACES_PATH: &str = "/v1/traces";
+
+/// Runtime driving the export of the spans.
+///
+/// The exporter lives for the whole lifetime of the process and is independent of the runtime of
+/// the node, so that the spans can still be flushed when the node is shutting down.
+static EXPORTER_RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+
+/// The protocol used to send the spans to the collector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OtlpProtocol {
+	/// OTLP over gRPC.
+	#[default]
+	Grpc,
+	/// OTLP over HTTP with protobuf encoded payloads.
+	Http,
+}
+
+/// Configuration of the OpenTelemetry span exporter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OtlpConfig {
+	/// Address of the collector, e.g. `http://localhost:4317`.
+	///
+	/// For [`OtlpProtocol::Http`] the `/v1/traces` path is appended if not already present.
+	pub endpoint: String,
+	/// The protocol used to send the spans to the collector.
+	pub protocol: OtlpProtocol,
+	/// The ratio of the traces to sample, between `0.0` and `1.0`.
+	///
+	/// Child spans follow the sampling decision of their parent.
+	pub sampling_ratio: f64,
+	/// Attributes describing the node (e.g. chain and node name), attached to every exported span.
+	pub resource_attributes: Vec<(String, String)>,
+}
+
+impl OtlpConfig {
+	/// Create a new configuration exporting all the spans to the given endpoint.
+	pub fn new(endpoint: impl Into<String>, protocol: OtlpProtocol) -> Self {
+		Self {
+			endpoint: endpoint.into(),
+			protocol,
+			sampling_ratio: 1.0,
+			resource_attributes: Vec::new(),
+		}
+	}
+
+	fn resource(&self) -> Resource {
+		let mut attributes = self
+			.resource_attributes
+			.iter()
+			.map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
+			.collect::<Vec<_>>();
+
+		if !self.resource_attributes.iter().any(|(key, _)| key == "service.name") {
+			attributes.push(KeyValue::new("service.name", DEFAULT_SERVICE_NAME));
+		}
+
+		Resource::new(attributes)
+	}
+
+	fn http_endpoint(&self) -> String {
+		let endpoint = self.endpoint.trim_end_matches('/');
+
+		if endpoint.ends_with(HTTP_TRACES_PATH) {
+			endpoint.to_string()
+		} else {
+			format!("{endpoint}{HTTP_TRACES_PATH}")
+		}
+	}
+}
+
+/// Build the tracer provider batching and exporting the spans as configured.
+pub(crate) fn tracer_provider(config: &OtlpConfig) -> super::Result<TracerProvider> {
+	let runtime = match EXPORTER_RUNTIME.get() {
+		Some(runtime) => runtime,
+		None => {
+			let runtime = tokio::runtime::Builder::new_multi_thread()
+				.worker_threads(1)
+				.thread_name("otlp-exporter")
+				.enable_all()
+				.build()?;
+			EXPORTER_RUNTIME.get_or_init(|| runtime)
+		},
+	};
+	// The exporter and the batch processor need to be created within the context of the runtime.
+	let _guard = runtime.enter();
+
+	let trace_config = Config::default()
+		.with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+			config.sampling_ratio,
+		))))
+		.with_resource(config.resource());
+
+	let pipeline = opentelemetry_otlp::new_pipeline().tracing().with_trace_config(trace_config);
+
+	let provider = match config.protocol {
+		OtlpProtocol::Grpc => pipeline
+			.with_exporter(
+				opentelemetry_otlp::new_exporter()
+					.tonic()
+					.with_endpoint(config.endpoint.clone()),
+			)
+			.install_batch(runtime::Tokio)?,
+		OtlpProtocol::Http => pipeline
+			.with_exporter(
+				opentelemetry_otlp::new_exporter().http().with_endpoint(config.http_endpoint()),
+			)
+			.install_batch(runtime::Tokio)?,
+	};
+
+	Ok(provider)
+}
+
+/// Create the layer exporting the spans to the OpenTelemetry collector.
+///
+/// The tracer provider is registered globally, so that it can be flushed with
+/// [`shutdown_otlp_exporter`].
+pub(crate) fn otlp_layer<S>(config: &OtlpConfig) -> super::Result<OpenTelemetryLayer<S, Tracer>>
+where
+	S: Subscriber + for<'a> LookupSpan<'a>,
+{
+	let provider = tracer_provider(config)?;
+	let layer = tracing_opentelemetry::layer().with_tracer(provider.tracer(TRACER_NAME));
+	global::set_tracer_provider(provider);
+
+	Ok(layer)
+}
+
+/// Flush the spans which were not exported yet and shut down the OpenTelemetry exporter.
+///
+/// Does nothing if the exporter was not enabled.
+pub fn shutdown_otlp_exporter() {
+	if EXPORTER_RUNTIME.get().is_some() {
+		global::shutdown_tracer_provider();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::{
+		io::{BufRead, BufReader, Read, Write},
+		net::TcpListener,
+		sync::mpsc,
+		time::Duration,
+	};
+	use tracing_subscriber::{layer::SubscriberExt, Registry};
+
+	/// A stand-in for the collector accepting a single OTLP/HTTP request.
+	///
+	/// Returns the address of the collector and the receiver of the request line and body.
+	fn collector_stand_in() -> (String, mpsc::Receiver<(String, Vec<u8>)>) {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let addr = listener.local_addr().unwrap();
+		let (tx, rx) = mpsc::channel();
+
+		std::thread::spawn(move || {
+			let (stream, _) = listener.accept().unwrap();
+			let mut reader = BufReader::new(stream);
+
+			let mut request_line = String::new();
+			reader.read_line(&mut request_line).unwrap();
+
+			let mut content_length = 0;
+			loop {
+				let mut header = String::new();
+				reader.read_line(&mut header).unwrap();
+				if header.trim().is_empty() {
+					break;
+				}
+				if let Some((name, value)) = header.split_once(':') {
+					if name.eq_ignore_ascii_case("content-length") {
+						content_length = value.trim().parse().unwrap();
+					}
+				}
+			}
+
+			let mut body = vec![0; content_length];
+			reader.read_exact(&mut body).unwrap();
+			reader
+				.get_mut()
+				.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+				.unwrap();
+
+			tx.send((request_line, body)).unwrap();
+		});
+
+		(format!("http://{addr}"), rx)
+	}
+
+	#[test]
+	fn http_endpoint_has_traces_path() {
+		let config = OtlpConfig::new("http://localhost:4318/", OtlpProtocol::Http);
+		assert_eq!(config.http_endpoint(), "http://localhost:4318/v1/traces");
+
+		let config = OtlpConfig::new("http://localhost:4318/v1/traces", OtlpProtocol::Http);
+		assert_eq!(config.http_endpoint(), "http://localhost:4318/v1/traces");
+	}
+
+	#[test]
+	fn spans_are_exported_to_collector() {
+		let (endpoint, requests) = collector_stand_in();
+		let config = OtlpConfig {
+			resource_attributes: vec![("chain".into(), "otlp-test-chain".into())],
+			..OtlpConfig::new(endpoint, OtlpProtocol::Http)
+		};
+
+		let provider = tracer_provider(&config).unwrap();
+		let subscriber = Registry::default()
+			.with(tracing_opentelemetry::layer().with_tracer(provider.tracer(TRACER_NAME)));
+
+		tracing::subscriber::with_default(subscriber, || {
+			let _span = tracing::info_span!("otlp_test_block_import").entered();
+		});
+
+		for result in provider.force_flush() {
+			result.unwrap();
+		}
+
+		let (request_line, body) = requests.recv_timeout(Duration::from_secs(10)).unwrap();
+		assert!(request_line.starts_with("POST /v1/traces "), "{request_line}");
+
+		// The payload is protobuf encoded, the strings are embedded verbatim.
+		let contains = |needle: &[u8]| body.windows(needle.len()).any(|w| w == needle);
+		assert!(contains(b"otlp_test_block_import"));
+		assert!(contains(b"otlp-test-chain"));
+		assert!(contains(DEFAULT_SERVICE_NAME.as_bytes()));
+	}
+}
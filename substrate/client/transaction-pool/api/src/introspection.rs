@@ -0,0 +1,160 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Transaction pool introspection API.
+//!
+//! Provides a detailed, read-only view into the internal state of the transaction pool. It is
+//! intended for operators debugging the pool (e.g. stuck nonces) and is exposed over the unsafe
+//! `txpool_*` RPC namespace.
+
+use crate::{BlockHash, TransactionPool, TxHash};
+use serde::{Deserialize, Serialize};
+use sp_runtime::transaction_validity::{
+	TransactionLongevity, TransactionPriority, TransactionSource, TransactionTag,
+};
+
+/// Event describing a transaction leaving the pool.
+///
+/// Contrary to [`TransactionStatus`](crate::TransactionStatus) these events are emitted for all
+/// transactions in the pool, not only for the watched ones.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "event")]
+pub enum PoolEvent<Hash> {
+	/// Transaction was dropped from the pool, e.g. because of the limits being enforced.
+	Dropped {
+		/// Hash of the dropped transaction.
+		hash: Hash,
+	},
+	/// Transaction was removed from the pool as invalid.
+	Invalid {
+		/// Hash of the invalid transaction.
+		hash: Hash,
+	},
+	/// Transaction was replaced by another transaction providing the same tags.
+	Usurped {
+		/// Hash of the replaced transaction.
+		hash: Hash,
+		/// Hash of the transaction that replaced it.
+		by: Hash,
+	},
+}
+
+impl<Hash> PoolEvent<Hash> {
+	/// Returns the hash of the transaction the event refers to.
+	pub fn hash(&self) -> &Hash {
+		match self {
+			Self::Dropped { hash } | Self::Invalid { hash } | Self::Usurped { hash, .. } => hash,
+		}
+	}
+}
+
+/// The stream of [`PoolEvent`]s.
+pub type PoolEventStream<H> = futures::channel::mpsc::Receiver<PoolEvent<H>>;
+
+/// Detailed information about a single transaction held in the pool.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InPoolTransactionInfo<Hash, BlockHash> {
+	/// Hash of the transaction.
+	pub hash: Hash,
+	/// Number of bytes of the transaction encoding.
+	pub bytes: usize,
+	/// Priority of the transaction.
+	pub priority: TransactionPriority,
+	/// Block number at which the transaction becomes invalid.
+	pub valid_till: TransactionLongevity,
+	/// Tags required by the transaction.
+	pub requires: Vec<TransactionTag>,
+	/// Tags provided by the transaction.
+	pub provides: Vec<TransactionTag>,
+	/// Whether the transaction is propagated to other peers.
+	pub propagate: bool,
+	/// The source of the transaction.
+	pub source: TransactionSource,
+	/// Views (identified by block hashes) in which the transaction is ready.
+	///
+	/// Always empty for pool implementations which do not maintain per-fork views.
+	pub ready_in: Vec<BlockHash>,
+	/// Views (identified by block hashes) in which the transaction is in the future queue.
+	///
+	/// Always empty for pool implementations which do not maintain per-fork views.
+	pub future_in: Vec<BlockHash>,
+}
+
+/// The content of the pool's ready and future queues.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolContent<Hash, BlockHash> {
+	/// The block hash of the view the content was taken from.
+	///
+	/// `None` for pool implementations which do not maintain per-fork views.
+	pub at: Option<BlockHash>,
+	/// Ready transactions, in the order they would be included in a block.
+	pub ready: Vec<InPoolTransactionInfo<Hash, BlockHash>>,
+	/// Future transactions.
+	pub future: Vec<InPoolTransactionInfo<Hash, BlockHash>>,
+}
+
+/// The result of inspecting a single transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionInspection<Hash, BlockHash> {
+	/// Details of the transaction, if it is currently held in the pool.
+	pub info: Option<InPoolTransactionInfo<Hash, BlockHash>>,
+	/// Whether the transaction is currently banned from entering the pool.
+	pub banned: bool,
+	/// The most recent event describing the removal of the transaction from the pool, if it is
+	/// still remembered.
+	pub removal: Option<PoolEvent<Hash>>,
+}
+
+/// Transaction pool introspection interface.
+pub trait TransactionPoolIntrospection: TransactionPool {
+	/// Returns the content of the ready and future queues.
+	///
+	/// For the pools maintaining per-fork views the content of the view at given block is
+	/// returned. If `at` is `None` the most recent view is used. Returns `None` if there is no
+	/// such view.
+	fn content_at(
+		&self,
+		at: Option<BlockHash<Self>>,
+	) -> Option<PoolContent<TxHash<Self>, BlockHash<Self>>>;
+
+	/// Returns everything the pool knows about the transaction with given hash.
+	fn inspect_transaction(
+		&self,
+		hash: &TxHash<Self>,
+	) -> TransactionInspection<TxHash<Self>, BlockHash<Self>>;
+
+	/// Returns a stream of [`PoolEvent`]s for all the transactions in the pool.
+	fn pool_event_stream(&self) -> PoolEventStream<TxHash<Self>>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn pool_event_serialization() {
+		let dropped = PoolEvent::<u64>::Dropped { hash: 1 };
+		assert_eq!(serde_json::to_string(&dropped).unwrap(), r#"{"event":"dropped","hash":1}"#);
+
+		let usurped = PoolEvent::<u64>::Usurped { hash: 1, by: 2 };
+		let json = r#"{"event":"usurped","hash":1,"by":2}"#;
+		assert_eq!(serde_json::to_string(&usurped).unwrap(), json);
+		assert_eq!(serde_json::from_str::<PoolEvent<u64>>(json).unwrap(), usurped);
+		assert_eq!(usurped.hash(), &1);
+	}
+}
@@ -26,10 +26,17 @@ use crate::{
 	TransactionPoolWrapper, LOG_TARGET,
 };
 use prometheus_endpoint::Registry as PrometheusRegistry;
-use sc_transaction_pool_api::{LocalTransactionPool, MaintainedTransactionPool};
+use sc_transaction_pool_api::{
+	LocalTransactionPool, MaintainedTransactionPool, TransactionPoolIntrospection,
+};
 use sp_core::traits::SpawnEssentialNamed;
 use sp_runtime::traits::Block as BlockT;
-use std::{marker::PhantomData, sync::Arc, time::Duration};
+use std::{
+	marker::PhantomData,
+	path::{Path, PathBuf},
+	sync::Arc,
+	time::Duration,
+};
 
 /// The type of transaction pool.
 #[derive(Debug, Clone)]
@@ -45,11 +52,16 @@ pub enum TransactionPoolType {
 pub struct TransactionPoolOptions {
 	txpool_type: TransactionPoolType,
 	options: Options,
+	journal_path: Option<PathBuf>,
 }
 
 impl Default for TransactionPoolOptions {
 	fn default() -> Self {
-		Self { txpool_type: TransactionPoolType::SingleState, options: Default::default() }
+		Self {
+			txpool_type: TransactionPoolType::SingleState,
+			options: Default::default(),
+			journal_path: None,
+		}
 	}
 }
 
@@ -81,7 +93,24 @@ impl TransactionPoolOptions {
 			Duration::from_secs(30 * 60)
 		};
 
-		TransactionPoolOptions { options, txpool_type }
+		TransactionPoolOptions { options, txpool_type, journal_path: None }
+	}
+
+	/// Enables the on-disk journal of the transactions, stored at the given path.
+	///
+	/// The pending transactions are restored from the journal when the node is restarted. The
+	/// journal is only supported by the fork-aware transaction pool.
+	///
+	/// When the configuration is created by `sc-cli`, a relative path is resolved against the
+	/// database directory of the node.
+	pub fn with_journal_path(mut self, journal_path: impl Into<PathBuf>) -> Self {
+		self.journal_path = Some(journal_path.into());
+		self
+	}
+
+	/// Returns the path of the on-disk journal of the transactions, if enabled.
+	pub fn journal_path(&self) -> Option<&Path> {
+		self.journal_path.as_deref()
 	}
 
 	/// Creates predefined options for benchmarking
@@ -100,15 +129,17 @@ impl TransactionPoolOptions {
 				ban_time: Duration::from_secs(30 * 60),
 			},
 			txpool_type: TransactionPoolType::SingleState,
+			journal_path: None,
 		}
 	}
 }
 
 /// `FullClientTransactionPool` is a trait that combines the functionality of
-/// `MaintainedTransactionPool` and `LocalTransactionPool` for a given `Client` and `Block`.
+/// `MaintainedTransactionPool`, `LocalTransactionPool` and `TransactionPoolIntrospection` for a
+/// given `Client` and `Block`.
 ///
 /// This trait defines the requirements for a full client transaction pool, ensuring
-/// that it can handle transactions submission and maintenance.
+/// that it can handle transactions submission, maintenance and introspection.
 pub trait FullClientTransactionPool<Block, Client>:
 	MaintainedTransactionPool<
 		Block = Block,
@@ -122,7 +153,7 @@ pub trait FullClientTransactionPool<Block, Client>:
 		Block = Block,
 		Hash = ExtrinsicHash<FullChainApi<Client, Block>>,
 		Error = <FullChainApi<Client, Block> as ChainApi>::Error,
-	>
+	> + TransactionPoolIntrospection
 where
 	Block: BlockT,
 	Client: sp_api::ProvideRuntimeApi<Block>
@@ -157,7 +188,7 @@ where
 			Block = Block,
 			Hash = ExtrinsicHash<FullChainApi<Client, Block>>,
 			Error = <FullChainApi<Client, Block> as ChainApi>::Error,
-		>,
+		> + TransactionPoolIntrospection,
 {
 }
 
@@ -229,18 +260,28 @@ where
 			txpool_type = ?self.options.txpool_type,
 			ready = ?self.options.options.ready,
 			future = ?self.options.options.future,
+			journal_path = ?self.options.journal_path,
 			"Creating transaction pool"
 		);
 		TransactionPoolWrapper::<Block, Client>(match self.options.txpool_type {
-			TransactionPoolType::SingleState => Box::new(SingleStateFullPool::new_full(
-				self.options.options,
-				self.is_validator,
-				self.prometheus,
-				self.spawner,
-				self.client,
-			)),
+			TransactionPoolType::SingleState => {
+				if self.options.journal_path.is_some() {
+					tracing::warn!(
+						target: LOG_TARGET,
+						"Transaction pool journal is not supported by the single-state pool, ignoring"
+					);
+				}
+				Box::new(SingleStateFullPool::new_full(
+					self.options.options,
+					self.is_validator,
+					self.prometheus,
+					self.spawner,
+					self.client,
+				))
+			},
 			TransactionPoolType::ForkAware => Box::new(ForkAwareFullPool::new_full(
 				self.options.options,
+				self.options.journal_path,
 				self.is_validator,
 				self.prometheus,
 				self.spawner,
@@ -0,0 +1,140 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Helpers for implementing the transaction pool introspection API.
+
+use crate::{graph::base_pool::Transaction, LOG_TARGET};
+use futures::channel::mpsc::{channel, Sender};
+use linked_hash_map::LinkedHashMap;
+use sc_transaction_pool_api::{InPoolTransactionInfo, PoolEvent, PoolEventStream};
+use std::hash;
+use tracing::warn;
+
+/// Maximum number of recently removed transactions for which the removal reason is remembered.
+const RECENT_REMOVALS_LIMIT: usize = 1024;
+
+/// Size of the buffer of every pool events stream.
+const POOL_EVENTS_CHANNEL_SIZE: usize = 1024;
+
+/// Dispatches [`PoolEvent`]s to the subscribers and remembers the reason of the most recent
+/// removals.
+pub(crate) struct PoolEvents<Hash> {
+	/// Sinks of the pool event streams.
+	sinks: Vec<Sender<PoolEvent<Hash>>>,
+	/// Most recent removal events, keyed by the transaction hash.
+	recent_removals: LinkedHashMap<Hash, PoolEvent<Hash>>,
+}
+
+impl<Hash> Default for PoolEvents<Hash>
+where
+	Hash: hash::Hash + Eq,
+{
+	fn default() -> Self {
+		Self { sinks: Default::default(), recent_removals: Default::default() }
+	}
+}
+
+impl<Hash> PoolEvents<Hash>
+where
+	Hash: hash::Hash + Eq + Clone + std::fmt::Debug,
+{
+	/// Creates a new stream of pool events.
+	pub(crate) fn event_stream(&mut self) -> PoolEventStream<Hash> {
+		let (sink, stream) = channel(POOL_EVENTS_CHANNEL_SIZE);
+		self.sinks.push(sink);
+		stream
+	}
+
+	/// Records the event and sends it out to all the subscribers.
+	pub(crate) fn notify(&mut self, event: PoolEvent<Hash>) {
+		self.recent_removals.insert(event.hash().clone(), event.clone());
+		while self.recent_removals.len() > RECENT_REMOVALS_LIMIT {
+			self.recent_removals.pop_front();
+		}
+
+		self.sinks.retain_mut(|sink| match sink.try_send(event.clone()) {
+			Ok(()) => true,
+			Err(e) =>
+				if e.is_full() {
+					warn!(
+						target: LOG_TARGET,
+						tx_hash = ?event.hash(),
+						"Trying to notify a pool event but the channel is full"
+					);
+					true
+				} else {
+					false
+				},
+		});
+	}
+
+	/// Returns the most recent removal event for given transaction, if it is still remembered.
+	pub(crate) fn recent_removal(&self, hash: &Hash) -> Option<PoolEvent<Hash>> {
+		self.recent_removals.get(hash).cloned()
+	}
+}
+
+/// Builds the introspection info for the given in-pool transaction.
+pub(crate) fn transaction_info<Hash: Clone, Ex, BlockHash>(
+	tx: &Transaction<Hash, Ex>,
+	ready_in: Vec<BlockHash>,
+	future_in: Vec<BlockHash>,
+) -> InPoolTransactionInfo<Hash, BlockHash> {
+	InPoolTransactionInfo {
+		hash: tx.hash.clone(),
+		bytes: tx.bytes,
+		priority: tx.priority,
+		valid_till: tx.valid_till,
+		requires: tx.requires.clone(),
+		provides: tx.provides.clone(),
+		propagate: tx.propagate,
+		source: tx.source.source,
+		ready_in,
+		future_in,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use futures::StreamExt;
+
+	#[test]
+	fn notify_sends_to_all_subscribers() {
+		let mut events = PoolEvents::<u64>::default();
+		let mut stream0 = events.event_stream();
+		let mut stream1 = events.event_stream();
+
+		events.notify(PoolEvent::Invalid { hash: 1 });
+
+		let expected = Some(PoolEvent::Invalid { hash: 1 });
+		assert_eq!(futures::executor::block_on(stream0.next()), expected);
+		assert_eq!(futures::executor::block_on(stream1.next()), expected);
+	}
+
+	#[test]
+	fn recent_removals_are_bounded() {
+		let mut events = PoolEvents::<u64>::default();
+		for hash in 0..(RECENT_REMOVALS_LIMIT as u64 + 10) {
+			events.notify(PoolEvent::Dropped { hash });
+		}
+
+		assert_eq!(events.recent_removal(&0), None);
+		assert_eq!(events.recent_removal(&10), Some(PoolEvent::Dropped { hash: 10 }));
+	}
+}
@@ -53,7 +53,8 @@ use parking_lot::Mutex;
 use prometheus_endpoint::Registry as PrometheusRegistry;
 use sc_transaction_pool_api::{
 	error::Error as TxPoolApiError, ChainEvent, ImportNotificationStream,
-	MaintainedTransactionPool, PoolStatus, TransactionFor, TransactionPool, TransactionPriority,
+	MaintainedTransactionPool, PoolContent, PoolEventStream, PoolStatus, TransactionFor,
+	TransactionInspection, TransactionPool, TransactionPoolIntrospection, TransactionPriority,
 	TransactionSource, TransactionStatusStreamFor, TxHash, TxInvalidityReportMap,
 };
 use sp_blockchain::{HashAndNumber, TreeRoute};
@@ -66,6 +67,7 @@ use sp_runtime::{
 };
 use std::{
 	collections::{BTreeMap, HashMap, HashSet},
+	path::PathBuf,
 	pin::Pin,
 	sync::Arc,
 	time::Instant,
@@ -338,8 +340,12 @@ where
 	///
 	/// The txpool essential tasks (including a revalidation worker) are spawned using provided
 	/// spawner.
+	///
+	/// If `journal_path` is provided, the transactions are persisted in the on-disk journal stored
+	/// at this path, and the transactions recorded by the previous instance are restored.
 	pub fn new_with_background_worker(
 		options: Options,
+		journal_path: Option<PathBuf>,
 		is_validator: IsValidator,
 		pool_api: Arc<ChainApi>,
 		prometheus: Option<&PrometheusRegistry>,
@@ -361,13 +367,25 @@ where
 		let (import_notification_sink, import_notification_sink_task) =
 			MultiViewImportNotificationSink::new_with_worker();
 
-		let mempool = Arc::from(TxMemPool::new(
+		let mut mempool = TxMemPool::new(
 			pool_api.clone(),
 			listener.clone(),
 			metrics.clone(),
 			TXMEMPOOL_TRANSACTION_LIMIT_MULTIPLIER * options.total_count(),
 			options.ready.total_bytes + options.future.total_bytes,
-		));
+		);
+		if let Some(journal_path) = journal_path {
+			let journal_task;
+			(mempool, journal_task) = mempool.with_journal(&journal_path);
+			if let Some(journal_task) = journal_task {
+				spawner.spawn_essential_blocking(
+					"txpool-journal",
+					Some("transaction-pool"),
+					journal_task,
+				);
+			}
+		}
+		let mempool = Arc::from(mempool);
 
 		let (dropped_stream_controller, dropped_stream) =
 			MultiViewDroppedWatcherController::<ChainApi>::new();
@@ -1001,6 +1019,38 @@ where
 	}
 }
 
+impl<ChainApi, Block> TransactionPoolIntrospection for ForkAwareTxPool<ChainApi, Block>
+where
+	Block: BlockT,
+	ChainApi: 'static + graph::ChainApi<Block = Block>,
+	<Block as BlockT>::Hash: Unpin,
+{
+	fn content_at(
+		&self,
+		at: Option<Block::Hash>,
+	) -> Option<PoolContent<ExtrinsicHash<ChainApi>, Block::Hash>> {
+		self.view_store.content_at(at)
+	}
+
+	fn inspect_transaction(
+		&self,
+		hash: &ExtrinsicHash<ChainApi>,
+	) -> TransactionInspection<ExtrinsicHash<ChainApi>, Block::Hash> {
+		TransactionInspection {
+			info: self
+				.view_store
+				.transaction_info(hash)
+				.or_else(|| self.mempool.transaction_info(*hash)),
+			banned: self.view_store.is_banned(hash),
+			removal: self.view_store.listener.recent_removal(hash),
+		}
+	}
+
+	fn pool_event_stream(&self) -> PoolEventStream<ExtrinsicHash<ChainApi>> {
+		self.view_store.listener.pool_event_stream()
+	}
+}
+
 impl<ChainApi, Block> sc_transaction_pool_api::LocalTransactionPool
 	for ForkAwareTxPool<ChainApi, Block>
 where
@@ -1587,6 +1637,7 @@ where
 	/// Performs a house-keeping required for finalized event. This includes:
 	/// - executing the on finalized procedure for the view store,
 	/// - purging finalized transactions from the mempool and triggering mempool revalidation,
+	/// - compacting the mempool journal,
 	async fn handle_finalized(&self, finalized_hash: Block::Hash, tree_route: &[Block::Hash]) {
 		let finalized_number = self.api.block_id_to_number(&BlockId::Hash(finalized_hash));
 		debug!(
@@ -1599,6 +1650,7 @@ where
 		let finalized_xts = self.view_store.handle_finalized(finalized_hash, tree_route).await;
 
 		self.mempool.purge_finalized_transactions(&finalized_xts).await;
+		self.mempool.compact_journal();
 		self.import_notification_sink.clean_notified_items(&finalized_xts);
 
 		self.metrics
@@ -1857,8 +1909,11 @@ where
 	<Block as BlockT>::Hash: std::marker::Unpin,
 {
 	/// Create new fork aware transaction pool for a full node with the provided api.
+	///
+	/// Refer to [`Self::new_with_background_worker`] for the description of `journal_path`.
 	pub fn new_full(
 		options: Options,
+		journal_path: Option<PathBuf>,
 		is_validator: IsValidator,
 		prometheus: Option<&PrometheusRegistry>,
 		spawner: impl SpawnEssentialNamed,
@@ -1867,6 +1922,7 @@ where
 		let pool_api = Arc::new(FullChainApi::new(client.clone(), prometheus, &spawner));
 		let pool = Self::new_with_background_worker(
 			options,
+			journal_path,
 			is_validator,
 			pool_api,
 			prometheus,
@@ -119,6 +119,13 @@
 //! are also periodically verified at every finalized block and removed from the *mempool* if no
 //! longer valid. This is process is called [*mempool* revalidation](#mempool-pruningrevalidation).
 //!
+//! Optionally the *mempool* is backed by an on-disk [journal][`TxJournal`], which records every
+//! accepted transaction with its source and priority. The journal is compacted on every finalized
+//! block. The journal file is written by a dedicated background worker, so the disk I/O does not
+//! delay the *mempool* operations. After the node restart the recorded transactions are inserted
+//! back into the *mempool* and validated at the first created view, like any other *mempool*
+//! transaction. The watchers of the transactions are not persisted.
+//!
 //! ## Flows
 //!
 //! The transaction pool internally is executing numerous tasks. This includes handling submitted
@@ -203,6 +210,7 @@
 //! - remove all the views (both active and inactive) that are lower then finalized block from the
 //! view store,
 //! - removal of finalized transaction from the *mempool*,
+//! - compaction of the *mempool* journal (if enabled),
 //! - trigger [*mempool* background revalidation](#mempool-pruningrevalidation).
 //! - clean up of multi-view listeners which is required to avoid ever-growing structures,
 //!
@@ -323,6 +331,7 @@
 //! [`mp::revalidate`]: crate::fork_aware_txpool::tx_mem_pool::TxMemPool::revalidate
 //! [`batch_size`]: crate::fork_aware_txpool::tx_mem_pool::TXMEMPOOL_MAX_REVALIDATION_BATCH_SIZE
 //! [`TxInMemPool`]: crate::fork_aware_txpool::tx_mem_pool::TxInMemPool
+//! [`TxJournal`]: crate::fork_aware_txpool::tx_journal::TxJournal
 //! [`MultiViewListener`]: crate::fork_aware_txpool::multi_view_listener::MultiViewListener
 //! [`Pool`]: crate::graph::Pool
 //! [`Watcher`]: crate::graph::watcher::Watcher
@@ -372,6 +381,7 @@ mod import_notification_sink;
 mod metrics;
 mod multi_view_listener;
 mod revalidation_worker;
+mod tx_journal;
 mod tx_mem_pool;
 mod view;
 mod view_store;
@@ -21,14 +21,16 @@
 //! aggregated streams of transaction events.
 
 use crate::{
-	common::tracing_log_xt::log_xt_trace,
+	common::{introspection::PoolEvents, tracing_log_xt::log_xt_trace},
 	fork_aware_txpool::{stream_map_util::next_event, view::TransactionStatusEvent},
 	graph::{self, BlockHash, ExtrinsicHash},
 	LOG_TARGET,
 };
 use futures::{Future, FutureExt, Stream, StreamExt};
-use parking_lot::RwLock;
-use sc_transaction_pool_api::{TransactionStatus, TransactionStatusStream, TxIndex};
+use parking_lot::{Mutex, RwLock};
+use sc_transaction_pool_api::{
+	PoolEvent, PoolEventStream, TransactionStatus, TransactionStatusStream, TxIndex,
+};
 use sc_utils::mpsc;
 use sp_runtime::traits::Block as BlockT;
 use std::{
@@ -255,6 +257,10 @@ pub struct MultiViewListener<ChainApi: graph::ChainApi> {
 	/// shared with listener's task.
 	external_controllers:
 		Arc<RwLock<HashMap<ExtrinsicHash<ChainApi>, Controller<ExternalWatcherCommand<ChainApi>>>>>,
+
+	/// Dispatcher of the events of transactions leaving the pool, emitted for all (also not
+	/// watched) transactions.
+	pool_events: Mutex<PoolEvents<ExtrinsicHash<ChainApi>>>,
 }
 
 /// A type representing a `MultiViewListener` task. For more details refer to
@@ -551,7 +557,10 @@ where
 		);
 		let task = Self::task(external_controllers.clone(), rx, events_metrics_collector);
 
-		(Self { external_controllers, controller: tx }, task.boxed())
+		(
+			Self { external_controllers, controller: tx, pool_events: Default::default() },
+			task.boxed(),
+		)
 	}
 
 	/// Creates an external tstream of events for given transaction.
@@ -687,6 +696,7 @@ where
 	pub(crate) fn transactions_invalidated(&self, invalid_hashes: &[ExtrinsicHash<ChainApi>]) {
 		log_xt_trace!(target: LOG_TARGET, invalid_hashes, "transactions_invalidated");
 		for tx_hash in invalid_hashes {
+			self.pool_events.lock().notify(PoolEvent::Invalid { hash: *tx_hash });
 			if let Err(error) =
 				self.controller.unbounded_send(ControllerCommand::new_invalidated(*tx_hash))
 			{
@@ -730,6 +740,12 @@ where
 	pub(crate) fn transaction_dropped(&self, dropped: DroppedTransaction<ExtrinsicHash<ChainApi>>) {
 		let DroppedTransaction { tx_hash, reason } = dropped;
 		trace!(target: LOG_TARGET, ?tx_hash, ?reason, "transaction_dropped");
+		let event = match &reason {
+			DroppedReason::Usurped(by) => PoolEvent::Usurped { hash: tx_hash, by: *by },
+			DroppedReason::LimitsEnforced => PoolEvent::Dropped { hash: tx_hash },
+			DroppedReason::Invalid => PoolEvent::Invalid { hash: tx_hash },
+		};
+		self.pool_events.lock().notify(event);
 		if let Err(error) =
 			self.controller.unbounded_send(ControllerCommand::new_dropped(tx_hash, reason))
 		{
@@ -742,6 +758,22 @@ where
 		}
 	}
 
+	/// Creates a new stream of events of transactions leaving the pool.
+	///
+	/// Contrary to the external watchers, the events are emitted for all the transactions.
+	pub(crate) fn pool_event_stream(&self) -> PoolEventStream<ExtrinsicHash<ChainApi>> {
+		self.pool_events.lock().event_stream()
+	}
+
+	/// Returns the most recent event of given transaction leaving the pool, if it is still
+	/// remembered.
+	pub(crate) fn recent_removal(
+		&self,
+		tx_hash: &ExtrinsicHash<ChainApi>,
+	) -> Option<PoolEvent<ExtrinsicHash<ChainApi>>> {
+		self.pool_events.lock().recent_removal(tx_hash)
+	}
+
 	/// Send `Finalized` event for given transaction at given block.
 	///
 	/// This will trigger `Finalized` event to the external watcher.
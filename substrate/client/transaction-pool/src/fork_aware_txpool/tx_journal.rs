@@ -0,0 +1,291 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! On-disk journal of the transactions kept in the [`TxMemPool`].
+//!
+//! The journal is an append-only file of SCALE encoded [`JournalEntry`] items. Every transaction
+//! accepted by the *mempool* is appended to the journal. Transactions removed from the *mempool*
+//! are not recorded, instead the journal is periodically rewritten (compacted) with the current
+//! content of the *mempool*.
+//!
+//! The file is only accessed by a background worker, the *mempool* sends the entries to write
+//! through a channel, so the disk I/O is neither done while the *mempool* is locked nor on the
+//! maintain path.
+//!
+//! When the node is restarted, the recorded transactions are inserted back into the *mempool*,
+//! from which they are submitted to (and validated at) the newly created views.
+//!
+//! [`TxMemPool`]: super::tx_mem_pool::TxMemPool
+
+use codec::{Decode, Encode};
+use futures::prelude::*;
+use sc_transaction_pool_api::{TransactionPriority, TransactionSource};
+use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedReceiver, TracingUnboundedSender};
+use std::{
+	fs::{self, File, OpenOptions},
+	io::{self, BufWriter, Write},
+	path::{Path, PathBuf},
+	pin::Pin,
+};
+use tracing::{debug, trace, warn};
+
+use crate::LOG_TARGET;
+
+/// A transaction recorded in the journal.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub(crate) struct JournalEntry {
+	/// The source used to submit the transaction.
+	pub(crate) source: TransactionSource,
+	/// The priority of the transaction, if known.
+	pub(crate) priority: Option<TransactionPriority>,
+	/// The encoded extrinsic.
+	pub(crate) data: Vec<u8>,
+}
+
+/// Request sent from the [`TxJournal`] to the background worker.
+enum WorkerPayload {
+	/// Append the entry to the journal.
+	Append(JournalEntry),
+	/// Replace the content of the journal with the entries.
+	Compact(Vec<JournalEntry>),
+}
+
+/// Handle to the journal of the *mempool*.
+///
+/// The requests are executed by the background worker in the order they were sent.
+pub(crate) struct TxJournal {
+	to_worker: TracingUnboundedSender<WorkerPayload>,
+}
+
+impl TxJournal {
+	/// Opens the journal at the given path, creating it if it does not exist.
+	///
+	/// Returns the journal along with the entries recorded in it, and the background worker
+	/// writing to the journal file. The worker performs blocking I/O and shall be spawned as a
+	/// blocking task, it terminates when the journal is dropped.
+	pub(crate) fn new_with_worker(
+		path: &Path,
+	) -> io::Result<(Self, Vec<JournalEntry>, Pin<Box<dyn Future<Output = ()> + Send>>)> {
+		let (file, entries) = JournalFile::open(path)?;
+		let (to_worker, from_journal) = tracing_unbounded("mpsc_txpool_journal", 100_000);
+		Ok((Self { to_worker }, entries, Self::run(file, from_journal).boxed()))
+	}
+
+	/// Appends the given entry to the journal.
+	pub(crate) fn append(&self, entry: JournalEntry) {
+		self.send(WorkerPayload::Append(entry));
+	}
+
+	/// Replaces the content of the journal with the given entries.
+	pub(crate) fn compact(&self, entries: Vec<JournalEntry>) {
+		self.send(WorkerPayload::Compact(entries));
+	}
+
+	fn send(&self, payload: WorkerPayload) {
+		if let Err(error) = self.to_worker.unbounded_send(payload) {
+			trace!(target: LOG_TARGET, ?error, "txpool journal: worker is not running");
+		}
+	}
+
+	/// The background worker main loop.
+	async fn run(mut file: JournalFile, from_journal: TracingUnboundedReceiver<WorkerPayload>) {
+		let mut from_journal = from_journal.fuse();
+		while let Some(payload) = from_journal.next().await {
+			match payload {
+				WorkerPayload::Append(entry) =>
+					if let Err(error) = file.append(&entry) {
+						warn!(target: LOG_TARGET, %error, "txpool journal: failed to append");
+					},
+				WorkerPayload::Compact(entries) =>
+					if let Err(error) = file.compact(entries) {
+						warn!(target: LOG_TARGET, %error, "txpool journal: failed to compact");
+					},
+			}
+		}
+	}
+}
+
+/// Append-only file storing the transactions of the *mempool*.
+struct JournalFile {
+	/// The path of the journal file.
+	path: PathBuf,
+	/// The writer appending to the journal file.
+	writer: BufWriter<File>,
+}
+
+impl JournalFile {
+	/// Opens the journal at the given path, creating it if it does not exist.
+	///
+	/// Returns the journal along with the entries recorded in it. An incomplete entry at the end
+	/// of the file (e.g. after a crash during a write) is discarded.
+	fn open(path: &Path) -> io::Result<(Self, Vec<JournalEntry>)> {
+		if let Some(dir) = path.parent() {
+			fs::create_dir_all(dir)?;
+		}
+
+		let bytes = match fs::read(path) {
+			Ok(bytes) => bytes,
+			Err(error) if error.kind() == io::ErrorKind::NotFound => Vec::new(),
+			Err(error) => return Err(error),
+		};
+		let (entries, valid_len) = decode_entries(&bytes);
+		if valid_len < bytes.len() {
+			warn!(
+				target: LOG_TARGET,
+				?path,
+				discarded_bytes = bytes.len() - valid_len,
+				"txpool journal: discarding incomplete entry"
+			);
+		}
+
+		let file = OpenOptions::new().create(true).append(true).open(path)?;
+		file.set_len(valid_len as u64)?;
+		debug!(target: LOG_TARGET, ?path, entries = entries.len(), "txpool journal: opened");
+
+		Ok((Self { path: path.to_path_buf(), writer: BufWriter::new(file) }, entries))
+	}
+
+	/// Appends the given entry to the journal.
+	fn append(&mut self, entry: &JournalEntry) -> io::Result<()> {
+		self.writer.write_all(&entry.encode())?;
+		self.writer.flush()
+	}
+
+	/// Replaces the content of the journal with the given entries.
+	///
+	/// The entries are written to a temporary file which is then atomically moved in place of the
+	/// journal, so the previous content is kept if the compaction fails.
+	fn compact(&mut self, entries: impl IntoIterator<Item = JournalEntry>) -> io::Result<()> {
+		let tmp_path = self.path.with_extension("tmp");
+		let mut tmp = BufWriter::new(File::create(&tmp_path)?);
+		let mut count = 0usize;
+		for entry in entries {
+			tmp.write_all(&entry.encode())?;
+			count += 1;
+		}
+		tmp.into_inner().map_err(|error| error.into_error())?.sync_all()?;
+		fs::rename(&tmp_path, &self.path)?;
+
+		self.writer = BufWriter::new(OpenOptions::new().append(true).open(&self.path)?);
+		debug!(target: LOG_TARGET, path = ?self.path, count, "txpool journal: compacted");
+		Ok(())
+	}
+}
+
+/// Decodes the entries stored in the journal.
+///
+/// Returns the decoded entries and the number of bytes they occupy.
+fn decode_entries(bytes: &[u8]) -> (Vec<JournalEntry>, usize) {
+	let mut input = bytes;
+	let mut entries = Vec::new();
+	let mut valid_len = 0;
+	while !input.is_empty() {
+		let Ok(entry) = JournalEntry::decode(&mut input) else { break };
+		entries.push(entry);
+		valid_len = bytes.len() - input.len();
+	}
+	(entries, valid_len)
+}
+
+#[cfg(test)]
+mod tx_journal_tests {
+	use super::*;
+
+	fn entry(data: u8) -> JournalEntry {
+		JournalEntry {
+			source: TransactionSource::External,
+			priority: Some(data as _),
+			data: vec![data; 16],
+		}
+	}
+
+	#[test]
+	fn entries_are_restored_after_reopening() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("txpool").join("journal");
+
+		let (mut journal, entries) = JournalFile::open(&path).unwrap();
+		assert!(entries.is_empty());
+		journal.append(&entry(1)).unwrap();
+		journal.append(&entry(2)).unwrap();
+		drop(journal);
+
+		let (mut journal, entries) = JournalFile::open(&path).unwrap();
+		assert_eq!(entries, vec![entry(1), entry(2)]);
+		journal.append(&entry(3)).unwrap();
+		drop(journal);
+
+		let (_, entries) = JournalFile::open(&path).unwrap();
+		assert_eq!(entries, vec![entry(1), entry(2), entry(3)]);
+	}
+
+	#[test]
+	fn compact_replaces_entries() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("journal");
+
+		let (mut journal, _) = JournalFile::open(&path).unwrap();
+		(1..5).for_each(|i| journal.append(&entry(i)).unwrap());
+		journal.compact(vec![entry(2), entry(4)]).unwrap();
+		journal.append(&entry(5)).unwrap();
+		drop(journal);
+
+		let (_, entries) = JournalFile::open(&path).unwrap();
+		assert_eq!(entries, vec![entry(2), entry(4), entry(5)]);
+		assert!(!path.with_extension("tmp").exists());
+	}
+
+	#[test]
+	fn worker_executes_requests_in_order() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("journal");
+
+		let (journal, _, worker) = TxJournal::new_with_worker(&path).unwrap();
+		journal.append(entry(1));
+		journal.append(entry(2));
+		journal.compact(vec![entry(2)]);
+		journal.append(entry(3));
+		drop(journal);
+		futures::executor::block_on(worker);
+
+		let (_, entries) = JournalFile::open(&path).unwrap();
+		assert_eq!(entries, vec![entry(2), entry(3)]);
+	}
+
+	#[test]
+	fn incomplete_entry_is_discarded() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("journal");
+
+		let (mut journal, _) = JournalFile::open(&path).unwrap();
+		journal.append(&entry(1)).unwrap();
+		drop(journal);
+
+		let mut bytes = fs::read(&path).unwrap();
+		bytes.extend_from_slice(&entry(2).encode()[..10]);
+		fs::write(&path, bytes).unwrap();
+
+		let (mut journal, entries) = JournalFile::open(&path).unwrap();
+		assert_eq!(entries, vec![entry(1)]);
+		journal.append(&entry(3)).unwrap();
+		drop(journal);
+
+		let (_, entries) = JournalFile::open(&path).unwrap();
+		assert_eq!(entries, vec![entry(1), entry(3)]);
+	}
+}
@@ -29,6 +29,8 @@
 use std::{
 	cmp::Ordering,
 	collections::{HashMap, HashSet},
+	path::Path,
+	pin::Pin,
 	sync::{
 		atomic::{self, AtomicU64},
 		Arc,
@@ -36,12 +38,15 @@ use std::{
 	time::Instant,
 };
 
-use futures::FutureExt;
+use codec::{Decode, Encode};
+use futures::{Future, FutureExt};
 use itertools::Itertools;
 use parking_lot::RwLock;
-use tracing::{debug, trace};
+use tracing::{debug, trace, warn};
 
-use sc_transaction_pool_api::{TransactionPriority, TransactionSource};
+use sc_transaction_pool_api::{
+	InPoolTransactionInfo, TransactionLongevity, TransactionPriority, TransactionSource,
+};
 use sp_blockchain::HashAndNumber;
 use sp_runtime::{
 	traits::Block as BlockT,
@@ -58,6 +63,7 @@ use crate::{
 use super::{
 	metrics::MetricsLink as PrometheusMetrics,
 	multi_view_listener::MultiViewListener,
+	tx_journal::{JournalEntry, TxJournal},
 	view_store::{ViewStore, ViewStoreSubmitOutcome},
 };
 
@@ -224,6 +230,9 @@ where
 
 	/// Maximal size of encodings of all transactions in the memory pool.
 	max_transactions_total_bytes: usize,
+
+	/// The on-disk journal of the transactions, if enabled.
+	journal: Option<TxJournal>,
 }
 
 /// Helper structure to encapsulate a result of [`TxMemPool::try_insert`].
@@ -265,9 +274,100 @@ where
 			metrics,
 			max_transactions_count,
 			max_transactions_total_bytes,
+			journal: None,
 		}
 	}
 
+	/// Attaches the on-disk journal stored at the given path.
+	///
+	/// The transactions recorded in the journal are inserted into the memory pool as unwatched
+	/// transactions, and will be validated when submitted to the views. Failing to open the
+	/// journal is not fatal, the memory pool is not persisted in such case.
+	///
+	/// Returns the background worker writing to the journal, if it was opened. The worker performs
+	/// blocking I/O and shall be spawned as a blocking task.
+	pub(super) fn with_journal(
+		mut self,
+		path: &Path,
+	) -> (Self, Option<Pin<Box<dyn Future<Output = ()> + Send>>>) {
+		let (journal, entries, journal_task) = match TxJournal::new_with_worker(path) {
+			Ok(result) => result,
+			Err(error) => {
+				warn!(
+					target: LOG_TARGET,
+					?path,
+					%error,
+					"mempool: failed to open the journal, transactions will not be persisted"
+				);
+				return (self, None)
+			},
+		};
+
+		let entries_count = entries.len();
+		let restored = entries
+			.into_iter()
+			.filter(|entry| self.restore_journal_entry(entry).is_some())
+			.count();
+		debug!(target: LOG_TARGET, entries_count, restored, "mempool: restored from journal");
+
+		self.journal = Some(journal);
+		// Drop the entries which were not restored, e.g. duplicates or undecodable extrinsics.
+		self.compact_journal();
+		(self, Some(journal_task))
+	}
+
+	/// Inserts a transaction recorded in the journal into the memory pool.
+	fn restore_journal_entry(&self, entry: &JournalEntry) -> Option<ExtrinsicHash<ChainApi>> {
+		let xt = Block::Extrinsic::decode(&mut &entry.data[..])
+			.inspect_err(|error| {
+				trace!(target: LOG_TARGET, %error, "mempool: undecodable journal entry");
+			})
+			.ok()?;
+		let xt = Arc::from(xt);
+		let (hash, length) = self.api.hash_and_length(&xt);
+		let tx = TxInMemPool::new_with_optional_priority(
+			false,
+			entry.source,
+			xt,
+			length,
+			entry.priority,
+		);
+		self.try_insert(hash, tx).ok().map(|insertion| insertion.hash)
+	}
+
+	/// Records the given transaction in the journal, if enabled.
+	///
+	/// Shall be called while holding the write lock of the transactions, so the journal requests
+	/// are sent in the same order as the memory pool is modified.
+	fn journal_append(&self, tx: &TxInMemPool<ChainApi, Block>) {
+		let Some(journal) = self.journal.as_ref() else { return };
+		journal.append(JournalEntry {
+			source: tx.source().into(),
+			priority: tx.priority(),
+			data: tx.tx.encode(),
+		});
+	}
+
+	/// Rewrites the journal with the transactions currently kept in the memory pool, if enabled.
+	///
+	/// The journal is rewritten in the background.
+	pub(super) fn compact_journal(&self) {
+		let Some(journal) = self.journal.as_ref() else { return };
+		// The read lock is held until the request is sent, so no transaction inserted after the
+		// snapshot is appended before the compaction.
+		let transactions = self.transactions.read();
+		let entries = transactions
+			.values()
+			.sorted_by_key(|tx| tx.source.timestamp)
+			.map(|tx| JournalEntry {
+				source: tx.source().into(),
+				priority: tx.priority(),
+				data: tx.tx.encode(),
+			})
+			.collect::<Vec<_>>();
+		journal.compact(entries);
+	}
+
 	/// Creates a new `TxMemPool` instance for testing purposes.
 	#[cfg(test)]
 	fn new_test(
@@ -282,6 +382,7 @@ where
 			metrics: Default::default(),
 			max_transactions_count,
 			max_transactions_total_bytes,
+			journal: None,
 		}
 	}
 
@@ -293,6 +394,29 @@ where
 		self.transactions.read().get(&hash).map(Clone::clone)
 	}
 
+	/// Returns the details of the transaction with given hash, if it is kept in the memory pool.
+	///
+	/// The validity of the transaction is not known by the memory pool: the priority is the last
+	/// known one (or the default), the tags are empty and the longevity is unbounded.
+	pub(super) fn transaction_info(
+		&self,
+		hash: ExtrinsicHash<ChainApi>,
+	) -> Option<InPoolTransactionInfo<ExtrinsicHash<ChainApi>, Block::Hash>> {
+		let tx = self.get_by_hash(hash)?;
+		Some(InPoolTransactionInfo {
+			hash,
+			bytes: tx.bytes,
+			priority: tx.priority().unwrap_or_default(),
+			valid_till: TransactionLongevity::MAX,
+			requires: vec![],
+			provides: vec![],
+			propagate: true,
+			source: tx.source.source,
+			ready_in: vec![],
+			future_in: vec![],
+		})
+	}
+
 	/// Returns a tuple with the count of unwatched and watched transactions in the memory pool.
 	pub fn unwatched_and_watched_count(&self) -> (usize, usize) {
 		let transactions = self.transactions.read();
@@ -334,6 +458,7 @@ where
 		) {
 			(false, false) => {
 				let source = tx.source();
+				self.journal_append(&tx);
 				transactions.insert(tx_hash, Arc::from(tx));
 				Ok(InsertionInfo::new(tx_hash, source))
 			},
@@ -421,6 +546,7 @@ where
 		}
 
 		let source = new_tx.source();
+		self.journal_append(&new_tx);
 		transactions.insert(hash, Arc::from(new_tx));
 		for worst_hash in &to_be_removed {
 			transactions.remove(worst_hash);
@@ -741,6 +867,49 @@ mod tx_mem_pool_tests {
 		));
 	}
 
+	#[test]
+	fn journal_restores_transactions() {
+		sp_tracing::try_init_simple();
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("journal");
+		let api = Arc::from(TestApi::default());
+		let hash = |xt: &ExtrinsicFor<TestApi>| api.hash_and_length(xt).0;
+
+		let new_mempool = || {
+			let (mempool, journal_task) =
+				TxMemPool::new_test(api.clone(), 10, usize::MAX).with_journal(&path);
+			let journal_task = journal_task.unwrap();
+			(mempool, std::thread::spawn(move || futures::executor::block_on(journal_task)))
+		};
+
+		let (mempool, journal_task) = new_mempool();
+		let xts = (0..5).map(|x| Arc::from(uxt(x as _))).collect::<Vec<_>>();
+
+		let results = mempool.extend_unwatched(TransactionSource::Local, &xts[..3]);
+		assert!(results.iter().all(Result::is_ok));
+		assert!(mempool.push_watched(TransactionSource::External, xts[3].clone()).is_ok());
+		*mempool.get_by_hash(hash(&xts[1])).unwrap().priority.write() = Some(7);
+		mempool.remove_transactions(&[hash(&xts[0])]);
+		mempool.compact_journal();
+
+		let results = mempool.extend_unwatched(TransactionSource::External, &xts[4..]);
+		assert!(results.iter().all(Result::is_ok));
+		drop(mempool);
+		journal_task.join().unwrap();
+
+		let (mempool, _) = new_mempool();
+		assert_eq!(mempool.unwatched_and_watched_count(), (4, 0));
+		assert!(mempool.get_by_hash(hash(&xts[0])).is_none());
+
+		let tx = mempool.get_by_hash(hash(&xts[1])).unwrap();
+		assert_eq!(TransactionSource::from(tx.source()), TransactionSource::Local);
+		assert_eq!(tx.priority(), Some(7));
+
+		let tx = mempool.get_by_hash(hash(&xts[4])).unwrap();
+		assert_eq!(TransactionSource::from(tx.source()), TransactionSource::External);
+		assert_eq!(tx.priority(), None);
+	}
+
 	#[test]
 	fn count_works() {
 		let max = 100;
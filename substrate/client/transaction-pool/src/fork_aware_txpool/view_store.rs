@@ -23,6 +23,7 @@ use super::{
 	view::{View, ViewPoolObserver},
 };
 use crate::{
+	common::introspection::transaction_info,
 	fork_aware_txpool::dropped_watcher::MultiViewDroppedWatcherController,
 	graph::{
 		self,
@@ -35,7 +36,8 @@ use crate::{
 use itertools::Itertools;
 use parking_lot::RwLock;
 use sc_transaction_pool_api::{
-	error::Error as PoolError, PoolStatus, TransactionTag as Tag, TxInvalidityReportMap,
+	error::Error as PoolError, InPoolTransactionInfo, PoolContent, PoolStatus,
+	TransactionTag as Tag, TxInvalidityReportMap,
 };
 use sp_blockchain::{HashAndNumber, TreeRoute};
 use sp_runtime::{
@@ -448,6 +450,79 @@ where
 			.map(|(v, _)| v.pool.validated_pool().pool.read().futures().cloned().collect())
 	}
 
+	/// Returns the content of the ready and future queues of the view at given block.
+	///
+	/// If `at` is `None`, the most recently notified view is used. Every transaction is annotated
+	/// with the active views in which it is ready or future.
+	pub(super) fn content_at(
+		&self,
+		at: Option<Block::Hash>,
+	) -> Option<PoolContent<ExtrinsicHash<ChainApi>, Block::Hash>> {
+		let at = at.or_else(|| *self.most_recent_view.read())?;
+		let (view, _) = self.get_view_at(at, true)?;
+		let (ready, future) = view.pool.validated_pool().ready_and_futures();
+
+		let placements = self.transactions_placements();
+		let info = |tx: &TransactionFor<ChainApi>| {
+			let (ready_in, future_in) = placements.get(&tx.hash).cloned().unwrap_or_default();
+			transaction_info(tx, ready_in, future_in)
+		};
+
+		Some(PoolContent {
+			at: Some(at),
+			ready: ready.iter().map(info).collect(),
+			future: future.iter().map(info).collect(),
+		})
+	}
+
+	/// Returns the details of the transaction with given hash, if any active view references it.
+	///
+	/// The transaction is annotated with the active views in which it is ready or future. The
+	/// transactions kept only in the *mempool* are not known to the views, see
+	/// [`TxMemPool::transaction_info`](super::tx_mem_pool::TxMemPool::transaction_info).
+	pub(super) fn transaction_info(
+		&self,
+		tx_hash: &ExtrinsicHash<ChainApi>,
+	) -> Option<InPoolTransactionInfo<ExtrinsicHash<ChainApi>, Block::Hash>> {
+		let mut found = None;
+		let mut ready_in = vec![];
+		let mut future_in = vec![];
+		for (at, view) in self.active_views.read().iter() {
+			if let Some((tx, is_ready)) = view.pool.validated_pool().transaction_by_hash(tx_hash) {
+				if is_ready {
+					ready_in.push(*at);
+				} else {
+					future_in.push(*at);
+				}
+				found.get_or_insert(tx);
+			}
+		}
+		found.map(|tx| transaction_info(&tx, ready_in, future_in))
+	}
+
+	/// Returns `true` if the transaction with given hash is banned in the most recently notified
+	/// view.
+	pub(super) fn is_banned(&self, tx_hash: &ExtrinsicHash<ChainApi>) -> bool {
+		self.most_recent_view
+			.read()
+			.and_then(|at| self.get_view_at(at, true))
+			.is_some_and(|(view, _)| view.pool.validated_pool().is_banned(tx_hash))
+	}
+
+	/// For every transaction referenced by any active view provides the hashes of the views in
+	/// which the transaction is ready and future respectively.
+	fn transactions_placements(
+		&self,
+	) -> HashMap<ExtrinsicHash<ChainApi>, (Vec<Block::Hash>, Vec<Block::Hash>)> {
+		let mut placements = HashMap::<_, (Vec<_>, Vec<_>)>::new();
+		for (at, view) in self.active_views.read().iter() {
+			let (ready, future) = view.pool.validated_pool().ready_and_futures();
+			ready.iter().for_each(|tx| placements.entry(tx.hash).or_default().0.push(*at));
+			future.iter().for_each(|tx| placements.entry(tx.hash).or_default().1.push(*at));
+		}
+		placements
+	}
+
 	/// Collects all the transactions included in the blocks on the provided `tree_route` and
 	/// triggers finalization event for them.
 	///
@@ -19,9 +19,11 @@
 use std::{collections::HashMap, fmt::Debug, hash};
 
 use linked_hash_map::LinkedHashMap;
+use sc_transaction_pool_api::{PoolEvent, PoolEventStream};
 use tracing::trace;
 
 use super::{watcher, BlockHash, ChainApi, ExtrinsicHash};
+use crate::common::introspection::PoolEvents;
 
 static LOG_TARGET: &str = "txpool::watcher";
 
@@ -79,6 +81,9 @@ pub struct EventDispatcher<H: hash::Hash + Eq, C: ChainApi, L: EventHandler<C>>
 	/// Optional event handler (listener) that will be notified about all transactions status
 	/// changes from the pool.
 	event_handler: Option<L>,
+
+	/// Dispatcher of the pool events for all (also not watched) transactions.
+	pool_events: PoolEvents<H>,
 }
 
 /// Maximum number of blocks awaiting finality at any time.
@@ -92,6 +97,7 @@ impl<H: hash::Hash + Eq + Debug, C: ChainApi, L: EventHandler<C>> Default
 			watchers: Default::default(),
 			finality_watchers: Default::default(),
 			event_handler: None,
+			pool_events: Default::default(),
 		}
 	}
 }
@@ -151,6 +157,7 @@ impl<C: ChainApi, L: EventHandler<C>> EventDispatcher<ExtrinsicHash<C>, C, L> {
 		self.fire(tx, |watcher| watcher.ready());
 		if let Some(old) = old {
 			self.fire(old, |watcher| watcher.usurped(*tx));
+			self.pool_events.notify(PoolEvent::Usurped { hash: *old, by: *tx });
 		}
 
 		self.event_handler.as_ref().map(|l| l.ready(*tx));
@@ -176,6 +183,7 @@ impl<C: ChainApi, L: EventHandler<C>> EventDispatcher<ExtrinsicHash<C>, C, L> {
 			"Dropped (limits enforced)."
 		);
 		self.fire(tx_hash, |watcher| watcher.limit_enforced());
+		self.pool_events.notify(PoolEvent::Dropped { hash: *tx_hash });
 
 		self.event_handler.as_ref().map(|l| l.limits_enforced(*tx_hash));
 	}
@@ -189,6 +197,7 @@ impl<C: ChainApi, L: EventHandler<C>> EventDispatcher<ExtrinsicHash<C>, C, L> {
 			"Dropped (replaced)."
 		);
 		self.fire(tx, |watcher| watcher.usurped(*by));
+		self.pool_events.notify(PoolEvent::Usurped { hash: *tx, by: *by });
 
 		self.event_handler.as_ref().map(|l| l.usurped(*tx, *by));
 	}
@@ -202,6 +211,7 @@ impl<C: ChainApi, L: EventHandler<C>> EventDispatcher<ExtrinsicHash<C>, C, L> {
 			"Dropped."
 		);
 		self.fire(tx_hash, |watcher| watcher.dropped());
+		self.pool_events.notify(PoolEvent::Dropped { hash: *tx_hash });
 		self.event_handler.as_ref().map(|l| l.dropped(*tx_hash));
 	}
 
@@ -213,6 +223,7 @@ impl<C: ChainApi, L: EventHandler<C>> EventDispatcher<ExtrinsicHash<C>, C, L> {
 			"Extrinsic invalid."
 		);
 		self.fire(tx_hash, |watcher| watcher.invalid());
+		self.pool_events.notify(PoolEvent::Invalid { hash: *tx_hash });
 		self.event_handler.as_ref().map(|l| l.invalid(*tx_hash));
 	}
 
@@ -273,4 +284,17 @@ impl<C: ChainApi, L: EventHandler<C>> EventDispatcher<ExtrinsicHash<C>, C, L> {
 	pub fn watched_transactions(&self) -> impl Iterator<Item = &ExtrinsicHash<C>> {
 		self.watchers.keys()
 	}
+
+	/// Creates a new stream of pool events, emitted for all the transactions in the pool.
+	pub fn pool_event_stream(&mut self) -> PoolEventStream<ExtrinsicHash<C>> {
+		self.pool_events.event_stream()
+	}
+
+	/// Returns the most recent removal event for given transaction, if it is still remembered.
+	pub fn recent_removal(
+		&self,
+		tx_hash: &ExtrinsicHash<C>,
+	) -> Option<PoolEvent<ExtrinsicHash<C>>> {
+		self.pool_events.recent_removal(tx_hash)
+	}
 }
@@ -25,7 +25,9 @@ use crate::{common::tracing_log_xt::log_xt_trace, LOG_TARGET};
 use futures::channel::mpsc::{channel, Sender};
 use indexmap::IndexMap;
 use parking_lot::{Mutex, RwLock};
-use sc_transaction_pool_api::{error, PoolStatus, ReadyTransactions, TransactionPriority};
+use sc_transaction_pool_api::{
+	error, PoolEvent, PoolEventStream, PoolStatus, ReadyTransactions, TransactionPriority,
+};
 use sp_blockchain::HashAndNumber;
 use sp_runtime::{
 	traits::SaturatedConversion,
@@ -783,6 +785,37 @@ impl<B: ChainApi, L: EventHandler<B>> ValidatedPool<B, L> {
 		self.pool.read().status()
 	}
 
+	/// Returns the transactions from the ready and the future queue.
+	///
+	/// Ready transactions are provided in the order they would be included in a block.
+	pub fn ready_and_futures(&self) -> (Vec<TransactionFor<B>>, Vec<TransactionFor<B>>) {
+		let pool = self.pool.read();
+		(pool.ready().collect(), pool.futures().cloned().map(Arc::new).collect())
+	}
+
+	/// Returns the transaction with given hash together with the flag indicating if it is placed
+	/// in the ready queue.
+	pub fn transaction_by_hash(
+		&self,
+		hash: &ExtrinsicHash<B>,
+	) -> Option<(TransactionFor<B>, bool)> {
+		let pool = self.pool.read();
+		if let Some(tx) = pool.ready_by_hash(hash) {
+			return Some((tx, true))
+		}
+		pool.by_hashes(&[*hash]).pop().flatten().map(|tx| (tx, false))
+	}
+
+	/// Return an event stream of pool events emitted for all the transactions in the pool.
+	pub fn pool_event_stream(&self) -> PoolEventStream<ExtrinsicHash<B>> {
+		self.event_dispatcher.write().pool_event_stream()
+	}
+
+	/// Returns the most recent removal event for given transaction, if it is still remembered.
+	pub fn recent_removal(&self, hash: &ExtrinsicHash<B>) -> Option<PoolEvent<ExtrinsicHash<B>>> {
+		self.event_dispatcher.read().recent_removal(hash)
+	}
+
 	/// Notify all watchers that transactions in the block with hash have been finalized
 	pub async fn on_block_finalized(&self, block_hash: BlockHash<B>) -> Result<(), B::Error> {
 		trace!(
@@ -27,6 +27,7 @@ use crate::{
 	common::{
 		enactment_state::{EnactmentAction, EnactmentState},
 		error,
+		introspection::transaction_info,
 		tracing_log_xt::log_xt_trace,
 	},
 	graph::{
@@ -41,7 +42,8 @@ use parking_lot::Mutex;
 use prometheus_endpoint::Registry as PrometheusRegistry;
 use sc_transaction_pool_api::{
 	error::Error as TxPoolError, ChainEvent, ImportNotificationStream, MaintainedTransactionPool,
-	PoolStatus, TransactionFor, TransactionPool, TransactionSource, TransactionStatusStreamFor,
+	PoolContent, PoolEventStream, PoolStatus, TransactionFor, TransactionInspection,
+	TransactionPool, TransactionPoolIntrospection, TransactionSource, TransactionStatusStreamFor,
 	TxHash, TxInvalidityReportMap,
 };
 use sp_blockchain::{HashAndNumber, TreeRoute};
@@ -418,6 +420,44 @@ where
 	}
 }
 
+impl<PoolApi, Block> TransactionPoolIntrospection for BasicPool<PoolApi, Block>
+where
+	Block: BlockT,
+	PoolApi: 'static + graph::ChainApi<Block = Block>,
+{
+	fn content_at(
+		&self,
+		_at: Option<Block::Hash>,
+	) -> Option<PoolContent<TxHash<Self>, Block::Hash>> {
+		// single-state pool does not maintain views, the content is the same for every block.
+		let (ready, future) = self.pool.validated_pool().ready_and_futures();
+		let info = |tx: &graph::TransactionFor<PoolApi>| transaction_info(tx, vec![], vec![]);
+		Some(PoolContent {
+			at: None,
+			ready: ready.iter().map(info).collect(),
+			future: future.iter().map(info).collect(),
+		})
+	}
+
+	fn inspect_transaction(
+		&self,
+		hash: &TxHash<Self>,
+	) -> TransactionInspection<TxHash<Self>, Block::Hash> {
+		let validated_pool = self.pool.validated_pool();
+		TransactionInspection {
+			info: validated_pool
+				.transaction_by_hash(hash)
+				.map(|(tx, _)| transaction_info(&tx, vec![], vec![])),
+			banned: validated_pool.is_banned(hash),
+			removal: validated_pool.recent_removal(hash),
+		}
+	}
+
+	fn pool_event_stream(&self) -> PoolEventStream<TxHash<Self>> {
+		self.pool.validated_pool().pool_event_stream()
+	}
+}
+
 impl<Block, Client> BasicPool<FullChainApi<Client, Block>, Block>
 where
 	Block: BlockT,
@@ -27,7 +27,8 @@ use crate::{
 use async_trait::async_trait;
 use sc_transaction_pool_api::{
 	ChainEvent, ImportNotificationStream, LocalTransactionFor, LocalTransactionPool,
-	MaintainedTransactionPool, PoolStatus, ReadyTransactions, TransactionFor, TransactionPool,
+	MaintainedTransactionPool, PoolContent, PoolEventStream, PoolStatus, ReadyTransactions,
+	TransactionFor, TransactionInspection, TransactionPool, TransactionPoolIntrospection,
 	TransactionSource, TransactionStatusStreamFor, TxHash, TxInvalidityReportMap,
 };
 use sp_runtime::traits::Block as BlockT;
@@ -188,3 +189,33 @@ where
 		self.0.submit_local(at, xt)
 	}
 }
+
+impl<Block, Client> TransactionPoolIntrospection for TransactionPoolWrapper<Block, Client>
+where
+	Block: BlockT,
+	Client: sp_api::ProvideRuntimeApi<Block>
+		+ sc_client_api::BlockBackend<Block>
+		+ sc_client_api::blockchain::HeaderBackend<Block>
+		+ sp_runtime::traits::BlockIdTo<Block>
+		+ sp_blockchain::HeaderMetadata<Block, Error = sp_blockchain::Error>
+		+ 'static,
+	Client::Api: sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>,
+{
+	fn content_at(
+		&self,
+		at: Option<<Self::Block as BlockT>::Hash>,
+	) -> Option<PoolContent<TxHash<Self>, <Self::Block as BlockT>::Hash>> {
+		self.0.content_at(at)
+	}
+
+	fn inspect_transaction(
+		&self,
+		hash: &TxHash<Self>,
+	) -> TransactionInspection<TxHash<Self>, <Self::Block as BlockT>::Hash> {
+		self.0.inspect_transaction(hash)
+	}
+
+	fn pool_event_stream(&self) -> PoolEventStream<TxHash<Self>> {
+		self.0.pool_event_stream()
+	}
+}
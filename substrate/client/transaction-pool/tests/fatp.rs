@@ -26,7 +26,7 @@ use futures::{executor::block_on, task::Poll, FutureExt, StreamExt};
 use sc_transaction_pool::ChainApi;
 use sc_transaction_pool_api::{
 	error::Error as TxPoolError, ChainEvent, MaintainedTransactionPool, TransactionPool,
-	TransactionStatus,
+	TransactionPoolIntrospection, TransactionStatus,
 };
 use sp_runtime::transaction_validity::InvalidTransaction;
 use std::{sync::Arc, time::Duration};
@@ -118,6 +118,27 @@ fn fatp_no_view_submit_already_imported_reports_error() {
 		.all(|r| { matches!(r.unwrap_err().0, TxPoolError::AlreadyImported(_)) }));
 }
 
+#[test]
+fn fatp_no_view_inspect_transaction_uses_mempool() {
+	sp_tracing::try_init_simple();
+
+	let (pool, api, _) = pool();
+
+	let header = api.push_block(1, vec![], true);
+
+	let xt0 = uxt(Alice, 200);
+	let xt0_hash = block_on(pool.submit_one(header.hash(), SOURCE, xt0.clone())).unwrap();
+
+	let info = pool.inspect_transaction(&xt0_hash).info.unwrap();
+	assert_eq!(info.hash, xt0_hash);
+	assert_eq!(info.bytes, api.hash_and_length(&xt0).1);
+	assert_eq!(info.source, SOURCE);
+	assert!(info.ready_in.is_empty());
+	assert!(info.future_in.is_empty());
+
+	assert!(pool.inspect_transaction(&api.hash_and_length(&uxt(Alice, 201)).0).info.is_none());
+}
+
 #[test]
 fn fatp_one_view_future_and_ready_submit_one_works() {
 	sp_tracing::try_init_simple();
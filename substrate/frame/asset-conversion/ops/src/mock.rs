@@ -115,6 +115,8 @@ impl pallet_asset_conversion::Config for Test {
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type MaxSwapPathLength = ConstU32<4>;
 	type MintMinLiquidity = ConstU64<100>;
+	type PriceObservationPeriod = ConstU64<10>;
+	type MaxPriceObservations = ConstU32<10>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -166,6 +166,64 @@ where
 	(lp_token, liquidity1, liquidity2)
 }
 
+/// Creates a stable-swap pool for a given asset pair and provides its initial liquidity.
+///
+/// The pool has the lowest amplification and far more liquidity of `asset2` than of `asset1`, so
+/// that the stable-swap calculations of its swaps take the most iterations to converge.
+fn create_imbalanced_stable_swap_pool<T: Config>(
+	caller: &T::AccountId,
+	asset1: &T::AssetKind,
+	asset2: &T::AssetKind,
+) where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+{
+	let (liquidity1, _) = valid_liquidity_amount::<T>(
+		T::Assets::minimum_balance(asset1.clone()),
+		T::Assets::minimum_balance(asset2.clone()),
+	);
+	let liquidity2 = T::Balance::from(u32::MAX) * T::Balance::from(u32::MAX);
+	create_asset::<T>(caller, asset1, liquidity1, true);
+	create_asset::<T>(caller, asset2, liquidity2, true);
+	let lp_token = AssetConversion::<T>::get_next_pool_asset_id();
+
+	mint_setup_fee_asset::<T>(caller, asset1, asset2, &lp_token);
+
+	assert_ok!(AssetConversion::<T>::create_pool_with_curve(
+		SystemOrigin::Signed(caller.clone()).into(),
+		Box::new(asset1.clone()),
+		Box::new(asset2.clone()),
+		PoolCurve::StableSwap { amplification: 1 },
+	));
+	assert_ok!(AssetConversion::<T>::add_liquidity(
+		SystemOrigin::Signed(caller.clone()).into(),
+		Box::new(asset1.clone()),
+		Box::new(asset2.clone()),
+		liquidity1,
+		liquidity2,
+		T::Balance::one(),
+		T::Balance::zero(),
+		caller.clone(),
+	));
+}
+
+/// Creates the imbalanced stable-swap pools along a swap path of `n` assets.
+fn create_stable_swap_path<T: Config>(caller: &T::AccountId, n: u32) -> Vec<Box<T::AssetKind>>
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+{
+	let mut path = vec![];
+	for n in 1..n {
+		let (asset1, asset2) = T::BenchmarkHelper::create_pair(n - 1, n);
+		if path.len() == 0 {
+			path = vec![Box::new(asset1.clone()), Box::new(asset2.clone())];
+		} else {
+			path.push(Box::new(asset2.clone()));
+		}
+		create_imbalanced_stable_swap_pool::<T>(caller, &asset1, &asset2);
+	}
+	path
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -199,6 +257,34 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn create_pool_with_curve() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, 1);
+		create_asset::<T>(&caller, &asset1, T::Assets::minimum_balance(asset1.clone()), true);
+		create_asset::<T>(&caller, &asset2, T::Assets::minimum_balance(asset2.clone()), true);
+
+		let lp_token = AssetConversion::<T>::get_next_pool_asset_id();
+		create_fee_asset::<T>(&caller);
+		mint_setup_fee_asset::<T>(&caller, &asset1, &asset2, &lp_token);
+		let curve = PoolCurve::StableSwap { amplification: PoolCurve::MAX_AMPLIFICATION };
+
+		#[extrinsic_call]
+		_(
+			SystemOrigin::Signed(caller.clone()),
+			Box::new(asset1.clone()),
+			Box::new(asset2.clone()),
+			curve,
+		);
+
+		let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).unwrap();
+		let pool_account = T::PoolLocator::address(&pool_id).unwrap();
+		assert_eq!(PoolCurves::<T>::get(&pool_id), curve);
+		assert_last_event::<T>(
+			Event::PoolCreated { creator: caller, pool_account, pool_id, lp_token }.into(),
+		);
+	}
+
 	#[benchmark]
 	fn add_liquidity() {
 		let caller: T::AccountId = whitelisted_caller();
@@ -319,6 +405,35 @@ mod benchmarks {
 		assert_eq!(actual_balance, init_caller_balance - swap_amount);
 	}
 
+	#[benchmark]
+	fn swap_exact_tokens_for_tokens_stable_swap(n: Linear<2, { T::MaxSwapPathLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		create_fee_asset::<T>(&caller);
+		let path = create_stable_swap_path::<T>(&caller, n);
+		let swap_amount = T::Balance::from(n);
+
+		let asset_in = *path.first().unwrap().clone();
+		assert_ok!(T::Assets::mint_into(
+			asset_in.clone(),
+			&caller,
+			swap_amount + T::Balance::one()
+		));
+		let init_caller_balance = T::Assets::balance(asset_in.clone(), &caller);
+
+		#[extrinsic_call]
+		swap_exact_tokens_for_tokens(
+			SystemOrigin::Signed(caller.clone()),
+			path,
+			swap_amount,
+			T::Balance::one(),
+			caller.clone(),
+			true,
+		);
+
+		let actual_balance = T::Assets::balance(asset_in, &caller);
+		assert_eq!(actual_balance, init_caller_balance - swap_amount);
+	}
+
 	#[benchmark]
 	fn swap_tokens_for_exact_tokens(n: Linear<2, { T::MaxSwapPathLength::get() }>) {
 		let mut max_swap_amount = T::Balance::one();
@@ -368,6 +483,32 @@ mod benchmarks {
 		assert_eq!(actual_balance, init_caller_balance + T::Balance::one());
 	}
 
+	#[benchmark]
+	fn swap_tokens_for_exact_tokens_stable_swap(n: Linear<2, { T::MaxSwapPathLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		create_fee_asset::<T>(&caller);
+		let path = create_stable_swap_path::<T>(&caller, n);
+		let max_swap_amount = T::Balance::from(n * 2);
+
+		let asset_in = *path.first().unwrap().clone();
+		let asset_out = *path.last().unwrap().clone();
+		assert_ok!(T::Assets::mint_into(asset_in, &caller, max_swap_amount));
+		let init_caller_balance = T::Assets::balance(asset_out.clone(), &caller);
+
+		#[extrinsic_call]
+		swap_tokens_for_exact_tokens(
+			SystemOrigin::Signed(caller.clone()),
+			path,
+			T::Balance::one(),
+			max_swap_amount,
+			caller.clone(),
+			true,
+		);
+
+		let actual_balance = T::Assets::balance(asset_out, &caller);
+		assert_eq!(actual_balance, init_caller_balance + T::Balance::one());
+	}
+
 	#[benchmark]
 	fn touch(n: Linear<0, 3>) {
 		let caller: T::AccountId = whitelisted_caller();
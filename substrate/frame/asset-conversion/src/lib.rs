@@ -24,6 +24,8 @@
 //! This pallet allows you to:
 //!
 //!  - [create a liquidity pool](`Pallet::create_pool()`) for 2 assets
+//!  - [create a liquidity pool with a given pricing curve](`Pallet::create_pool_with_curve()`),
+//!    e.g. a stable-swap pool for assets pegged to each other
 //!  - [provide the liquidity](`Pallet::add_liquidity()`) and receive back an LP token
 //!  - [exchange the LP token back to assets](`Pallet::remove_liquidity()`)
 //!  - [swap a specific amount of assets for another](`Pallet::swap_exact_tokens_for_tokens()`) if
@@ -34,6 +36,8 @@
 //!    a runtime call endpoint
 //!  - [query the size of a liquidity pool](`AssetConversionApi::get_reserves`) via a runtime api
 //!    endpoint.
+//!  - [query a time-weighted average price](`AssetConversionApi::quote_twap_price`) of a pool via a
+//!    runtime api endpoint, or [convert balances](`TwapToAssetBalance`) with it.
 //!
 //! The `quote_price_exact_tokens_for_tokens` and `quote_price_tokens_for_exact_tokens` functions
 //! both take a path parameter of the route to take. If you want to swap from native asset to
@@ -58,6 +62,7 @@
 mod benchmarking;
 #[cfg(test)]
 mod mock;
+mod oracle;
 mod swap;
 #[cfg(test)]
 mod tests;
@@ -65,6 +70,7 @@ mod types;
 pub mod weights;
 #[cfg(feature = "runtime-benchmarks")]
 pub use benchmarking::{BenchmarkHelper, NativeOrWithIdFactory};
+pub use oracle::*;
 pub use pallet::*;
 pub use swap::*;
 pub use types::*;
@@ -97,6 +103,9 @@ use sp_runtime::{
 	DispatchError, Saturating, TokenError, TransactionOutcome,
 };
 
+/// Upper bound on the Newton's method iterations of the stable-swap calculations.
+const STABLE_SWAP_MAX_ITERATIONS: u32 = 255;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -126,7 +135,8 @@ pub mod pallet {
 			+ Unsigned
 			+ From<u32>
 			+ From<Self::Balance>
-			+ TryInto<Self::Balance>;
+			+ TryInto<Self::Balance>
+			+ Copy;
 
 		/// Type of asset class, sourced from [`Config::Assets`], utilized to offer liquidity to a
 		/// pool.
@@ -186,6 +196,17 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSwapPathLength: Get<u32>;
 
+		/// The minimum number of blocks between two recorded price observations of a pool.
+		#[pallet::constant]
+		type PriceObservationPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of price observations recorded per pool.
+		///
+		/// Together with [`Config::PriceObservationPeriod`] it bounds the longest window a
+		/// time-weighted average price can be queried for.
+		#[pallet::constant]
+		type MaxPriceObservations: Get<u32>;
+
 		/// The pallet's id, used for deriving its sovereign account ID.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -204,6 +225,19 @@ pub mod pallet {
 	pub type Pools<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::PoolId, PoolInfo<T::PoolAssetId>, OptionQuery>;
 
+	/// Map from `PoolId` to the pricing curve of the pool.
+	///
+	/// Pools without an entry use [`PoolCurve::ConstantProduct`].
+	#[pallet::storage]
+	pub type PoolCurves<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, PoolCurve, ValueQuery>;
+
+	/// Map from `PoolId` to the price accumulators of the pool, used to derive time-weighted
+	/// average prices.
+	#[pallet::storage]
+	pub type PriceOracles<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, PriceOracleOf<T>, OptionQuery>;
+
 	/// Stores the `PoolAssetId` that is going to be used for the next lp token.
 	/// This gets incremented whenever a new lp pool is created.
 	#[pallet::storage]
@@ -349,6 +383,14 @@ pub mod pallet {
 		IncorrectPoolAssetId,
 		/// The destination account cannot exist with the swapped funds.
 		BelowMinimum,
+		/// The provided pool curve parameters are out of bounds.
+		InvalidPoolCurve,
+		/// The stable-swap invariant calculation did not converge.
+		InvariantNotConverged,
+		/// The window of a time-weighted average price can't be zero.
+		ZeroTwapWindow,
+		/// The price history of the pool doesn't span the requested window.
+		InsufficientPriceHistory,
 	}
 
 	#[pallet::hooks]
@@ -358,6 +400,10 @@ pub mod pallet {
 				T::MaxSwapPathLength::get() > 1,
 				"the `MaxSwapPathLength` should be greater than 1",
 			);
+			assert!(
+				T::MaxPriceObservations::get() > 0,
+				"the `MaxPriceObservations` should be greater than 0",
+			);
 		}
 	}
 
@@ -376,51 +422,7 @@ pub mod pallet {
 			asset2: Box<T::AssetKind>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
-
-			// prepare pool_id
-			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
-				.map_err(|_| Error::<T>::InvalidAssetPair)?;
-			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
-
-			let pool_account =
-				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
-
-			// pay the setup fee
-			let fee =
-				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
-			T::PoolSetupFeeTarget::on_unbalanced(fee);
-
-			if T::Assets::should_touch(*asset1.clone(), &pool_account) {
-				T::Assets::touch(*asset1, &pool_account, &sender)?
-			};
-
-			if T::Assets::should_touch(*asset2.clone(), &pool_account) {
-				T::Assets::touch(*asset2, &pool_account, &sender)?
-			};
-
-			let lp_token = NextPoolAssetId::<T>::get()
-				.or(T::PoolAssetId::initial_value())
-				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
-			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
-
-			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
-			if T::PoolAssets::should_touch(lp_token.clone(), &pool_account) {
-				T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?
-			};
-
-			let pool_info = PoolInfo { lp_token: lp_token.clone() };
-			Pools::<T>::insert(pool_id.clone(), pool_info);
-
-			Self::deposit_event(Event::PoolCreated {
-				creator: sender,
-				pool_id,
-				pool_account,
-				lp_token,
-			});
-
-			Ok(())
+			Self::do_create_pool(sender, *asset1, *asset2, PoolCurve::ConstantProduct)
 		}
 
 		/// Provide liquidity into the pool of `asset1` and `asset2`.
@@ -505,6 +507,13 @@ pub mod pallet {
 				Error::<T>::AmountTwoLessThanMinimal
 			);
 
+			Self::update_price_oracle(
+				&pool_id,
+				&asset1,
+				reserve1.saturating_add(amount1),
+				reserve2.saturating_add(amount2),
+			);
+
 			T::Assets::transfer(*asset1, &sender, &pool_account, amount1, Preserve)?;
 			T::Assets::transfer(*asset2, &sender, &pool_account, amount2, Preserve)?;
 
@@ -598,6 +607,8 @@ pub mod pallet {
 				Error::<T>::ReserveLeftLessThanMinimal
 			);
 
+			Self::update_price_oracle(&pool_id, &asset1, reserve1_left, reserve2_left);
+
 			// burn the provided lp token amount that includes the fee
 			T::PoolAssets::burn_from(
 				pool.lp_token.clone(),
@@ -632,7 +643,10 @@ pub mod pallet {
 		/// [`AssetConversionApi::quote_price_exact_tokens_for_tokens`] runtime call can be called
 		/// for a quote.
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::swap_exact_tokens_for_tokens(path.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::swap_exact_tokens_for_tokens(path.len() as u32)
+				.max(T::WeightInfo::swap_exact_tokens_for_tokens_stable_swap(path.len() as u32))
+		)]
 		pub fn swap_exact_tokens_for_tokens(
 			origin: OriginFor<T>,
 			path: Vec<Box<T::AssetKind>>,
@@ -640,17 +654,25 @@ pub mod pallet {
 			amount_out_min: T::Balance,
 			send_to: T::AccountId,
 			keep_alive: bool,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
+			let path: Vec<_> = path.into_iter().map(|a| *a).collect();
 			Self::do_swap_exact_tokens_for_tokens(
 				sender,
-				path.into_iter().map(|a| *a).collect(),
+				path.clone(),
 				amount_in,
 				Some(amount_out_min),
 				send_to,
 				keep_alive,
 			)?;
-			Ok(())
+
+			let n = path.len() as u32;
+			Ok(Some(Self::swap_weight(
+				&path,
+				T::WeightInfo::swap_exact_tokens_for_tokens(n),
+				T::WeightInfo::swap_exact_tokens_for_tokens_stable_swap(n),
+			))
+			.into())
 		}
 
 		/// Swap any amount of `asset1` to get the exact amount of `asset2`.
@@ -660,7 +682,10 @@ pub mod pallet {
 		/// [`AssetConversionApi::quote_price_tokens_for_exact_tokens`] runtime call can be called
 		/// for a quote.
 		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::swap_tokens_for_exact_tokens(path.len() as u32))]
+		#[pallet::weight(
+			T::WeightInfo::swap_tokens_for_exact_tokens(path.len() as u32)
+				.max(T::WeightInfo::swap_tokens_for_exact_tokens_stable_swap(path.len() as u32))
+		)]
 		pub fn swap_tokens_for_exact_tokens(
 			origin: OriginFor<T>,
 			path: Vec<Box<T::AssetKind>>,
@@ -668,17 +693,25 @@ pub mod pallet {
 			amount_in_max: T::Balance,
 			send_to: T::AccountId,
 			keep_alive: bool,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
+			let path: Vec<_> = path.into_iter().map(|a| *a).collect();
 			Self::do_swap_tokens_for_exact_tokens(
 				sender,
-				path.into_iter().map(|a| *a).collect(),
+				path.clone(),
 				amount_out,
 				Some(amount_in_max),
 				send_to,
 				keep_alive,
 			)?;
-			Ok(())
+
+			let n = path.len() as u32;
+			Ok(Some(Self::swap_weight(
+				&path,
+				T::WeightInfo::swap_tokens_for_exact_tokens(n),
+				T::WeightInfo::swap_tokens_for_exact_tokens_stable_swap(n),
+			))
+			.into())
 		}
 
 		/// Touch an existing pool to fulfill prerequisites before providing liquidity, such as
@@ -723,9 +756,85 @@ pub mod pallet {
 			Self::deposit_event(Event::Touched { pool_id, who });
 			Ok(Some(T::WeightInfo::touch(refunds_number)).into())
 		}
+
+		/// Creates an empty liquidity pool priced with the given `curve` and an associated new
+		/// `lp_token` asset (the id of which is returned in the `Event::PoolCreated` event).
+		///
+		/// Behaves as [`Pallet::create_pool`], which always uses [`PoolCurve::ConstantProduct`].
+		/// The curve of a pool can't be changed once the pool is created.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::create_pool_with_curve())]
+		pub fn create_pool_with_curve(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			curve: PoolCurve,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_create_pool(sender, *asset1, *asset2, curve)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Create an empty liquidity pool of `asset1` and `asset2` priced with `curve`, paying the
+		/// setup fee from `sender`.
+		pub(crate) fn do_create_pool(
+			sender: T::AccountId,
+			asset1: T::AssetKind,
+			asset2: T::AssetKind,
+			curve: PoolCurve,
+		) -> DispatchResult {
+			ensure!(asset1 != asset2, Error::<T>::InvalidAssetPair);
+			ensure!(curve.is_valid(), Error::<T>::InvalidPoolCurve);
+
+			// prepare pool_id
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			ensure!(!Pools::<T>::contains_key(&pool_id), Error::<T>::PoolExists);
+
+			let pool_account =
+				T::PoolLocator::address(&pool_id).map_err(|_| Error::<T>::InvalidAssetPair)?;
+
+			// pay the setup fee
+			let fee =
+				Self::withdraw(T::PoolSetupFeeAsset::get(), &sender, T::PoolSetupFee::get(), true)?;
+			T::PoolSetupFeeTarget::on_unbalanced(fee);
+
+			if T::Assets::should_touch(asset1.clone(), &pool_account) {
+				T::Assets::touch(asset1, &pool_account, &sender)?
+			};
+
+			if T::Assets::should_touch(asset2.clone(), &pool_account) {
+				T::Assets::touch(asset2, &pool_account, &sender)?
+			};
+
+			let lp_token = NextPoolAssetId::<T>::get()
+				.or(T::PoolAssetId::initial_value())
+				.ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			let next_lp_token_id = lp_token.increment().ok_or(Error::<T>::IncorrectPoolAssetId)?;
+			NextPoolAssetId::<T>::set(Some(next_lp_token_id));
+
+			T::PoolAssets::create(lp_token.clone(), pool_account.clone(), false, 1u32.into())?;
+			if T::PoolAssets::should_touch(lp_token.clone(), &pool_account) {
+				T::PoolAssets::touch(lp_token.clone(), &pool_account, &sender)?
+			};
+
+			let pool_info = PoolInfo { lp_token: lp_token.clone() };
+			Pools::<T>::insert(pool_id.clone(), pool_info);
+			if curve != PoolCurve::ConstantProduct {
+				PoolCurves::<T>::insert(pool_id.clone(), curve);
+			}
+
+			Self::deposit_event(Event::PoolCreated {
+				creator: sender,
+				pool_id,
+				pool_account,
+				lp_token,
+			});
+
+			Ok(())
+		}
+
 		/// Swap exactly `amount_in` of asset `path[0]` for asset `path[1]`.
 		/// If an `amount_out_min` is specified, it will return an error if it is unable to acquire
 		/// the amount desired.
@@ -1000,6 +1109,17 @@ pub mod pallet {
 			T::Assets::resolve(&pool_to, credit_in)
 				.map_err(|c| (c, Error::<T>::BelowMinimum.into()))?;
 
+			// store the spot prices at the reserves left by the swap
+			for pair in path.windows(2) {
+				if let [(asset1, _), (asset2, _)] = pair {
+					let Ok(pool_id) = T::PoolLocator::pool_id(asset1, asset2) else { continue };
+					let Ok(pool_account) = T::PoolLocator::address(&pool_id) else { continue };
+					let reserve1 = Self::get_balance(&pool_account, asset1.clone());
+					let reserve2 = Self::get_balance(&pool_account, asset2.clone());
+					Self::update_price_oracle(&pool_id, asset1, reserve1, reserve2);
+				}
+			}
+
 			Ok(credit_out)
 		}
 
@@ -1025,7 +1145,7 @@ pub mod pallet {
 
 		/// Get the `owner`'s balance of `asset`, which could be the chain's native asset or another
 		/// fungible. Returns a value in the form of an `Balance`.
-		fn get_balance(owner: &T::AccountId, asset: T::AssetKind) -> T::Balance {
+		pub(crate) fn get_balance(owner: &T::AccountId, asset: T::AssetKind) -> T::Balance {
 			T::Assets::reducible_balance(asset, owner, Expendable, Polite)
 		}
 
@@ -1066,8 +1186,10 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let curve = Self::pool_curve(asset1, &asset2);
 				balance_path.push((asset2, amount_in));
-				amount_in = Self::get_amount_in(&amount_in, &reserve_in, &reserve_out)?;
+				amount_in =
+					Self::get_curve_amount_in(curve, &amount_in, &reserve_in, &reserve_out)?;
 			}
 			balance_path.reverse();
 
@@ -1092,8 +1214,10 @@ pub mod pallet {
 					},
 				};
 				let (reserve_in, reserve_out) = Self::get_reserves(asset1.clone(), asset2.clone())?;
+				let curve = Self::pool_curve(&asset1, asset2);
 				balance_path.push((asset1, amount_out));
-				amount_out = Self::get_amount_out(&amount_out, &reserve_in, &reserve_out)?;
+				amount_out =
+					Self::get_curve_amount_out(curve, &amount_out, &reserve_in, &reserve_out)?;
 			}
 			Ok(balance_path)
 		}
@@ -1106,14 +1230,27 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let curve = Self::pool_curve(&asset1, &asset2);
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
 				if include_fee {
-					Self::get_amount_out(&amount, &balance1, &balance2).ok()
+					Self::get_curve_amount_out(curve, &amount, &balance1, &balance2).ok()
 				} else {
-					Self::quote(&amount, &balance1, &balance2).ok()
+					match curve {
+						PoolCurve::ConstantProduct =>
+							Self::quote(&amount, &balance1, &balance2).ok(),
+						PoolCurve::StableSwap { amplification } =>
+							Self::get_stable_swap_amount_out(
+								amplification,
+								0,
+								&amount,
+								&balance1,
+								&balance2,
+							)
+							.ok(),
+					}
 				}
 			} else {
 				None
@@ -1128,14 +1265,26 @@ pub mod pallet {
 			include_fee: bool,
 		) -> Option<T::Balance> {
 			let pool_account = T::PoolLocator::pool_address(&asset1, &asset2).ok()?;
+			let curve = Self::pool_curve(&asset1, &asset2);
 
 			let balance1 = Self::get_balance(&pool_account, asset1);
 			let balance2 = Self::get_balance(&pool_account, asset2);
 			if !balance1.is_zero() {
 				if include_fee {
-					Self::get_amount_in(&amount, &balance1, &balance2).ok()
+					Self::get_curve_amount_in(curve, &amount, &balance1, &balance2).ok()
 				} else {
-					Self::quote(&amount, &balance2, &balance1).ok()
+					match curve {
+						PoolCurve::ConstantProduct =>
+							Self::quote(&amount, &balance2, &balance1).ok(),
+						PoolCurve::StableSwap { amplification } => Self::get_stable_swap_amount_in(
+							amplification,
+							0,
+							&amount,
+							&balance1,
+							&balance2,
+						)
+						.ok(),
+					}
 				}
 			} else {
 				None
@@ -1260,6 +1409,274 @@ pub mod pallet {
 			result.try_into().map_err(|_| Error::<T>::Overflow)
 		}
 
+		/// Returns the pricing curve of the pool of `asset1` and `asset2`.
+		///
+		/// Falls back to [`PoolCurve::ConstantProduct`] if the pair has no pool.
+		pub fn pool_curve(asset1: &T::AssetKind, asset2: &T::AssetKind) -> PoolCurve {
+			T::PoolLocator::pool_id(asset1, asset2)
+				.map(PoolCurves::<T>::get)
+				.unwrap_or_default()
+		}
+
+		/// The weight of a swap along the `path`, given the weights of the same swap through
+		/// constant product pools only and through stable-swap pools only.
+		///
+		/// Each hop through a stable-swap pool is charged its share of the difference.
+		fn swap_weight(
+			path: &[T::AssetKind],
+			constant_product: Weight,
+			stable_swap: Weight,
+		) -> Weight {
+			let hops = path.len().saturating_sub(1).max(1) as u64;
+			let stable_swap_hops = path
+				.windows(2)
+				.filter(|pair| {
+					matches!(Self::pool_curve(&pair[0], &pair[1]), PoolCurve::StableSwap { .. })
+				})
+				.count() as u64;
+			let extra = stable_swap.saturating_sub(constant_product);
+			constant_product.saturating_add(extra.saturating_mul(stable_swap_hops) / hops)
+		}
+
+		/// Calculates amount out using the given pool `curve`.
+		///
+		/// Given an input amount of an asset and pair reserves, returns the maximum output amount
+		/// of the other asset.
+		pub fn get_curve_amount_out(
+			curve: PoolCurve,
+			amount_in: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			match curve {
+				PoolCurve::ConstantProduct =>
+					Self::get_amount_out(amount_in, reserve_in, reserve_out),
+				PoolCurve::StableSwap { amplification } => Self::get_stable_swap_amount_out(
+					amplification,
+					T::LPFee::get(),
+					amount_in,
+					reserve_in,
+					reserve_out,
+				),
+			}
+		}
+
+		/// Calculates amount in using the given pool `curve`.
+		///
+		/// Given an output amount of an asset and pair reserves, returns a required input amount
+		/// of the other asset.
+		pub fn get_curve_amount_in(
+			curve: PoolCurve,
+			amount_out: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			match curve {
+				PoolCurve::ConstantProduct =>
+					Self::get_amount_in(amount_out, reserve_in, reserve_out),
+				PoolCurve::StableSwap { amplification } => Self::get_stable_swap_amount_in(
+					amplification,
+					T::LPFee::get(),
+					amount_out,
+					reserve_in,
+					reserve_out,
+				),
+			}
+		}
+
+		/// Calculates amount out of a stable-swap pool with the `amplification` coefficient,
+		/// charging `lp_fee` 10ths of a percent of the `amount_in`.
+		fn get_stable_swap_amount_out(
+			amplification: u32,
+			lp_fee: u32,
+			amount_in: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			let amount_in = T::HigherPrecisionBalance::from(*amount_in);
+			let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
+			let reserve_out = T::HigherPrecisionBalance::from(*reserve_out);
+
+			if reserve_in.is_zero() || reserve_out.is_zero() {
+				return Err(Error::<T>::ZeroLiquidity)
+			}
+
+			let amount_in_with_fee = amount_in
+				.checked_mul(&(T::HigherPrecisionBalance::from(1000u32) - lp_fee.into()))
+				.ok_or(Error::<T>::Overflow)?
+				.checked_div(&1000u32.into())
+				.ok_or(Error::<T>::Overflow)?;
+
+			let d = Self::stable_swap_invariant(amplification, reserve_in, reserve_out)?;
+			let new_reserve_in =
+				reserve_in.checked_add(&amount_in_with_fee).ok_or(Error::<T>::Overflow)?;
+			let new_reserve_out = Self::stable_swap_reserve(amplification, new_reserve_in, d)?;
+
+			// round down in favour of the pool
+			let result = reserve_out
+				.checked_sub(&new_reserve_out)
+				.and_then(|r| r.checked_sub(&One::one()))
+				.unwrap_or_else(Zero::zero);
+
+			result.try_into().map_err(|_| Error::<T>::Overflow)
+		}
+
+		/// Calculates amount in of a stable-swap pool with the `amplification` coefficient,
+		/// charging `lp_fee` 10ths of a percent of the returned amount.
+		fn get_stable_swap_amount_in(
+			amplification: u32,
+			lp_fee: u32,
+			amount_out: &T::Balance,
+			reserve_in: &T::Balance,
+			reserve_out: &T::Balance,
+		) -> Result<T::Balance, Error<T>> {
+			let amount_out = T::HigherPrecisionBalance::from(*amount_out);
+			let reserve_in = T::HigherPrecisionBalance::from(*reserve_in);
+			let reserve_out = T::HigherPrecisionBalance::from(*reserve_out);
+
+			if reserve_in.is_zero() || reserve_out.is_zero() {
+				Err(Error::<T>::ZeroLiquidity)?
+			}
+
+			if amount_out >= reserve_out {
+				Err(Error::<T>::AmountOutTooHigh)?
+			}
+
+			// leave one unit more in the pool, mirroring the rounding of the amount out
+			let new_reserve_out = (reserve_out - amount_out)
+				.checked_sub(&One::one())
+				.filter(|r| !r.is_zero())
+				.ok_or(Error::<T>::AmountOutTooHigh)?;
+
+			let d = Self::stable_swap_invariant(amplification, reserve_in, reserve_out)?;
+			let new_reserve_in = Self::stable_swap_reserve(amplification, new_reserve_out, d)?;
+
+			// round up in favour of the pool, the invariant being only precise up to one unit
+			let amount_in_with_fee = new_reserve_in
+				.checked_sub(&reserve_in)
+				.unwrap_or_else(Zero::zero)
+				.checked_add(&One::one())
+				.ok_or(Error::<T>::Overflow)?;
+
+			let result = amount_in_with_fee
+				.checked_mul(&1000u32.into())
+				.ok_or(Error::<T>::Overflow)?
+				.checked_div(&(T::HigherPrecisionBalance::from(1000u32) - lp_fee.into()))
+				.ok_or(Error::<T>::Overflow)?
+				.checked_add(&One::one())
+				.ok_or(Error::<T>::Overflow)?;
+
+			result.try_into().map_err(|_| Error::<T>::Overflow)
+		}
+
+		/// Calculates the stable-swap invariant `D` of a pool with the reserves `x` and `y`.
+		///
+		/// Solves `4A(x + y) + D = 4AD + D^3 / 4xy` for `D` with Newton's method.
+		pub(crate) fn stable_swap_invariant(
+			amplification: u32,
+			x: T::HigherPrecisionBalance,
+			y: T::HigherPrecisionBalance,
+		) -> Result<T::HigherPrecisionBalance, Error<T>> {
+			let two = T::HigherPrecisionBalance::from(2u32);
+			let ann = T::HigherPrecisionBalance::from(amplification)
+				.checked_mul(&4u32.into())
+				.ok_or(Error::<T>::Overflow)?;
+			let sum = x.checked_add(&y).ok_or(Error::<T>::Overflow)?;
+			let ann_sum = ann.checked_mul(&sum).ok_or(Error::<T>::Overflow)?;
+			let (low, high) = if x < y { (x, y) } else { (y, x) };
+
+			let mut d = sum;
+			for _ in 0..STABLE_SWAP_MAX_ITERATIONS {
+				// d_p = d^3 / 4xy, dividing by the lower reserve first not to lose the precision
+				// needed to converge in imbalanced pools
+				let d_p = d
+					.checked_mul(&d)
+					.and_then(|d_p| d_p.checked_div(&low.checked_mul(&two)?))
+					.and_then(|d_p| d_p.checked_mul(&d))
+					.and_then(|d_p| d_p.checked_div(&high.checked_mul(&two)?))
+					.ok_or(Error::<T>::Overflow)?;
+
+				// d = (4A * sum + 2 * d_p) * d / ((4A - 1) * d + 3 * d_p)
+				let numerator = d_p
+					.checked_mul(&two)
+					.and_then(|n| n.checked_add(&ann_sum))
+					.and_then(|n| n.checked_mul(&d))
+					.ok_or(Error::<T>::Overflow)?;
+				let denominator = (ann - One::one())
+					.checked_mul(&d)
+					.and_then(|m| m.checked_add(&d_p.checked_mul(&3u32.into())?))
+					.ok_or(Error::<T>::Overflow)?;
+
+				let prev = d;
+				d = numerator.checked_div(&denominator).ok_or(Error::<T>::Overflow)?;
+				if Self::abs_diff(d, prev) <= One::one() {
+					return Ok(d)
+				}
+			}
+
+			Err(Error::<T>::InvariantNotConverged)
+		}
+
+		/// Calculates the reserve of one asset of a stable-swap pool with the invariant `d`, given
+		/// the reserve `x` of the other asset.
+		///
+		/// Solves `y^2 + (x + D / 4A - D) * y = D^3 / (16A * x)` for `y` with Newton's method.
+		fn stable_swap_reserve(
+			amplification: u32,
+			x: T::HigherPrecisionBalance,
+			d: T::HigherPrecisionBalance,
+		) -> Result<T::HigherPrecisionBalance, Error<T>> {
+			let two = T::HigherPrecisionBalance::from(2u32);
+			let ann = T::HigherPrecisionBalance::from(amplification)
+				.checked_mul(&4u32.into())
+				.ok_or(Error::<T>::Overflow)?;
+
+			// c = d^3 / (4x * ann)
+			let c = d
+				.checked_mul(&d)
+				.and_then(|c| c.checked_div(&x.checked_mul(&two)?))
+				.and_then(|c| c.checked_mul(&d))
+				.and_then(|c| c.checked_div(&ann.checked_mul(&two)?))
+				.ok_or(Error::<T>::Overflow)?;
+			let b = d
+				.checked_div(&ann)
+				.and_then(|b| b.checked_add(&x))
+				.ok_or(Error::<T>::Overflow)?;
+
+			let mut y = d;
+			for _ in 0..STABLE_SWAP_MAX_ITERATIONS {
+				// y = (y^2 + c) / (2y + b - d)
+				let numerator = y
+					.checked_mul(&y)
+					.and_then(|n| n.checked_add(&c))
+					.ok_or(Error::<T>::Overflow)?;
+				let denominator = y
+					.checked_mul(&two)
+					.and_then(|m| m.checked_add(&b))
+					.and_then(|m| m.checked_sub(&d))
+					.ok_or(Error::<T>::Overflow)?;
+
+				let prev = y;
+				y = numerator.checked_div(&denominator).ok_or(Error::<T>::Overflow)?;
+				if Self::abs_diff(y, prev) <= One::one() {
+					return Ok(y)
+				}
+			}
+
+			Err(Error::<T>::InvariantNotConverged)
+		}
+
+		fn abs_diff(
+			a: T::HigherPrecisionBalance,
+			b: T::HigherPrecisionBalance,
+		) -> T::HigherPrecisionBalance {
+			if a > b {
+				a - b
+			} else {
+				b - a
+			}
+		}
+
 		/// Ensure that a path is valid.
 		fn validate_swap_path(path: &Vec<T::AssetKind>) -> Result<(), DispatchError> {
 			ensure!(path.len() >= 2, Error::<T>::InvalidPath);
@@ -1323,6 +1740,16 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns the size of the liquidity pool for the given asset pair.
 		fn get_reserves(asset1: AssetId, asset2: AssetId) -> Option<(Balance, Balance)>;
+
+		/// Provides the amount of `asset2` worth `amount` of `asset1` at the time-weighted average
+		/// price of their pool over at least the last `window` blocks.
+		#[api_version(2)]
+		fn quote_twap_price(
+			asset1: AssetId,
+			asset2: AssetId,
+			amount: Balance,
+			window: u32,
+		) -> Option<Balance>;
 	}
 }
 
@@ -0,0 +1,336 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Time-weighted average price oracle of the liquidity pools.
+//!
+//! Every pool accumulates the price of its assets weighted by the number of blocks the price was
+//! in effect, following the Uniswap V2 oracle design. Every swap and liquidity change first
+//! accumulates the stored prices, then stores the spot prices at the reserves it leaves behind,
+//! given by the slope of the [`PoolCurve`] of the pool. Funds sent to a pool outside of these
+//! operations are not accounted for until the next one, and a price needs to persist for at least
+//! one block to have any effect on the average. A bounded history of the accumulators is kept to
+//! derive averages over a requested window.
+
+use super::*;
+use codec::{Decode, Encode, MaxEncodedLen};
+use core::marker::PhantomData;
+use frame_support::{ensure, traits::tokens::ConversionToAssetBalance, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_arithmetic::{
+	traits::{AtLeast32BitUnsigned, Bounded, UniqueSaturatedInto},
+	FixedPointNumber, FixedU128,
+};
+use sp_runtime::RuntimeDebug;
+
+/// Cumulative prices of a pool at a given block.
+///
+/// Prices are [`FixedU128`] inner values multiplied by the number of blocks they were in effect
+/// for. The accumulators wrap around on overflow, only the difference between two observations
+/// is meaningful.
+#[derive(Decode, Encode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PriceObservation<BlockNumber> {
+	/// The block of the observation.
+	pub block: BlockNumber,
+	/// Cumulative price of the base asset denominated in the quote asset.
+	pub base_price_cumulative: u128,
+	/// Cumulative price of the quote asset denominated in the base asset.
+	pub quote_price_cumulative: u128,
+}
+
+/// Price accumulators of a pool.
+#[derive(Decode, Encode, MaxEncodedLen, TypeInfo)]
+#[codec(mel_bound(AssetKind: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxObservations))]
+pub struct PriceOracle<AssetKind, BlockNumber, MaxObservations: Get<u32>> {
+	/// The pool asset the base price is recorded for, the other pool asset being the quote one.
+	pub base_asset: AssetKind,
+	/// The accumulators as of the last update of the pool.
+	pub last_update: PriceObservation<BlockNumber>,
+	/// The price of the base asset denominated in the quote asset, set by the last update of the
+	/// pool and in effect until the next one.
+	pub base_price: FixedU128,
+	/// The price of the quote asset denominated in the base asset, set by the last update of the
+	/// pool and in effect until the next one.
+	pub quote_price: FixedU128,
+	/// The history of the accumulators, recorded at most once per
+	/// [`Config::PriceObservationPeriod`], oldest first.
+	pub observations: BoundedVec<PriceObservation<BlockNumber>, MaxObservations>,
+}
+
+/// [`PriceOracle`] of a pool of the pallet.
+pub type PriceOracleOf<T> =
+	PriceOracle<<T as Config>::AssetKind, BlockNumberFor<T>, <T as Config>::MaxPriceObservations>;
+
+impl<AssetKind, BlockNumber, MaxObservations> PriceOracle<AssetKind, BlockNumber, MaxObservations>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+	MaxObservations: Get<u32>,
+{
+	/// Creates empty accumulators starting at the block `now`.
+	pub fn new(base_asset: AssetKind, now: BlockNumber) -> Self {
+		let observation =
+			PriceObservation { block: now, base_price_cumulative: 0, quote_price_cumulative: 0 };
+		let mut observations = BoundedVec::new();
+		let _ = observations.try_push(observation.clone());
+		Self {
+			base_asset,
+			last_update: observation,
+			base_price: Zero::zero(),
+			quote_price: Zero::zero(),
+			observations,
+		}
+	}
+
+	/// Returns the accumulators at the block `now`, given that the stored prices are in effect
+	/// since the last update.
+	pub fn observe(&self, now: BlockNumber) -> PriceObservation<BlockNumber> {
+		let last = &self.last_update;
+		let elapsed: u128 = now.saturating_sub(last.block).unique_saturated_into();
+		PriceObservation {
+			block: now,
+			base_price_cumulative: last
+				.base_price_cumulative
+				.wrapping_add(self.base_price.into_inner().wrapping_mul(elapsed)),
+			quote_price_cumulative: last
+				.quote_price_cumulative
+				.wrapping_add(self.quote_price.into_inner().wrapping_mul(elapsed)),
+		}
+	}
+
+	/// Accumulates the stored prices up to the block `now` and records the accumulators in the
+	/// history if the last record is at least `period` old. Then stores the given prices, in
+	/// effect from now on.
+	pub fn update(
+		&mut self,
+		now: BlockNumber,
+		base_price: FixedU128,
+		quote_price: FixedU128,
+		period: BlockNumber,
+	) {
+		let observation = self.observe(now);
+		let record = match self.observations.last() {
+			Some(last) => now >= last.block.saturating_add(period),
+			None => true,
+		};
+		if record {
+			if self.observations.is_full() {
+				// drop the oldest record
+				self.observations.remove(0);
+			}
+			// can't fail, there is space left or the oldest record was just dropped.
+			let _ = self.observations.try_push(observation.clone());
+		}
+		self.last_update = observation;
+		self.base_price = base_price;
+		self.quote_price = quote_price;
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Accumulates the prices of the pool of `asset1` and `asset2` up to the current block, then
+	/// stores the spot prices at the reserves `reserve1` and `reserve2`.
+	///
+	/// Must be called by every operation changing the reserves of the pool, with the reserves it
+	/// leaves in the pool.
+	pub(crate) fn update_price_oracle(
+		pool_id: &T::PoolId,
+		asset1: &T::AssetKind,
+		reserve1: T::Balance,
+		reserve2: T::Balance,
+	) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let curve = PoolCurves::<T>::get(pool_id);
+		PriceOracles::<T>::mutate(pool_id, |maybe_oracle| {
+			let oracle = maybe_oracle.get_or_insert_with(|| PriceOracle::new(asset1.clone(), now));
+			let (base_reserve, quote_reserve) = if oracle.base_asset == *asset1 {
+				(reserve1, reserve2)
+			} else {
+				(reserve2, reserve1)
+			};
+			// the stored prices stay in effect if the curve can't be evaluated at the reserves
+			let (base_price, quote_price) = Self::spot_prices(curve, base_reserve, quote_reserve)
+				.unwrap_or((oracle.base_price, oracle.quote_price));
+			oracle.update(now, base_price, quote_price, T::PriceObservationPeriod::get());
+		});
+	}
+
+	/// Returns the spot prices of a pool priced with `curve`, the price of the base asset
+	/// denominated in the quote asset and the price of the quote asset denominated in the base
+	/// asset.
+	///
+	/// The spot price is the marginal price of an infinitesimal swap without the fee, i.e. the
+	/// slope of the curve at the given reserves. Both prices are zero for an empty pool.
+	pub fn spot_prices(
+		curve: PoolCurve,
+		base_reserve: T::Balance,
+		quote_reserve: T::Balance,
+	) -> Result<(FixedU128, FixedU128), Error<T>> {
+		if base_reserve.is_zero() || quote_reserve.is_zero() {
+			return Ok((Zero::zero(), Zero::zero()))
+		}
+		let base_price = FixedU128::saturating_from_rational(quote_reserve, base_reserve);
+		let quote_price = FixedU128::saturating_from_rational(base_reserve, quote_reserve);
+
+		match curve {
+			PoolCurve::ConstantProduct => Ok((base_price, quote_price)),
+			PoolCurve::StableSwap { amplification } => {
+				let (base_weight, quote_weight) =
+					Self::stable_swap_price_weights(amplification, base_reserve, quote_reserve)?;
+				Ok((
+					base_price.saturating_mul(FixedU128::saturating_from_rational(
+						base_weight,
+						quote_weight,
+					)),
+					quote_price.saturating_mul(FixedU128::saturating_from_rational(
+						quote_weight,
+						base_weight,
+					)),
+				))
+			},
+		}
+	}
+
+	/// Returns the factors `4Ax + p` and `4Ay + p` applied to the constant product price of a
+	/// stable-swap pool with the reserves `x` and `y`, where `p = D^3 / 4xy`.
+	///
+	/// Differentiating the invariant `4A(x + y) + D = 4AD + D^3 / 4xy` gives the slope
+	/// `dy/dx = -(y / x) * (4Ax + p) / (4Ay + p)`. The factors are scaled down to fit a balance.
+	fn stable_swap_price_weights(
+		amplification: u32,
+		x: T::Balance,
+		y: T::Balance,
+	) -> Result<(T::Balance, T::Balance), Error<T>> {
+		let two = T::HigherPrecisionBalance::from(2u32);
+		let x = T::HigherPrecisionBalance::from(x);
+		let y = T::HigherPrecisionBalance::from(y);
+		let ann = T::HigherPrecisionBalance::from(amplification)
+			.checked_mul(&4u32.into())
+			.ok_or(Error::<T>::Overflow)?;
+		let d = Self::stable_swap_invariant(amplification, x, y)?;
+
+		let (low, high) = if x < y { (x, y) } else { (y, x) };
+		let p = d
+			.checked_mul(&d)
+			.and_then(|p| p.checked_div(&low.checked_mul(&two)?))
+			.and_then(|p| p.checked_mul(&d))
+			.and_then(|p| p.checked_div(&high.checked_mul(&two)?))
+			.ok_or(Error::<T>::Overflow)?;
+		let x_weight = ann.checked_mul(&x).and_then(|w| w.checked_add(&p));
+		let y_weight = ann.checked_mul(&y).and_then(|w| w.checked_add(&p));
+		let (x_weight, y_weight) = x_weight.zip(y_weight).ok_or(Error::<T>::Overflow)?;
+
+		let max_weight = if x_weight > y_weight { x_weight } else { y_weight };
+		let scale = max_weight
+			.checked_div(&T::Balance::max_value().into())
+			.and_then(|s| s.checked_add(&One::one()))
+			.ok_or(Error::<T>::Overflow)?;
+		let scale_down = |weight: T::HigherPrecisionBalance| {
+			weight
+				.checked_div(&scale)
+				.and_then(|w| w.try_into().ok())
+				.ok_or(Error::<T>::Overflow)
+		};
+		Ok((scale_down(x_weight)?, scale_down(y_weight)?))
+	}
+
+	/// Returns the time-weighted average price of `asset1` denominated in `asset2` over at least
+	/// the last `window` blocks.
+	///
+	/// Fails if the price history of the pool doesn't span the `window`, which can't exceed
+	/// [`Config::MaxPriceObservations`] times the [`Config::PriceObservationPeriod`].
+	pub fn get_twap_price(
+		asset1: &T::AssetKind,
+		asset2: &T::AssetKind,
+		window: BlockNumberFor<T>,
+	) -> Result<FixedU128, Error<T>> {
+		ensure!(!window.is_zero(), Error::<T>::ZeroTwapWindow);
+
+		let pool_id =
+			T::PoolLocator::pool_id(asset1, asset2).map_err(|_| Error::<T>::InvalidAssetPair)?;
+		let oracle =
+			PriceOracles::<T>::get(&pool_id).ok_or(Error::<T>::InsufficientPriceHistory)?;
+
+		let is_base = oracle.base_asset == *asset1;
+		let now = frame_system::Pallet::<T>::block_number();
+		let current = oracle.observe(now);
+
+		let since = now.checked_sub(&window).ok_or(Error::<T>::InsufficientPriceHistory)?;
+		let start = if oracle.last_update.block <= since {
+			// the stored prices are in effect since
+			&oracle.last_update
+		} else {
+			oracle
+				.observations
+				.iter()
+				.rev()
+				.find(|o| o.block <= since)
+				.ok_or(Error::<T>::InsufficientPriceHistory)?
+		};
+
+		let elapsed: u128 = now.saturating_sub(start.block).unique_saturated_into();
+		let cumulative = if is_base {
+			current.base_price_cumulative.wrapping_sub(start.base_price_cumulative)
+		} else {
+			current.quote_price_cumulative.wrapping_sub(start.quote_price_cumulative)
+		};
+
+		Ok(FixedU128::from_inner(cumulative / elapsed))
+	}
+
+	/// Used by the RPC service to provide time-weighted average prices.
+	///
+	/// Returns the amount of `asset2` worth `amount` of `asset1` at the average price over at
+	/// least the last `window` blocks.
+	pub fn quote_twap_price(
+		asset1: T::AssetKind,
+		asset2: T::AssetKind,
+		amount: T::Balance,
+		window: BlockNumberFor<T>,
+	) -> Option<T::Balance> {
+		let price = Self::get_twap_price(&asset1, &asset2, window).ok()?;
+		Some(price.saturating_mul_int(amount))
+	}
+}
+
+/// Converts a balance of the `Native` asset into a balance of another asset at the time-weighted
+/// average price of their pool over at least the last `Window` blocks.
+///
+/// Unlike the pool reserves, the average price can't be moved within a single block, making it
+/// suitable for pricing e.g. transaction fees in assets.
+pub struct TwapToAssetBalance<T, Native, Window>(PhantomData<(T, Native, Window)>);
+impl<T, Native, Window> ConversionToAssetBalance<T::Balance, T::AssetKind, T::Balance>
+	for TwapToAssetBalance<T, Native, Window>
+where
+	T: Config,
+	Native: Get<T::AssetKind>,
+	Window: Get<BlockNumberFor<T>>,
+{
+	type Error = Error<T>;
+
+	fn to_asset_balance(
+		balance: T::Balance,
+		asset_id: T::AssetKind,
+	) -> Result<T::Balance, Error<T>> {
+		let native = Native::get();
+		if asset_id == native {
+			return Ok(balance)
+		}
+		let price = Pallet::<T>::get_twap_price(&native, &asset_id, Window::get())?;
+		Ok(price.saturating_mul_int(balance))
+	}
+}
@@ -24,10 +24,11 @@ use frame_support::{
 		fungible::{Inspect as FungibleInspect, NativeOrWithId},
 		fungibles,
 		fungibles::{Inspect, InspectEnumerable},
-		Get,
+		tokens::ConversionToAssetBalance,
+		ConstU64, Get,
 	},
 };
-use sp_arithmetic::Permill;
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
 use sp_runtime::{DispatchError, TokenError};
 
 fn events() -> Vec<Event<Test>> {
@@ -2493,3 +2494,551 @@ fn swap_credit_invalid_path() {
 		assert_eq!(error, (expected_credit_in, Error::<Test>::InvalidPath.into()));
 	});
 }
+
+#[test]
+fn can_create_pool_with_stable_swap_curve() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+		let pool_id = (token_2.clone(), token_3.clone());
+		let curve = PoolCurve::StableSwap { amplification: 100 };
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000));
+
+		assert_noop!(
+			AssetConversion::create_pool_with_curve(
+				RuntimeOrigin::signed(user),
+				Box::new(token_3.clone()),
+				Box::new(token_2.clone()),
+				PoolCurve::StableSwap { amplification: 0 },
+			),
+			Error::<Test>::InvalidPoolCurve
+		);
+		assert_noop!(
+			AssetConversion::create_pool_with_curve(
+				RuntimeOrigin::signed(user),
+				Box::new(token_3.clone()),
+				Box::new(token_2.clone()),
+				PoolCurve::StableSwap { amplification: PoolCurve::MAX_AMPLIFICATION + 1 },
+			),
+			Error::<Test>::InvalidPoolCurve
+		);
+
+		assert_ok!(AssetConversion::create_pool_with_curve(
+			RuntimeOrigin::signed(user),
+			Box::new(token_3.clone()),
+			Box::new(token_2.clone()),
+			curve,
+		));
+		assert_eq!(pools(), vec![pool_id.clone()]);
+		assert_eq!(PoolCurves::<Test>::get(&pool_id), curve);
+		assert_eq!(AssetConversion::pool_curve(&token_2, &token_3), curve);
+
+		// pools created with `create_pool` keep the constant product curve
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+		assert!(!PoolCurves::<Test>::contains_key(&(token_1.clone(), token_2.clone())));
+		assert_eq!(AssetConversion::pool_curve(&token_1, &token_2), PoolCurve::ConstantProduct);
+	});
+}
+
+#[test]
+fn stable_swap_curve_prices_balanced_pool_close_to_parity() {
+	new_test_ext().execute_with(|| {
+		let curve = PoolCurve::StableSwap { amplification: 100 };
+		let reserve = 1_000_000_000_000u128;
+		let amount_in = 1_000_000_000u128;
+
+		let amount_out =
+			AssetConversion::get_curve_amount_out(curve, &amount_in, &reserve, &reserve).unwrap();
+		assert_eq!(amount_out, 996_995_054);
+		// only the 0.3% fee and a negligible slippage are taken
+		let xyk_amount_out =
+			AssetConversion::get_amount_out(&amount_in, &reserve, &reserve).unwrap();
+		assert!(amount_out > xyk_amount_out);
+		assert!(amount_out <= amount_in * 997 / 1000);
+
+		// rounding is always in favour of the pool
+		let amount_in =
+			AssetConversion::get_curve_amount_in(curve, &amount_out, &reserve, &reserve).unwrap();
+		assert_eq!(amount_in, 1_000_000_002);
+		assert!(
+			AssetConversion::get_curve_amount_out(curve, &amount_in, &reserve, &reserve).unwrap() >=
+				amount_out
+		);
+
+		assert!(matches!(
+			AssetConversion::get_curve_amount_in(curve, &(reserve - 1), &reserve, &reserve),
+			Err(Error::<Test>::AmountOutTooHigh)
+		));
+		assert!(matches!(
+			AssetConversion::get_curve_amount_out(curve, &amount_in, &0, &reserve),
+			Err(Error::<Test>::ZeroLiquidity)
+		));
+	});
+}
+
+#[test]
+fn stable_swap_curve_converges_in_imbalanced_pools() {
+	new_test_ext().execute_with(|| {
+		let curve = PoolCurve::StableSwap { amplification: 1 };
+		let low = 1_000u128;
+		let high = 1_000_000_000_000u128;
+
+		assert_eq!(
+			AssetConversion::get_curve_amount_out(curve, &low, &low, &high).ok(),
+			Some(292_085_886_436)
+		);
+		assert_eq!(
+			AssetConversion::get_curve_amount_out(curve, &1_000_000_000, &high, &low).ok(),
+			Some(1)
+		);
+
+		// the invariant is only precise up to one unit, a dust amount out still costs more than
+		// nothing
+		let high = 1u128 << 80;
+		assert_eq!(AssetConversion::get_curve_amount_in(curve, &1, &300, &high).ok(), Some(2));
+	});
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_across_mixed_curves() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let token_3 = NativeOrWithId::WithId(3);
+
+		create_tokens(user, vec![token_2.clone(), token_3.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+		assert_ok!(AssetConversion::create_pool_with_curve(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_3.clone()),
+			PoolCurve::StableSwap { amplification: 100 },
+		));
+
+		let ed = get_native_ed();
+		let base1 = 10000;
+		let base2 = 2_000_000;
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, base1 * 2 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, base2));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, base2));
+
+		let liquidity1 = 10000;
+		let liquidity2 = 1_000_000;
+
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			liquidity1,
+			liquidity1,
+			1,
+			1,
+			user,
+		));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_2.clone()),
+			Box::new(token_3.clone()),
+			liquidity2,
+			liquidity2,
+			1,
+			1,
+			user,
+		));
+
+		let input_amount = 500;
+		let expect_out2 = 474;
+		let expect_out3 = 471;
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(
+				token_1.clone(),
+				token_2.clone(),
+				input_amount,
+				true,
+			),
+			Some(expect_out2)
+		);
+		assert_eq!(
+			AssetConversion::quote_price_exact_tokens_for_tokens(
+				token_2.clone(),
+				token_3.clone(),
+				expect_out2,
+				true,
+			),
+			Some(expect_out3)
+		);
+		assert_eq!(
+			AssetConversion::quote_price_tokens_for_exact_tokens(
+				token_2.clone(),
+				token_3.clone(),
+				400,
+				true,
+			),
+			Some(404)
+		);
+
+		let info = AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone(), token_3.clone()],
+			input_amount,
+			expect_out3,
+			user,
+			true,
+		)
+		.unwrap();
+
+		// one of the two hops is charged as a stable-swap one
+		let weight = <() as WeightInfo>::swap_exact_tokens_for_tokens(3);
+		let stable_swap_weight = <() as WeightInfo>::swap_exact_tokens_for_tokens_stable_swap(3);
+		assert_eq!(info.actual_weight, Some(weight + (stable_swap_weight - weight) / 2));
+
+		let pool_id1 = (token_1.clone(), token_2.clone());
+		let pool_id2 = (token_2.clone(), token_3.clone());
+		let pallet_account1 = <Test as Config>::PoolLocator::address(&pool_id1).unwrap();
+		let pallet_account2 = <Test as Config>::PoolLocator::address(&pool_id2).unwrap();
+
+		assert_eq!(balance(pallet_account1, token_1.clone()), liquidity1 + input_amount);
+		assert_eq!(balance(pallet_account1, token_2.clone()), liquidity1 - expect_out2);
+		assert_eq!(balance(pallet_account2, token_2.clone()), liquidity2 + expect_out2);
+		assert_eq!(balance(pallet_account2, token_3.clone()), liquidity2 - expect_out3);
+		assert_eq!(balance(user, token_3.clone()), base2 - liquidity2 + expect_out3);
+	});
+}
+
+#[test]
+fn twap_price_is_weighted_by_blocks() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+
+		create_tokens(user, vec![token_2.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 20000));
+
+		// the oracle starts with the first liquidity provided at block 1
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			10000,
+			1,
+			1,
+			user,
+		));
+		assert!(matches!(
+			AssetConversion::get_twap_price(&token_1, &token_2, 1),
+			Err(Error::<Test>::InsufficientPriceHistory)
+		));
+
+		// the `1:1` price is in effect for 10 blocks
+		System::set_block_number(11);
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			1000,
+			1,
+			user,
+			false,
+		));
+		let (reserve1, reserve2) =
+			AssetConversion::get_reserves(token_1.clone(), token_2.clone()).unwrap();
+		let price = FixedU128::saturating_from_rational(reserve2, reserve1);
+		let inverse_price = FixedU128::saturating_from_rational(reserve1, reserve2);
+
+		// and the new price for another 10 blocks
+		System::set_block_number(21);
+		let average = |price: FixedU128| {
+			let one = FixedU128::one().into_inner();
+			FixedU128::from_inner((one * 10 + price.into_inner() * 10) / 20)
+		};
+		assert_eq!(
+			AssetConversion::get_twap_price(&token_1, &token_2, 20).ok(),
+			Some(average(price))
+		);
+		assert_eq!(
+			AssetConversion::get_twap_price(&token_2, &token_1, 20).ok(),
+			Some(average(inverse_price))
+		);
+		assert_eq!(AssetConversion::get_twap_price(&token_1, &token_2, 10).ok(), Some(price));
+		assert_eq!(
+			AssetConversion::quote_twap_price(token_1.clone(), token_2.clone(), 1000, 20),
+			Some(average(price).saturating_mul_int(1000))
+		);
+
+		// a swap doesn't move the average within its block
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			5000,
+			1,
+			user,
+			false,
+		));
+		assert_eq!(AssetConversion::get_twap_price(&token_1, &token_2, 10).ok(), Some(price));
+
+		assert!(matches!(
+			AssetConversion::get_twap_price(&token_1, &token_2, 0),
+			Err(Error::<Test>::ZeroTwapWindow)
+		));
+		assert!(matches!(
+			AssetConversion::get_twap_price(&token_1, &token_2, 21),
+			Err(Error::<Test>::InsufficientPriceHistory)
+		));
+		assert!(matches!(
+			AssetConversion::get_twap_price(&token_1, &NativeOrWithId::WithId(3), 10),
+			Err(Error::<Test>::InsufficientPriceHistory)
+		));
+	});
+}
+
+#[test]
+fn twap_price_history_is_bounded() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		create_tokens(user, vec![token_2.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 20000));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			10000,
+			1,
+			1,
+			user,
+		));
+
+		let period: u64 = <Test as Config>::PriceObservationPeriod::get();
+		let max_observations: u32 = <Test as Config>::MaxPriceObservations::get();
+		for i in 1..=(max_observations as u64 + 5) {
+			// updates within the observation period of the last record are not recorded
+			System::set_block_number(i * period);
+			assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+				RuntimeOrigin::signed(user),
+				bvec![token_1.clone(), token_2.clone()],
+				10,
+				1,
+				user,
+				false,
+			));
+			System::set_block_number(i * period + 1);
+			assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+				RuntimeOrigin::signed(user),
+				bvec![token_2.clone(), token_1.clone()],
+				10,
+				1,
+				user,
+				false,
+			));
+		}
+
+		let oracle = PriceOracles::<Test>::get(&pool_id).unwrap();
+		assert_eq!(oracle.observations.len() as u32, max_observations);
+		// the oldest records got dropped
+		let oldest = 6 * period + 1;
+		assert_eq!(oracle.observations.first().map(|o| o.block), Some(oldest));
+		assert_eq!(oracle.last_update.block, (max_observations as u64 + 5) * period + 1);
+
+		let now = System::block_number();
+		assert_ok!(AssetConversion::get_twap_price(&token_1, &token_2, now - oldest));
+		assert!(matches!(
+			AssetConversion::get_twap_price(&token_1, &token_2, now - oldest + 1),
+			Err(Error::<Test>::InsufficientPriceHistory)
+		));
+	});
+}
+
+#[test]
+fn twap_to_asset_balance_conversion() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		type Conversion = TwapToAssetBalance<Test, Native, ConstU64<10>>;
+
+		create_tokens(user, vec![token_2.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 20000));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			5000,
+			1,
+			1,
+			user,
+		));
+
+		assert!(matches!(
+			Conversion::to_asset_balance(100, token_2.clone()),
+			Err(Error::<Test>::InsufficientPriceHistory)
+		));
+		assert_eq!(Conversion::to_asset_balance(100, token_1.clone()).ok(), Some(100));
+
+		System::set_block_number(11);
+		assert_eq!(Conversion::to_asset_balance(100, token_2.clone()).ok(), Some(50));
+	});
+}
+
+#[test]
+fn twap_price_is_not_moved_by_transfers_into_the_pool() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let pool_id = (token_1.clone(), token_2.clone());
+		let pool_account = <Test as Config>::PoolLocator::address(&pool_id).unwrap();
+		type Conversion = TwapToAssetBalance<Test, Native, ConstU64<10>>;
+
+		create_tokens(user, vec![token_2.clone()]);
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 20000));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			10000,
+			1,
+			1,
+			user,
+		));
+
+		// funds sent to the pool outside of its operations are not priced in
+		System::set_block_number(11);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(user), 2, pool_account, 5000));
+		System::set_block_number(21);
+		assert_eq!(
+			AssetConversion::get_twap_price(&token_1, &token_2, 20).ok(),
+			Some(FixedU128::one())
+		);
+		assert_eq!(
+			AssetConversion::get_twap_price(&token_1, &token_2, 10).ok(),
+			Some(FixedU128::one())
+		);
+		assert_eq!(Conversion::to_asset_balance(100, token_2.clone()).ok(), Some(100));
+
+		// until the next swap, which prices them in from its block on
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			1000,
+			1,
+			user,
+			false,
+		));
+		assert_eq!(
+			AssetConversion::get_twap_price(&token_1, &token_2, 20).ok(),
+			Some(FixedU128::one())
+		);
+		let (reserve1, reserve2) =
+			AssetConversion::get_reserves(token_1.clone(), token_2.clone()).unwrap();
+
+		System::set_block_number(31);
+		assert_eq!(
+			AssetConversion::get_twap_price(&token_1, &token_2, 10).ok(),
+			Some(FixedU128::saturating_from_rational(reserve2, reserve1))
+		);
+	});
+}
+
+#[test]
+fn twap_price_follows_the_stable_swap_curve() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+		let curve = PoolCurve::StableSwap { amplification: 100 };
+
+		create_tokens(user, vec![token_2.clone()]);
+		assert_ok!(AssetConversion::create_pool_with_curve(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			curve,
+		));
+
+		let (reserve1, reserve2) = (2_000_000_000_000, 1_000_000_000_000);
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, reserve1 * 2 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, reserve2 * 2));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			reserve1,
+			reserve2,
+			1,
+			1,
+			user,
+		));
+
+		System::set_block_number(11);
+		let price = AssetConversion::get_twap_price(&token_1, &token_2, 10).unwrap();
+		let inverse_price = AssetConversion::get_twap_price(&token_2, &token_1, 10).unwrap();
+
+		// the price is the one of a marginal swap without the fee, not the `2:1` ratio of the
+		// reserves
+		let amount_in = 1_000_000;
+		let amount_out =
+			AssetConversion::get_curve_amount_out(curve, &amount_in, &reserve1, &reserve2).unwrap();
+		let swap_price = FixedU128::saturating_from_rational(amount_out, amount_in * 997 / 1000);
+		let tolerance = FixedU128::saturating_from_rational(1, 10_000);
+		assert!(price > FixedU128::saturating_from_rational(1, 2) && price < FixedU128::one());
+		assert!(price.max(swap_price) - price.min(swap_price) < tolerance);
+		assert!(
+			(price * inverse_price).max(FixedU128::one()) -
+				(price * inverse_price).min(FixedU128::one()) <
+				tolerance
+		);
+	});
+}
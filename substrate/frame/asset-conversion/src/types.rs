@@ -16,10 +16,10 @@
 // limitations under the License.
 
 use super::*;
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use core::marker::PhantomData;
 use scale_info::TypeInfo;
-use sp_runtime::traits::TryConvert;
+use sp_runtime::{traits::TryConvert, RuntimeDebug};
 
 /// Represents a swap path with associated asset amounts indicating how much of the asset needs to
 /// be deposited to get the following asset's amount withdrawn (this is inclusive of fees).
@@ -41,6 +41,52 @@ pub struct PoolInfo<PoolAssetId> {
 	pub lp_token: PoolAssetId,
 }
 
+/// Pricing curve used by a pool to calculate swap amounts.
+///
+/// Both curves charge the [`Config::LPFee`] on the input amount. Liquidity is always provided and
+/// withdrawn proportionally to the pool reserves, regardless of the curve.
+#[derive(
+	Decode,
+	DecodeWithMemTracking,
+	Encode,
+	Default,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub enum PoolCurve {
+	/// Uniswap V2 style constant product invariant `x * y = k`.
+	#[default]
+	ConstantProduct,
+	/// Curve style stable-swap invariant for assets expected to trade close to `1:1`.
+	///
+	/// The higher the `amplification`, the flatter the curve is around the balanced point and
+	/// the closer it gets to a constant sum. With an `amplification` of zero the invariant
+	/// degenerates to the constant product one, hence it is not allowed.
+	StableSwap {
+		/// The amplification coefficient `A` of the invariant.
+		amplification: u32,
+	},
+}
+
+impl PoolCurve {
+	/// The maximum amplification coefficient accepted for a [`PoolCurve::StableSwap`] pool.
+	pub const MAX_AMPLIFICATION: u32 = 1_000_000;
+
+	/// Whether the curve parameters are within the accepted bounds.
+	pub fn is_valid(&self) -> bool {
+		match self {
+			PoolCurve::ConstantProduct => true,
+			PoolCurve::StableSwap { amplification } =>
+				(1..=Self::MAX_AMPLIFICATION).contains(amplification),
+		}
+	}
+}
+
 /// Provides means to resolve the `PoolId` and `AccountId` from a pair of assets.
 ///
 /// Resulting `PoolId` remains consistent whether the asset pair is presented as (asset1, asset2)
@@ -35,9 +35,9 @@
 //! Autogenerated weights for `pallet_asset_conversion`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-21, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `4563561839a5`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
 
 // Executed Command:
@@ -48,8 +48,8 @@
 // --extrinsic=*
 // --runtime=target/production/wbuild/kitchensink-runtime/kitchensink_runtime.wasm
 // --pallet=pallet_asset_conversion
-// --header=/__w/polkadot-sdk/polkadot-sdk/substrate/HEADER-APACHE2
-// --output=/__w/polkadot-sdk/polkadot-sdk/substrate/frame/asset-conversion/src/weights.rs
+// --header=substrate/HEADER-APACHE2
+// --output=substrate/frame/asset-conversion/src/weights.rs
 // --wasm-execution=compiled
 // --steps=50
 // --repeat=20
@@ -73,10 +73,13 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_asset_conversion`.
 pub trait WeightInfo {
 	fn create_pool() -> Weight;
+	fn create_pool_with_curve() -> Weight;
 	fn add_liquidity() -> Weight;
 	fn remove_liquidity() -> Weight;
 	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight;
+	fn swap_exact_tokens_for_tokens_stable_swap(n: u32, ) -> Weight;
 	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight;
+	fn swap_tokens_for_exact_tokens_stable_swap(n: u32, ) -> Weight;
 	fn touch(n: u32, ) -> Weight;
 }
 
@@ -101,17 +104,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `476`
 		//  Estimated: `6360`
-		// Minimum execution time: 81_898_000 picoseconds.
-		Weight::from_parts(83_910_000, 6360)
+		// Minimum execution time: 223_588_000 picoseconds.
+		Weight::from_parts(260_353_000, 6360)
 			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:1)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:2 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::NextPoolAssetId` (r:1 w:1)
+	/// Proof: `AssetConversion::NextPoolAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Asset` (r:1 w:1)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::NextAssetId` (r:1 w:0)
+	/// Proof: `PoolAssets::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Account` (r:1 w:1)
+	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:0 w:1)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	fn create_pool_with_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `476`
+		//  Estimated: `6360`
+		// Minimum execution time: 215_595_000 picoseconds.
+		Weight::from_parts(250_920_000, 6360)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
 	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Asset` (r:2 w:2)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:4 w:4)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:1 w:1)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(964), added: 3439, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
@@ -122,10 +154,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `1090`
 		//  Estimated: `11426`
-		// Minimum execution time: 138_751_000 picoseconds.
-		Weight::from_parts(141_390_000, 11426)
-			.saturating_add(T::DbWeight::get().reads(11_u64))
-			.saturating_add(T::DbWeight::get().writes(10_u64))
+		// Minimum execution time: 370_074_000 picoseconds.
+		Weight::from_parts(423_928_000, 11426)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
 	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
@@ -135,49 +167,111 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
 	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:1 w:1)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(964), added: 3439, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Account` (r:1 w:1)
 	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	fn remove_liquidity() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1233`
+		//  Measured:  `1393`
 		//  Estimated: `11426`
-		// Minimum execution time: 124_722_000 picoseconds.
-		Weight::from_parts(128_644_000, 11426)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(8_u64))
+		// Minimum execution time: 339_016_000 picoseconds.
+		Weight::from_parts(372_805_000, 11426)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Assets::Asset` (r:4 w:4)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:8 w:8)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:3 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:3 w:3)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(964), added: 3439, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 4]`.
 	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (419 ±0)`
-		//  Estimated: `990 + n * (5218 ±0)`
-		// Minimum execution time: 88_884_000 picoseconds.
-		Weight::from_parts(91_036_000, 990)
-			// Standard Error: 337_841
-			.saturating_add(Weight::from_parts(11_478_919, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+		//  Measured:  `0 + n * (566 ±0)`
+		//  Estimated: `4429 + n * (5218 ±24)`
+		// Minimum execution time: 236_248_000 picoseconds.
+		Weight::from_parts(299_470_000, 4429)
+			// Standard Error: 1_253_548
+			.saturating_add(Weight::from_parts(49_330_597, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
+	}
+	/// Storage: `Assets::Asset` (r:4 w:4)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:8 w:8)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:3 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:3 w:3)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(964), added: 3439, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 4]`.
+	fn swap_exact_tokens_for_tokens_stable_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (605 ±0)`
+		//  Estimated: `4429 + n * (5218 ±0)`
+		// Minimum execution time: 451_788_000 picoseconds.
+		Weight::from_parts(548_979_000, 4429)
+			// Standard Error: 2_558_197
+			.saturating_add(Weight::from_parts(99_336_421, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
 	}
 	/// Storage: `Assets::Asset` (r:4 w:4)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:8 w:8)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:3 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:3 w:3)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(964), added: 3439, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 4]`.
 	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (419 ±0)`
-		//  Estimated: `990 + n * (5218 ±0)`
-		// Minimum execution time: 89_080_000 picoseconds.
-		Weight::from_parts(90_913_000, 990)
-			// Standard Error: 340_609
-			.saturating_add(Weight::from_parts(11_562_623, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+		//  Measured:  `0 + n * (566 ±0)`
+		//  Estimated: `4429 + n * (5218 ±0)`
+		// Minimum execution time: 160_853_000 picoseconds.
+		Weight::from_parts(65_567_785, 4429)
+			// Standard Error: 4_267_942
+			.saturating_add(Weight::from_parts(119_082_759, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
+	}
+	/// Storage: `Assets::Asset` (r:4 w:4)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:8 w:8)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:3 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:3 w:3)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(964), added: 3439, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 4]`.
+	fn swap_tokens_for_exact_tokens_stable_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (605 ±0)`
+		//  Estimated: `4429 + n * (5218 ±0)`
+		// Minimum execution time: 270_382_000 picoseconds.
+		Weight::from_parts(464_345_000, 4429)
+			// Standard Error: 2_414_092
+			.saturating_add(Weight::from_parts(89_453_452, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
@@ -197,10 +291,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `1154`
 		//  Estimated: `6360`
-		// Minimum execution time: 43_815_000 picoseconds.
-		Weight::from_parts(46_005_208, 6360)
-			// Standard Error: 68_937
-			.saturating_add(Weight::from_parts(19_974_807, 0).saturating_mul(n.into()))
+		// Minimum execution time: 64_168_000 picoseconds.
+		Weight::from_parts(98_461_044, 6360)
+			// Standard Error: 1_214_005
+			.saturating_add(Weight::from_parts(49_917_569, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
@@ -226,17 +320,46 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `476`
 		//  Estimated: `6360`
-		// Minimum execution time: 81_898_000 picoseconds.
-		Weight::from_parts(83_910_000, 6360)
+		// Minimum execution time: 223_588_000 picoseconds.
+		Weight::from_parts(260_353_000, 6360)
 			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:1)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:2 w:0)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::NextPoolAssetId` (r:1 w:1)
+	/// Proof: `AssetConversion::NextPoolAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Asset` (r:1 w:1)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::NextAssetId` (r:1 w:0)
+	/// Proof: `PoolAssets::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Account` (r:1 w:1)
+	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:0 w:1)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	fn create_pool_with_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `476`
+		//  Estimated: `6360`
+		// Minimum execution time: 215_595_000 picoseconds.
+		Weight::from_parts(250_920_000, 6360)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
 	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Asset` (r:2 w:2)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:4 w:4)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:1 w:1)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(964), added: 3439, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
@@ -247,10 +370,10 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `1090`
 		//  Estimated: `11426`
-		// Minimum execution time: 138_751_000 picoseconds.
-		Weight::from_parts(141_390_000, 11426)
-			.saturating_add(RocksDbWeight::get().reads(11_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+		// Minimum execution time: 370_074_000 picoseconds.
+		Weight::from_parts(423_928_000, 11426)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
 	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
@@ -260,49 +383,111 @@ impl WeightInfo for () {
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Asset` (r:1 w:1)
 	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:1 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:1 w:1)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(964), added: 3439, mode: `MaxEncodedLen`)
 	/// Storage: `PoolAssets::Account` (r:1 w:1)
 	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
 	fn remove_liquidity() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1233`
+		//  Measured:  `1393`
 		//  Estimated: `11426`
-		// Minimum execution time: 124_722_000 picoseconds.
-		Weight::from_parts(128_644_000, 11426)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+		// Minimum execution time: 339_016_000 picoseconds.
+		Weight::from_parts(372_805_000, 11426)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Assets::Asset` (r:4 w:4)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:8 w:8)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:3 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:3 w:3)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(964), added: 3439, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 4]`.
 	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (419 ±0)`
-		//  Estimated: `990 + n * (5218 ±0)`
-		// Minimum execution time: 88_884_000 picoseconds.
-		Weight::from_parts(91_036_000, 990)
-			// Standard Error: 337_841
-			.saturating_add(Weight::from_parts(11_478_919, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+		//  Measured:  `0 + n * (566 ±0)`
+		//  Estimated: `4429 + n * (5218 ±24)`
+		// Minimum execution time: 236_248_000 picoseconds.
+		Weight::from_parts(299_470_000, 4429)
+			// Standard Error: 1_253_548
+			.saturating_add(Weight::from_parts(49_330_597, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
+	}
+	/// Storage: `Assets::Asset` (r:4 w:4)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:8 w:8)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:3 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:3 w:3)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(964), added: 3439, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 4]`.
+	fn swap_exact_tokens_for_tokens_stable_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (605 ±0)`
+		//  Estimated: `4429 + n * (5218 ±0)`
+		// Minimum execution time: 451_788_000 picoseconds.
+		Weight::from_parts(548_979_000, 4429)
+			// Standard Error: 2_558_197
+			.saturating_add(Weight::from_parts(99_336_421, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
 	}
 	/// Storage: `Assets::Asset` (r:4 w:4)
 	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
 	/// Storage: `Assets::Account` (r:8 w:8)
 	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:3 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:3 w:3)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(964), added: 3439, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[2, 4]`.
 	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0 + n * (419 ±0)`
-		//  Estimated: `990 + n * (5218 ±0)`
-		// Minimum execution time: 89_080_000 picoseconds.
-		Weight::from_parts(90_913_000, 990)
-			// Standard Error: 340_609
-			.saturating_add(Weight::from_parts(11_562_623, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
-			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+		//  Measured:  `0 + n * (566 ±0)`
+		//  Estimated: `4429 + n * (5218 ±0)`
+		// Minimum execution time: 160_853_000 picoseconds.
+		Weight::from_parts(65_567_785, 4429)
+			// Standard Error: 4_267_942
+			.saturating_add(Weight::from_parts(119_082_759, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
+	}
+	/// Storage: `Assets::Asset` (r:4 w:4)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:8 w:8)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PoolCurves` (r:3 w:0)
+	/// Proof: `AssetConversion::PoolCurves` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::PriceOracles` (r:3 w:3)
+	/// Proof: `AssetConversion::PriceOracles` (`max_values`: None, `max_size`: Some(964), added: 3439, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 4]`.
+	fn swap_tokens_for_exact_tokens_stable_swap(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (605 ±0)`
+		//  Estimated: `4429 + n * (5218 ±0)`
+		// Minimum execution time: 270_382_000 picoseconds.
+		Weight::from_parts(464_345_000, 4429)
+			// Standard Error: 2_414_092
+			.saturating_add(Weight::from_parts(89_453_452, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 5218).saturating_mul(n.into()))
 	}
 	/// Storage: `AssetConversion::Pools` (r:1 w:0)
@@ -322,10 +507,10 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `1154`
 		//  Estimated: `6360`
-		// Minimum execution time: 43_815_000 picoseconds.
-		Weight::from_parts(46_005_208, 6360)
-			// Standard Error: 68_937
-			.saturating_add(Weight::from_parts(19_974_807, 0).saturating_mul(n.into()))
+		// Minimum execution time: 64_168_000 picoseconds.
+		Weight::from_parts(98_461_044, 6360)
+			// Standard Error: 1_214_005
+			.saturating_add(Weight::from_parts(49_917_569, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
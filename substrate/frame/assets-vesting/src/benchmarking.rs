@@ -0,0 +1,368 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Assets vesting pallet benchmarking.
+
+use super::*;
+use frame_benchmarking::{v2::*, BenchmarkError};
+use frame_support::{
+	assert_ok,
+	traits::fungibles::{Create, Inspect, Mutate},
+};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// Benchmark Helper
+pub trait BenchmarkHelper<AssetId> {
+	/// Returns the vested asset id.
+	///
+	/// If the asset does not exist, it will be created by the benchmark.
+	fn vested_asset() -> AssetId;
+}
+
+// Returns the vested asset, creating it if needed, along with the amount locked by the schedules
+// of `add_vesting_schedules`.
+fn setup_asset<T: Config<I>, I: 'static>() -> (T::AssetId, T::Balance)
+where
+	T::Assets: Create<T::AccountId>,
+{
+	let asset = T::BenchmarkHelper::vested_asset();
+	let min_balance = T::Assets::minimum_balance(asset.clone()).max(One::one());
+	if !T::Assets::asset_exists(asset.clone()) {
+		let owner = account("owner", 0, SEED);
+		assert_ok!(T::Assets::create(asset.clone(), owner, true, min_balance));
+	}
+	// Schedules have a duration of 20.
+	(asset, min_balance.saturating_mul(20u32.into()))
+}
+
+fn add_vesting_schedules<T: Config<I>, I: 'static>(
+	target: &T::AccountId,
+	asset: &T::AssetId,
+	locked: T::Balance,
+	n: u32,
+) -> T::Balance {
+	let per_block = locked / 20u32.into();
+	let starting_block = 1_u32;
+
+	let source = account("source", 0, SEED);
+	assert_ok!(T::Assets::mint_into(
+		asset.clone(),
+		&source,
+		locked.saturating_mul(n.into()).saturating_add(per_block)
+	));
+
+	T::BlockNumberProvider::set_block_number(BlockNumberFor::<T>::zero());
+
+	let mut total_locked: T::Balance = Zero::zero();
+	for _ in 0..n {
+		total_locked += locked;
+
+		let schedule =
+			VestingSchedule::Linear { locked, per_block, starting_block: starting_block.into() };
+		assert_ok!(Pallet::<T, I>::do_vested_transfer(&source, target, asset.clone(), schedule));
+	}
+
+	total_locked
+}
+
+#[instance_benchmarks(where T::Assets: Create<T::AccountId>)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn vest_locked(s: Linear<1, { T::MaxVestingSchedules::get() }>) -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller();
+		let (asset, locked) = setup_asset::<T, I>();
+		let expected_balance = add_vesting_schedules::<T, I>(&caller, &asset, locked, s);
+
+		// At block zero, everything is vested.
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(&caller, asset.clone()),
+			Some(expected_balance),
+			"Vesting schedule not added",
+		);
+
+		#[extrinsic_call]
+		vest(RawOrigin::Signed(caller.clone()), Box::new(asset.clone()));
+
+		// Nothing happened since everything is still vested.
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(&caller, asset),
+			Some(expected_balance),
+			"Vesting schedule was removed",
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn vest_unlocked(
+		s: Linear<1, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller();
+		let (asset, locked) = setup_asset::<T, I>();
+		add_vesting_schedules::<T, I>(&caller, &asset, locked, s);
+
+		// At block 21, everything is unlocked.
+		T::BlockNumberProvider::set_block_number(21_u32.into());
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(&caller, asset.clone()),
+			Some(Zero::zero()),
+			"Vesting schedule still active",
+		);
+
+		#[extrinsic_call]
+		vest(RawOrigin::Signed(caller.clone()), Box::new(asset.clone()));
+
+		// Vesting schedule is removed!
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(&caller, asset),
+			None,
+			"Vesting schedule was not removed",
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn vest_other_locked(
+		s: Linear<1, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let other: T::AccountId = account("other", 0, SEED);
+		let (asset, locked) = setup_asset::<T, I>();
+		let expected_balance = add_vesting_schedules::<T, I>(&other, &asset, locked, s);
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		vest_other(
+			RawOrigin::Signed(caller),
+			T::Lookup::unlookup(other.clone()),
+			Box::new(asset.clone()),
+		);
+
+		// Nothing happened since everything is still vested.
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(&other, asset),
+			Some(expected_balance),
+			"Vesting schedule was removed",
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn vest_other_unlocked(
+		s: Linear<1, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let other: T::AccountId = account("other", 0, SEED);
+		let (asset, locked) = setup_asset::<T, I>();
+		add_vesting_schedules::<T, I>(&other, &asset, locked, s);
+
+		// At block 21 everything is unlocked.
+		T::BlockNumberProvider::set_block_number(21_u32.into());
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		vest_other(
+			RawOrigin::Signed(caller),
+			T::Lookup::unlookup(other.clone()),
+			Box::new(asset.clone()),
+		);
+
+		// Vesting schedule is removed.
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(&other, asset),
+			None,
+			"Vesting schedule was not removed",
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn vested_transfer(
+		s: Linear<0, { T::MaxVestingSchedules::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let target: T::AccountId = account("target", 0, SEED);
+		let (asset, locked) = setup_asset::<T, I>();
+		// Add existing schedules.
+		let expected_balance = add_vesting_schedules::<T, I>(&target, &asset, locked, s);
+
+		assert_ok!(T::Assets::mint_into(
+			asset.clone(),
+			&caller,
+			locked.saturating_mul(2u32.into())
+		));
+		let schedule = VestingSchedule::Cliff {
+			locked,
+			per_block: locked / 20u32.into(),
+			starting_block: 1u32.into(),
+			cliff: 10u32.into(),
+		};
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			T::Lookup::unlookup(target.clone()),
+			Box::new(asset.clone()),
+			schedule,
+		);
+
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(&target, asset),
+			Some(expected_balance.saturating_add(locked)),
+			"Lock not correctly updated",
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_vested_transfer(
+		s: Linear<0, { T::MaxVestingSchedules::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let source: T::AccountId = account("transfer_source", 0, SEED);
+		let target: T::AccountId = account("target", 0, SEED);
+		let (asset, locked) = setup_asset::<T, I>();
+		// Add existing schedules.
+		let expected_balance = add_vesting_schedules::<T, I>(&target, &asset, locked, s);
+
+		assert_ok!(T::Assets::mint_into(
+			asset.clone(),
+			&source,
+			locked.saturating_mul(2u32.into())
+		));
+		let schedule = VestingSchedule::Cliff {
+			locked,
+			per_block: locked / 20u32.into(),
+			starting_block: 1u32.into(),
+			cliff: 10u32.into(),
+		};
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Root,
+			T::Lookup::unlookup(source),
+			T::Lookup::unlookup(target.clone()),
+			Box::new(asset.clone()),
+			schedule,
+		);
+
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(&target, asset),
+			Some(expected_balance.saturating_add(locked)),
+			"Lock not correctly updated",
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn not_unlocking_merge_schedules(
+		s: Linear<2, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset, locked) = setup_asset::<T, I>();
+		// Schedules are not vesting at block 0.
+		let expected_balance = add_vesting_schedules::<T, I>(&caller, &asset, locked, s);
+
+		#[extrinsic_call]
+		merge_schedules(RawOrigin::Signed(caller.clone()), Box::new(asset.clone()), 0, s - 1);
+
+		let expected_schedule = VestingSchedule::Linear {
+			locked: locked.saturating_mul(2u32.into()),
+			per_block: locked.saturating_mul(2u32.into()) / 20u32.into(),
+			starting_block: 1u32.into(),
+		};
+		assert_eq!(
+			Vesting::<T, I>::get(&caller, &asset).and_then(|s| s.last().copied()),
+			Some(expected_schedule),
+			"New schedule is properly created and placed"
+		);
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(&caller, asset),
+			Some(expected_balance),
+			"Vesting balance should equal sum locked of all schedules",
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unlocking_merge_schedules(
+		s: Linear<2, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset, locked) = setup_asset::<T, I>();
+		// Schedules are fully vested at block 21.
+		add_vesting_schedules::<T, I>(&caller, &asset, locked, s);
+
+		// Go to about half way through all the schedules duration. (They all start at 1, and
+		// have a duration of 20).
+		T::BlockNumberProvider::set_block_number(11_u32.into());
+
+		#[extrinsic_call]
+		merge_schedules(RawOrigin::Signed(caller.clone()), Box::new(asset.clone()), 0, s - 1);
+
+		// The merged schedule releases the remaining half of both schedules over the remaining
+		// duration.
+		let expected_schedule = VestingSchedule::Linear {
+			locked,
+			per_block: locked / 10u32.into(),
+			starting_block: 11u32.into(),
+		};
+		assert_eq!(
+			Vesting::<T, I>::get(&caller, &asset).and_then(|s| s.last().copied()),
+			Some(expected_schedule),
+			"New schedule is properly created and placed"
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_remove_vesting_schedule(
+		s: Linear<2, { T::MaxVestingSchedules::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let (asset, locked) = setup_asset::<T, I>();
+		// Give target existing schedules.
+		let expected_balance = add_vesting_schedules::<T, I>(&target, &asset, locked, s);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, T::Lookup::unlookup(target.clone()), Box::new(asset.clone()), 0);
+
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(&target, asset),
+			Some(expected_balance.saturating_sub(locked)),
+			"Schedule was not removed",
+		);
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite! {
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	}
+}
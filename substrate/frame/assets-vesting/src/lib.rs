@@ -0,0 +1,742 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Assets Vesting Pallet
+//!
+//! - [`Config`]
+//! - [`Call`]
+//!
+//! ## Overview
+//!
+//! The counterpart of `pallet_vesting` for `fungibles` assets. Vested funds of any asset are
+//! kept frozen through [`Config::AssetsFreezer`] until they are released by their
+//! [`VestingSchedule`], which is either:
+//! - [`VestingSchedule::Linear`]: releasing an amount every block, as in `pallet_vesting`;
+//! - [`VestingSchedule::Cliff`]: releasing nothing before a cliff block, then linearly;
+//! - [`VestingSchedule::Stepped`]: releasing an amount every number of blocks.
+//!
+//! Accounts can have up to [`Config::MaxVestingSchedules`] schedules per asset. As the vested
+//! amount increases over time, the frozen amount is only reduced on behalf of the account,
+//! either through `vest` or `vest_other`.
+//!
+//! The pallet is instantiable, allowing e.g. separate instances for the native currency and the
+//! assets.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `vest` - Update the freeze, reducing it in line with the amount vested so far.
+//! - `vest_other` - Update the freeze of another account, reducing it in line with the amount
+//!   vested so far.
+//! - `vested_transfer` - Transfer an amount of an asset which vests according to a schedule.
+//! - `force_vested_transfer` - As `vested_transfer`, from any account.
+//! - `merge_schedules` - Merge two schedules of an asset into a single one.
+//! - `force_remove_vesting_schedule` - Remove a schedule, releasing its funds.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+#[cfg(test)]
+mod mock;
+mod schedule;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+extern crate alloc;
+
+use alloc::{boxed::Box, vec::Vec};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	storage::bounded_vec::BoundedVec,
+	traits::{
+		fungibles::{Inspect, Mutate, MutateFreeze},
+		tokens::{AssetId, Balance, Preservation},
+		Get,
+	},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	traits::{BlockNumberProvider, Convert, One, Saturating, StaticLookup, Zero},
+	DispatchError,
+};
+
+pub use pallet::*;
+pub use schedule::*;
+pub use weights::WeightInfo;
+
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+
+/// The vesting schedule of an asset of the pallet.
+pub type VestingScheduleOf<T, I = ()> =
+	VestingSchedule<<T as Config<I>>::Balance, BlockNumberFor<T>>;
+
+/// Actions to take against a user's `Vesting` storage entry.
+#[derive(Clone, Copy)]
+enum VestingAction {
+	/// Do not actively remove any schedules.
+	Passive,
+	/// Remove the schedule specified by the index.
+	Remove { index: usize },
+	/// Remove the two schedules, specified by index, so they can be merged.
+	Merge { index1: usize, index2: usize },
+}
+
+impl VestingAction {
+	/// Whether or not the filter says the schedule index should be removed.
+	fn should_remove(&self, index: usize) -> bool {
+		match self {
+			Self::Passive => false,
+			Self::Remove { index: index1 } => *index1 == index,
+			Self::Merge { index1, index2 } => *index1 == index || *index2 == index,
+		}
+	}
+
+	/// Pick the schedules that this action dictates should continue vesting undisturbed.
+	fn pick_schedules<T: Config<I>, I: 'static>(
+		&self,
+		schedules: Vec<VestingScheduleOf<T, I>>,
+	) -> impl Iterator<Item = VestingScheduleOf<T, I>> + '_ {
+		schedules.into_iter().enumerate().filter_map(move |(index, schedule)| {
+			if self.should_remove(index) {
+				None
+			} else {
+				Some(schedule)
+			}
+		})
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T, I = ()>(_);
+
+	/// A reason for the pallet freezing funds.
+	#[pallet::composite_enum]
+	pub enum FreezeReason<I: 'static = ()> {
+		/// Funds are vesting.
+		#[codec(index = 0)]
+		Vesting,
+	}
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The overarching event type.
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier for each type of asset.
+		type AssetId: AssetId + Member + Parameter + MaxEncodedLen;
+
+		/// The type in which the assets are measured.
+		type Balance: Balance + TypeInfo;
+
+		/// Registry of the assets that can be vested.
+		type Assets: Inspect<Self::AccountId, AssetId = Self::AssetId, Balance = Self::Balance>
+			+ Mutate<Self::AccountId>;
+
+		/// Freezer for the assets.
+		type AssetsFreezer: MutateFreeze<
+			Self::AccountId,
+			Id = Self::RuntimeFreezeReason,
+			AssetId = Self::AssetId,
+			Balance = Self::Balance,
+		>;
+
+		/// The overarching freeze reason.
+		type RuntimeFreezeReason: From<FreezeReason<I>>;
+
+		/// Convert the block number into a balance.
+		type BlockNumberToBalance: Convert<BlockNumberFor<Self>, Self::Balance>;
+
+		/// Query the current block number.
+		///
+		/// Must return monotonically increasing values when called from consecutive blocks. See
+		/// `pallet_vesting::Config::BlockNumberProvider` for the suggested values.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Maximum number of vesting schedules an account may have for an asset at a given
+		/// moment.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper for benchmarking.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: benchmarking::BenchmarkHelper<Self::AssetId>;
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn integrity_test() {
+			assert!(
+				T::MaxVestingSchedules::get() > 0,
+				"`MaxVestingSchedules` must be greater than 0"
+			);
+		}
+	}
+
+	/// The vesting schedules of an account for an asset.
+	#[pallet::storage]
+	pub type Vesting<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AssetId,
+		BoundedVec<VestingScheduleOf<T, I>, T::MaxVestingSchedules>,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A vesting schedule has been created.
+		VestingCreated { account: T::AccountId, asset: T::AssetId, schedule_index: u32 },
+		/// The amount vested has been updated. This could indicate a change in funds available.
+		/// The balance given is the amount which is left unvested (and thus frozen).
+		VestingUpdated { account: T::AccountId, asset: T::AssetId, unvested: T::Balance },
+		/// An account has become fully vested in an asset.
+		VestingCompleted { account: T::AccountId, asset: T::AssetId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The account given is not vesting the asset.
+		NotVesting,
+		/// The account already has `MaxVestingSchedules` count of schedules for the asset and
+		/// thus cannot add another one. Consider merging existing schedules in order to add
+		/// another.
+		AtMaxVestingSchedules,
+		/// Amount being transferred is too low to create a vesting schedule.
+		AmountLow,
+		/// An index was out of bounds of the vesting schedules.
+		ScheduleIndexOutOfBounds,
+		/// Failed to create a new schedule because some parameter was invalid.
+		InvalidScheduleParams,
+		/// The schedules can't be merged as one of them doesn't release linearly yet.
+		ScheduleNotMergeable,
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Unlock any vested funds of the sender account.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have funds of
+		/// the `asset` still frozen under this pallet.
+		///
+		/// Emits either `VestingCompleted` or `VestingUpdated`.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::vest_locked(T::MaxVestingSchedules::get())
+			.max(T::WeightInfo::vest_unlocked(T::MaxVestingSchedules::get()))
+		)]
+		pub fn vest(origin: OriginFor<T>, asset: Box<T::AssetId>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_vest(who, *asset)
+		}
+
+		/// Unlock any vested funds of a `target` account.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: The account whose vested funds should be unlocked. Must have funds of the
+		/// `asset` still frozen under this pallet.
+		/// - `asset`: The vested asset.
+		///
+		/// Emits either `VestingCompleted` or `VestingUpdated`.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::vest_other_locked(T::MaxVestingSchedules::get())
+			.max(T::WeightInfo::vest_other_unlocked(T::MaxVestingSchedules::get()))
+		)]
+		pub fn vest_other(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			asset: Box<T::AssetId>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(target)?;
+			Self::do_vest(who, *asset)
+		}
+
+		/// Create a vested transfer.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: The account receiving the vested funds.
+		/// - `asset`: The asset to transfer.
+		/// - `schedule`: The vesting schedule attached to the transfer. Must vest at least the
+		///   minimum balance of the `asset`.
+		///
+		/// Emits `VestingCreated`.
+		///
+		/// NOTE: This will unlock all schedules of the `asset` through the current block.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::vested_transfer(T::MaxVestingSchedules::get()))]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			asset: Box<T::AssetId>,
+			schedule: VestingScheduleOf<T, I>,
+		) -> DispatchResult {
+			let transactor = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::do_vested_transfer(&transactor, &target, *asset, schedule)
+		}
+
+		/// Force a vested transfer.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// - `source`: The account whose funds should be transferred.
+		/// - `target`: The account that should be transferred the vested funds.
+		/// - `asset`: The asset to transfer.
+		/// - `schedule`: The vesting schedule attached to the transfer. Must vest at least the
+		///   minimum balance of the `asset`.
+		///
+		/// Emits `VestingCreated`.
+		///
+		/// NOTE: This will unlock all schedules of the `asset` through the current block.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::force_vested_transfer(T::MaxVestingSchedules::get()))]
+		pub fn force_vested_transfer(
+			origin: OriginFor<T>,
+			source: AccountIdLookupOf<T>,
+			target: AccountIdLookupOf<T>,
+			asset: Box<T::AssetId>,
+			schedule: VestingScheduleOf<T, I>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let source = T::Lookup::lookup(source)?;
+			Self::do_vested_transfer(&source, &target, *asset, schedule)
+		}
+
+		/// Merge two vesting schedules of an asset together, creating a new linear vesting
+		/// schedule that unlocks over the highest possible start and end blocks. If both
+		/// schedules have already started the current block will be used as the schedule start;
+		/// with the caveat that if one schedule is finished by the current block, the other will
+		/// be treated as the new merged schedule, unmodified.
+		///
+		/// Schedules which are not released linearly from the current block on, i.e. cliff
+		/// schedules before their cliff and stepped schedules, can only be merged with a
+		/// finished schedule.
+		///
+		/// NOTE: If `schedule1_index == schedule2_index` this is a no-op.
+		/// NOTE: This will unlock all schedules through the current block prior to merging.
+		/// NOTE: If both schedules have ended by the current block, no new schedule will be created
+		/// and both will be removed.
+		///
+		/// Merged schedule attributes:
+		/// - `starting_block`: `MAX(schedule1.starting_block, scheduled2.starting_block,
+		///   current_block)`.
+		/// - `ending_block`: `MAX(schedule1.ending_block, schedule2.ending_block)`.
+		/// - `locked`: `schedule1.locked_at(current_block) + schedule2.locked_at(current_block)`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `asset`: The vested asset.
+		/// - `schedule1_index`: index of the first schedule to merge.
+		/// - `schedule2_index`: index of the second schedule to merge.
+		#[pallet::call_index(4)]
+		#[pallet::weight(
+			T::WeightInfo::not_unlocking_merge_schedules(T::MaxVestingSchedules::get())
+			.max(T::WeightInfo::unlocking_merge_schedules(T::MaxVestingSchedules::get()))
+		)]
+		pub fn merge_schedules(
+			origin: OriginFor<T>,
+			asset: Box<T::AssetId>,
+			schedule1_index: u32,
+			schedule2_index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if schedule1_index == schedule2_index {
+				return Ok(())
+			};
+			let asset = *asset;
+			let schedule1_index = schedule1_index as usize;
+			let schedule2_index = schedule2_index as usize;
+
+			let schedules = Vesting::<T, I>::get(&who, &asset).ok_or(Error::<T, I>::NotVesting)?;
+			let merge_action =
+				VestingAction::Merge { index1: schedule1_index, index2: schedule2_index };
+
+			let (schedules, locked_now) = Self::exec_action(schedules.to_vec(), merge_action)?;
+
+			Self::write_vesting(&who, &asset, schedules)?;
+			Self::write_freeze(&who, &asset, locked_now)
+		}
+
+		/// Force remove a vesting schedule of an asset.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// - `target`: An account that has a vesting schedule
+		/// - `asset`: The vested asset.
+		/// - `schedule_index`: The vesting schedule index that should be removed
+		#[pallet::call_index(5)]
+		#[pallet::weight(
+			T::WeightInfo::force_remove_vesting_schedule(T::MaxVestingSchedules::get())
+		)]
+		pub fn force_remove_vesting_schedule(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			asset: Box<T::AssetId>,
+			schedule_index: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let who = T::Lookup::lookup(target)?;
+			let asset = *asset;
+
+			let schedules_count = Vesting::<T, I>::decode_len(&who, &asset).unwrap_or_default();
+			ensure!(schedule_index < schedules_count as u32, Error::<T, I>::InvalidScheduleParams);
+
+			Self::remove_vesting_schedule(&who, asset, schedule_index)?;
+
+			Ok(Some(T::WeightInfo::force_remove_vesting_schedule(schedules_count as u32)).into())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Get the amount of the `asset` that is currently being vested and cannot be transferred out
+	/// of the account `who`.
+	pub fn vesting_balance(who: &T::AccountId, asset: T::AssetId) -> Option<T::Balance> {
+		let schedules = Vesting::<T, I>::get(who, &asset)?;
+		let now = T::BlockNumberProvider::current_block_number();
+		let total_locked_now = schedules.iter().fold(Zero::zero(), |total: T::Balance, schedule| {
+			schedule.locked_at::<T::BlockNumberToBalance>(now).saturating_add(total)
+		});
+		Some(T::Assets::balance(asset, who).min(total_locked_now))
+	}
+
+	/// Adds a vesting schedule of the `asset` to the account `who`.
+	///
+	/// If the account has `MaxVestingSchedules` for the asset, an error is returned and nothing
+	/// is updated.
+	///
+	/// On success, the funds released by the schedule will be frozen. In order to realise any
+	/// reduction of the freeze over time, the account owner must use `vest` or `vest_other`.
+	///
+	/// NOTE: This doesn't alter the balance of the account.
+	pub fn add_vesting_schedule(
+		who: &T::AccountId,
+		asset: T::AssetId,
+		schedule: VestingScheduleOf<T, I>,
+	) -> DispatchResult {
+		ensure!(schedule.is_valid(), Error::<T, I>::InvalidScheduleParams);
+
+		let mut schedules = Vesting::<T, I>::get(who, &asset).unwrap_or_default();
+
+		// NOTE: we must push the new schedule so that `exec_action`
+		// will give the correct new locked amount.
+		ensure!(schedules.try_push(schedule).is_ok(), Error::<T, I>::AtMaxVestingSchedules);
+
+		debug_assert!(schedules.len() > 0, "schedules cannot be empty after insertion");
+		let schedule_index = schedules.len() - 1;
+		Self::deposit_event(Event::<T, I>::VestingCreated {
+			account: who.clone(),
+			asset: asset.clone(),
+			schedule_index: schedule_index as u32,
+		});
+
+		let (schedules, locked_now) =
+			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+
+		Self::write_vesting(who, &asset, schedules)?;
+		Self::write_freeze(who, &asset, locked_now)
+	}
+
+	/// Ensure we can call `add_vesting_schedule` without error. This should always
+	/// be called prior to `add_vesting_schedule`.
+	pub fn can_add_vesting_schedule(
+		who: &T::AccountId,
+		asset: &T::AssetId,
+		schedule: &VestingScheduleOf<T, I>,
+	) -> DispatchResult {
+		ensure!(schedule.is_valid(), Error::<T, I>::InvalidScheduleParams);
+		ensure!(
+			(Vesting::<T, I>::decode_len(who, asset).unwrap_or_default() as u32) <
+				T::MaxVestingSchedules::get(),
+			Error::<T, I>::AtMaxVestingSchedules
+		);
+
+		Ok(())
+	}
+
+	/// Remove a vesting schedule of the `asset` for a given account.
+	pub fn remove_vesting_schedule(
+		who: &T::AccountId,
+		asset: T::AssetId,
+		schedule_index: u32,
+	) -> DispatchResult {
+		let schedules = Vesting::<T, I>::get(who, &asset).ok_or(Error::<T, I>::NotVesting)?;
+		let remove_action = VestingAction::Remove { index: schedule_index as usize };
+
+		let (schedules, locked_now) = Self::exec_action(schedules.to_vec(), remove_action)?;
+
+		Self::write_vesting(who, &asset, schedules)?;
+		Self::write_freeze(who, &asset, locked_now)
+	}
+
+	// Create a new linear `VestingSchedule`, based off of two other `VestingSchedule`s.
+	// NOTE: We assume both schedules have had funds unlocked up through the current block.
+	fn merge_vesting_schedules(
+		now: BlockNumberFor<T>,
+		schedule1: VestingScheduleOf<T, I>,
+		schedule2: VestingScheduleOf<T, I>,
+	) -> Result<Option<VestingScheduleOf<T, I>>, DispatchError> {
+		let schedule1_ending_block = schedule1.ending_block_as_balance::<T::BlockNumberToBalance>();
+		let schedule2_ending_block = schedule2.ending_block_as_balance::<T::BlockNumberToBalance>();
+		let now_as_balance = T::BlockNumberToBalance::convert(now);
+
+		// Check if one or both schedules have ended.
+		match (schedule1_ending_block <= now_as_balance, schedule2_ending_block <= now_as_balance) {
+			// If both schedules have ended, we don't merge and exit early.
+			(true, true) => return Ok(None),
+			// If one schedule has ended, we treat the one that has not ended as the new
+			// merged schedule.
+			(true, false) => return Ok(Some(schedule2)),
+			(false, true) => return Ok(Some(schedule1)),
+			// If neither schedule has ended don't exit early.
+			_ => {},
+		}
+
+		// A linear schedule would release funds of a cliff or stepped one ahead of time.
+		ensure!(
+			schedule1.is_linear_from(now) && schedule2.is_linear_from(now),
+			Error::<T, I>::ScheduleNotMergeable
+		);
+
+		let locked = schedule1
+			.locked_at::<T::BlockNumberToBalance>(now)
+			.saturating_add(schedule2.locked_at::<T::BlockNumberToBalance>(now));
+		// This shouldn't happen because we know at least one ending block is greater than now,
+		// thus at least a schedule a some locked balance.
+		debug_assert!(
+			!locked.is_zero(),
+			"merge_vesting_schedules validation checks failed to catch a locked of 0"
+		);
+
+		let ending_block = schedule1_ending_block.max(schedule2_ending_block);
+		let starting_block = now.max(schedule1.starting_block()).max(schedule2.starting_block());
+
+		let per_block = {
+			let duration = ending_block
+				.saturating_sub(T::BlockNumberToBalance::convert(starting_block))
+				.max(One::one());
+			(locked / duration).max(One::one())
+		};
+
+		let schedule = VestingSchedule::Linear { locked, per_block, starting_block };
+		debug_assert!(
+			schedule.is_valid(),
+			"merge_vesting_schedules schedule validation check failed"
+		);
+
+		Ok(Some(schedule))
+	}
+
+	// Execute a vested transfer of the `asset` from `source` to `target` with the given
+	// `schedule`.
+	fn do_vested_transfer(
+		source: &T::AccountId,
+		target: &T::AccountId,
+		asset: T::AssetId,
+		schedule: VestingScheduleOf<T, I>,
+	) -> DispatchResult {
+		// Validate user inputs.
+		ensure!(
+			schedule.locked() >= T::Assets::minimum_balance(asset.clone()),
+			Error::<T, I>::AmountLow
+		);
+
+		// Check we can add to this account prior to any storage writes.
+		Self::can_add_vesting_schedule(target, &asset, &schedule)?;
+
+		T::Assets::transfer(
+			asset.clone(),
+			source,
+			target,
+			schedule.locked(),
+			Preservation::Expendable,
+		)?;
+
+		// We can't let this fail because the transfer has already happened.
+		// Must be successful as it has been checked before.
+		// Better to return error on failure anyway.
+		let res = Self::add_vesting_schedule(target, asset, schedule);
+		debug_assert!(res.is_ok(), "Failed to add a schedule when we had to succeed.");
+
+		res
+	}
+
+	/// Iterate through the schedules to track the current locked amount and
+	/// filter out completed and specified schedules.
+	///
+	/// Returns a tuple that consists of:
+	/// - Vec of vesting schedules, where completed schedules and those specified
+	/// 	by filter are removed. (Note the vec is not checked for respecting
+	/// 	bounded length.)
+	/// - The amount locked at the current block number based on the given schedules.
+	///
+	/// NOTE: the amount locked does not include any schedules that are filtered out via `action`.
+	fn report_schedule_updates(
+		schedules: Vec<VestingScheduleOf<T, I>>,
+		action: VestingAction,
+	) -> (Vec<VestingScheduleOf<T, I>>, T::Balance) {
+		let now = T::BlockNumberProvider::current_block_number();
+
+		let mut total_locked_now: T::Balance = Zero::zero();
+		let filtered_schedules = action
+			.pick_schedules::<T, I>(schedules)
+			.filter(|schedule| {
+				let locked_now = schedule.locked_at::<T::BlockNumberToBalance>(now);
+				let keep = !locked_now.is_zero();
+				if keep {
+					total_locked_now = total_locked_now.saturating_add(locked_now);
+				}
+				keep
+			})
+			.collect::<Vec<_>>();
+
+		(filtered_schedules, total_locked_now)
+	}
+
+	/// Write an account's updated vesting freeze of the `asset` to storage.
+	fn write_freeze(
+		who: &T::AccountId,
+		asset: &T::AssetId,
+		total_locked_now: T::Balance,
+	) -> DispatchResult {
+		let reason = FreezeReason::<I>::Vesting.into();
+		if total_locked_now.is_zero() {
+			T::AssetsFreezer::thaw(asset.clone(), &reason, who)?;
+			Self::deposit_event(Event::<T, I>::VestingCompleted {
+				account: who.clone(),
+				asset: asset.clone(),
+			});
+		} else {
+			T::AssetsFreezer::set_freeze(asset.clone(), &reason, who, total_locked_now)?;
+			Self::deposit_event(Event::<T, I>::VestingUpdated {
+				account: who.clone(),
+				asset: asset.clone(),
+				unvested: total_locked_now,
+			});
+		};
+
+		Ok(())
+	}
+
+	/// Write an account's updated vesting schedules of the `asset` to storage.
+	fn write_vesting(
+		who: &T::AccountId,
+		asset: &T::AssetId,
+		schedules: Vec<VestingScheduleOf<T, I>>,
+	) -> DispatchResult {
+		let schedules: BoundedVec<VestingScheduleOf<T, I>, T::MaxVestingSchedules> =
+			schedules.try_into().map_err(|_| Error::<T, I>::AtMaxVestingSchedules)?;
+
+		if schedules.len() == 0 {
+			Vesting::<T, I>::remove(who, asset);
+		} else {
+			Vesting::<T, I>::insert(who, asset, schedules)
+		}
+
+		Ok(())
+	}
+
+	/// Unlock any vested funds of the `asset` of `who`.
+	fn do_vest(who: T::AccountId, asset: T::AssetId) -> DispatchResult {
+		let schedules = Vesting::<T, I>::get(&who, &asset).ok_or(Error::<T, I>::NotVesting)?;
+
+		let (schedules, locked_now) =
+			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+
+		Self::write_vesting(&who, &asset, schedules)?;
+		Self::write_freeze(&who, &asset, locked_now)
+	}
+
+	/// Execute a `VestingAction` against the given `schedules`. Returns the updated schedules
+	/// and locked amount.
+	fn exec_action(
+		schedules: Vec<VestingScheduleOf<T, I>>,
+		action: VestingAction,
+	) -> Result<(Vec<VestingScheduleOf<T, I>>, T::Balance), DispatchError> {
+		let (schedules, locked_now) = match action {
+			VestingAction::Merge { index1: idx1, index2: idx2 } => {
+				// The schedule index is based off of the schedule ordering prior to filtering out
+				// any schedules that may be ending at this block.
+				let schedule1 =
+					*schedules.get(idx1).ok_or(Error::<T, I>::ScheduleIndexOutOfBounds)?;
+				let schedule2 =
+					*schedules.get(idx2).ok_or(Error::<T, I>::ScheduleIndexOutOfBounds)?;
+
+				// The length of `schedules` decreases by 2 here since we filter out 2 schedules.
+				// Thus we know below that we can push the new merged schedule without error
+				// (assuming initial state was valid).
+				let (mut schedules, mut locked_now) =
+					Self::report_schedule_updates(schedules.to_vec(), action);
+
+				let now = T::BlockNumberProvider::current_block_number();
+				if let Some(new_schedule) =
+					Self::merge_vesting_schedules(now, schedule1, schedule2)?
+				{
+					// Merging created a new schedule so we:
+					// 1) need to add it to the accounts vesting schedule collection,
+					schedules.push(new_schedule);
+					// (we use `locked_at` in case this is a schedule that started in the past)
+					let new_schedule_locked =
+						new_schedule.locked_at::<T::BlockNumberToBalance>(now);
+					// and 2) update the locked amount to reflect the schedule we just added.
+					locked_now = locked_now.saturating_add(new_schedule_locked);
+				} // In the None case there was no new schedule to account for.
+
+				(schedules, locked_now)
+			},
+			_ => Self::report_schedule_updates(schedules.to_vec(), action),
+		};
+
+		debug_assert!(
+			locked_now > Zero::zero() && schedules.len() > 0 ||
+				locked_now == Zero::zero() && schedules.len() == 0
+		);
+
+		Ok((schedules, locked_now))
+	}
+}
@@ -0,0 +1,178 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Vesting schedule types.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Convert, One, Zero},
+	RuntimeDebug,
+};
+
+/// The vesting schedule of an amount of an asset.
+///
+/// The amount is fully locked up to and including the `starting_block` of the schedule.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub enum VestingSchedule<Balance, BlockNumber> {
+	/// `per_block` is unlocked every block after `starting_block`.
+	Linear {
+		/// The amount vested by the schedule.
+		locked: Balance,
+		/// The amount unlocked every block.
+		per_block: Balance,
+		/// The block after which unlocking starts.
+		starting_block: BlockNumber,
+	},
+	/// As [`VestingSchedule::Linear`], except that nothing is unlocked before the `cliff` block,
+	/// at which everything vested since `starting_block` is unlocked at once.
+	Cliff {
+		/// The amount vested by the schedule.
+		locked: Balance,
+		/// The amount unlocked every block.
+		per_block: Balance,
+		/// The block after which unlocking starts.
+		starting_block: BlockNumber,
+		/// The block before which nothing is unlocked. Not before `starting_block`.
+		cliff: BlockNumber,
+	},
+	/// `per_step` is unlocked every `step` blocks after `starting_block`.
+	Stepped {
+		/// The amount vested by the schedule.
+		locked: Balance,
+		/// The amount unlocked every step.
+		per_step: Balance,
+		/// The number of blocks between two releases.
+		step: BlockNumber,
+		/// The block after which unlocking starts.
+		starting_block: BlockNumber,
+	},
+}
+
+impl<Balance, BlockNumber> VestingSchedule<Balance, BlockNumber>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// Validate the parameters of the schedule. Note that this does not check the amount against
+	/// the minimum balance of the asset.
+	pub fn is_valid(&self) -> bool {
+		match *self {
+			Self::Linear { locked, per_block, .. } => !locked.is_zero() && !per_block.is_zero(),
+			Self::Cliff { locked, per_block, starting_block, cliff } =>
+				!locked.is_zero() && !per_block.is_zero() && cliff >= starting_block,
+			Self::Stepped { locked, per_step, step, .. } =>
+				!locked.is_zero() && !per_step.is_zero() && !step.is_zero(),
+		}
+	}
+
+	/// The amount vested by the schedule.
+	pub fn locked(&self) -> Balance {
+		match *self {
+			Self::Linear { locked, .. } |
+			Self::Cliff { locked, .. } |
+			Self::Stepped { locked, .. } => locked,
+		}
+	}
+
+	/// The block after which unlocking starts.
+	pub fn starting_block(&self) -> BlockNumber {
+		match *self {
+			Self::Linear { starting_block, .. } |
+			Self::Cliff { starting_block, .. } |
+			Self::Stepped { starting_block, .. } => starting_block,
+		}
+	}
+
+	/// Whether the schedule unlocks linearly from the block `now` on, i.e. it can be merged with
+	/// another linear schedule without unlocking funds earlier than it would have.
+	pub fn is_linear_from(&self, now: BlockNumber) -> bool {
+		match *self {
+			Self::Linear { .. } => true,
+			Self::Cliff { cliff, .. } => now >= cliff,
+			Self::Stepped { step, .. } => step.is_one(),
+		}
+	}
+
+	/// Amount locked at block `n`.
+	pub fn locked_at<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
+		&self,
+		n: BlockNumber,
+	) -> Balance {
+		// Number of periods that count toward vesting, saturating to 0 when n < starting_block,
+		// along with the amount unlocked every period.
+		let (periods, per_period) = match *self {
+			Self::Linear { per_block, starting_block, .. } =>
+				(n.saturating_sub(starting_block), per_block),
+			Self::Cliff { locked, .. } if n < self.cliff_block() => return locked,
+			Self::Cliff { per_block, starting_block, .. } =>
+				(n.saturating_sub(starting_block), per_block),
+			Self::Stepped { per_step, step, starting_block, .. } =>
+				(n.saturating_sub(starting_block) / step.max(One::one()), per_step),
+		};
+		// Return amount that is still locked in vesting.
+		BlockNumberToBalance::convert(periods)
+			.checked_mul(&per_period)
+			.map(|to_unlock| self.locked().saturating_sub(to_unlock))
+			.unwrap_or(Zero::zero())
+	}
+
+	/// Block number at which the schedule ends (as type `Balance`).
+	pub fn ending_block_as_balance<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
+		&self,
+	) -> Balance {
+		let (per_period, period) = match *self {
+			Self::Linear { per_block, .. } | Self::Cliff { per_block, .. } =>
+				(per_block, One::one()),
+			Self::Stepped { per_step, step, .. } =>
+				(per_step, BlockNumberToBalance::convert(step.max(One::one()))),
+		};
+		let per_period = per_period.max(One::one());
+		let locked = self.locked();
+		// The number of periods needed to unlock everything, including a last period unlocking
+		// less than `per_period` if it does not perfectly divide `locked`.
+		let periods = if per_period >= locked {
+			One::one()
+		} else {
+			locked / per_period +
+				if (locked % per_period).is_zero() { Zero::zero() } else { One::one() }
+		};
+
+		let starting_block = BlockNumberToBalance::convert(self.starting_block());
+		let ending_block = starting_block.saturating_add(periods.saturating_mul(period));
+		ending_block.max(BlockNumberToBalance::convert(self.cliff_block()))
+	}
+
+	/// The block before which nothing is unlocked.
+	fn cliff_block(&self) -> BlockNumber {
+		match *self {
+			Self::Cliff { cliff, .. } => cliff,
+			_ => self.starting_block(),
+		}
+	}
+}
@@ -0,0 +1,377 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{mock::*, *};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungible::NativeOrWithId,
+		fungibles::InspectFreeze,
+		tokens::{Fortitude, Preservation},
+	},
+};
+use sp_runtime::{
+	traits::{BadOrigin, ConvertInto},
+	TokenError,
+};
+
+fn frozen(who: u128, asset: NativeOrWithId<u32>) -> u128 {
+	NativeAndAssetsFreezer::balance_frozen(asset, &FreezeReason::Vesting.into(), &who)
+}
+
+fn reducible(who: u128, asset: NativeOrWithId<u32>) -> u128 {
+	NativeAndAssets::reducible_balance(asset, &who, Preservation::Expendable, Fortitude::Polite)
+}
+
+fn linear(locked: u128, per_block: u128, starting_block: u64) -> VestingScheduleOf<Test> {
+	VestingSchedule::Linear { locked, per_block, starting_block }
+}
+
+#[test]
+fn schedules_release_as_expected() {
+	let linear_schedule = linear(1000, 100, 10);
+	let cliff = VestingSchedule::Cliff {
+		locked: 1000u128,
+		per_block: 100,
+		starting_block: 10u64,
+		cliff: 15,
+	};
+	let stepped = VestingSchedule::Stepped {
+		locked: 1000u128,
+		per_step: 300,
+		step: 5u64,
+		starting_block: 10,
+	};
+
+	let locked_at = |schedule: &VestingScheduleOf<Test>, blocks: &[u64]| {
+		blocks.iter().map(|n| schedule.locked_at::<ConvertInto>(*n)).collect::<Vec<_>>()
+	};
+	let blocks = [0, 10, 14, 15, 19, 20, 25, 30];
+	assert_eq!(locked_at(&linear_schedule, &blocks), vec![1000, 1000, 600, 500, 100, 0, 0, 0]);
+	assert_eq!(locked_at(&cliff, &blocks), vec![1000, 1000, 1000, 500, 100, 0, 0, 0]);
+	assert_eq!(locked_at(&stepped, &blocks), vec![1000, 1000, 1000, 700, 700, 400, 100, 0]);
+
+	assert_eq!(linear_schedule.ending_block_as_balance::<ConvertInto>(), 20);
+	assert_eq!(cliff.ending_block_as_balance::<ConvertInto>(), 20);
+	assert_eq!(stepped.ending_block_as_balance::<ConvertInto>(), 30);
+	// A cliff after the linear release ends the schedule at once.
+	let late_cliff = VestingSchedule::Cliff {
+		locked: 1000u128,
+		per_block: 100,
+		starting_block: 10u64,
+		cliff: 25,
+	};
+	assert_eq!(late_cliff.locked_at::<ConvertInto>(24), 1000);
+	assert_eq!(late_cliff.locked_at::<ConvertInto>(25), 0);
+	assert_eq!(late_cliff.ending_block_as_balance::<ConvertInto>(), 25);
+}
+
+#[test]
+fn vested_transfer_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsVesting::vested_transfer(
+			RuntimeOrigin::signed(1),
+			4,
+			Box::new(ASSET),
+			linear(1000, 100, 10),
+		));
+		System::assert_has_event(
+			Event::VestingCreated { account: 4, asset: ASSET, schedule_index: 0 }.into(),
+		);
+		assert_eq!(NativeAndAssets::balance(ASSET, &1), 9000);
+		assert_eq!(frozen(4, ASSET), 1000);
+		assert_eq!(reducible(4, ASSET), 0);
+		assert_noop!(
+			NativeAndAssets::transfer(ASSET, &4, &1, 1, Preservation::Expendable),
+			TokenError::Frozen
+		);
+
+		// Vesting is only reflected in the freeze once vested.
+		System::set_block_number(15);
+		assert_eq!(AssetsVesting::vesting_balance(&4, ASSET), Some(500));
+		assert_eq!(frozen(4, ASSET), 1000);
+		assert_ok!(AssetsVesting::vest(RuntimeOrigin::signed(4), Box::new(ASSET)));
+		System::assert_last_event(
+			Event::VestingUpdated { account: 4, asset: ASSET, unvested: 500 }.into(),
+		);
+		assert_eq!(frozen(4, ASSET), 500);
+		assert_eq!(reducible(4, ASSET), 500);
+
+		System::set_block_number(20);
+		assert_ok!(AssetsVesting::vest_other(RuntimeOrigin::signed(1), 4, Box::new(ASSET)));
+		System::assert_last_event(Event::VestingCompleted { account: 4, asset: ASSET }.into());
+		assert_eq!(frozen(4, ASSET), 0);
+		assert_eq!(reducible(4, ASSET), 1000);
+		assert!(Vesting::<Test>::get(4, ASSET).is_none());
+		assert_noop!(
+			AssetsVesting::vest(RuntimeOrigin::signed(4), Box::new(ASSET)),
+			Error::<Test>::NotVesting
+		);
+	});
+}
+
+#[test]
+fn vesting_is_per_asset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsVesting::force_vested_transfer(
+			RuntimeOrigin::root(),
+			1,
+			2,
+			Box::new(NativeOrWithId::Native),
+			linear(1000, 100, 10),
+		));
+		assert_eq!(frozen(2, NativeOrWithId::Native), 1000);
+		assert_eq!(frozen(2, ASSET), 0);
+		assert_eq!(AssetsVesting::vesting_balance(&2, ASSET), None);
+		assert_noop!(
+			AssetsVesting::vest(RuntimeOrigin::signed(2), Box::new(ASSET)),
+			Error::<Test>::NotVesting
+		);
+		assert_noop!(
+			AssetsVesting::force_vested_transfer(
+				RuntimeOrigin::signed(1),
+				1,
+				2,
+				Box::new(ASSET),
+				linear(1000, 100, 10),
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn cliff_and_stepped_schedules_are_vested() {
+	new_test_ext().execute_with(|| {
+		let cliff = VestingSchedule::Cliff {
+			locked: 1000,
+			per_block: 100,
+			starting_block: 10,
+			cliff: 15,
+		};
+		let stepped =
+			VestingSchedule::Stepped { locked: 900, per_step: 300, step: 5, starting_block: 10 };
+		assert_ok!(AssetsVesting::vested_transfer(
+			RuntimeOrigin::signed(1),
+			4,
+			Box::new(ASSET),
+			cliff
+		));
+		assert_ok!(AssetsVesting::vested_transfer(
+			RuntimeOrigin::signed(1),
+			4,
+			Box::new(ASSET),
+			stepped
+		));
+		assert_eq!(frozen(4, ASSET), 1900);
+
+		// Nothing is released before the cliff nor the first step.
+		System::set_block_number(14);
+		assert_ok!(AssetsVesting::vest(RuntimeOrigin::signed(4), Box::new(ASSET)));
+		assert_eq!(frozen(4, ASSET), 1900);
+
+		System::set_block_number(15);
+		assert_ok!(AssetsVesting::vest(RuntimeOrigin::signed(4), Box::new(ASSET)));
+		assert_eq!(frozen(4, ASSET), 500 + 600);
+
+		// The cliff schedule is over and removed.
+		System::set_block_number(20);
+		assert_ok!(AssetsVesting::vest(RuntimeOrigin::signed(4), Box::new(ASSET)));
+		assert_eq!(frozen(4, ASSET), 300);
+		assert_eq!(Vesting::<Test>::get(4, ASSET).unwrap().to_vec(), vec![stepped]);
+
+		System::set_block_number(25);
+		assert_ok!(AssetsVesting::vest(RuntimeOrigin::signed(4), Box::new(ASSET)));
+		assert_eq!(frozen(4, ASSET), 0);
+		assert!(Vesting::<Test>::get(4, ASSET).is_none());
+	});
+}
+
+#[test]
+fn vested_transfer_checks_schedule() {
+	new_test_ext().execute_with(|| {
+		let transfer = |schedule| {
+			AssetsVesting::vested_transfer(RuntimeOrigin::signed(1), 4, Box::new(ASSET), schedule)
+		};
+
+		// Below the minimum balance of the asset.
+		assert_noop!(transfer(linear(9, 1, 10)), Error::<Test>::AmountLow);
+		assert_noop!(transfer(linear(1000, 0, 10)), Error::<Test>::InvalidScheduleParams);
+		assert_noop!(
+			transfer(VestingSchedule::Cliff {
+				locked: 1000,
+				per_block: 100,
+				starting_block: 10,
+				cliff: 9
+			}),
+			Error::<Test>::InvalidScheduleParams
+		);
+		assert_noop!(
+			transfer(VestingSchedule::Stepped {
+				locked: 1000,
+				per_step: 100,
+				step: 0,
+				starting_block: 10
+			}),
+			Error::<Test>::InvalidScheduleParams
+		);
+		assert_noop!(transfer(linear(20_000, 100, 10)), TokenError::FundsUnavailable);
+
+		for _ in 0..3 {
+			assert_ok!(transfer(linear(1000, 100, 10)));
+		}
+		assert_noop!(transfer(linear(1000, 100, 10)), Error::<Test>::AtMaxVestingSchedules);
+	});
+}
+
+#[test]
+fn merge_schedules_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsVesting::vested_transfer(
+			RuntimeOrigin::signed(1),
+			4,
+			Box::new(ASSET),
+			linear(1000, 100, 10),
+		));
+		assert_ok!(AssetsVesting::vested_transfer(
+			RuntimeOrigin::signed(1),
+			4,
+			Box::new(ASSET),
+			linear(2000, 100, 10),
+		));
+
+		// Merging a schedule with itself is a no-op.
+		assert_ok!(AssetsVesting::merge_schedules(RuntimeOrigin::signed(4), Box::new(ASSET), 1, 1));
+		assert_noop!(
+			AssetsVesting::merge_schedules(RuntimeOrigin::signed(4), Box::new(ASSET), 0, 2),
+			Error::<Test>::ScheduleIndexOutOfBounds
+		);
+
+		System::set_block_number(15);
+		assert_ok!(AssetsVesting::merge_schedules(RuntimeOrigin::signed(4), Box::new(ASSET), 0, 1));
+		// 500 + 1500 left to vest until block 30.
+		assert_eq!(
+			Vesting::<Test>::get(4, ASSET).unwrap().to_vec(),
+			vec![linear(2000, 133, 15)]
+		);
+		assert_eq!(frozen(4, ASSET), 2000);
+
+		// A finished schedule is dropped, the other one is kept unmodified.
+		let stepped =
+			VestingSchedule::Stepped { locked: 900, per_step: 300, step: 5, starting_block: 40 };
+		assert_ok!(AssetsVesting::vested_transfer(
+			RuntimeOrigin::signed(1),
+			4,
+			Box::new(ASSET),
+			stepped,
+		));
+		System::set_block_number(35);
+		assert_ok!(AssetsVesting::merge_schedules(RuntimeOrigin::signed(4), Box::new(ASSET), 0, 1));
+		assert_eq!(Vesting::<Test>::get(4, ASSET).unwrap().to_vec(), vec![stepped]);
+		assert_eq!(frozen(4, ASSET), 900);
+	});
+}
+
+#[test]
+fn merge_schedules_keeps_cliffs_and_steps() {
+	new_test_ext().execute_with(|| {
+		let cliff = VestingSchedule::Cliff {
+			locked: 1000,
+			per_block: 100,
+			starting_block: 10,
+			cliff: 15,
+		};
+		assert_ok!(AssetsVesting::vested_transfer(
+			RuntimeOrigin::signed(1),
+			4,
+			Box::new(ASSET),
+			cliff,
+		));
+		assert_ok!(AssetsVesting::vested_transfer(
+			RuntimeOrigin::signed(1),
+			4,
+			Box::new(ASSET),
+			linear(1000, 100, 10),
+		));
+		assert_ok!(AssetsVesting::vested_transfer(
+			RuntimeOrigin::signed(1),
+			4,
+			Box::new(ASSET),
+			VestingSchedule::Stepped { locked: 900, per_step: 300, step: 5, starting_block: 10 },
+		));
+
+		// The cliff is not reached yet.
+		System::set_block_number(12);
+		assert_noop!(
+			AssetsVesting::merge_schedules(RuntimeOrigin::signed(4), Box::new(ASSET), 0, 1),
+			Error::<Test>::ScheduleNotMergeable
+		);
+		assert_noop!(
+			AssetsVesting::merge_schedules(RuntimeOrigin::signed(4), Box::new(ASSET), 1, 2),
+			Error::<Test>::ScheduleNotMergeable
+		);
+
+		// Past the cliff, the cliff schedule releases linearly.
+		System::set_block_number(15);
+		assert_ok!(AssetsVesting::merge_schedules(RuntimeOrigin::signed(4), Box::new(ASSET), 0, 1));
+		let schedules = Vesting::<Test>::get(4, ASSET).unwrap();
+		assert_eq!(schedules.len(), 2);
+		assert_eq!(schedules[1], linear(1000, 200, 15));
+		assert_eq!(frozen(4, ASSET), 1000 + 600);
+	});
+}
+
+#[test]
+fn force_remove_vesting_schedule_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(AssetsVesting::vested_transfer(
+			RuntimeOrigin::signed(1),
+			4,
+			Box::new(ASSET),
+			linear(1000, 100, 10),
+		));
+		assert_noop!(
+			AssetsVesting::force_remove_vesting_schedule(
+				RuntimeOrigin::signed(1),
+				4,
+				Box::new(ASSET),
+				0
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AssetsVesting::force_remove_vesting_schedule(
+				RuntimeOrigin::root(),
+				4,
+				Box::new(ASSET),
+				1
+			),
+			Error::<Test>::InvalidScheduleParams
+		);
+
+		assert_ok!(AssetsVesting::force_remove_vesting_schedule(
+			RuntimeOrigin::root(),
+			4,
+			Box::new(ASSET),
+			0
+		));
+		System::assert_last_event(Event::VestingCompleted { account: 4, asset: ASSET }.into());
+		assert!(Vesting::<Test>::get(4, ASSET).is_none());
+		assert_eq!(frozen(4, ASSET), 0);
+		assert_eq!(reducible(4, ASSET), 1000);
+	});
+}
@@ -0,0 +1,439 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for `pallet_assets_vesting`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
+
+// Executed Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --extrinsic=*
+// --runtime=target/production/wbuild/asset-hub-westend-runtime/asset_hub_westend_runtime.wasm
+// --pallet=pallet_assets_vesting
+// --header=substrate/HEADER-APACHE2
+// --output=substrate/frame/assets-vesting/src/weights.rs
+// --wasm-execution=compiled
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --template=substrate/.maintain/frame-weight-template.hbs
+// --no-storage-info
+// --no-min-squares
+// --no-median-slopes
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_assets_vesting`.
+pub trait WeightInfo {
+	fn vest_locked(s: u32, ) -> Weight;
+	fn vest_unlocked(s: u32, ) -> Weight;
+	fn vest_other_locked(s: u32, ) -> Weight;
+	fn vest_other_unlocked(s: u32, ) -> Weight;
+	fn vested_transfer(s: u32, ) -> Weight;
+	fn force_vested_transfer(s: u32, ) -> Weight;
+	fn not_unlocking_merge_schedules(s: u32, ) -> Weight;
+	fn unlocking_merge_schedules(s: u32, ) -> Weight;
+	fn force_remove_vesting_schedule(s: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_assets_vesting` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_locked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 55_917_000 picoseconds.
+		Weight::from_parts(96_464_534, 4682)
+			// Standard Error: 34_637
+			.saturating_add(Weight::from_parts(957_827, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_unlocked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 60_532_000 picoseconds.
+		Weight::from_parts(108_220_302, 4682)
+			// Standard Error: 62_649
+			.saturating_add(Weight::from_parts(1_026_990, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_other_locked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 68_132_000 picoseconds.
+		Weight::from_parts(95_336_853, 4682)
+			// Standard Error: 66_777
+			.saturating_add(Weight::from_parts(1_040_898, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_other_unlocked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 75_979_000 picoseconds.
+		Weight::from_parts(101_857_959, 4682)
+			// Standard Error: 75_918
+			.saturating_add(Weight::from_parts(1_233_249, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:2 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 27]`.
+	fn vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `997 + s * (37 ±0)`
+		//  Estimated: `6208`
+		// Minimum execution time: 182_269_000 picoseconds.
+		Weight::from_parts(209_408_425, 6208)
+			// Standard Error: 97_067
+			.saturating_add(Weight::from_parts(4_087_804, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:2 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 27]`.
+	fn force_vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `997 + s * (37 ±0)`
+		//  Estimated: `6208`
+		// Minimum execution time: 192_526_000 picoseconds.
+		Weight::from_parts(275_558_851, 6208)
+			// Standard Error: 110_696
+			.saturating_add(Weight::from_parts(634_258, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 28]`.
+	fn not_unlocking_merge_schedules(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 68_033_000 picoseconds.
+		Weight::from_parts(78_522_314, 4682)
+			// Standard Error: 88_241
+			.saturating_add(Weight::from_parts(1_684_330, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 28]`.
+	fn unlocking_merge_schedules(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 70_544_000 picoseconds.
+		Weight::from_parts(70_060_580, 4682)
+			// Standard Error: 58_801
+			.saturating_add(Weight::from_parts(2_982_491, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 28]`.
+	fn force_remove_vesting_schedule(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 79_031_000 picoseconds.
+		Weight::from_parts(101_032_689, 4682)
+			// Standard Error: 48_375
+			.saturating_add(Weight::from_parts(1_335_197, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_locked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 55_917_000 picoseconds.
+		Weight::from_parts(96_464_534, 4682)
+			// Standard Error: 34_637
+			.saturating_add(Weight::from_parts(957_827, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_unlocked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 60_532_000 picoseconds.
+		Weight::from_parts(108_220_302, 4682)
+			// Standard Error: 62_649
+			.saturating_add(Weight::from_parts(1_026_990, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_other_locked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 68_132_000 picoseconds.
+		Weight::from_parts(95_336_853, 4682)
+			// Standard Error: 66_777
+			.saturating_add(Weight::from_parts(1_040_898, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_other_unlocked(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 75_979_000 picoseconds.
+		Weight::from_parts(101_857_959, 4682)
+			// Standard Error: 75_918
+			.saturating_add(Weight::from_parts(1_233_249, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:2 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 27]`.
+	fn vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `997 + s * (37 ±0)`
+		//  Estimated: `6208`
+		// Minimum execution time: 182_269_000 picoseconds.
+		Weight::from_parts(209_408_425, 6208)
+			// Standard Error: 97_067
+			.saturating_add(Weight::from_parts(4_087_804, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:2 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 27]`.
+	fn force_vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `997 + s * (37 ±0)`
+		//  Estimated: `6208`
+		// Minimum execution time: 192_526_000 picoseconds.
+		Weight::from_parts(275_558_851, 6208)
+			// Standard Error: 110_696
+			.saturating_add(Weight::from_parts(634_258, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 28]`.
+	fn not_unlocking_merge_schedules(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 68_033_000 picoseconds.
+		Weight::from_parts(78_522_314, 4682)
+			// Standard Error: 88_241
+			.saturating_add(Weight::from_parts(1_684_330, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 28]`.
+	fn unlocking_merge_schedules(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 70_544_000 picoseconds.
+		Weight::from_parts(70_060_580, 4682)
+			// Standard Error: 58_801
+			.saturating_add(Weight::from_parts(2_982_491, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1217), added: 3692, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(123), added: 2598, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 28]`.
+	fn force_remove_vesting_schedule(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `463 + s * (37 ±0)`
+		//  Estimated: `4682`
+		// Minimum execution time: 79_031_000 picoseconds.
+		Weight::from_parts(101_032_689, 4682)
+			// Standard Error: 48_375
+			.saturating_add(Weight::from_parts(1_335_197, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
@@ -393,6 +393,7 @@ impl pallet_proxy::Config for Test {
 	type ProxyType = ();
 	type ProxyDepositBase = ConstU64<1>;
 	type ProxyDepositFactor = ConstU64<1>;
+	type LimitedProxyDepositFactor = ConstU64<1>;
 	type MaxProxies = ConstU32<32>;
 	type WeightInfo = ();
 	type MaxPending = ConstU32<32>;
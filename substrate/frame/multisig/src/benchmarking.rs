@@ -359,5 +359,57 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// `z`: Transaction Length, leaving room for the encoding of the call around it
+	/// `s`: Signatories, need at least 2 people
+	#[benchmark]
+	fn propose_as_multi(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+		z: Linear<0, { T::MaxCallSize::get().saturating_sub(8) }>,
+	) -> Result<(), BenchmarkError> {
+		let (mut signatories, call) = setup_multi::<T>(s, z)?;
+		let call_hash = call.using_encoded(blake2_256);
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s as u16, signatories, call, None);
+
+		assert!(Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+		assert!(Proposals::<T>::contains_key(&multi_account_id, call_hash));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_expired_multisig() -> Result<(), BenchmarkError> {
+		let s = T::MaxSignatories::get();
+		let (mut signatories, call) = setup_multi::<T>(s, T::MaxCallSize::get().saturating_sub(8))?;
+		let call_hash = call.using_encoded(blake2_256);
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let expiry = T::BlockNumberProvider::current_block_number() + 1u32.into();
+		// Propose the multi
+		Multisig::<T>::propose_as_multi(
+			RawOrigin::Signed(caller).into(),
+			s as u16,
+			signatories,
+			call,
+			Some(expiry),
+		)?;
+		T::BlockNumberProvider::set_block_number(expiry);
+		let clearing: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(clearing), multi_account_id.clone(), call_hash);
+
+		assert!(!Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+		assert!(!Proposals::<T>::contains_key(&multi_account_id, call_hash));
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
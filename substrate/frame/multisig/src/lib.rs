@@ -39,6 +39,9 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `propose_as_multi` - Approve a call from a composite origin, storing it on-chain with an
+//!   optional expiry.
+//! * `clear_expired_multisig` - Clear an expired call from a composite origin.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -120,6 +123,38 @@ where
 	pub approvals: BoundedVec<AccountId, MaxApprovals>,
 }
 
+/// A call stored on-chain for an open multisig operation.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxCallSize))]
+pub struct Proposal<BlockNumber, Balance, AccountId, MaxCallSize>
+where
+	MaxCallSize: Get<u32>,
+{
+	/// The encoded call to be dispatched once the operation is approved.
+	pub call: BoundedVec<u8, MaxCallSize>,
+	/// The amount held in reserve of the `depositor`, to be returned once the operation ends.
+	pub deposit: Balance,
+	/// The account who stored the call.
+	pub depositor: AccountId,
+	/// The block from which on the operation can no longer be approved and may be cleared by
+	/// anyone.
+	pub expiry: Option<BlockNumber>,
+}
+
+pub type MultisigOf<T> = Multisig<
+	BlockNumberFor<T>,
+	BalanceOf<T>,
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::MaxSignatories,
+>;
+
+pub type ProposalOf<T> = Proposal<
+	BlockNumberFor<T>,
+	BalanceOf<T>,
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::MaxCallSize,
+>;
+
 type CallHash = [u8; 32];
 
 enum CallOrHash<T: Config> {
@@ -155,7 +190,8 @@ pub mod pallet {
 		#[pallet::constant]
 		type DepositBase: Get<BalanceOf<Self>>;
 
-		/// The amount of currency needed per unit threshold when creating a multisig execution.
+		/// The amount of currency needed per unit threshold when creating a multisig execution,
+		/// or per 32 bytes of a dispatch call stored for later.
 		///
 		/// This is held for adding 32 bytes more into a pre-existing storage value.
 		#[pallet::constant]
@@ -165,6 +201,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSignatories: Get<u32>;
 
+		/// The maximum length of an encoded dispatch call stored for later.
+		#[pallet::constant]
+		type MaxCallSize: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: weights::WeightInfo;
 
@@ -211,6 +251,17 @@ pub mod pallet {
 		Multisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
 	>;
 
+	/// The calls of open multisig operations stored on-chain by `propose_as_multi`.
+	#[pallet::storage]
+	pub type Proposals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		[u8; 32],
+		ProposalOf<T>,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -242,6 +293,12 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The call is too large to be stored on-chain.
+		CallTooLarge,
+		/// The multisig operation has expired, or the given expiry has already been reached.
+		Expired,
+		/// The multisig operation has no expiry, or it has not been reached yet.
+		NotExpired,
 	}
 
 	#[pallet::event]
@@ -278,11 +335,39 @@ pub mod pallet {
 			old_deposit: BalanceOf<T>,
 			new_deposit: BalanceOf<T>,
 		},
+		/// A multisig operation has been proposed with its call stored on-chain.
+		MultisigProposed {
+			proposer: T::AccountId,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+			expiry: Option<BlockNumberFor<T>>,
+		},
+		/// An expired multisig operation has been cleared.
+		MultisigExpired {
+			clearing: T::AccountId,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<frame_system::pallet_prelude::BlockNumberFor<T>> for Pallet<T> {}
 
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// The open operations of the multisig account `multisig`, by call hash, together with
+		/// their calls if they are stored on-chain.
+		pub fn pending_multisigs(
+			multisig: T::AccountId,
+		) -> Vec<(CallHash, MultisigOf<T>, Option<ProposalOf<T>>)> {
+			Multisigs::<T>::iter_prefix(&multisig)
+				.map(|(call_hash, m)| (call_hash, m, Proposals::<T>::get(&multisig, call_hash)))
+				.collect()
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Immediately dispatch a multi-signature call using a single approval from the caller.
@@ -431,7 +516,8 @@ pub mod pallet {
 		/// transaction index) of the first approval transaction.
 		/// - `call_hash`: The hash of the call to be executed.
 		///
-		/// NOTE: If this is the final approval, you will want to use `as_multi` instead.
+		/// NOTE: If this is the final approval, you will want to use `as_multi` instead, unless
+		/// the call was stored on-chain with `propose_as_multi`, in which case it is dispatched.
 		///
 		/// ## Complexity
 		/// - `O(S)`.
@@ -450,6 +536,7 @@ pub mod pallet {
 
 			T::WeightInfo::approve_as_multi_create(s)
 				.max(T::WeightInfo::approve_as_multi_approve(s))
+				.max(T::WeightInfo::as_multi_complete(s, T::MaxCallSize::get()))
 				.saturating_add(*max_weight)
 		})]
 		pub fn approve_as_multi(
@@ -517,6 +604,7 @@ pub mod pallet {
 			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
 			debug_assert!(err_amount.is_zero());
 			<Multisigs<T>>::remove(&id, &call_hash);
+			Self::remove_proposal(&id, &call_hash);
 
 			Self::deposit_event(Event::MultisigCancelled {
 				cancelling: who,
@@ -604,6 +692,132 @@ pub mod pallet {
 				},
 			)
 		}
+
+		/// Register approval for a dispatch to be made from a deterministic composite account if
+		/// approved by a total of `threshold - 1` of `other_signatories`, storing the call
+		/// on-chain.
+		///
+		/// The other signatories may then approve the dispatch with `approve_as_multi` using the
+		/// hash of the call only, the final approval dispatching the stored call.
+		///
+		/// Payment: On top of the deposit of `as_multi`, `DepositBase` plus `DepositFactor` for
+		/// every 32 bytes of the encoded call will be reserved. Both are returned once this
+		/// dispatch happens, is cancelled or is cleared after its expiry.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `call`: The call to be executed. Its encoded length may not exceed `MaxCallSize`.
+		/// - `maybe_expiry`: The block from which on the dispatch can no longer be approved and
+		/// may be cleared by anyone with `clear_expired_multisig`.
+		///
+		/// Emits `NewMultisig` and `MultisigProposed`.
+		///
+		/// ## Complexity
+		/// - `O(S + Z)`.
+		/// - Two balance-reserve operations.
+		/// - One call encode & hash, both of complexity `O(Z)` where `Z` is tx-len.
+		/// - Two inserts, of `O(S)` and `O(Z)` respectively.
+		/// - Two events.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::propose_as_multi(
+			other_signatories.len() as u32,
+			call.using_encoded(|d| d.len()) as u32,
+		))]
+		pub fn propose_as_multi(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			call: Box<<T as Config>::RuntimeCall>,
+			maybe_expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let now = T::BlockNumberProvider::current_block_number();
+			ensure!(maybe_expiry.map_or(true, |expiry| expiry > now), Error::<T>::Expired);
+			let call: BoundedVec<u8, T::MaxCallSize> =
+				call.encode().try_into().map_err(|_| Error::<T>::CallTooLarge)?;
+			let call_hash = blake2_256(&call);
+			let signatories =
+				Self::ensure_sorted_and_insert(other_signatories.clone(), who.clone())?;
+			let id = Self::multi_account_id(&signatories, threshold);
+			ensure!(!<Proposals<T>>::contains_key(&id, call_hash), Error::<T>::AlreadyStored);
+
+			Self::operate(
+				who.clone(),
+				threshold,
+				other_signatories,
+				None,
+				CallOrHash::Hash(call_hash),
+				Weight::zero(),
+			)
+			.map_err(|e| e.error)?;
+
+			let deposit = Self::call_deposit(call.len());
+			T::Currency::reserve(&who, deposit)?;
+			<Proposals<T>>::insert(
+				&id,
+				call_hash,
+				Proposal { call, deposit, depositor: who.clone(), expiry: maybe_expiry },
+			);
+
+			Self::deposit_event(Event::MultisigProposed {
+				proposer: who,
+				timepoint: Self::timepoint(),
+				multisig: id,
+				call_hash,
+				expiry: maybe_expiry,
+			});
+			Ok(())
+		}
+
+		/// Clear a multisig operation whose expiry has been reached. Any deposit reserved
+		/// previously for this operation will be unreserved on success.
+		///
+		/// The dispatch origin for this call must be _Signed_, by any account.
+		///
+		/// - `multisig`: The composite account of the operation.
+		/// - `call_hash`: The hash of the call of the operation.
+		///
+		/// Emits `MultisigExpired`.
+		///
+		/// ## Complexity
+		/// - `O(S + Z)`.
+		/// - Up to two balance-unreserve operations.
+		/// - I/O: 2 reads `O(S)` and `O(Z)`, two removes.
+		/// - One event.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::clear_expired_multisig())]
+		pub fn clear_expired_multisig(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let m = <Multisigs<T>>::get(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+			let expiry = <Proposals<T>>::get(&multisig, call_hash)
+				.and_then(|p| p.expiry)
+				.ok_or(Error::<T>::NotExpired)?;
+			ensure!(
+				T::BlockNumberProvider::current_block_number() >= expiry,
+				Error::<T>::NotExpired
+			);
+
+			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+			debug_assert!(err_amount.is_zero());
+			<Multisigs<T>>::remove(&multisig, &call_hash);
+			Self::remove_proposal(&multisig, &call_hash);
+
+			Self::deposit_event(Event::MultisigExpired {
+				clearing: who,
+				timepoint: m.when,
+				multisig,
+				call_hash,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -650,6 +864,15 @@ impl<T: Config> Pallet<T> {
 			let timepoint = maybe_timepoint.ok_or(Error::<T>::NoTimepoint)?;
 			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
 
+			// Ensure that the operation has not expired.
+			let maybe_proposal = <Proposals<T>>::get(&id, call_hash);
+			if let Some(expiry) = maybe_proposal.as_ref().and_then(|p| p.expiry) {
+				ensure!(
+					T::BlockNumberProvider::current_block_number() < expiry,
+					Error::<T>::Expired
+				);
+			}
+
 			// Ensure that either we have not yet signed or that it is at threshold.
 			let mut approvals = m.approvals.len() as u16;
 			// We only bother with the approval if we're below threshold.
@@ -659,6 +882,15 @@ impl<T: Config> Pallet<T> {
 				approvals += 1;
 			}
 
+			// Fall back to the call stored on-chain if none was given.
+			let (call_len, maybe_call) = match (maybe_call, maybe_proposal) {
+				(None, Some(proposal)) if approvals >= threshold => (
+					proposal.call.len(),
+					<T as Config>::RuntimeCall::decode(&mut &proposal.call[..]).ok(),
+				),
+				(maybe_call, _) => (call_len, maybe_call),
+			};
+
 			// We only bother fetching/decoding call if we know that we're ready to execute.
 			if let Some(call) = maybe_call.filter(|_| approvals >= threshold) {
 				// verify weight
@@ -671,6 +903,7 @@ impl<T: Config> Pallet<T> {
 				// attack.
 				<Multisigs<T>>::remove(&id, call_hash);
 				T::Currency::unreserve(&m.depositor, m.deposit);
+				Self::remove_proposal(&id, &call_hash);
 
 				let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
 				Self::deposit_event(Event::MultisigExecuted {
@@ -783,6 +1016,21 @@ impl<T: Config> Pallet<T> {
 	pub fn deposit(threshold: u16) -> BalanceOf<T> {
 		T::DepositBase::get() + T::DepositFactor::get() * threshold.into()
 	}
+
+	/// Calculate the deposit for storing a call of `len` bytes on-chain.
+	///
+	/// The deposit is calculated as `DepositBase + DepositFactor * ceil(len / 32)`.
+	pub fn call_deposit(len: usize) -> BalanceOf<T> {
+		T::DepositBase::get() + T::DepositFactor::get() * (len.div_ceil(32) as u32).into()
+	}
+
+	/// Remove the call stored on-chain for a multisig operation, if any, and return its deposit.
+	fn remove_proposal(id: &T::AccountId, call_hash: &CallHash) {
+		if let Some(proposal) = <Proposals<T>>::take(id, call_hash) {
+			let err_amount = T::Currency::unreserve(&proposal.depositor, proposal.deposit);
+			debug_assert!(err_amount.is_zero());
+		}
+	}
 }
 
 /// Return the weight of a dispatch call result as an `Option`.
@@ -71,6 +71,7 @@ impl Config for Test {
 	type DepositBase = MultisigDepositBase;
 	type DepositFactor = MultisigDepositFactor;
 	type MaxSignatories = ConstU32<3>;
+	type MaxCallSize = ConstU32<128>;
 	type WeightInfo = ();
 	type BlockNumberProvider = frame_system::Pallet<Test>;
 }
@@ -1005,3 +1006,278 @@ fn poke_deposit_handles_insufficient_balance() {
 		);
 	});
 }
+
+#[test]
+fn propose_as_multi_stores_call_until_final_approval() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 10);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::propose_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			call.clone(),
+			None
+		));
+		// Multisig deposit of 1 + 1 * 2, call deposit of 1 + 1 * ceil(11 / 32).
+		assert_eq!(Balances::reserved_balance(1), 5);
+		assert_eq!(
+			Proposals::<Test>::get(multi, hash).map(|p| p.call.into_inner()),
+			Some(call.encode())
+		);
+		System::assert_last_event(
+			Event::MultisigProposed {
+				proposer: 1,
+				timepoint: now(),
+				multisig: multi,
+				call_hash: hash,
+				expiry: None,
+			}
+			.into(),
+		);
+
+		// The final approval only needs the hash.
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			Some(now()),
+			hash,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 10);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 10);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		assert!(!Proposals::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn propose_as_multi_checks_call_size_and_expiry() {
+	new_test_ext().execute_with(|| {
+		let call =
+			Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![0; 128] }));
+		assert_noop!(
+			Multisig::propose_as_multi(RuntimeOrigin::signed(1), 2, vec![2, 3], call, None),
+			Error::<Test>::CallTooLarge,
+		);
+		assert_noop!(
+			Multisig::propose_as_multi(
+				RuntimeOrigin::signed(1),
+				2,
+				vec![2, 3],
+				call_transfer(6, 10),
+				Some(1)
+			),
+			Error::<Test>::Expired,
+		);
+
+		assert_ok!(Multisig::propose_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			call_transfer(6, 10),
+			None
+		));
+		assert_noop!(
+			Multisig::propose_as_multi(
+				RuntimeOrigin::signed(1),
+				2,
+				vec![2, 3],
+				call_transfer(6, 10),
+				None
+			),
+			Error::<Test>::AlreadyStored,
+		);
+	});
+}
+
+#[test]
+fn propose_as_multi_does_not_replace_a_stored_proposal() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		let hash = blake2_256(&call_transfer(6, 10).encode());
+		assert_ok!(Multisig::propose_as_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			call_transfer(6, 10),
+			Some(10)
+		));
+		assert_eq!(Balances::reserved_balance(1), 6);
+
+		// Another signatory can neither take over the deposit nor reset the expiry.
+		assert_noop!(
+			Multisig::propose_as_multi(
+				RuntimeOrigin::signed(2),
+				3,
+				vec![1, 3],
+				call_transfer(6, 10),
+				Some(20)
+			),
+			Error::<Test>::AlreadyStored,
+		);
+		let proposal = Proposals::<Test>::get(multi, hash).unwrap();
+		assert_eq!((proposal.depositor, proposal.expiry), (1, Some(10)));
+
+		// It can still approve the stored proposal by its hash.
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			3,
+			vec![1, 3],
+			Some(now()),
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(1), 6);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn cancel_proposed_multisig_returns_deposits() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		let hash = blake2_256(&call_transfer(6, 10).encode());
+		assert_ok!(Multisig::propose_as_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			call_transfer(6, 10),
+			Some(10)
+		));
+		assert_eq!(Balances::reserved_balance(1), 6);
+
+		assert_ok!(Multisig::cancel_as_multi(RuntimeOrigin::signed(1), 3, vec![2, 3], now(), hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!Proposals::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn expired_multisig_can_be_cleared_by_anyone() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		let call = call_transfer(6, 10);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+		let timepoint = now();
+		assert_ok!(Multisig::propose_as_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			call,
+			Some(5)
+		));
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			3,
+			vec![1, 3],
+			Some(timepoint),
+			hash,
+			Weight::zero()
+		));
+		assert_noop!(
+			Multisig::clear_expired_multisig(RuntimeOrigin::signed(4), multi, hash),
+			Error::<Test>::NotExpired,
+		);
+
+		System::set_block_number(5);
+		assert_noop!(
+			Multisig::approve_as_multi(
+				RuntimeOrigin::signed(3),
+				3,
+				vec![1, 2],
+				Some(timepoint),
+				hash,
+				call_weight
+			),
+			Error::<Test>::Expired,
+		);
+
+		assert_ok!(Multisig::clear_expired_multisig(RuntimeOrigin::signed(4), multi, hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 10);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		assert!(!Proposals::<Test>::contains_key(multi, hash));
+		System::assert_last_event(
+			Event::MultisigExpired { clearing: 4, timepoint, multisig: multi, call_hash: hash }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn clear_expired_multisig_requires_expiry() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let hash = blake2_256(&call_transfer(6, 10).encode());
+		assert_noop!(
+			Multisig::clear_expired_multisig(RuntimeOrigin::signed(4), multi, hash),
+			Error::<Test>::NotFound,
+		);
+
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		System::set_block_number(100);
+		assert_noop!(
+			Multisig::clear_expired_multisig(RuntimeOrigin::signed(4), multi, hash),
+			Error::<Test>::NotExpired,
+		);
+	});
+}
+
+#[test]
+fn pending_multisigs_lists_open_operations() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let proposed = call_transfer(6, 10);
+		let proposed_hash = blake2_256(&proposed.encode());
+		let approved_hash = blake2_256(&call_transfer(7, 5).encode());
+		assert_ok!(Multisig::propose_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			proposed.clone(),
+			Some(10)
+		));
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			None,
+			approved_hash,
+			Weight::zero()
+		));
+
+		let mut pending = Multisig::pending_multisigs(multi);
+		pending.sort_by_key(|(_, m, _)| m.depositor);
+		assert_eq!(pending.len(), 2);
+
+		let (call_hash, m, proposal) = &pending[0];
+		assert_eq!(*call_hash, proposed_hash);
+		assert_eq!(m.approvals.to_vec(), vec![1]);
+		let proposal = proposal.as_ref().expect("call is stored");
+		assert_eq!(proposal.call.to_vec(), proposed.encode());
+		assert_eq!(proposal.expiry, Some(10));
+
+		let (call_hash, m, proposal) = &pending[1];
+		assert_eq!(*call_hash, approved_hash);
+		assert_eq!(m.approvals.to_vec(), vec![2]);
+		assert!(proposal.is_none());
+
+		assert!(Multisig::pending_multisigs(4).is_empty());
+	});
+}
@@ -35,9 +35,9 @@
 //! Autogenerated weights for `pallet_multisig`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-25, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `c8c7296f7413`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
 
 // Executed Command:
@@ -48,8 +48,8 @@
 // --extrinsic=*
 // --runtime=target/production/wbuild/kitchensink-runtime/kitchensink_runtime.wasm
 // --pallet=pallet_multisig
-// --header=/__w/polkadot-sdk/polkadot-sdk/substrate/HEADER-APACHE2
-// --output=/__w/polkadot-sdk/polkadot-sdk/substrate/frame/multisig/src/weights.rs
+// --header=substrate/HEADER-APACHE2
+// --output=substrate/frame/multisig/src/weights.rs
 // --wasm-execution=compiled
 // --steps=50
 // --repeat=20
@@ -79,6 +79,8 @@ pub trait WeightInfo {
 	fn approve_as_multi_approve(s: u32, ) -> Weight;
 	fn cancel_as_multi(s: u32, ) -> Weight;
 	fn poke_deposit(s: u32, ) -> Weight;
+	fn propose_as_multi(s: u32, z: u32, ) -> Weight;
+	fn clear_expired_multisig() -> Weight;
 }
 
 /// Weights for `pallet_multisig` using the Substrate node and recommended hardware.
@@ -93,10 +95,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `3997`
-		// Minimum execution time: 18_622_000 picoseconds.
-		Weight::from_parts(20_470_382, 3997)
-			// Standard Error: 21
-			.saturating_add(Weight::from_parts(397, 0).saturating_mul(z.into()))
+		// Minimum execution time: 40_402_000 picoseconds.
+		Weight::from_parts(63_790_556, 3997)
+			// Standard Error: 357
+			.saturating_add(Weight::from_parts(3_192, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -107,34 +109,38 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `229 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 41_568_000 picoseconds.
-		Weight::from_parts(29_976_762, 6811)
-			// Standard Error: 1_712
-			.saturating_add(Weight::from_parts(144_320, 0).saturating_mul(s.into()))
-			// Standard Error: 16
-			.saturating_add(Weight::from_parts(1_819, 0).saturating_mul(z.into()))
+		// Minimum execution time: 83_059_000 picoseconds.
+		Weight::from_parts(86_301_667, 6811)
+			// Standard Error: 21_817
+			.saturating_add(Weight::from_parts(820_260, 0).saturating_mul(s.into()))
+			// Standard Error: 213
+			.saturating_add(Weight::from_parts(4_153, 0).saturating_mul(z.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `185`
-		//  Estimated: `6811`
-		// Minimum execution time: 26_237_000 picoseconds.
-		Weight::from_parts(14_821_348, 6811)
-			// Standard Error: 1_425
-			.saturating_add(Weight::from_parts(127_874, 0).saturating_mul(s.into()))
-			// Standard Error: 13
-			.saturating_add(Weight::from_parts(1_968, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		//  Estimated: `19994`
+		// Minimum execution time: 57_846_000 picoseconds.
+		Weight::from_parts(96_813_283, 19994)
+			// Standard Error: 21_050
+			.saturating_add(Weight::from_parts(215_997, 0).saturating_mul(s.into()))
+			// Standard Error: 206
+			.saturating_add(Weight::from_parts(1_236, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `SafeMode::EnteredUntil` (r:1 w:0)
@@ -146,14 +152,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `288 + s * (33 ±0)`
-		//  Estimated: `6811`
-		// Minimum execution time: 50_942_000 picoseconds.
-		Weight::from_parts(34_745_418, 6811)
-			// Standard Error: 2_993
-			.saturating_add(Weight::from_parts(193_313, 0).saturating_mul(s.into()))
-			// Standard Error: 29
-			.saturating_add(Weight::from_parts(2_057, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		//  Estimated: `19994`
+		// Minimum execution time: 132_885_000 picoseconds.
+		Weight::from_parts(91_936_496, 19994)
+			// Standard Error: 29_127
+			.saturating_add(Weight::from_parts(1_096_136, 0).saturating_mul(s.into()))
+			// Standard Error: 285
+			.saturating_add(Weight::from_parts(6_217, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -163,39 +169,43 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `233 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 25_583_000 picoseconds.
-		Weight::from_parts(27_463_275, 6811)
-			// Standard Error: 1_158
-			.saturating_add(Weight::from_parts(139_226, 0).saturating_mul(s.into()))
+		// Minimum execution time: 75_032_000 picoseconds.
+		Weight::from_parts(87_684_513, 6811)
+			// Standard Error: 30_366
+			.saturating_add(Weight::from_parts(554_268, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `185`
-		//  Estimated: `6811`
-		// Minimum execution time: 13_086_000 picoseconds.
-		Weight::from_parts(14_074_258, 6811)
-			// Standard Error: 1_279
-			.saturating_add(Weight::from_parts(126_544, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		//  Estimated: `19994`
+		// Minimum execution time: 40_568_000 picoseconds.
+		Weight::from_parts(53_604_942, 19994)
+			// Standard Error: 20_323
+			.saturating_add(Weight::from_parts(286_218, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `357 + s * (1 ±0)`
-		//  Estimated: `6811`
-		// Minimum execution time: 26_950_000 picoseconds.
-		Weight::from_parts(28_623_566, 6811)
-			// Standard Error: 1_795
-			.saturating_add(Weight::from_parts(135_351, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		//  Estimated: `19994`
+		// Minimum execution time: 81_931_000 picoseconds.
+		Weight::from_parts(86_274_174, 19994)
+			// Standard Error: 23_167
+			.saturating_add(Weight::from_parts(510_139, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -205,13 +215,47 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `357 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 25_804_000 picoseconds.
-		Weight::from_parts(27_349_525, 6811)
-			// Standard Error: 1_276
-			.saturating_add(Weight::from_parts(121_813, 0).saturating_mul(s.into()))
+		// Minimum execution time: 60_568_000 picoseconds.
+		Weight::from_parts(78_625_882, 6811)
+			// Standard Error: 18_571
+			.saturating_add(Weight::from_parts(644_751, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Multisig::Proposals` (r:1 w:1)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 16376]`.
+	fn propose_as_multi(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233 + s * (2 ±0)`
+		//  Estimated: `19994`
+		// Minimum execution time: 162_832_000 picoseconds.
+		Weight::from_parts(105_944_838, 19994)
+			// Standard Error: 29_995
+			.saturating_add(Weight::from_parts(1_270_110, 0).saturating_mul(s.into()))
+			// Standard Error: 179
+			.saturating_add(Weight::from_parts(6_469, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:1)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn clear_expired_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `17112`
+		//  Estimated: `19994`
+		// Minimum execution time: 224_016_000 picoseconds.
+		Weight::from_parts(231_480_000, 19994)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -225,10 +269,10 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `3997`
-		// Minimum execution time: 18_622_000 picoseconds.
-		Weight::from_parts(20_470_382, 3997)
-			// Standard Error: 21
-			.saturating_add(Weight::from_parts(397, 0).saturating_mul(z.into()))
+		// Minimum execution time: 40_402_000 picoseconds.
+		Weight::from_parts(63_790_556, 3997)
+			// Standard Error: 357
+			.saturating_add(Weight::from_parts(3_192, 0).saturating_mul(z.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -239,34 +283,38 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `229 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 41_568_000 picoseconds.
-		Weight::from_parts(29_976_762, 6811)
-			// Standard Error: 1_712
-			.saturating_add(Weight::from_parts(144_320, 0).saturating_mul(s.into()))
-			// Standard Error: 16
-			.saturating_add(Weight::from_parts(1_819, 0).saturating_mul(z.into()))
+		// Minimum execution time: 83_059_000 picoseconds.
+		Weight::from_parts(86_301_667, 6811)
+			// Standard Error: 21_817
+			.saturating_add(Weight::from_parts(820_260, 0).saturating_mul(s.into()))
+			// Standard Error: 213
+			.saturating_add(Weight::from_parts(4_153, 0).saturating_mul(z.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[3, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_approve(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `185`
-		//  Estimated: `6811`
-		// Minimum execution time: 26_237_000 picoseconds.
-		Weight::from_parts(14_821_348, 6811)
-			// Standard Error: 1_425
-			.saturating_add(Weight::from_parts(127_874, 0).saturating_mul(s.into()))
-			// Standard Error: 13
-			.saturating_add(Weight::from_parts(1_968, 0).saturating_mul(z.into()))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		//  Estimated: `19994`
+		// Minimum execution time: 57_846_000 picoseconds.
+		Weight::from_parts(96_813_283, 19994)
+			// Standard Error: 21_050
+			.saturating_add(Weight::from_parts(215_997, 0).saturating_mul(s.into()))
+			// Standard Error: 206
+			.saturating_add(Weight::from_parts(1_236, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `SafeMode::EnteredUntil` (r:1 w:0)
@@ -278,14 +326,14 @@ impl WeightInfo for () {
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `288 + s * (33 ±0)`
-		//  Estimated: `6811`
-		// Minimum execution time: 50_942_000 picoseconds.
-		Weight::from_parts(34_745_418, 6811)
-			// Standard Error: 2_993
-			.saturating_add(Weight::from_parts(193_313, 0).saturating_mul(s.into()))
-			// Standard Error: 29
-			.saturating_add(Weight::from_parts(2_057, 0).saturating_mul(z.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		//  Estimated: `19994`
+		// Minimum execution time: 132_885_000 picoseconds.
+		Weight::from_parts(91_936_496, 19994)
+			// Standard Error: 29_127
+			.saturating_add(Weight::from_parts(1_096_136, 0).saturating_mul(s.into()))
+			// Standard Error: 285
+			.saturating_add(Weight::from_parts(6_217, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -295,39 +343,43 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `233 + s * (2 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 25_583_000 picoseconds.
-		Weight::from_parts(27_463_275, 6811)
-			// Standard Error: 1_158
-			.saturating_add(Weight::from_parts(139_226, 0).saturating_mul(s.into()))
+		// Minimum execution time: 75_032_000 picoseconds.
+		Weight::from_parts(87_684_513, 6811)
+			// Standard Error: 30_366
+			.saturating_add(Weight::from_parts(554_268, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn approve_as_multi_approve(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `185`
-		//  Estimated: `6811`
-		// Minimum execution time: 13_086_000 picoseconds.
-		Weight::from_parts(14_074_258, 6811)
-			// Standard Error: 1_279
-			.saturating_add(Weight::from_parts(126_544, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		//  Estimated: `19994`
+		// Minimum execution time: 40_568_000 picoseconds.
+		Weight::from_parts(53_604_942, 19994)
+			// Standard Error: 20_323
+			.saturating_add(Weight::from_parts(286_218, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:0)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `357 + s * (1 ±0)`
-		//  Estimated: `6811`
-		// Minimum execution time: 26_950_000 picoseconds.
-		Weight::from_parts(28_623_566, 6811)
-			// Standard Error: 1_795
-			.saturating_add(Weight::from_parts(135_351, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		//  Estimated: `19994`
+		// Minimum execution time: 81_931_000 picoseconds.
+		Weight::from_parts(86_274_174, 19994)
+			// Standard Error: 23_167
+			.saturating_add(Weight::from_parts(510_139, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
@@ -337,11 +389,45 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `357 + s * (1 ±0)`
 		//  Estimated: `6811`
-		// Minimum execution time: 25_804_000 picoseconds.
-		Weight::from_parts(27_349_525, 6811)
-			// Standard Error: 1_276
-			.saturating_add(Weight::from_parts(121_813, 0).saturating_mul(s.into()))
+		// Minimum execution time: 60_568_000 picoseconds.
+		Weight::from_parts(78_625_882, 6811)
+			// Standard Error: 18_571
+			.saturating_add(Weight::from_parts(644_751, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Multisig::Proposals` (r:1 w:1)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 16376]`.
+	fn propose_as_multi(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `233 + s * (2 ±0)`
+		//  Estimated: `19994`
+		// Minimum execution time: 162_832_000 picoseconds.
+		Weight::from_parts(105_944_838, 19994)
+			// Standard Error: 29_995
+			.saturating_add(Weight::from_parts(1_270_110, 0).saturating_mul(s.into()))
+			// Standard Error: 179
+			.saturating_add(Weight::from_parts(6_469, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Proposals` (r:1 w:1)
+	/// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(16529), added: 19004, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn clear_expired_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `17112`
+		//  Estimated: `19994`
+		// Minimum execution time: 224_016_000 picoseconds.
+		Weight::from_parts(231_480_000, 19994)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -251,6 +251,32 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn add_limited_proxy(
+		p: Linear<1, { T::MaxProxies::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		add_proxies::<T>(p, None)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let real = T::Lookup::unlookup(account("target", T::MaxProxies::get(), SEED));
+		let now = T::BlockNumberProvider::current_block_number();
+		let expiry = now.saturating_add(100u32.into());
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			real,
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::zero(),
+			Some(expiry),
+			Some((BalanceOf::<T>::max_value(), 10u32.into())),
+		);
+
+		let (proxies, _) = Proxies::<T>::get(caller);
+		assert_eq!(proxies.len() as u32, p + 1);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn remove_proxy(p: Linear<1, { T::MaxProxies::get() - 1 }>) -> Result<(), BenchmarkError> {
 		add_proxies::<T>(p, None)?;
@@ -457,5 +483,22 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn migration_v1_step() -> Result<(), BenchmarkError> {
+		let delegator: T::AccountId = account("delegator", 0, SEED);
+		let delegates = (0..T::MaxProxies::get()).map(|i| account("target", i, SEED)).collect();
+		migrations::v1::insert_old_proxies::<T>(&delegator, delegates, 0u32.into());
+
+		#[block]
+		{
+			migrations::v1::LazyMigrationToV1::<T>::proxies_step(None);
+		}
+
+		let (proxies, _) = Proxies::<T>::get(delegator);
+		assert_eq!(proxies.len() as u32, T::MaxProxies::get());
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Proxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
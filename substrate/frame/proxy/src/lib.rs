@@ -30,12 +30,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+pub mod migrations;
 mod tests;
 pub mod weights;
 
 extern crate alloc;
 use alloc::{boxed::Box, vec};
 use frame::{
+	deps::frame_support::storage::with_storage_layer,
 	prelude::*,
 	traits::{Currency, InstanceFilter, ReservableCurrency},
 };
@@ -52,6 +54,14 @@ pub type BlockNumberFor<T> =
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+/// [`ProxyDefinition`] of the pallet.
+pub type ProxyDefinitionOf<T> = ProxyDefinition<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::ProxyType,
+	BlockNumberFor<T>,
+	BalanceOf<T>,
+>;
+
 /// The parameters under which a particular account has a proxy relationship with some other
 /// account.
 #[derive(
@@ -68,7 +78,7 @@ type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup
 	MaxEncodedLen,
 	TypeInfo,
 )]
-pub struct ProxyDefinition<AccountId, ProxyType, BlockNumber> {
+pub struct ProxyDefinition<AccountId, ProxyType, BlockNumber, Balance> {
 	/// The account which may act on behalf of another.
 	pub delegate: AccountId,
 	/// A value defining the subset of calls that it is allowed to make.
@@ -76,6 +86,71 @@ pub struct ProxyDefinition<AccountId, ProxyType, BlockNumber> {
 	/// The number of blocks that an announcement must be in place for before the corresponding
 	/// call may be dispatched. If zero, then no announcement is needed.
 	pub delay: BlockNumber,
+	/// The block from which the proxy may no longer be used. If `None`, the proxy never expires.
+	pub expiry: Option<BlockNumber>,
+	/// The amount of native currency the proxy may spend on behalf of the delegator. If `None`,
+	/// the spending is not limited.
+	pub allowance: Option<ProxyAllowance<Balance, BlockNumber>>,
+}
+
+impl<AccountId, ProxyType, BlockNumber: PartialOrd, Balance>
+	ProxyDefinition<AccountId, ProxyType, BlockNumber, Balance>
+{
+	/// Whether the proxy has expired as of the block `now`.
+	pub fn is_expired(&self, now: &BlockNumber) -> bool {
+		self.expiry.as_ref().is_some_and(|expiry| now >= expiry)
+	}
+
+	/// Whether the proxy is bound by an expiry or an allowance.
+	pub fn is_limited(&self) -> bool {
+		self.expiry.is_some() || self.allowance.is_some()
+	}
+
+	/// The parameters identifying the proxy among the proxies of a delegator.
+	///
+	/// Proxies are kept sorted by these, as there may be at most one proxy for each of them.
+	fn key(&self) -> (&AccountId, &ProxyType, &BlockNumber) {
+		(&self.delegate, &self.proxy_type, &self.delay)
+	}
+}
+
+/// The amount of native currency a proxy may spend on behalf of its delegator per period.
+///
+/// The spending of a call is measured as the decrease of the total balance of the delegator
+/// during its dispatch.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct ProxyAllowance<Balance, BlockNumber> {
+	/// The maximum amount that may be spent within a period.
+	pub limit: Balance,
+	/// The length of a period in blocks. If zero, the allowance is never renewed.
+	pub period: BlockNumber,
+	/// The amount spent within the current period.
+	pub spent: Balance,
+	/// The block at which the current period started.
+	pub period_start: BlockNumber,
+}
+
+impl<Balance: Zero, BlockNumber: AtLeast32BitUnsigned + Copy> ProxyAllowance<Balance, BlockNumber> {
+	/// Starts a new period if the current one is over as of the block `now`.
+	pub fn renew(&mut self, now: BlockNumber) {
+		if !self.period.is_zero() && now >= self.period_start.saturating_add(self.period) {
+			self.period_start = now - (now - self.period_start) % self.period;
+			self.spent = Zero::zero();
+		}
+	}
 }
 
 /// Details surrounding a specific instance of an announcement to make a call.
@@ -113,7 +188,11 @@ pub enum DepositKind {
 pub mod pallet {
 	use super::*;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configuration trait.
@@ -161,6 +240,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type ProxyDepositFactor: Get<BalanceOf<Self>>;
 
+		/// The additional amount of currency needed per proxy bound by an expiry or an allowance.
+		///
+		/// This is held on top of `ProxyDepositFactor` for the optional expiry and allowance of
+		/// the proxy, so that proxies without them keep the same deposit.
+		#[pallet::constant]
+		type LimitedProxyDepositFactor: Get<BalanceOf<Self>>;
+
 		/// The maximum amount of proxies allowed for a single account.
 		#[pallet::constant]
 		type MaxProxies: Get<u32>;
@@ -219,6 +305,9 @@ pub mod pallet {
 		/// Dispatch the given `call` from an account that the sender is authorised for through
 		/// `add_proxy`.
 		///
+		/// Fails with `NotProxy` if the proxy has expired. If the proxy has an allowance, the
+		/// dispatch of `call` is reverted with `AllowanceExceeded` if it would exceed it.
+		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// Parameters:
@@ -231,6 +320,8 @@ pub mod pallet {
 			(T::WeightInfo::proxy(T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				 // Proxies for the allowance of the proxy, if any.
+				.saturating_add(T::DbWeight::get().writes(1))
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -337,8 +428,13 @@ pub mod pallet {
 			let pure = Self::pure_account(&who, &proxy_type, index, None);
 			ensure!(!Proxies::<T>::contains_key(&pure), Error::<T>::Duplicate);
 
-			let proxy_def =
-				ProxyDefinition { delegate: who.clone(), proxy_type: proxy_type.clone(), delay };
+			let proxy_def = ProxyDefinition {
+				delegate: who.clone(),
+				proxy_type: proxy_type.clone(),
+				delay,
+				expiry: None,
+				allowance: None,
+			};
 			let bounded_proxies: BoundedVec<_, T::MaxProxies> =
 				vec![proxy_def].try_into().map_err(|_| Error::<T>::TooMany)?;
 
@@ -426,17 +522,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let real = T::Lookup::lookup(real)?;
+			let now = T::BlockNumberProvider::current_block_number();
 			Proxies::<T>::get(&real)
 				.0
 				.into_iter()
-				.find(|x| x.delegate == who)
+				.find(|x| x.delegate == who && !x.is_expired(&now))
 				.ok_or(Error::<T>::NotProxy)?;
 
-			let announcement = Announcement {
-				real: real.clone(),
-				call_hash,
-				height: T::BlockNumberProvider::current_block_number(),
-			};
+			let announcement = Announcement { real: real.clone(), call_hash, height: now };
 
 			Announcements::<T>::try_mutate(&who, |(ref mut pending, ref mut deposit)| {
 				pending.try_push(announcement).map_err(|_| Error::<T>::TooMany)?;
@@ -516,7 +609,8 @@ pub mod pallet {
 		/// Dispatch the given `call` from an account that the sender is authorized for through
 		/// `add_proxy`.
 		///
-		/// Removes any corresponding announcement(s).
+		/// Removes any corresponding announcement(s). Expiry and allowance of the proxy are
+		/// enforced as in `proxy`.
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
@@ -530,6 +624,8 @@ pub mod pallet {
 			(T::WeightInfo::proxy_announced(T::MaxPending::get(), T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				 // Proxies for the allowance of the proxy, if any.
+				.saturating_add(T::DbWeight::get().writes(1))
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -576,13 +672,9 @@ pub mod pallet {
 			// Check and update proxy deposits
 			Proxies::<T>::try_mutate_exists(&who, |maybe_proxies| -> DispatchResult {
 				let (proxies, old_deposit) = maybe_proxies.take().unwrap_or_default();
-				let maybe_new_deposit = Self::rejig_deposit(
-					&who,
-					old_deposit,
-					T::ProxyDepositBase::get(),
-					T::ProxyDepositFactor::get(),
-					proxies.len(),
-				)?;
+				let new_deposit = Self::proxies_deposit(&proxies);
+				Self::update_deposit(&who, old_deposit, new_deposit)?;
+				let maybe_new_deposit = (!proxies.is_empty()).then_some(new_deposit);
 
 				match maybe_new_deposit {
 					Some(new_deposit) if new_deposit != old_deposit => {
@@ -657,6 +749,73 @@ pub mod pallet {
 
 			Ok(if deposit_updated { Pays::No.into() } else { Pays::Yes.into() })
 		}
+
+		/// Register a proxy account for the sender that is able to make calls on its behalf,
+		/// until an expiry and within an allowance.
+		///
+		/// A limited proxy may not register proxies nor dispatch calls through other proxies.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `proxy`: The account that the `caller` would like to make a proxy.
+		/// - `proxy_type`: The permissions allowed for this proxy account.
+		/// - `delay`: The announcement period required of the initial proxy. Will generally be
+		/// zero.
+		/// - `expiry`: The block from which the proxy may no longer be used, if any.
+		/// - `allowance`: The amount of native currency the proxy may spend per number of blocks,
+		/// if any. A period of zero blocks makes the allowance a one-off.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::add_limited_proxy(T::MaxProxies::get()))]
+		pub fn add_limited_proxy(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+			expiry: Option<BlockNumberFor<T>>,
+			allowance: Option<(BalanceOf<T>, BlockNumberFor<T>)>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			Self::add_limited_proxy_delegate(&who, delegate, proxy_type, delay, expiry, allowance)
+		}
+
+		/// Unregister the expired proxies of an account, refunding the deposit held for them.
+		///
+		/// The dispatch origin for this call must be _Signed_. The transaction fee is waived if
+		/// any proxy was removed.
+		///
+		/// Parameters:
+		/// - `delegator`: The account whose expired proxies should be removed.
+		///
+		/// Emits `ProxyExpired` for every removed proxy.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::remove_proxy(T::MaxProxies::get()))]
+		pub fn remove_expired_proxies(
+			origin: OriginFor<T>,
+			delegator: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let delegator = T::Lookup::lookup(delegator)?;
+			let now = T::BlockNumberProvider::current_block_number();
+			Proxies::<T>::try_mutate_exists(&delegator, |x| -> DispatchResult {
+				let (mut proxies, old_deposit) = x.take().ok_or(Error::<T>::NotFound)?;
+				ensure!(
+					Self::remove_expired_proxy_delegates(&delegator, &mut proxies, &now),
+					Error::<T>::NotFound
+				);
+				// Removing proxies never requires reserving more, even if the deposit factors
+				// were raised.
+				let new_deposit = Self::proxies_deposit(&proxies).min(old_deposit);
+				Self::update_deposit(&delegator, old_deposit, new_deposit)?;
+				if !proxies.is_empty() {
+					*x = Some((proxies, new_deposit))
+				}
+				Ok(())
+			})?;
+
+			Ok(Pays::No.into())
+		}
 	}
 
 	#[pallet::event]
@@ -706,6 +865,13 @@ pub mod pallet {
 			old_deposit: BalanceOf<T>,
 			new_deposit: BalanceOf<T>,
 		},
+		/// An expired proxy was removed.
+		ProxyExpired {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -726,6 +892,10 @@ pub mod pallet {
 		Unannounced,
 		/// Cannot add self as proxy.
 		NoSelfProxy,
+		/// The expiry of the proxy is not in the future.
+		Expired,
+		/// The call would spend more than the allowance of the proxy.
+		AllowanceExceeded,
 	}
 
 	/// The set of account proxies. Maps the account which has delegated to the accounts
@@ -735,13 +905,7 @@ pub mod pallet {
 		_,
 		Twox64Concat,
 		T::AccountId,
-		(
-			BoundedVec<
-				ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
-				T::MaxProxies,
-			>,
-			BalanceOf<T>,
-		),
+		(BoundedVec<ProxyDefinitionOf<T>, T::MaxProxies>, BalanceOf<T>),
 		ValueQuery,
 	>;
 
@@ -779,10 +943,7 @@ impl<T: Config> Pallet<T> {
 	/// Public function to proxies storage.
 	pub fn proxies(
 		account: T::AccountId,
-	) -> (
-		BoundedVec<ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>, T::MaxProxies>,
-		BalanceOf<T>,
-	) {
+	) -> (BoundedVec<ProxyDefinitionOf<T>, T::MaxProxies>, BalanceOf<T>) {
 		Proxies::<T>::get(account)
 	}
 
@@ -839,17 +1000,55 @@ impl<T: Config> Pallet<T> {
 		delegatee: T::AccountId,
 		proxy_type: T::ProxyType,
 		delay: BlockNumberFor<T>,
+	) -> DispatchResult {
+		Self::add_limited_proxy_delegate(delegator, delegatee, proxy_type, delay, None, None)
+	}
+
+	/// Register a proxy account for the delegator that is able to make calls on its behalf,
+	/// until an expiry and within an allowance.
+	///
+	/// Expired proxies of the delegator are removed beforehand.
+	///
+	/// Parameters:
+	/// - `delegator`: The delegator account.
+	/// - `delegatee`: The account that the `delegator` would like to make a proxy.
+	/// - `proxy_type`: The permissions allowed for this proxy account.
+	/// - `delay`: The announcement period required of the initial proxy. Will generally be
+	/// zero.
+	/// - `expiry`: The block from which the proxy may no longer be used, if any.
+	/// - `allowance`: The amount of native currency the proxy may spend per number of blocks, if
+	/// any.
+	pub fn add_limited_proxy_delegate(
+		delegator: &T::AccountId,
+		delegatee: T::AccountId,
+		proxy_type: T::ProxyType,
+		delay: BlockNumberFor<T>,
+		expiry: Option<BlockNumberFor<T>>,
+		allowance: Option<(BalanceOf<T>, BlockNumberFor<T>)>,
 	) -> DispatchResult {
 		ensure!(delegator != &delegatee, Error::<T>::NoSelfProxy);
+		let now = T::BlockNumberProvider::current_block_number();
+		ensure!(expiry.map_or(true, |expiry| expiry > now), Error::<T>::Expired);
 		Proxies::<T>::try_mutate(delegator, |(ref mut proxies, ref mut deposit)| {
+			Self::remove_expired_proxy_delegates(delegator, proxies, &now);
 			let proxy_def = ProxyDefinition {
 				delegate: delegatee.clone(),
 				proxy_type: proxy_type.clone(),
 				delay,
+				expiry,
+				allowance: allowance.map(|(limit, period)| ProxyAllowance {
+					limit,
+					period,
+					spent: Zero::zero(),
+					period_start: now,
+				}),
 			};
-			let i = proxies.binary_search(&proxy_def).err().ok_or(Error::<T>::Duplicate)?;
+			let i = proxies
+				.binary_search_by(|d| d.key().cmp(&proxy_def.key()))
+				.err()
+				.ok_or(Error::<T>::Duplicate)?;
 			proxies.try_insert(i, proxy_def).map_err(|_| Error::<T>::TooMany)?;
-			let new_deposit = Self::deposit(proxies.len() as u32);
+			let new_deposit = Self::proxies_deposit(proxies);
 			if new_deposit > *deposit {
 				T::Currency::reserve(delegator, new_deposit - *deposit)?;
 			} else if new_deposit < *deposit {
@@ -868,6 +1067,8 @@ impl<T: Config> Pallet<T> {
 
 	/// Unregister a proxy account for the delegator.
 	///
+	/// Expired proxies of the delegator are removed as well.
+	///
 	/// Parameters:
 	/// - `delegator`: The delegator account.
 	/// - `delegatee`: The account that the `delegator` would like to make a proxy.
@@ -882,19 +1083,17 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		Proxies::<T>::try_mutate_exists(delegator, |x| {
 			let (mut proxies, old_deposit) = x.take().ok_or(Error::<T>::NotFound)?;
-			let proxy_def = ProxyDefinition {
-				delegate: delegatee.clone(),
-				proxy_type: proxy_type.clone(),
-				delay,
-			};
-			let i = proxies.binary_search(&proxy_def).ok().ok_or(Error::<T>::NotFound)?;
+			let i = proxies
+				.binary_search_by(|d| d.key().cmp(&(&delegatee, &proxy_type, &delay)))
+				.ok()
+				.ok_or(Error::<T>::NotFound)?;
 			proxies.remove(i);
-			let new_deposit = Self::deposit(proxies.len() as u32);
-			if new_deposit > old_deposit {
-				T::Currency::reserve(delegator, new_deposit - old_deposit)?;
-			} else if new_deposit < old_deposit {
-				T::Currency::unreserve(delegator, old_deposit - new_deposit);
-			}
+			let now = T::BlockNumberProvider::current_block_number();
+			Self::remove_expired_proxy_delegates(delegator, &mut proxies, &now);
+			// Removing a proxy never requires reserving more, even if the deposit factors were
+			// raised, so that a compromised proxy can always be removed.
+			let new_deposit = Self::proxies_deposit(&proxies).min(old_deposit);
+			T::Currency::unreserve(delegator, old_deposit - new_deposit);
 			if !proxies.is_empty() {
 				*x = Some((proxies, new_deposit))
 			}
@@ -908,6 +1107,30 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Remove the proxies of the delegator which have expired as of the block `now`.
+	///
+	/// Returns whether any proxy was removed. The deposit is left to the caller to adjust.
+	fn remove_expired_proxy_delegates(
+		delegator: &T::AccountId,
+		proxies: &mut BoundedVec<ProxyDefinitionOf<T>, T::MaxProxies>,
+		now: &BlockNumberFor<T>,
+	) -> bool {
+		let orig_len = proxies.len();
+		proxies.retain(|x| {
+			if !x.is_expired(now) {
+				return true
+			}
+			Self::deposit_event(Event::<T>::ProxyExpired {
+				delegator: delegator.clone(),
+				delegatee: x.delegate.clone(),
+				proxy_type: x.proxy_type.clone(),
+				delay: x.delay,
+			});
+			false
+		});
+		proxies.len() < orig_len
+	}
+
 	pub fn deposit(num_proxies: u32) -> BalanceOf<T> {
 		if num_proxies == 0 {
 			Zero::zero()
@@ -916,6 +1139,12 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The deposit for `proxies`, including the surcharge of the limited ones.
+	pub fn proxies_deposit(proxies: &[ProxyDefinitionOf<T>]) -> BalanceOf<T> {
+		let limited = proxies.iter().filter(|x| x.is_limited()).count() as u32;
+		Self::deposit(proxies.len() as u32) + T::LimitedProxyDepositFactor::get() * limited.into()
+	}
+
 	fn rejig_deposit(
 		who: &T::AccountId,
 		old_deposit: BalanceOf<T>,
@@ -925,6 +1154,17 @@ impl<T: Config> Pallet<T> {
 	) -> Result<Option<BalanceOf<T>>, DispatchError> {
 		let new_deposit =
 			if len == 0 { BalanceOf::<T>::zero() } else { base + factor * (len as u32).into() };
+		Self::update_deposit(who, old_deposit, new_deposit)?;
+		Ok(if len == 0 { None } else { Some(new_deposit) })
+	}
+
+	/// Reserve or unreserve the difference between the `old_deposit` and the `new_deposit` of
+	/// `who`.
+	fn update_deposit(
+		who: &T::AccountId,
+		old_deposit: BalanceOf<T>,
+		new_deposit: BalanceOf<T>,
+	) -> DispatchResult {
 		if new_deposit > old_deposit {
 			T::Currency::reserve(who, new_deposit.saturating_sub(old_deposit))?;
 		} else if new_deposit < old_deposit {
@@ -937,7 +1177,7 @@ impl<T: Config> Pallet<T> {
 				);
 			}
 		}
-		Ok(if len == 0 { None } else { Some(new_deposit) })
+		Ok(())
 	}
 
 	fn edit_announcements<
@@ -963,33 +1203,44 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Find the proxy of `real` for `delegate`, ignoring expired ones.
 	pub fn find_proxy(
 		real: &T::AccountId,
 		delegate: &T::AccountId,
 		force_proxy_type: Option<T::ProxyType>,
-	) -> Result<ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>, DispatchError> {
-		let f = |x: &ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>| -> bool {
+	) -> Result<ProxyDefinitionOf<T>, DispatchError> {
+		let now = T::BlockNumberProvider::current_block_number();
+		let f = |x: &ProxyDefinitionOf<T>| -> bool {
 			&x.delegate == delegate &&
-				force_proxy_type.as_ref().map_or(true, |y| &x.proxy_type == y)
+				force_proxy_type.as_ref().map_or(true, |y| &x.proxy_type == y) &&
+				!x.is_expired(&now)
 		};
 		Ok(Proxies::<T>::get(real).0.into_iter().find(f).ok_or(Error::<T>::NotProxy)?)
 	}
 
-	fn do_proxy(
-		def: ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
-		real: T::AccountId,
-		call: <T as Config>::RuntimeCall,
-	) {
+	fn do_proxy(def: ProxyDefinitionOf<T>, real: T::AccountId, call: <T as Config>::RuntimeCall) {
 		use frame::traits::{InstanceFilter as _, OriginTrait as _};
+		let limited = def.is_limited();
+		let maybe_allowance = def.allowance;
+		let key = (def.delegate.clone(), def.proxy_type.clone(), def.delay);
 		// This is a freshly authenticated new account, the origin restrictions doesn't apply.
-		let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real).into();
+		let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real.clone()).into();
 		origin.add_filter(move |c: &<T as frame_system::Config>::RuntimeCall| {
 			let c = <T as Config>::RuntimeCall::from_ref(c);
 			// We make sure the proxy call does access this pallet to change modify proxies.
 			match c.is_sub_type() {
+				// Limited proxy call cannot add proxies nor act through other proxies, which would
+				// let it escape its expiry and allowance.
+				Some(Call::add_proxy { .. }) |
+				Some(Call::add_limited_proxy { .. }) |
+				Some(Call::proxy { .. }) |
+				Some(Call::proxy_announced { .. })
+					if limited =>
+					false,
 				// Proxy call cannot add or remove a proxy with more permissions than it already
 				// has.
 				Some(Call::add_proxy { ref proxy_type, .. }) |
+				Some(Call::add_limited_proxy { ref proxy_type, .. }) |
 				Some(Call::remove_proxy { ref proxy_type, .. })
 					if !def.proxy_type.is_superset(proxy_type) =>
 					false,
@@ -1001,8 +1252,32 @@ impl<T: Config> Pallet<T> {
 				_ => def.proxy_type.filter(c),
 			}
 		});
-		let e = call.dispatch(origin);
-		Self::deposit_event(Event::ProxyExecuted { result: e.map(|_| ()).map_err(|e| e.error) });
+		let result = match maybe_allowance {
+			None => call.dispatch(origin).map(|_| ()).map_err(|e| e.error),
+			// The dispatch is reverted if it spends more than the allowance.
+			Some(mut allowance) => with_storage_layer(|| {
+				let balance = T::Currency::total_balance(&real);
+				call.dispatch(origin).map_err(|e| e.error)?;
+				let spent = balance.saturating_sub(T::Currency::total_balance(&real));
+
+				allowance.renew(T::BlockNumberProvider::current_block_number());
+				allowance.spent = allowance.spent.saturating_add(spent);
+				ensure!(allowance.spent <= allowance.limit, Error::<T>::AllowanceExceeded);
+
+				// The call may have removed the proxy, in which case there is nothing to track.
+				Proxies::<T>::mutate_exists(&real, |maybe_proxies| {
+					let Some((proxies, _)) = maybe_proxies else { return };
+					let key = (&key.0, &key.1, &key.2);
+					if let Ok(i) = proxies.binary_search_by(|x| x.key().cmp(&key)) {
+						if let Some(ref mut a) = proxies[i].allowance {
+							*a = allowance;
+						}
+					}
+				});
+				Ok(())
+			}),
+		};
+		Self::deposit_event(Event::ProxyExecuted { result });
 	}
 
 	/// Removes all proxy delegates for a given delegator.
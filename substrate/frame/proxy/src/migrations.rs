@@ -0,0 +1,224 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Migrations for Proxy Pallet
+
+use crate::{BlockNumberFor, *};
+use alloc::vec::Vec;
+use frame::prelude::*;
+
+pub mod v1 {
+	use super::*;
+	use frame::{
+		deps::frame_support::{
+			migrations::{MigrationId, SteppedMigration, SteppedMigrationError, VersionedMigration},
+			weights::WeightMeter,
+		},
+		traits::UncheckedOnRuntimeUpgrade,
+	};
+
+	const PALLET_MIGRATIONS_ID: &[u8; 12] = b"pallet-proxy";
+
+	/// The proxy definition before the introduction of expiries and allowances.
+	#[derive(Encode, Decode)]
+	pub(crate) struct OldProxyDefinition<AccountId, ProxyType, BlockNumber> {
+		pub(crate) delegate: AccountId,
+		pub(crate) proxy_type: ProxyType,
+		pub(crate) delay: BlockNumber,
+	}
+
+	type OldProxies<T> = (
+		BoundedVec<
+			OldProxyDefinition<
+				<T as frame_system::Config>::AccountId,
+				<T as Config>::ProxyType,
+				BlockNumberFor<T>,
+			>,
+			<T as Config>::MaxProxies,
+		>,
+		BalanceOf<T>,
+	);
+
+	/// The storage items before the introduction of expiries and allowances.
+	pub(crate) mod v0 {
+		use super::*;
+
+		#[frame::storage_alias]
+		pub(crate) type Proxies<T: Config> = StorageMap<
+			Pallet<T>,
+			Twox64Concat,
+			<T as frame_system::Config>::AccountId,
+			OldProxies<T>,
+		>;
+	}
+
+	fn migrate_proxies<T: Config>(
+		(old, deposit): OldProxies<T>,
+	) -> (BoundedVec<ProxyDefinitionOf<T>, T::MaxProxies>, BalanceOf<T>) {
+		let proxies = old
+			.into_iter()
+			.map(|def| ProxyDefinition {
+				delegate: def.delegate,
+				proxy_type: def.proxy_type,
+				delay: def.delay,
+				expiry: None,
+				allowance: None,
+			})
+			.collect::<Vec<_>>();
+		(BoundedVec::truncate_from(proxies), deposit)
+	}
+
+	/// Stores the proxies of the default type of `delegator` in the former format.
+	#[cfg(any(test, feature = "runtime-benchmarks"))]
+	pub(crate) fn insert_old_proxies<T: Config>(
+		delegator: &T::AccountId,
+		delegates: Vec<T::AccountId>,
+		deposit: BalanceOf<T>,
+	) {
+		let proxies = delegates
+			.into_iter()
+			.map(|delegate| OldProxyDefinition {
+				delegate,
+				proxy_type: T::ProxyType::default(),
+				delay: Zero::zero(),
+			})
+			.collect::<Vec<_>>();
+		v0::Proxies::<T>::insert(delegator, (BoundedVec::truncate_from(proxies), deposit));
+	}
+
+	/// Migrates the proxies of all delegators to definitions without expiry nor allowance, within
+	/// a single block.
+	///
+	/// Only suitable for chains with few delegators; use [`LazyMigrationToV1`] otherwise.
+	pub struct VersionUncheckedMigrateToV1<T>(core::marker::PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateToV1<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame::try_runtime::TryRuntimeError> {
+			let count = Proxies::<T>::iter_keys().count() as u32;
+			Ok(count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			let mut count = 0u64;
+			Proxies::<T>::translate::<OldProxies<T>, _>(|_, old| {
+				count.saturating_inc();
+				Some(migrate_proxies::<T>(old))
+			});
+
+			// Reads and writes: Proxies
+			T::DbWeight::get().reads_writes(count, count)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame::try_runtime::TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).expect("encoded in pre_upgrade; qed");
+			ensure!(
+				Proxies::<T>::iter_keys().count() as u32 == count,
+				"the number of delegators changed"
+			);
+			Ok(())
+		}
+	}
+
+	/// [`VersionUncheckedMigrateToV1`] wrapped in a [`VersionedMigration`], which ensures that
+	/// the migration is only performed when the on-chain version is 0.
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		VersionUncheckedMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	/// Migrates the proxies of all delegators to definitions without expiry nor allowance, one
+	/// delegator at a time over as many blocks as needed.
+	pub struct LazyMigrationToV1<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> LazyMigrationToV1<T> {
+		/// Migrates the proxies of the delegator following `cursor`, if any is left, and returns
+		/// that delegator.
+		pub(crate) fn proxies_step(cursor: Option<&T::AccountId>) -> Option<T::AccountId> {
+			let mut iter = match cursor {
+				// The old and the new map hash their keys the same way, so the iteration can
+				// resume right after the last migrated delegator.
+				Some(last) => v0::Proxies::<T>::iter_from(v0::Proxies::<T>::hashed_key_for(last)),
+				None => v0::Proxies::<T>::iter(),
+			};
+			let (delegator, old) = iter.next()?;
+			Proxies::<T>::insert(&delegator, migrate_proxies::<T>(old));
+			Some(delegator)
+		}
+	}
+
+	impl<T: Config> SteppedMigration for LazyMigrationToV1<T> {
+		type Cursor = T::AccountId;
+		type Identifier = MigrationId<12>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+				return Ok(None)
+			}
+
+			// Worst case weight of a step, for a delegator with the maximum number of proxies.
+			let required = T::WeightInfo::migration_v1_step();
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required })
+			}
+
+			loop {
+				if meter.try_consume(required).is_err() {
+					break
+				}
+				match Self::proxies_step(cursor.as_ref()) {
+					Some(delegator) => cursor = Some(delegator),
+					None => {
+						StorageVersion::new(Self::id().version_to as u16).put::<Pallet<T>>();
+						return Ok(None)
+					},
+				}
+			}
+			Ok(cursor)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, frame::try_runtime::TryRuntimeError> {
+			let count = v0::Proxies::<T>::iter_keys().count() as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), frame::try_runtime::TryRuntimeError> {
+			let count = u32::decode(&mut &state[..]).expect("encoded in pre_upgrade; qed");
+			ensure!(
+				Proxies::<T>::iter().count() as u32 == count,
+				"the number of delegators changed"
+			);
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() >= 1,
+				"the storage version was not updated"
+			);
+			Ok(())
+		}
+	}
+}
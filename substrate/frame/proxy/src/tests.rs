@@ -111,6 +111,7 @@ impl Contains<RuntimeCall> for BaseFilter {
 parameter_types! {
 	pub static ProxyDepositBase: u64 = 1;
 	pub static ProxyDepositFactor: u64 = 1;
+	pub static LimitedProxyDepositFactor: u64 = 0;
 	pub static AnnouncementDepositBase: u64 = 1;
 	pub static AnnouncementDepositFactor: u64 = 1;
 }
@@ -122,6 +123,7 @@ impl Config for Test {
 	type ProxyType = ProxyType;
 	type ProxyDepositBase = ProxyDepositBase;
 	type ProxyDepositFactor = ProxyDepositFactor;
+	type LimitedProxyDepositFactor = LimitedProxyDepositFactor;
 	type MaxProxies = ConstU32<4>;
 	type WeightInfo = ();
 	type CallHasher = BlakeTwo256;
@@ -836,3 +838,323 @@ fn poke_deposit_fails_for_unsigned_origin() {
 		assert_noop!(Proxy::poke_deposit(RuntimeOrigin::none()), DispatchError::BadOrigin,);
 	});
 }
+
+#[test]
+fn expired_proxy_cannot_be_used() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Proxy::add_limited_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0, Some(1), None),
+			Error::<Test>::Expired
+		);
+		assert_ok!(Proxy::add_limited_proxy(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(3),
+			None
+		));
+
+		let call = Box::new(call_transfer(6, 1));
+		System::set_block_number(2);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call.clone()));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 1);
+
+		System::set_block_number(3);
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call.clone()),
+			Error::<Test>::NotProxy
+		);
+		assert_noop!(
+			Proxy::announce(RuntimeOrigin::signed(2), 1, BlakeTwo256::hash_of(&call)),
+			Error::<Test>::NotProxy
+		);
+	});
+}
+
+#[test]
+fn remove_expired_proxies_refunds_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_limited_proxy(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(3),
+			None
+		));
+		assert_ok!(Proxy::add_limited_proxy(
+			RuntimeOrigin::signed(1),
+			3,
+			ProxyType::JustTransfer,
+			0,
+			Some(5),
+			None
+		));
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_noop!(
+			Proxy::remove_expired_proxies(RuntimeOrigin::signed(4), 1),
+			Error::<Test>::NotFound
+		);
+
+		System::set_block_number(3);
+		let result = Proxy::remove_expired_proxies(RuntimeOrigin::signed(4), 1);
+		assert_eq!(result.unwrap().pays_fee, Pays::No);
+		System::assert_has_event(
+			ProxyEvent::ProxyExpired {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 2);
+		assert_eq!(Proxies::<Test>::get(1).0.len(), 1);
+
+		System::set_block_number(5);
+		assert_ok!(Proxy::remove_expired_proxies(RuntimeOrigin::signed(4), 1));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!Proxies::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn expired_proxies_are_removed_lazily() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_limited_proxy(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(3),
+			None
+		));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		System::set_block_number(3);
+		// The expired proxy can be added again, replacing the previous one.
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		System::assert_has_event(
+			ProxyEvent::ProxyExpired {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert!(Proxies::<Test>::get(1).0.iter().all(|x| x.expiry.is_none()));
+	});
+}
+
+#[test]
+fn allowance_is_enforced_and_renewed() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 1000);
+		assert_ok!(Proxy::add_limited_proxy(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			None,
+			Some((5, 10))
+		));
+
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 3))));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 3);
+
+		// The transfer is reverted as it would exceed the allowance.
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 3))));
+		let de = DispatchError::from(Error::<Test>::AllowanceExceeded).stripped();
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Err(de) }.into());
+		assert_eq!(Balances::free_balance(6), 3);
+
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 2))));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 5);
+		let allowance = Proxies::<Test>::get(1).0[0].allowance.unwrap();
+		assert_eq!((allowance.spent, allowance.period_start), (5, 1));
+
+		// The allowance is renewed in the next period.
+		System::set_block_number(12);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 4))));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 9);
+		let allowance = Proxies::<Test>::get(1).0[0].allowance.unwrap();
+		assert_eq!((allowance.spent, allowance.period_start), (4, 11));
+	});
+}
+
+#[test]
+fn limited_proxy_cannot_add_proxies() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_limited_proxy(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(10),
+			None
+		));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(3), 1, ProxyType::Any, 0));
+
+		let call = Box::new(RuntimeCall::Proxy(ProxyCall::new_call_variant_add_proxy(
+			2,
+			ProxyType::Any,
+			1,
+		)));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+
+		// Nor act through the proxies of the delegator.
+		let call = Box::new(RuntimeCall::Proxy(ProxyCall::new_call_variant_proxy(
+			3,
+			None,
+			Box::new(call_transfer(6, 1)),
+		)));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+		assert_eq!(Balances::free_balance(6), 0);
+	});
+}
+
+#[test]
+fn only_limited_proxies_pay_the_limited_deposit_factor() {
+	new_test_ext().execute_with(|| {
+		LimitedProxyDepositFactor::set(2);
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 2);
+		assert_ok!(Proxy::add_limited_proxy(
+			RuntimeOrigin::signed(1),
+			3,
+			ProxyType::Any,
+			0,
+			Some(10),
+			None
+		));
+		assert_eq!(Balances::reserved_balance(1), 5);
+
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 2);
+	});
+}
+
+#[test]
+fn removing_a_proxy_never_reserves_more() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		// The remaining proxy would now require a deposit of 5.
+		ProxyDepositFactor::set(4);
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_eq!(Proxies::<Test>::get(1).1, 3);
+
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn lazy_migration_to_v1_decodes_old_proxies() {
+	use frame::deps::frame_support::{migrations::SteppedMigration, weights::WeightMeter};
+	use migrations::v1::{insert_old_proxies, LazyMigrationToV1};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Proxy>();
+		insert_old_proxies::<Test>(&1, vec![2, 3], 3);
+		insert_old_proxies::<Test>(&2, vec![3], 2);
+		insert_old_proxies::<Test>(&3, vec![1, 2, 4, 5], 5);
+
+		// Each step has room for a single delegator.
+		let required = <() as WeightInfo>::migration_v1_step();
+		let mut cursor = None;
+		let mut steps = 0;
+		loop {
+			let mut meter = WeightMeter::with_limit(required);
+			cursor = LazyMigrationToV1::<Test>::step(cursor, &mut meter).unwrap();
+			steps += 1;
+			if cursor.is_none() {
+				break
+			}
+		}
+		assert_eq!(steps, 4);
+		assert_eq!(Proxy::on_chain_storage_version(), 1);
+
+		for (delegator, delegates, deposit) in
+			[(1, vec![2, 3], 3), (2, vec![3], 2), (3, vec![1, 2, 4, 5], 5)]
+		{
+			let (proxies, stored_deposit) = Proxies::<Test>::get(delegator);
+			assert_eq!(stored_deposit, deposit);
+			assert_eq!(
+				proxies.into_inner(),
+				delegates
+					.into_iter()
+					.map(|delegate| ProxyDefinition {
+						delegate,
+						proxy_type: ProxyType::Any,
+						delay: 0,
+						expiry: None,
+						allowance: None,
+					})
+					.collect::<Vec<_>>()
+			);
+		}
+
+		// The migration is a no-op once the storage version is updated.
+		assert!(matches!(
+			LazyMigrationToV1::<Test>::step(None, &mut WeightMeter::with_limit(required)),
+			Ok(None)
+		));
+	});
+}
+
+#[test]
+fn lazy_migration_to_v1_requires_weight_for_a_step() {
+	use frame::deps::frame_support::{
+		migrations::{SteppedMigration, SteppedMigrationError},
+		weights::WeightMeter,
+	};
+	use migrations::v1::{insert_old_proxies, LazyMigrationToV1};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Proxy>();
+		insert_old_proxies::<Test>(&1, vec![2, 3], 3);
+
+		let required = <() as WeightInfo>::migration_v1_step();
+		assert!(matches!(
+			LazyMigrationToV1::<Test>::step(None, &mut WeightMeter::with_limit(Weight::zero())),
+			Err(SteppedMigrationError::InsufficientWeight { required: r }) if r == required
+		));
+		assert_eq!(Proxy::on_chain_storage_version(), 0);
+	});
+}
+
+#[test]
+fn migration_to_v1_decodes_old_proxies() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Proxy>();
+		migrations::v1::insert_old_proxies::<Test>(&1, vec![2, 3], 3);
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Proxy::on_chain_storage_version(), 1);
+		let (proxies, deposit) = Proxies::<Test>::get(1);
+		assert_eq!(deposit, 3);
+		assert_eq!(
+			proxies.iter().map(|p| (p.delegate, p.expiry, p.allowance.clone())).collect::<Vec<_>>(),
+			vec![(2, None, None), (3, None, None)]
+		);
+	});
+}
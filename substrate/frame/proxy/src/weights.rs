@@ -35,9 +35,9 @@
 //! Autogenerated weights for `pallet_proxy`
 //!
 //! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-03-04, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! DATE: 2026-10-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `99fc4dfa9c86`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! HOSTNAME: `vm`, CPU: `Intel(R) Xeon(R) Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
 
 // Executed Command:
@@ -48,8 +48,8 @@
 // --extrinsic=*
 // --runtime=target/production/wbuild/kitchensink-runtime/kitchensink_runtime.wasm
 // --pallet=pallet_proxy
-// --header=/__w/polkadot-sdk/polkadot-sdk/substrate/HEADER-APACHE2
-// --output=/__w/polkadot-sdk/polkadot-sdk/substrate/frame/proxy/src/weights.rs
+// --header=substrate/HEADER-APACHE2
+// --output=substrate/frame/proxy/src/weights.rs
 // --wasm-execution=compiled
 // --steps=50
 // --repeat=20
@@ -76,18 +76,20 @@ pub trait WeightInfo {
 	fn reject_announcement(a: u32, p: u32, ) -> Weight;
 	fn announce(a: u32, p: u32, ) -> Weight;
 	fn add_proxy(p: u32, ) -> Weight;
+	fn add_limited_proxy(p: u32, ) -> Weight;
 	fn remove_proxy(p: u32, ) -> Weight;
 	fn remove_proxies(p: u32, ) -> Weight;
 	fn create_pure(p: u32, ) -> Weight;
 	fn kill_pure(p: u32, ) -> Weight;
 	fn poke_deposit() -> Weight;
+	fn migration_v1_step() -> Weight;
 }
 
 /// Weights for `pallet_proxy` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `SafeMode::EnteredUntil` (r:1 w:0)
 	/// Proof: `SafeMode::EnteredUntil` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
@@ -95,16 +97,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `p` is `[1, 31]`.
 	fn proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `339 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 23_353_000 picoseconds.
-		Weight::from_parts(25_084_085, 4706)
-			// Standard Error: 2_569
-			.saturating_add(Weight::from_parts(33_574, 0).saturating_mul(p.into()))
+		//  Measured:  `339 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 59_768_000 picoseconds.
+		Weight::from_parts(67_098_867, 6178)
+			// Standard Error: 49_658
+			.saturating_add(Weight::from_parts(516_493, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -117,14 +119,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `p` is `[1, 31]`.
 	fn proxy_announced(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `666 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
-		// Minimum execution time: 47_196_000 picoseconds.
-		Weight::from_parts(48_686_812, 5698)
-			// Standard Error: 3_711
-			.saturating_add(Weight::from_parts(171_107, 0).saturating_mul(a.into()))
-			// Standard Error: 3_834
-			.saturating_add(Weight::from_parts(34_523, 0).saturating_mul(p.into()))
+		//  Measured:  `666 + a * (68 ±0) + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 100_193_000 picoseconds.
+		Weight::from_parts(153_195_496, 6178)
+			// Standard Error: 70_467
+			.saturating_add(Weight::from_parts(1_455_655, 0).saturating_mul(a.into()))
+			// Standard Error: 72_807
+			.saturating_add(Weight::from_parts(291_561, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -138,12 +140,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `436 + a * (68 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 29_341_000 picoseconds.
-		Weight::from_parts(30_320_504, 5698)
-			// Standard Error: 1_821
-			.saturating_add(Weight::from_parts(158_572, 0).saturating_mul(a.into()))
-			// Standard Error: 1_881
-			.saturating_add(Weight::from_parts(8_433, 0).saturating_mul(p.into()))
+		// Minimum execution time: 55_238_000 picoseconds.
+		Weight::from_parts(88_449_571, 5698)
+			// Standard Error: 54_640
+			.saturating_add(Weight::from_parts(362_781, 0).saturating_mul(a.into()))
+			// Standard Error: 56_454
+			.saturating_add(Weight::from_parts(584_629, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
@@ -153,21 +155,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `a` is `[0, 31]`.
 	/// The range of component `p` is `[1, 31]`.
-	fn reject_announcement(a: u32, p: u32, ) -> Weight {
+	fn reject_announcement(_a: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `436 + a * (68 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 28_422_000 picoseconds.
-		Weight::from_parts(29_754_384, 5698)
-			// Standard Error: 1_840
-			.saturating_add(Weight::from_parts(176_827, 0).saturating_mul(a.into()))
-			// Standard Error: 1_901
-			.saturating_add(Weight::from_parts(9_607, 0).saturating_mul(p.into()))
+		// Minimum execution time: 56_182_000 picoseconds.
+		Weight::from_parts(115_658_840, 5698)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -176,108 +174,131 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// The range of component `p` is `[1, 31]`.
 	fn announce(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `453 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
-		// Minimum execution time: 36_885_000 picoseconds.
-		Weight::from_parts(38_080_636, 5698)
-			// Standard Error: 2_642
-			.saturating_add(Weight::from_parts(157_335, 0).saturating_mul(a.into()))
-			// Standard Error: 2_730
-			.saturating_add(Weight::from_parts(28_872, 0).saturating_mul(p.into()))
+		//  Measured:  `453 + a * (68 ±0) + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 73_155_000 picoseconds.
+		Weight::from_parts(49_970_083, 6178)
+			// Standard Error: 58_085
+			.saturating_add(Weight::from_parts(1_734_739, 0).saturating_mul(a.into()))
+			// Standard Error: 60_014
+			.saturating_add(Weight::from_parts(1_440_429, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn add_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 27_016_000 picoseconds.
-		Weight::from_parts(28_296_216, 4706)
-			// Standard Error: 1_643
-			.saturating_add(Weight::from_parts(50_271, 0).saturating_mul(p.into()))
+		//  Measured:  `194 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 57_633_000 picoseconds.
+		Weight::from_parts(85_894_005, 6178)
+			// Standard Error: 34_585
+			.saturating_add(Weight::from_parts(350_817, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 31]`.
+	fn add_limited_proxy(_p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `194 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 50_795_000 picoseconds.
+		Weight::from_parts(91_424_765, 6178)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 26_955_000 picoseconds.
-		Weight::from_parts(28_379_566, 4706)
-			// Standard Error: 1_547
-			.saturating_add(Weight::from_parts(45_784, 0).saturating_mul(p.into()))
+		//  Measured:  `194 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 51_341_000 picoseconds.
+		Weight::from_parts(84_445_950, 6178)
+			// Standard Error: 58_905
+			.saturating_add(Weight::from_parts(643_164, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 24_656_000 picoseconds.
-		Weight::from_parts(25_821_878, 4706)
-			// Standard Error: 2_300
-			.saturating_add(Weight::from_parts(33_972, 0).saturating_mul(p.into()))
+		//  Measured:  `194 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 61_883_000 picoseconds.
+		Weight::from_parts(78_205_956, 6178)
+			// Standard Error: 27_313
+			.saturating_add(Weight::from_parts(460_779, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn create_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `206`
-		//  Estimated: `4706`
-		// Minimum execution time: 28_416_000 picoseconds.
-		Weight::from_parts(29_662_728, 4706)
-			// Standard Error: 1_851
-			.saturating_add(Weight::from_parts(29_928, 0).saturating_mul(p.into()))
+		//  Estimated: `6178`
+		// Minimum execution time: 73_772_000 picoseconds.
+		Weight::from_parts(90_831_494, 6178)
+			// Standard Error: 30_703
+			.saturating_add(Weight::from_parts(356_539, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `231 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 25_505_000 picoseconds.
-		Weight::from_parts(26_780_627, 4706)
-			// Standard Error: 1_581
-			.saturating_add(Weight::from_parts(33_085, 0).saturating_mul(p.into()))
+		//  Measured:  `233 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 70_992_000 picoseconds.
+		Weight::from_parts(87_693_925, 6178)
+			// Standard Error: 35_070
+			.saturating_add(Weight::from_parts(621_915, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	fn poke_deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `519`
-		//  Estimated: `5698`
-		// Minimum execution time: 46_733_000 picoseconds.
-		Weight::from_parts(47_972_000, 5698)
+		//  Measured:  `521`
+		//  Estimated: `6178`
+		// Minimum execution time: 131_636_000 picoseconds.
+		Weight::from_parts(144_124_000, 6178)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Proxy::Proxies` (r:2 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1378`
+		//  Estimated: `11366`
+		// Minimum execution time: 33_335_000 picoseconds.
+		Weight::from_parts(36_036_000, 11366)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `SafeMode::EnteredUntil` (r:1 w:0)
 	/// Proof: `SafeMode::EnteredUntil` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
@@ -285,16 +306,16 @@ impl WeightInfo for () {
 	/// The range of component `p` is `[1, 31]`.
 	fn proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `339 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 23_353_000 picoseconds.
-		Weight::from_parts(25_084_085, 4706)
-			// Standard Error: 2_569
-			.saturating_add(Weight::from_parts(33_574, 0).saturating_mul(p.into()))
+		//  Measured:  `339 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 59_768_000 picoseconds.
+		Weight::from_parts(67_098_867, 6178)
+			// Standard Error: 49_658
+			.saturating_add(Weight::from_parts(516_493, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -307,14 +328,14 @@ impl WeightInfo for () {
 	/// The range of component `p` is `[1, 31]`.
 	fn proxy_announced(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `666 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
-		// Minimum execution time: 47_196_000 picoseconds.
-		Weight::from_parts(48_686_812, 5698)
-			// Standard Error: 3_711
-			.saturating_add(Weight::from_parts(171_107, 0).saturating_mul(a.into()))
-			// Standard Error: 3_834
-			.saturating_add(Weight::from_parts(34_523, 0).saturating_mul(p.into()))
+		//  Measured:  `666 + a * (68 ±0) + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 100_193_000 picoseconds.
+		Weight::from_parts(153_195_496, 6178)
+			// Standard Error: 70_467
+			.saturating_add(Weight::from_parts(1_455_655, 0).saturating_mul(a.into()))
+			// Standard Error: 72_807
+			.saturating_add(Weight::from_parts(291_561, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -328,12 +349,12 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `436 + a * (68 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 29_341_000 picoseconds.
-		Weight::from_parts(30_320_504, 5698)
-			// Standard Error: 1_821
-			.saturating_add(Weight::from_parts(158_572, 0).saturating_mul(a.into()))
-			// Standard Error: 1_881
-			.saturating_add(Weight::from_parts(8_433, 0).saturating_mul(p.into()))
+		// Minimum execution time: 55_238_000 picoseconds.
+		Weight::from_parts(88_449_571, 5698)
+			// Standard Error: 54_640
+			.saturating_add(Weight::from_parts(362_781, 0).saturating_mul(a.into()))
+			// Standard Error: 56_454
+			.saturating_add(Weight::from_parts(584_629, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
@@ -343,21 +364,17 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `a` is `[0, 31]`.
 	/// The range of component `p` is `[1, 31]`.
-	fn reject_announcement(a: u32, p: u32, ) -> Weight {
+	fn reject_announcement(_a: u32, _p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `436 + a * (68 ±0)`
 		//  Estimated: `5698`
-		// Minimum execution time: 28_422_000 picoseconds.
-		Weight::from_parts(29_754_384, 5698)
-			// Standard Error: 1_840
-			.saturating_add(Weight::from_parts(176_827, 0).saturating_mul(a.into()))
-			// Standard Error: 1_901
-			.saturating_add(Weight::from_parts(9_607, 0).saturating_mul(p.into()))
+		// Minimum execution time: 56_182_000 picoseconds.
+		Weight::from_parts(115_658_840, 5698)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -366,100 +383,123 @@ impl WeightInfo for () {
 	/// The range of component `p` is `[1, 31]`.
 	fn announce(a: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `453 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
-		// Minimum execution time: 36_885_000 picoseconds.
-		Weight::from_parts(38_080_636, 5698)
-			// Standard Error: 2_642
-			.saturating_add(Weight::from_parts(157_335, 0).saturating_mul(a.into()))
-			// Standard Error: 2_730
-			.saturating_add(Weight::from_parts(28_872, 0).saturating_mul(p.into()))
+		//  Measured:  `453 + a * (68 ±0) + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 73_155_000 picoseconds.
+		Weight::from_parts(49_970_083, 6178)
+			// Standard Error: 58_085
+			.saturating_add(Weight::from_parts(1_734_739, 0).saturating_mul(a.into()))
+			// Standard Error: 60_014
+			.saturating_add(Weight::from_parts(1_440_429, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn add_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 27_016_000 picoseconds.
-		Weight::from_parts(28_296_216, 4706)
-			// Standard Error: 1_643
-			.saturating_add(Weight::from_parts(50_271, 0).saturating_mul(p.into()))
+		//  Measured:  `194 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 57_633_000 picoseconds.
+		Weight::from_parts(85_894_005, 6178)
+			// Standard Error: 34_585
+			.saturating_add(Weight::from_parts(350_817, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 31]`.
+	fn add_limited_proxy(_p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `194 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 50_795_000 picoseconds.
+		Weight::from_parts(91_424_765, 6178)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxy(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 26_955_000 picoseconds.
-		Weight::from_parts(28_379_566, 4706)
-			// Standard Error: 1_547
-			.saturating_add(Weight::from_parts(45_784, 0).saturating_mul(p.into()))
+		//  Measured:  `194 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 51_341_000 picoseconds.
+		Weight::from_parts(84_445_950, 6178)
+			// Standard Error: 58_905
+			.saturating_add(Weight::from_parts(643_164, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 24_656_000 picoseconds.
-		Weight::from_parts(25_821_878, 4706)
-			// Standard Error: 2_300
-			.saturating_add(Weight::from_parts(33_972, 0).saturating_mul(p.into()))
+		//  Measured:  `194 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 61_883_000 picoseconds.
+		Weight::from_parts(78_205_956, 6178)
+			// Standard Error: 27_313
+			.saturating_add(Weight::from_parts(460_779, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn create_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `206`
-		//  Estimated: `4706`
-		// Minimum execution time: 28_416_000 picoseconds.
-		Weight::from_parts(29_662_728, 4706)
-			// Standard Error: 1_851
-			.saturating_add(Weight::from_parts(29_928, 0).saturating_mul(p.into()))
+		//  Estimated: `6178`
+		// Minimum execution time: 73_772_000 picoseconds.
+		Weight::from_parts(90_831_494, 6178)
+			// Standard Error: 30_703
+			.saturating_add(Weight::from_parts(356_539, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `231 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 25_505_000 picoseconds.
-		Weight::from_parts(26_780_627, 4706)
-			// Standard Error: 1_581
-			.saturating_add(Weight::from_parts(33_085, 0).saturating_mul(p.into()))
+		//  Measured:  `233 + p * (39 ±0)`
+		//  Estimated: `6178`
+		// Minimum execution time: 70_992_000 picoseconds.
+		Weight::from_parts(87_693_925, 6178)
+			// Standard Error: 35_070
+			.saturating_add(Weight::from_parts(621_915, 0).saturating_mul(p.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
-	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	fn poke_deposit() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `519`
-		//  Estimated: `5698`
-		// Minimum execution time: 46_733_000 picoseconds.
-		Weight::from_parts(47_972_000, 5698)
+		//  Measured:  `521`
+		//  Estimated: `6178`
+		// Minimum execution time: 131_636_000 picoseconds.
+		Weight::from_parts(144_124_000, 6178)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Proxy::Proxies` (r:2 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(2713), added: 5188, mode: `MaxEncodedLen`)
+	fn migration_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1378`
+		//  Estimated: `11366`
+		// Minimum execution time: 33_335_000 picoseconds.
+		Weight::from_parts(36_036_000, 11366)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,41 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Stores the call input, padded to a word, under a fixed-size key as Solidity contracts do.
+#![no_std]
+#![no_main]
+include!("../panic_handler.rs");
+
+use uapi::{input, HostFn, HostFnImpl as api, StorageFlags};
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn deploy() {}
+
+#[no_mangle]
+#[polkavm_derive::polkavm_export]
+pub extern "C" fn call() {
+	input!(data: [u8; 4], );
+
+	let mut value = [0u8; 32];
+	value[..4].copy_from_slice(data);
+
+	let mut key = [0u8; 32];
+	key[0] = 1;
+
+	api::set_storage_or_clear(StorageFlags::empty(), &key, &value);
+}
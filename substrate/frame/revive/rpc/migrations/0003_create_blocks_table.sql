@@ -0,0 +1,50 @@
+-- The indexed blocks, used to prune old blocks and to skip the blocks that can't match a log
+-- filter.
+--
+-- `logs_bloom` is the bloom filter of all the logs of the block, or `NULL` when unknown.
+CREATE TABLE IF NOT EXISTS blocks (
+	block_hash BLOB NOT NULL PRIMARY KEY,
+	block_number INTEGER NOT NULL,
+	logs_bloom BLOB
+);
+
+CREATE INDEX IF NOT EXISTS idx_blocks_block_number ON blocks (
+	block_number
+);
+
+-- Backfill the blocks indexed before this migration.
+-- Their logs bloom is computed when they are re-indexed with the `reindex` command.
+INSERT OR IGNORE INTO blocks (block_hash, block_number)
+SELECT DISTINCT block_hash, block_number FROM logs;
+
+-- Indexes for the log queries filtering on an address or a topic over a range of blocks.
+CREATE INDEX IF NOT EXISTS idx_logs_address_block_number ON logs (
+	address,
+	block_number
+);
+
+CREATE INDEX IF NOT EXISTS idx_logs_topic_0_block_number ON logs (
+	topic_0,
+	block_number
+);
+
+CREATE INDEX IF NOT EXISTS idx_logs_topic_1_block_number ON logs (
+	topic_1,
+	block_number
+);
+
+CREATE INDEX IF NOT EXISTS idx_logs_topic_2_block_number ON logs (
+	topic_2,
+	block_number
+);
+
+CREATE INDEX IF NOT EXISTS idx_logs_topic_3_block_number ON logs (
+	topic_3,
+	block_number
+);
+
+-- The `idx_block_hash` index of the logs table was never created, as the name is already used by
+-- the index of the `transaction_hashes` table.
+CREATE INDEX IF NOT EXISTS idx_logs_block_hash ON logs (
+	block_hash
+);
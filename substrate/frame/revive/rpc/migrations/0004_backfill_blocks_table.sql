@@ -0,0 +1,11 @@
+-- The blocks with transactions but no logs, indexed before the `blocks` table was created.
+--
+-- Their number is not stored in the database, so they are backfilled into the `blocks` table with
+-- the number fetched from the node when the database is opened, and removed from this table.
+CREATE TABLE IF NOT EXISTS blocks_backfill (
+	block_hash BLOB NOT NULL PRIMARY KEY
+);
+
+INSERT OR IGNORE INTO blocks_backfill (block_hash)
+SELECT DISTINCT block_hash FROM transaction_hashes
+WHERE block_hash NOT IN (SELECT block_hash FROM blocks);
@@ -18,6 +18,9 @@ use crate::*;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 
 /// Debug Ethererum JSON-RPC apis.
+///
+/// The `callTracer` and `prestateTracer` (including its `diffMode`) tracers are supported, as
+/// well as the default struct logger, used when no `tracer` is specified.
 #[rpc(server, client)]
 pub trait DebugRpc {
 	/// Returns the tracing of the execution of a specific block using its number.
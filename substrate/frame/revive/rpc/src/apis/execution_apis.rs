@@ -32,24 +32,41 @@ pub trait EthRpc {
 	async fn block_number(&self) -> RpcResult<U256>;
 
 	/// Executes a new message call immediately without creating a transaction on the block chain.
+	///
+	/// The call is executed with the given state and block overrides, if any.
 	#[method(name = "eth_call")]
 	async fn call(
 		&self,
 		transaction: GenericTransaction,
 		block: Option<BlockNumberOrTagOrHash>,
+		state_overrides: Option<StateOverrideSet>,
+		block_overrides: Option<BlockOverrides>,
 	) -> RpcResult<Bytes>;
 
 	/// Returns the chain ID of the current network.
 	#[method(name = "eth_chainId")]
 	async fn chain_id(&self) -> RpcResult<U256>;
 
+	/// Generates an access list for a transaction, along with the gas it uses with that access
+	/// list.
+	#[method(name = "eth_createAccessList")]
+	async fn create_access_list(
+		&self,
+		transaction: GenericTransaction,
+		block: Option<BlockNumberOrTag>,
+	) -> RpcResult<AccessListResult>;
+
 	/// Generates and returns an estimate of how much gas is necessary to allow the transaction to
 	/// complete.
+	///
+	/// The transaction is executed with the given state and block overrides, if any.
 	#[method(name = "eth_estimateGas")]
 	async fn estimate_gas(
 		&self,
 		transaction: GenericTransaction,
 		block: Option<BlockNumberOrTag>,
+		state_overrides: Option<StateOverrideSet>,
+		block_overrides: Option<BlockOverrides>,
 	) -> RpcResult<U256>;
 
 	/// Returns the current price per gas in wei.
@@ -99,6 +116,27 @@ pub trait EthRpc {
 	#[method(name = "eth_getLogs")]
 	async fn get_logs(&self, filter: Option<Filter>) -> RpcResult<FilterResults>;
 
+	/// Polling method for a filter, which returns an array of logs or hashes which occurred since
+	/// last poll.
+	#[method(name = "eth_getFilterChanges")]
+	async fn get_filter_changes(&self, filter_id: U256) -> RpcResult<FilterResults>;
+
+	/// Returns an array of all logs matching filter with given id.
+	#[method(name = "eth_getFilterLogs")]
+	async fn get_filter_logs(&self, filter_id: U256) -> RpcResult<FilterResults>;
+
+	/// Returns the merkle proof for a given account and optionally some storage keys.
+	///
+	/// The proofs are substrate trie proofs, see [`pallet_revive::proof`] for how to verify
+	/// them.
+	#[method(name = "eth_getProof")]
+	async fn get_proof(
+		&self,
+		address: Address,
+		storage_keys: Vec<U256>,
+		block: BlockNumberOrTagOrHash,
+	) -> RpcResult<AccountProof>;
+
 	/// Returns the value from a storage position at a given address.
 	#[method(name = "eth_getStorageAt")]
 	async fn get_storage_at(
@@ -150,6 +188,18 @@ pub trait EthRpc {
 	#[method(name = "eth_maxPriorityFeePerGas")]
 	async fn max_priority_fee_per_gas(&self) -> RpcResult<U256>;
 
+	/// Creates a filter in the node, to notify when a new block arrives.
+	#[method(name = "eth_newBlockFilter")]
+	async fn new_block_filter(&self) -> RpcResult<U256>;
+
+	/// Creates a filter object, based on filter options, to notify when the state changes (logs).
+	#[method(name = "eth_newFilter")]
+	async fn new_filter(&self, filter: Filter) -> RpcResult<U256>;
+
+	/// Creates a filter in the node, to notify when new pending transactions arrive.
+	#[method(name = "eth_newPendingTransactionFilter")]
+	async fn new_pending_transaction_filter(&self) -> RpcResult<U256>;
+
 	/// Submits a raw transaction. For EIP-4844 transactions, the raw form must be the network form.
 	/// This means it includes the blobs, KZG commitments, and KZG proofs.
 	#[method(name = "eth_sendRawTransaction")]
@@ -163,6 +213,10 @@ pub trait EthRpc {
 	#[method(name = "eth_syncing")]
 	async fn syncing(&self) -> RpcResult<SyncingStatus>;
 
+	/// Uninstalls a filter with given id.
+	#[method(name = "eth_uninstallFilter")]
+	async fn uninstall_filter(&self, filter_id: U256) -> RpcResult<bool>;
+
 	/// Returns true when the client is actively listening for network connections, otherwise false
 	#[method(name = "net_listening")]
 	async fn net_listening(&self) -> RpcResult<bool>;
@@ -0,0 +1,220 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Ethereum publish/subscribe JSON-RPC methods.
+
+use crate::{client::ChainEvent, *};
+use jsonrpsee::{
+	core::SubscriptionResult, proc_macros::rpc, PendingSubscriptionSink, SubscriptionMessage,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::error::RecvError;
+
+/// The kind of events a subscription is notified of.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SubscriptionKind {
+	/// A new block header each time a block is added to the chain, including during reorgs.
+	NewHeads,
+	/// The logs included in new blocks that match the filter criteria. Logs of blocks retracted
+	/// by a reorg are sent again with `removed` set to `true`.
+	Logs,
+	/// The hash of the transactions submitted through this server.
+	NewPendingTransactions,
+}
+
+/// An item sent to a subscriber.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum SubscriptionItem {
+	/// The hash of a pending transaction.
+	TransactionHash(H256),
+	/// A log matching the subscription filter.
+	Log(Log),
+	/// A new block header.
+	Header(Block),
+}
+
+/// Ethereum publish/subscribe JSON-RPC apis.
+///
+/// ## References
+///
+/// - <https://geth.ethereum.org/docs/interacting-with-geth/rpc/pubsub>
+#[rpc(server, client)]
+pub trait EthPubSubRpc {
+	/// Subscribe to the given kind of events.
+	///
+	/// The filter is only used by the `logs` subscriptions, where only its `address` and
+	/// `topics` criteria are considered.
+	#[subscription(
+		name = "eth_subscribe" => "eth_subscription",
+		unsubscribe = "eth_unsubscribe",
+		item = SubscriptionItem
+	)]
+	async fn subscribe(&self, kind: SubscriptionKind, filter: Option<Filter>)
+		-> SubscriptionResult;
+}
+
+pub struct EthPubSubRpcServerImpl {
+	client: client::Client,
+}
+
+impl EthPubSubRpcServerImpl {
+	pub fn new(client: client::Client) -> Self {
+		Self { client }
+	}
+}
+
+/// Returns `true` if the log matches the `address` and `topics` criteria of the filter.
+fn matches_filter(filter: &Filter, log: &Log) -> bool {
+	let address_matches = match &filter.address {
+		None => true,
+		Some(AddressOrAddresses::Address(address)) => *address == log.address,
+		Some(AddressOrAddresses::Addresses(addresses)) =>
+			addresses.is_empty() || addresses.contains(&log.address),
+	};
+
+	let topics_match = filter.topics.iter().flatten().enumerate().all(|(i, topic)| {
+		match (topic, log.topics.get(i)) {
+			(_, None) => false,
+			(FilterTopic::Single(expected), Some(topic)) => expected == topic,
+			(FilterTopic::Multiple(expected), Some(topic)) =>
+				expected.is_empty() || expected.contains(topic),
+		}
+	});
+
+	address_matches && topics_match
+}
+
+/// Returns the items to send to a subscriber of the given kind, for the given event.
+fn subscription_items(
+	kind: SubscriptionKind,
+	filter: &Filter,
+	event: ChainEvent,
+) -> Vec<SubscriptionItem> {
+	match (kind, event) {
+		(SubscriptionKind::NewHeads, ChainEvent::NewBlock { block, .. }) => {
+			let header = Block { transactions: Default::default(), ..(*block).clone() };
+			vec![SubscriptionItem::Header(header)]
+		},
+		(SubscriptionKind::Logs, ChainEvent::NewBlock { logs, .. }) => logs
+			.iter()
+			.filter(|log| matches_filter(filter, log))
+			.cloned()
+			.map(SubscriptionItem::Log)
+			.collect(),
+		(SubscriptionKind::NewPendingTransactions, ChainEvent::NewPendingTransaction(hash)) =>
+			vec![SubscriptionItem::TransactionHash(hash)],
+		_ => Vec::new(),
+	}
+}
+
+#[async_trait]
+impl EthPubSubRpcServer for EthPubSubRpcServerImpl {
+	async fn subscribe(
+		&self,
+		pending: PendingSubscriptionSink,
+		kind: SubscriptionKind,
+		filter: Option<Filter>,
+	) -> SubscriptionResult {
+		let filter = filter.unwrap_or_default();
+		if filter.topics.as_ref().is_some_and(|topics| topics.len() > 4) {
+			pending
+				.reject(EthRpcError::InvalidFilter("exceed max topics".to_string()))
+				.await;
+			return Ok(());
+		}
+
+		let mut events = self.client.subscribe_chain_events();
+		let sink = pending.accept().await?;
+
+		loop {
+			let event = tokio::select! {
+				_ = sink.closed() => break,
+				event = events.recv() => event,
+			};
+
+			let event = match event {
+				Ok(event) => event,
+				Err(RecvError::Lagged(skipped)) => {
+					log::warn!(
+						target: LOG_TARGET,
+						"Subscription {:?} lagged behind, skipped {skipped} event(s)",
+						sink.subscription_id()
+					);
+					continue;
+				},
+				Err(RecvError::Closed) => break,
+			};
+
+			for item in subscription_items(kind, &filter, event) {
+				let message = SubscriptionMessage::from_json(&item)?;
+				if sink.send(message).await.is_err() {
+					return Ok(());
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn log(address: H160, topics: Vec<H256>) -> Log {
+		Log { address, topics, ..Default::default() }
+	}
+
+	#[test]
+	fn matches_filter_works() {
+		let (a, b) = (H160::repeat_byte(1), H160::repeat_byte(2));
+		let (t1, t2) = (H256::repeat_byte(1), H256::repeat_byte(2));
+
+		let filter = Filter::default();
+		assert!(matches_filter(&filter, &log(a, vec![])));
+
+		let filter = Filter { address: Some(a.into()), ..Default::default() };
+		assert!(matches_filter(&filter, &log(a, vec![t1])));
+		assert!(!matches_filter(&filter, &log(b, vec![t1])));
+
+		let filter = Filter { address: Some(vec![a, b].into()), ..Default::default() };
+		assert!(matches_filter(&filter, &log(b, vec![])));
+
+		let filter = Filter {
+			topics: Some(vec![FilterTopic::Multiple(vec![]), FilterTopic::Single(t2)]),
+			..Default::default()
+		};
+		assert!(matches_filter(&filter, &log(a, vec![t1, t2])));
+		assert!(!matches_filter(&filter, &log(a, vec![t2, t1])));
+		assert!(!matches_filter(&filter, &log(a, vec![t1])));
+
+		let filter = Filter {
+			topics: Some(vec![FilterTopic::Multiple(vec![t1, t2])]),
+			..Default::default()
+		};
+		assert!(matches_filter(&filter, &log(a, vec![t2])));
+	}
+
+	#[test]
+	fn subscription_kind_serialization_works() {
+		let kind: SubscriptionKind = serde_json::from_str(r#""newPendingTransactions""#).unwrap();
+		assert_eq!(kind, SubscriptionKind::NewPendingTransactions);
+		let kind: SubscriptionKind = serde_json::from_str(r#""newHeads""#).unwrap();
+		assert_eq!(kind, SubscriptionKind::NewHeads);
+	}
+}
@@ -17,9 +17,9 @@
 //! The Ethereum JSON-RPC server.
 use crate::{
 	client::{connect, Client, SubscriptionType, SubstrateBlockNumber},
-	DebugRpcServer, DebugRpcServerImpl, EthRpcServer, EthRpcServerImpl, ReceiptExtractor,
-	ReceiptProvider, SubxtBlockInfoProvider, SystemHealthRpcServer, SystemHealthRpcServerImpl,
-	LOG_TARGET,
+	DebugRpcServer, DebugRpcServerImpl, EthPubSubRpcServer, EthPubSubRpcServerImpl, EthRpcServer,
+	EthRpcServerImpl, LogsQueryLimits, ReceiptExtractor, ReceiptProvider, SubxtBlockInfoProvider,
+	SystemHealthRpcServer, SystemHealthRpcServerImpl, DEFAULT_MAX_LOGS, LOG_TARGET,
 };
 use clap::Parser;
 use futures::{pin_mut, FutureExt};
@@ -64,6 +64,24 @@ pub struct CliCommand {
 	#[clap(long)]
 	pub index_last_n_blocks: Option<SubstrateBlockNumber>,
 
+	/// The number of latest blocks whose receipts and logs are kept in the database, the older
+	/// blocks are pruned.
+	/// Defaults to `cache_size` for the in memory database, and to keeping all blocks otherwise.
+	#[clap(long)]
+	pub receipts_retention: Option<usize>,
+
+	/// The maximum number of logs returned by `eth_getLogs` and `eth_getFilterLogs`.
+	#[clap(long, default_value_t = DEFAULT_MAX_LOGS)]
+	pub max_logs: usize,
+
+	/// The maximum number of blocks covered by a log query, if any.
+	#[clap(long)]
+	pub max_logs_block_range: Option<u64>,
+
+	#[allow(missing_docs)]
+	#[clap(subcommand)]
+	pub subcommand: Option<Subcommand>,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub shared_params: SharedParams,
@@ -77,6 +95,26 @@ pub struct CliCommand {
 	pub prometheus_params: PrometheusParams,
 }
 
+/// The subcommands of the server.
+#[derive(clap::Subcommand, Debug)]
+pub enum Subcommand {
+	/// Re-index the receipts and logs of a range of blocks in the database, then exit.
+	Reindex(ReindexCmd),
+}
+
+/// Re-index the receipts and logs of a range of blocks.
+#[derive(clap::Args, Debug)]
+pub struct ReindexCmd {
+	/// The first block to re-index.
+	/// The blocks older than `--receipts-retention` blocks are skipped.
+	#[clap(long)]
+	pub from: SubstrateBlockNumber,
+
+	/// The last block to re-index, defaults to the latest finalized block.
+	#[clap(long)]
+	pub to: Option<SubstrateBlockNumber>,
+}
+
 /// Initialize the logger
 #[cfg(not(test))]
 fn init_logger(params: &SharedParams) -> anyhow::Result<()> {
@@ -104,6 +142,8 @@ fn build_client(
 	earliest_receipt_block: Option<SubstrateBlockNumber>,
 	node_rpc_url: &str,
 	database_url: &str,
+	receipts_retention: Option<usize>,
+	logs_limits: LogsQueryLimits,
 	abort_signal: Signals,
 ) -> anyhow::Result<Client> {
 	let fut = async {
@@ -111,10 +151,11 @@ fn build_client(
 		let block_provider = SubxtBlockInfoProvider::new( api.clone(), rpc.clone()).await?;
 
 		let keep_latest_n_blocks = if database_url == IN_MEMORY_DB {
-			log::warn!( target: LOG_TARGET, "💾 Using in-memory database, keeping only {cache_size} blocks in memory");
-			Some(cache_size)
+			let keep_latest_n_blocks = receipts_retention.unwrap_or(cache_size);
+			log::warn!( target: LOG_TARGET, "💾 Using in-memory database, keeping only {keep_latest_n_blocks} blocks in memory");
+			Some(keep_latest_n_blocks)
 		} else {
-			None
+			receipts_retention
 		};
 
 		let receipt_extractor = ReceiptExtractor::new(
@@ -127,7 +168,8 @@ fn build_client(
 				receipt_extractor.clone(),
 				keep_latest_n_blocks,
 			)
-			.await?;
+			.await?
+			.with_logs_limits(logs_limits);
 
 		let client =
 			Client::new(api, rpc_client, rpc, block_provider, receipt_provider).await?;
@@ -154,10 +196,18 @@ pub fn run(cmd: CliCommand) -> anyhow::Result<()> {
 		database_url,
 		earliest_receipt_block,
 		index_last_n_blocks,
+		receipts_retention,
+		max_logs,
+		max_logs_block_range,
+		subcommand,
 		shared_params,
 		..
 	} = cmd;
 
+	if subcommand.is_some() && database_url == IN_MEMORY_DB {
+		anyhow::bail!("Re-indexing requires a persistent database, see `--database-url`");
+	}
+
 	#[cfg(not(test))]
 	init_logger(&shared_params)?;
 	let is_dev = shared_params.dev;
@@ -178,6 +228,8 @@ pub fn run(cmd: CliCommand) -> anyhow::Result<()> {
 		message_buffer_capacity: rpc_params.rpc_message_buffer_capacity_per_connection,
 		batch_config: rpc_params.rpc_batch_config()?,
 		rate_limit: rpc_params.rpc_rate_limit,
+		rate_limit_per_ip: rpc_params.rpc_rate_limit_per_ip,
+		rate_limit_method_costs: rpc_params.rpc_method_cost,
 		rate_limit_whitelisted_ips: rpc_params.rpc_rate_limit_whitelisted_ips,
 		rate_limit_trust_proxy_headers: rpc_params.rpc_rate_limit_trust_proxy_headers,
 	};
@@ -196,9 +248,27 @@ pub fn run(cmd: CliCommand) -> anyhow::Result<()> {
 		earliest_receipt_block,
 		&node_rpc_url,
 		&database_url,
+		receipts_retention,
+		LogsQueryLimits { max_results: max_logs, max_block_range: max_logs_block_range },
 		tokio_runtime.block_on(async { Signals::capture() })?,
 	)?;
 
+	if let Some(Subcommand::Reindex(ReindexCmd { from, to })) = subcommand {
+		let to = match to {
+			Some(to) => to,
+			None => tokio_runtime.block_on(client.latest_finalized_block()).number(),
+		};
+		if from > to {
+			anyhow::bail!("Invalid block range #{from}..=#{to}");
+		}
+		let signals = tokio_runtime.block_on(async { Signals::capture() })?;
+		let fut = client.reindex_blocks(from, to).fuse();
+		return match tokio_runtime.block_on(signals.try_until_signal(fut)) {
+			Ok(res) => Ok(res?),
+			Err(_) => anyhow::bail!("Process interrupted"),
+		};
+	}
+
 	// Prometheus metrics.
 	if let Some(PrometheusConfig { port, registry }) = prometheus_config.clone() {
 		task_manager.spawn_handle().spawn(
@@ -247,11 +317,13 @@ fn rpc_module(is_dev: bool, client: Client) -> Result<RpcModule<()>, sc_service:
 		.into_rpc();
 
 	let health_api = SystemHealthRpcServerImpl::new(client.clone()).into_rpc();
+	let pubsub_api = EthPubSubRpcServerImpl::new(client.clone()).into_rpc();
 	let debug_api = DebugRpcServerImpl::new(client).into_rpc();
 
 	let mut module = RpcModule::new(());
 	module.merge(eth_api).map_err(|e| sc_service::Error::Application(e.into()))?;
 	module.merge(health_api).map_err(|e| sc_service::Error::Application(e.into()))?;
+	module.merge(pubsub_api).map_err(|e| sc_service::Error::Application(e.into()))?;
 	module.merge(debug_api).map_err(|e| sc_service::Error::Application(e.into()))?;
 	Ok(module)
 }
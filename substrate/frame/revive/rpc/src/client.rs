@@ -25,8 +25,9 @@ use storage_api::StorageApi;
 
 use crate::{
 	subxt_client::{self, revive::calls::types::EthTransact, SrcChainConfig},
-	BlockInfoProvider, BlockTag, FeeHistoryProvider, ReceiptProvider, SubxtBlockInfoProvider,
-	TracerType, TransactionInfo, LOG_TARGET,
+	BlockInfo, BlockInfoProvider, BlockTag, FeeHistoryProvider, FilterChanges, FilterKind,
+	FilterProvider, ReceiptProvider, SubxtBlockInfoProvider, TracerType, TransactionInfo,
+	LOG_TARGET,
 };
 use jsonrpsee::{
 	core::traits::ToRpcParams,
@@ -35,12 +36,15 @@ use jsonrpsee::{
 };
 use pallet_revive::{
 	evm::{
-		decode_revert_reason, Block, BlockNumberOrTag, BlockNumberOrTagOrHash, FeeHistoryResult,
-		Filter, GenericTransaction, Log, ReceiptInfo, SyncingProgress, SyncingStatus, Trace,
-		TransactionSigned, TransactionTrace, H256, U256,
+		decode_revert_reason, AccountProof, Block, BlockNumberOrTag, BlockNumberOrTagOrHash, Bytes,
+		FeeHistoryResult, Filter, FilterResults, GenericTransaction, Log, ReceiptInfo,
+		StorageProof, SyncingProgress, SyncingStatus, Trace, TransactionSigned, TransactionTrace,
+		H160, H256, U256,
 	},
 	EthTransactError,
 };
+use sc_rpc_api::state::ReadProof;
+use sp_core::keccak_256;
 use sp_runtime::traits::Block as BlockT;
 use sp_weights::Weight;
 use std::{ops::Range, sync::Arc, time::Duration};
@@ -49,13 +53,14 @@ use subxt::{
 		legacy::{rpc_methods::SystemHealth, LegacyRpcMethods},
 		rpc::{
 			reconnecting_rpc_client::{ExponentialBackoff, RpcClient as ReconnectingRpcClient},
-			RpcClient,
+			RpcClient, RpcParams,
 		},
 	},
 	config::Header,
 	Config, OnlineClient,
 };
 use thiserror::Error;
+use tokio::sync::broadcast;
 
 /// The substrate block type.
 pub type SubstrateBlock = subxt::blocks::Block<SrcChainConfig, OnlineClient<SrcChainConfig>>;
@@ -72,6 +77,24 @@ pub type SubstrateBlockHash = <SrcChainConfig as Config>::Hash;
 /// The runtime balance type.
 pub type Balance = u128;
 
+/// The capacity of the channel used to notify the chain events to the subscribers.
+const CHAIN_EVENTS_CAPACITY: usize = 256;
+
+/// An event notified to the subscribers of the client.
+#[derive(Debug, Clone)]
+pub enum ChainEvent {
+	/// A new best block was imported.
+	NewBlock {
+		/// The new best block.
+		block: Arc<Block>,
+		/// The logs emitted by the block, preceded by the logs of the blocks retracted and
+		/// enacted by a reorg, if any. Logs of retracted blocks are flagged as removed.
+		logs: Arc<Vec<Log>>,
+	},
+	/// A transaction was submitted through this client.
+	NewPendingTransaction(H256),
+}
+
 /// The subscription type used to listen to new blocks.
 #[derive(Debug, Clone, Copy)]
 pub enum SubscriptionType {
@@ -122,11 +145,28 @@ pub enum ClientError {
 	#[error("failed to recover eth address")]
 	RecoverEthAddressFailed,
 	/// Failed to filter logs.
-	#[error("Failed to filter logs")]
+	#[error("Failed to filter logs: {0}")]
 	LogFilterFailed(#[from] anyhow::Error),
+	/// The log query matched more logs than allowed.
+	#[error("query returned more than {0} results")]
+	LogsLimitExceeded(usize),
+	/// The log query covered more blocks than allowed.
+	#[error("query exceeds the maximum block range of {0} blocks")]
+	LogsBlockRangeTooLarge(u64),
+	/// The filter was not found, or has expired.
+	#[error("filter not found")]
+	FilterNotFound,
+	/// The maximum number of installed filters was reached.
+	#[error("too many filters installed")]
+	TooManyFilters,
+	/// The runtime implements a version of the `ReviveApi` without EIP-7702 support.
+	#[error("authorization lists are not supported by the runtime")]
+	AuthorizationListNotSupported,
 }
 
 const REVERT_CODE: i32 = 3;
+/// The error code of requests exceeding a limit, see EIP-1474.
+const LIMIT_EXCEEDED_CODE: i32 = -32005;
 impl From<ClientError> for ErrorObjectOwned {
 	fn from(err: ClientError) -> Self {
 		match err {
@@ -146,6 +186,8 @@ impl From<ClientError> for ErrorObjectOwned {
 			},
 			ClientError::TransactError(EthTransactError::Message(msg)) =>
 				ErrorObjectOwned::owned::<String>(CALL_EXECUTION_FAILED_CODE, msg, None),
+			ClientError::LogsLimitExceeded(_) | ClientError::LogsBlockRangeTooLarge(_) =>
+				ErrorObjectOwned::owned::<String>(LIMIT_EXCEEDED_CODE, err.to_string(), None),
 			_ =>
 				ErrorObjectOwned::owned::<String>(CALL_EXECUTION_FAILED_CODE, err.to_string(), None),
 		}
@@ -161,8 +203,10 @@ pub struct Client {
 	receipt_provider: ReceiptProvider,
 	block_provider: SubxtBlockInfoProvider,
 	fee_history_provider: FeeHistoryProvider,
+	filter_provider: FilterProvider,
 	chain_id: u64,
 	max_block_weight: Weight,
+	chain_events: broadcast::Sender<ChainEvent>,
 }
 
 /// Fetch the chain ID from the substrate chain.
@@ -228,8 +272,10 @@ impl Client {
 			receipt_provider,
 			block_provider,
 			fee_history_provider: FeeHistoryProvider::default(),
+			filter_provider: FilterProvider::default(),
 			chain_id,
 			max_block_weight,
+			chain_events: broadcast::channel(CHAIN_EVENTS_CAPACITY).0,
 		})
 	}
 
@@ -325,6 +371,11 @@ impl Client {
 	) -> Result<(), ClientError> {
 		log::info!(target: LOG_TARGET, "🔌 Subscribing to new blocks ({subscription_type:?})");
 		self.subscribe_new_blocks(subscription_type, |block| async {
+			let reorg_logs = match subscription_type {
+				SubscriptionType::BestBlocks => self.reorg_logs(&block).await?,
+				SubscriptionType::FinalizedBlocks => Vec::new(),
+			};
+
 			let (signed_txs, receipts): (Vec<_>, Vec<_>) =
 				self.receipt_provider.insert_block_receipts(&block).await?.into_iter().unzip();
 
@@ -333,11 +384,80 @@ impl Client {
 			self.block_provider.update_latest(block, subscription_type).await;
 
 			self.fee_history_provider.update_fee_history(&evm_block, &receipts).await;
+
+			if let SubscriptionType::BestBlocks = subscription_type {
+				self.filter_provider.notify_new_block(evm_block.hash).await;
+
+				let logs = reorg_logs
+					.into_iter()
+					.chain(receipts.into_iter().flat_map(|receipt| receipt.logs))
+					.map(|log| Log { removed: log.removed.or(Some(false)), ..log })
+					.collect();
+
+				// Sending only fails when there are no subscribers.
+				let _ = self.chain_events.send(ChainEvent::NewBlock {
+					block: Arc::new(evm_block),
+					logs: Arc::new(logs),
+				});
+			}
 			Ok(())
 		})
 		.await
 	}
 
+	/// Get the logs of the blocks retracted and enacted by a reorg, when `block` becomes the new
+	/// best block.
+	///
+	/// The logs of the retracted blocks come first, newest block first and flagged as removed,
+	/// followed by the logs of the enacted blocks, excluding `block` itself.
+	async fn reorg_logs(&self, block: &SubstrateBlock) -> Result<Vec<Log>, ClientError> {
+		let latest = self.block_provider.latest_block().await;
+		let finalized_number = self.block_provider.latest_finalized_block().await.number();
+		let (retracted_blocks, enacted_blocks) = reorg_route(
+			latest,
+			block,
+			finalized_number,
+			|block| block.header().parent_hash,
+			|hash| async move { self.block_provider.block_by_hash(&hash).await },
+		)
+		.await?;
+
+		if !retracted_blocks.is_empty() {
+			log::debug!(
+				target: LOG_TARGET,
+				"Reorg at block #{}: {} block(s) retracted, {} block(s) enacted",
+				block.number(),
+				retracted_blocks.len(),
+				enacted_blocks.len()
+			);
+		}
+
+		let mut retracted_logs = Vec::new();
+		for retracted in retracted_blocks {
+			let receipts = self.receipt_provider.receipts_from_block(&retracted).await?;
+			retracted_logs.push(receipts.into_iter().flat_map(|(_, r)| r.logs).collect());
+		}
+
+		let mut enacted_logs = Vec::new();
+		for enacted in enacted_blocks {
+			let receipts = self.receipt_provider.receipts_from_block(&enacted).await?;
+			enacted_logs.push(receipts.into_iter().flat_map(|(_, r)| r.logs).collect());
+		}
+
+		Ok(merge_reorg_logs(retracted_logs, enacted_logs))
+	}
+
+	/// Subscribe to the chain events notified by the client.
+	pub fn subscribe_chain_events(&self) -> broadcast::Receiver<ChainEvent> {
+		self.chain_events.subscribe()
+	}
+
+	/// Notify the subscribers and filters that a transaction was submitted.
+	pub async fn notify_pending_transaction(&self, hash: H256) {
+		self.filter_provider.notify_pending_transaction(hash).await;
+		let _ = self.chain_events.send(ChainEvent::NewPendingTransaction(hash));
+	}
+
 	/// Cache old blocks up to the given block number.
 	pub async fn subscribe_and_cache_blocks(
 		&self,
@@ -356,6 +476,40 @@ impl Client {
 		Ok(())
 	}
 
+	/// Re-index the receipts and logs of the blocks in the given inclusive range, replacing the
+	/// entries previously indexed for these blocks.
+	///
+	/// The blocks that the retention policy would prune are skipped.
+	pub async fn reindex_blocks(
+		&self,
+		from: SubstrateBlockNumber,
+		to: SubstrateBlockNumber,
+	) -> Result<(), ClientError> {
+		let latest = self.latest_block().await.number();
+		let from = match self.receipt_provider.oldest_retained_block(latest) {
+			Some(oldest) if oldest > from => {
+				log::info!(
+					target: LOG_TARGET,
+					"🗄️ Skipping blocks #{from}..#{oldest}, older than the receipts retention"
+				);
+				oldest
+			},
+			_ => from,
+		};
+		if from > to {
+			return Ok(());
+		}
+
+		log::info!(target: LOG_TARGET, "🗄️ Re-indexing blocks #{from}..=#{to}");
+		self.subscribe_past_blocks(from..to, |block| async move {
+			self.receipt_provider.reindex_block_receipts(&block).await
+		})
+		.await?;
+
+		log::info!(target: LOG_TARGET, "🗄️ Finished re-indexing blocks");
+		Ok(())
+	}
+
 	/// Get the block hash for the given block number or tag.
 	pub async fn block_hash_for_tag(
 		&self,
@@ -424,6 +578,110 @@ impl Client {
 		Ok(sync_state)
 	}
 
+	/// Get the proof of the given storage entries of the main trie.
+	async fn read_proof(
+		&self,
+		keys: Vec<Vec<u8>>,
+		block_hash: H256,
+	) -> Result<Vec<Bytes>, ClientError> {
+		let client = RpcClient::new(self.rpc_client.clone());
+		let keys: Vec<_> = keys.into_iter().map(sp_core::Bytes).collect();
+		let mut params = RpcParams::new();
+		params.push(keys)?;
+		params.push(block_hash)?;
+		let proof: ReadProof<H256> = client.request("state_getReadProof", params).await?;
+		Ok(proof.proof.into_iter().map(|node| Bytes(node.0)).collect())
+	}
+
+	/// Get the proof of the given storage entries of a child trie.
+	async fn child_read_proof(
+		&self,
+		child_trie_key: Vec<u8>,
+		keys: Vec<Vec<u8>>,
+		block_hash: H256,
+	) -> Result<Vec<Bytes>, ClientError> {
+		let client = RpcClient::new(self.rpc_client.clone());
+		let keys: Vec<_> = keys.into_iter().map(sp_core::Bytes).collect();
+		let mut params = RpcParams::new();
+		params.push(sp_core::Bytes(child_trie_key))?;
+		params.push(keys)?;
+		params.push(block_hash)?;
+		let proof: ReadProof<H256> = client.request("state_getChildReadProof", params).await?;
+		Ok(proof.proof.into_iter().map(|node| Bytes(node.0)).collect())
+	}
+
+	/// Get the account and storage proofs of the given address at the given block.
+	///
+	/// See [`pallet_revive::proof`] for how to verify the returned proofs.
+	pub async fn get_proof(
+		&self,
+		address: H160,
+		storage_keys: Vec<U256>,
+		at: BlockNumberOrTagOrHash,
+	) -> Result<AccountProof, ClientError> {
+		let block_hash = self.block_hash_for_tag(at).await?;
+		let runtime_api = self.runtime_api(block_hash);
+
+		let slots: Vec<[u8; 32]> = storage_keys.iter().map(|key| key.to_big_endian()).collect();
+		let keys = runtime_api.account_proof_keys(address, slots.clone()).await?;
+		let (balance, nonce) =
+			tokio::try_join!(runtime_api.balance(address), runtime_api.nonce(address))?;
+		let account_proof = self.read_proof(keys.account_keys, block_hash).await?;
+
+		let code = match self.storage_api(block_hash).get_contract_code(&address).await {
+			Ok(code) => code.unwrap_or_default(),
+			Err(ClientError::ContractNotFound) => Vec::new(),
+			Err(err) => return Err(err),
+		};
+
+		let Some(child_trie_key) = keys.child_trie_key else {
+			let storage_proof = storage_keys
+				.into_iter()
+				.map(|key| StorageProof { key, value: U256::zero(), proof: Vec::new() })
+				.collect();
+			return Ok(AccountProof {
+				address,
+				account_proof,
+				balance,
+				code_hash: H256(keccak_256(&code)),
+				nonce,
+				storage_hash: H256::zero(),
+				storage_proof,
+			});
+		};
+
+		let storage_hash = self
+			.rpc
+			.state_get_storage(&child_trie_key, Some(block_hash))
+			.await?
+			.filter(|root| root.len() == 32)
+			.map(|root| H256::from_slice(&root))
+			.unwrap_or_default();
+
+		let mut storage_proof = Vec::with_capacity(storage_keys.len());
+		for ((key, slot), hashed_key) in storage_keys.into_iter().zip(slots).zip(keys.storage_keys)
+		{
+			let value = runtime_api.get_storage(address, slot).await?.unwrap_or_default();
+			if value.len() > 32 {
+				return Err(ClientError::ConversionFailed);
+			}
+			let proof = self
+				.child_read_proof(child_trie_key.clone(), vec![hashed_key], block_hash)
+				.await?;
+			storage_proof.push(StorageProof { key, value: U256::from_big_endian(&value), proof });
+		}
+
+		Ok(AccountProof {
+			address,
+			account_proof,
+			balance,
+			code_hash: H256(keccak_256(&code)),
+			nonce,
+			storage_hash,
+			storage_proof,
+		})
+	}
+
 	/// Get the syncing status of the chain.
 	pub async fn syncing(&self) -> Result<SyncingStatus, ClientError> {
 		let health = self.rpc.system_health().await?;
@@ -685,9 +943,35 @@ impl Client {
 
 	/// Get the logs matching the given filter.
 	pub async fn logs(&self, filter: Option<Filter>) -> Result<Vec<Log>, ClientError> {
-		let logs =
-			self.receipt_provider.logs(filter).await.map_err(ClientError::LogFilterFailed)?;
-		Ok(logs)
+		self.receipt_provider.logs(filter).await
+	}
+
+	/// Install a new filter, and return its id.
+	pub async fn new_filter(&self, kind: FilterKind) -> Result<U256, ClientError> {
+		let latest_block = self.block_provider.latest_block_number().await;
+		self.filter_provider.install(kind, latest_block).await
+	}
+
+	/// Uninstall the filter with the given id.
+	pub async fn uninstall_filter(&self, id: &U256) -> bool {
+		self.filter_provider.uninstall(id).await
+	}
+
+	/// Get the changes of the filter with the given id since it was last polled.
+	pub async fn filter_changes(&self, id: &U256) -> Result<FilterResults, ClientError> {
+		let latest_block = self.block_provider.latest_block_number().await;
+		match self.filter_provider.poll(id, latest_block).await? {
+			FilterChanges::Hashes(hashes) => Ok(FilterResults::Hashes(hashes)),
+			FilterChanges::Logs(None) => Ok(FilterResults::Logs(Vec::new())),
+			FilterChanges::Logs(Some(filter)) =>
+				Ok(FilterResults::Logs(self.logs(Some(filter)).await?)),
+		}
+	}
+
+	/// Get all the logs matching the log filter with the given id.
+	pub async fn filter_logs(&self, id: &U256) -> Result<Vec<Log>, ClientError> {
+		let filter = self.filter_provider.log_filter(id).await?;
+		self.logs(Some(filter)).await
 	}
 
 	pub async fn fee_history(
@@ -705,3 +989,178 @@ impl Client {
 			.await
 	}
 }
+
+/// Find the blocks retracted and enacted when `new_best` becomes the best block in place of
+/// `old_best`, walking back both branches to their common ancestor.
+///
+/// Both lists are ordered newest block first. `new_best` itself is not part of the enacted blocks,
+/// and blocks can't be retracted beyond the block number `finalized_number`.
+async fn reorg_route<B, F, Fut>(
+	old_best: B,
+	new_best: &B::Target,
+	finalized_number: SubstrateBlockNumber,
+	parent_hash: impl Fn(&B::Target) -> H256,
+	block_by_hash: F,
+) -> Result<(Vec<B>, Vec<B>), ClientError>
+where
+	B: std::ops::Deref,
+	B::Target: BlockInfo,
+	F: Fn(H256) -> Fut,
+	Fut: std::future::Future<Output = Result<Option<B>, ClientError>>,
+{
+	let mut retracted = old_best;
+	let mut retracted_blocks = Vec::new();
+	let mut enacted_blocks = Vec::new();
+	let Some(mut enacted_number) = new_best.number().checked_sub(1) else {
+		return Ok((retracted_blocks, enacted_blocks));
+	};
+	let mut enacted_hash = parent_hash(new_best);
+	if retracted.hash() == enacted_hash || retracted.hash() == new_best.hash() {
+		return Ok((retracted_blocks, enacted_blocks));
+	}
+
+	while retracted.hash() != enacted_hash {
+		// Step back the highest branch, or the retracted one when both are at the same height.
+		if retracted.number() >= enacted_number {
+			// Blocks can't be retracted beyond the latest finalized block.
+			if retracted.number() <= finalized_number {
+				break;
+			}
+			let parent = parent_hash(&retracted);
+			retracted_blocks.push(retracted);
+			retracted = block_by_hash(parent).await?.ok_or(ClientError::BlockNotFound)?;
+		} else {
+			let enacted = block_by_hash(enacted_hash).await?.ok_or(ClientError::BlockNotFound)?;
+			enacted_hash = parent_hash(&enacted);
+			enacted_number = enacted.number().saturating_sub(1);
+			enacted_blocks.push(enacted);
+		}
+	}
+
+	Ok((retracted_blocks, enacted_blocks))
+}
+
+/// Merge the logs of the blocks retracted and enacted by a reorg, as returned by
+/// [`reorg_route`].
+///
+/// The logs of the retracted blocks are flagged as removed and emitted in reverse order, followed
+/// by the logs of the enacted blocks, oldest block first.
+fn merge_reorg_logs(retracted: Vec<Vec<Log>>, enacted: Vec<Vec<Log>>) -> Vec<Log> {
+	let removed = retracted
+		.into_iter()
+		.flat_map(|logs| logs.into_iter().rev())
+		.map(|log| Log { removed: Some(true), ..log });
+	removed.chain(enacted.into_iter().rev().flatten()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test::MockBlockInfo;
+	use std::collections::HashMap;
+
+	/// A test chain, mapping the block hashes to the blocks and their parent hash.
+	struct Chain(HashMap<H256, (Arc<MockBlockInfo>, H256)>);
+
+	impl Chain {
+		/// Build a chain from a list of `(name, number, parent name)`.
+		fn new(blocks: &[(u8, SubstrateBlockNumber, u8)]) -> Self {
+			Self(
+				blocks
+					.iter()
+					.map(|&(name, number, parent)| {
+						let hash = H256::repeat_byte(name);
+						let block = MockBlockInfo { hash, number };
+						(hash, (Arc::new(block), H256::repeat_byte(parent)))
+					})
+					.collect(),
+			)
+		}
+
+		fn block(&self, name: u8) -> Arc<MockBlockInfo> {
+			self.0[&H256::repeat_byte(name)].0.clone()
+		}
+
+		async fn route(
+			&self,
+			old_best: u8,
+			new_best: u8,
+			finalized_number: SubstrateBlockNumber,
+		) -> (Vec<H256>, Vec<H256>) {
+			let (retracted, enacted) = reorg_route(
+				self.block(old_best),
+				&self.block(new_best),
+				finalized_number,
+				|block| self.0[&block.hash].1,
+				|hash| async move { Ok(self.0.get(&hash).map(|(block, _)| block.clone())) },
+			)
+			.await
+			.unwrap();
+			let hashes = |blocks: Vec<Arc<MockBlockInfo>>| -> Vec<H256> {
+				blocks.iter().map(|block| block.hash).collect()
+			};
+			(hashes(retracted), hashes(enacted))
+		}
+	}
+
+	fn log(block: u8, index: u8) -> Log {
+		Log {
+			block_hash: H256::repeat_byte(block),
+			log_index: index.into(),
+			..Default::default()
+		}
+	}
+
+	#[tokio::test]
+	async fn reorg_route_works() {
+		// 1 <- 2 <- 3 <- 4
+		//        \- 5 <- 6 <- 7
+		let chain = Chain::new(&[
+			(1, 1, 0),
+			(2, 2, 1),
+			(3, 3, 2),
+			(4, 4, 3),
+			(5, 3, 2),
+			(6, 4, 5),
+			(7, 5, 6),
+		]);
+		let hashes = |names: &[u8]| names.iter().map(|n| H256::repeat_byte(*n)).collect::<Vec<_>>();
+
+		// Extending the best block is not a reorg.
+		assert_eq!(chain.route(3, 4, 1).await, (vec![], vec![]));
+
+		// Switching to a longer fork retracts the old branch and enacts the new one, up to the
+		// parent of the new best block.
+		assert_eq!(chain.route(4, 7, 1).await, (hashes(&[4, 3]), hashes(&[6, 5])));
+
+		// Switching to a fork of the same length.
+		assert_eq!(chain.route(4, 6, 1).await, (hashes(&[4, 3]), hashes(&[5])));
+
+		// Switching to a shorter fork.
+		assert_eq!(chain.route(4, 5, 1).await, (hashes(&[4, 3]), hashes(&[])));
+
+		// Finalized blocks are never retracted.
+		assert_eq!(chain.route(4, 7, 3).await, (hashes(&[4]), hashes(&[6])));
+		assert_eq!(chain.route(3, 7, 2).await, (hashes(&[3]), hashes(&[6, 5])));
+	}
+
+	#[test]
+	fn merge_reorg_logs_flags_removed_logs() {
+		// Blocks are given newest first, as returned by `reorg_route`.
+		let retracted = vec![vec![log(4, 0), log(4, 1)], vec![log(3, 0)]];
+		let enacted = vec![vec![log(6, 0)], vec![log(5, 0), log(5, 1)]];
+
+		let removed = |log: Log| Log { removed: Some(true), ..log };
+		assert_eq!(
+			merge_reorg_logs(retracted, enacted),
+			vec![
+				removed(log(4, 1)),
+				removed(log(4, 0)),
+				removed(log(3, 0)),
+				log(5, 0),
+				log(5, 1),
+				log(6, 0),
+			]
+		);
+	}
+}
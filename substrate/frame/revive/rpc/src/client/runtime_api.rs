@@ -20,10 +20,16 @@ use crate::{
 	subxt_client::{self, SrcChainConfig},
 	ClientError, LOG_TARGET,
 };
+use codec::{Decode, Encode};
 use pallet_revive::{
-	evm::{GenericTransaction, Trace, H160, U256},
-	EthTransactInfo,
+	evm::{
+		AccessListResult, BlockOverrides, GenericTransaction, GenericTransactionV1,
+		StateOverrideSet, Trace, H160, U256,
+	},
+	AccountProofKeys, EthTransactError, EthTransactInfo,
 };
+use sp_crypto_hashing::blake2_64;
+use sp_version::RuntimeVersion;
 use subxt::OnlineClient;
 
 /// A Wrapper around subxt Runtime API
@@ -38,6 +44,41 @@ impl RuntimeApi {
 		Self(api)
 	}
 
+	/// Call a method added in version 2 of the `ReviveApi` runtime API.
+	///
+	/// These methods are not described by the `revive_chain.metadata` file the typed payloads are
+	/// generated from, so their parameters and result are SCALE encoded here, and the call is
+	/// rejected by runtimes implementing an older version of the API.
+	async fn call_v2<R: Decode>(
+		&self,
+		method: &str,
+		params: impl Encode,
+	) -> Result<R, ClientError> {
+		let params = params.encode();
+		let result = self.0.call_raw(&format!("ReviveApi_{method}"), Some(&params)).await?;
+		Ok(result)
+	}
+
+	/// Get the version of the `ReviveApi` implemented by the runtime.
+	async fn revive_api_version(&self) -> Result<u32, ClientError> {
+		let version: RuntimeVersion = self.0.call_raw("Core_version", None).await?;
+		Ok(version.api_version(&blake2_64(b"ReviveApi")).unwrap_or_default())
+	}
+
+	/// Encode the transaction as expected by the `ReviveApi` version of the runtime.
+	///
+	/// Runtimes implementing version 1 of the API decode a [`GenericTransactionV1`], without the
+	/// EIP-7702 authorization list.
+	async fn encode_transaction(&self, tx: GenericTransaction) -> Result<Vec<u8>, ClientError> {
+		if self.revive_api_version().await? >= 2 {
+			return Ok(tx.encode());
+		}
+		if !tx.authorization_list.is_empty() {
+			return Err(ClientError::AuthorizationListNotSupported);
+		}
+		Ok(GenericTransactionV1::from(tx).encode())
+	}
+
 	/// Get the balance of the given address.
 	pub async fn balance(&self, address: H160) -> Result<U256, ClientError> {
 		let address = address.0.into();
@@ -58,20 +99,61 @@ impl RuntimeApi {
 		Ok(result)
 	}
 
+	/// Get the storage keys needed to prove the state of the given address and storage slots.
+	pub async fn account_proof_keys(
+		&self,
+		address: H160,
+		storage_keys: Vec<[u8; 32]>,
+	) -> Result<AccountProofKeys, ClientError> {
+		self.call_v2("account_proof_keys", (address, storage_keys)).await
+	}
+
 	/// Dry run a transaction and returns the [`EthTransactInfo`] for the transaction.
 	pub async fn dry_run(
 		&self,
 		tx: GenericTransaction,
 	) -> Result<EthTransactInfo<Balance>, ClientError> {
-		let payload = subxt_client::apis().revive_api().eth_transact(tx.into());
-		let result = self.0.call(payload).await?;
-		match result {
-			Err(err) => {
-				log::debug!(target: LOG_TARGET, "Dry run failed {err:?}");
-				Err(ClientError::TransactError(err.0))
-			},
-			Ok(result) => Ok(result.0),
+		let params = self.encode_transaction(tx).await?;
+		let result: Result<EthTransactInfo<Balance>, EthTransactError> =
+			self.0.call_raw("ReviveApi_eth_transact", Some(&params)).await?;
+		result.map_err(|err| {
+			log::debug!(target: LOG_TARGET, "Dry run failed {err:?}");
+			ClientError::TransactError(err)
+		})
+	}
+
+	/// Dry run a transaction with the given state and block overrides, and returns the
+	/// [`EthTransactInfo`] for the transaction.
+	pub async fn dry_run_with_overrides(
+		&self,
+		tx: GenericTransaction,
+		state_overrides: StateOverrideSet,
+		block_overrides: BlockOverrides,
+	) -> Result<EthTransactInfo<Balance>, ClientError> {
+		if state_overrides.is_empty() && block_overrides.is_empty() {
+			return self.dry_run(tx).await;
 		}
+
+		let result: Result<EthTransactInfo<Balance>, EthTransactError> = self
+			.call_v2("eth_transact_with_overrides", (tx, state_overrides, block_overrides))
+			.await?;
+		result.map_err(|err| {
+			log::debug!(target: LOG_TARGET, "Dry run with overrides failed {err:?}");
+			ClientError::TransactError(err)
+		})
+	}
+
+	/// Dry run a transaction and returns its access list.
+	pub async fn create_access_list(
+		&self,
+		tx: GenericTransaction,
+	) -> Result<AccessListResult, ClientError> {
+		let result: Result<AccessListResult, EthTransactError> =
+			self.call_v2("eth_create_access_list", tx).await?;
+		result.map_err(|err| {
+			log::debug!(target: LOG_TARGET, "Create access list failed {err:?}");
+			ClientError::TransactError(err)
+		})
 	}
 
 	/// Get the nonce of the given address.
@@ -138,11 +220,10 @@ impl RuntimeApi {
 		transaction: GenericTransaction,
 		tracer_type: crate::TracerType,
 	) -> Result<Trace, ClientError> {
-		let payload = subxt_client::apis()
-			.revive_api()
-			.trace_call(transaction.into(), tracer_type.into());
-
-		let trace = self.0.call(payload).await?.map_err(|err| ClientError::TransactError(err.0))?;
-		Ok(trace.0)
+		let mut params = self.encode_transaction(transaction).await?;
+		tracer_type.encode_to(&mut params);
+		let result: Result<Trace, EthTransactError> =
+			self.0.call_raw("ReviveApi_trace_call", Some(&params)).await?;
+		result.map_err(ClientError::TransactError)
 	}
 }
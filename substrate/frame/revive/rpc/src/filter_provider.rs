@@ -0,0 +1,310 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{client::SubstrateBlockNumber, BlockNumberOrTag, ClientError};
+use pallet_revive::evm::Filter;
+use sp_core::{H256, U256};
+use std::{
+	collections::{HashMap, VecDeque},
+	sync::Arc,
+	time::{Duration, Instant},
+};
+use tokio::sync::Mutex;
+
+/// How long a filter is kept when it is not polled.
+const FILTER_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// The maximum number of filters that can be installed at the same time.
+const MAX_FILTERS: usize = 1024;
+
+/// The maximum number of hashes buffered for a filter between two polls.
+const MAX_BUFFERED_HASHES: usize = 1024;
+
+/// The kind of an installed filter.
+#[derive(Debug, Clone)]
+pub enum FilterKind {
+	/// Notify the logs matching the given filter.
+	Logs(Filter),
+	/// Notify the hashes of new blocks.
+	Blocks,
+	/// Notify the hashes of the transactions submitted through this server.
+	PendingTransactions,
+}
+
+/// The changes of a filter since it was last polled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterChanges {
+	/// The hashes of the new blocks or pending transactions.
+	Hashes(Vec<H256>),
+	/// The filter to query the new logs with, restricted to the blocks added since the last
+	/// poll, or `None` if there are no new blocks to query.
+	Logs(Option<Filter>),
+}
+
+/// An installed filter.
+struct InstalledFilter {
+	/// The kind of the filter.
+	kind: FilterKind,
+	/// The last time the filter was polled.
+	last_poll: Instant,
+	/// The next block to query the logs from, for log filters.
+	next_block: SubstrateBlockNumber,
+	/// The hashes buffered since the last poll, for block and pending transaction filters.
+	hashes: VecDeque<H256>,
+}
+
+impl InstalledFilter {
+	/// Buffer the given hash, dropping the oldest one if the buffer is full.
+	fn push_hash(&mut self, hash: H256) {
+		if self.hashes.len() >= MAX_BUFFERED_HASHES {
+			self.hashes.pop_front();
+		}
+		self.hashes.push_back(hash);
+	}
+}
+
+/// Manages the filters installed with `eth_newFilter`, `eth_newBlockFilter` and
+/// `eth_newPendingTransactionFilter`.
+///
+/// Filters that are not polled within the filter timeout are uninstalled.
+#[derive(Clone)]
+pub struct FilterProvider {
+	/// The installed filters, by id.
+	///
+	/// The ids are random 128-bit numbers, so that a client can't guess the filters of others.
+	filters: Arc<Mutex<HashMap<U256, InstalledFilter>>>,
+	/// How long a filter is kept when it is not polled.
+	timeout: Duration,
+}
+
+impl Default for FilterProvider {
+	fn default() -> Self {
+		Self::new(FILTER_TIMEOUT)
+	}
+}
+
+impl FilterProvider {
+	/// Create a new [`FilterProvider`] with the given filter timeout.
+	pub fn new(timeout: Duration) -> Self {
+		Self { filters: Default::default(), timeout }
+	}
+
+	/// Install a new filter, and return its id.
+	///
+	/// `latest_block` is the latest block at the time of the installation, log filters report
+	/// the logs of the blocks added after it.
+	pub async fn install(
+		&self,
+		kind: FilterKind,
+		latest_block: SubstrateBlockNumber,
+	) -> Result<U256, ClientError> {
+		let mut filters = self.filters.lock().await;
+		self.prune(&mut filters);
+		if filters.len() >= MAX_FILTERS {
+			return Err(ClientError::TooManyFilters);
+		}
+
+		let id = loop {
+			let id = U256::from(rand::random::<u128>());
+			if !filters.contains_key(&id) {
+				break id;
+			}
+		};
+
+		filters.insert(
+			id,
+			InstalledFilter {
+				kind,
+				last_poll: Instant::now(),
+				next_block: latest_block.saturating_add(1),
+				hashes: Default::default(),
+			},
+		);
+		Ok(id)
+	}
+
+	/// Uninstall the filter with the given id.
+	///
+	/// Returns `true` if the filter was installed.
+	pub async fn uninstall(&self, id: &U256) -> bool {
+		let mut filters = self.filters.lock().await;
+		self.prune(&mut filters);
+		filters.remove(id).is_some()
+	}
+
+	/// Get the log filter with the given id.
+	pub async fn log_filter(&self, id: &U256) -> Result<Filter, ClientError> {
+		let mut filters = self.filters.lock().await;
+		self.prune(&mut filters);
+		let installed = filters.get_mut(id).ok_or(ClientError::FilterNotFound)?;
+		installed.last_poll = Instant::now();
+		match &installed.kind {
+			FilterKind::Logs(filter) => Ok(filter.clone()),
+			_ => Err(ClientError::FilterNotFound),
+		}
+	}
+
+	/// Get the changes of the filter with the given id since it was last polled.
+	pub async fn poll(
+		&self,
+		id: &U256,
+		latest_block: SubstrateBlockNumber,
+	) -> Result<FilterChanges, ClientError> {
+		let mut filters = self.filters.lock().await;
+		self.prune(&mut filters);
+		let installed = filters.get_mut(id).ok_or(ClientError::FilterNotFound)?;
+		installed.last_poll = Instant::now();
+
+		let filter = match &installed.kind {
+			FilterKind::Blocks | FilterKind::PendingTransactions =>
+				return Ok(FilterChanges::Hashes(installed.hashes.drain(..).collect())),
+			FilterKind::Logs(filter) => filter,
+		};
+
+		// Filters on a single block hash have no changes.
+		if filter.block_hash.is_some() {
+			return Ok(FilterChanges::Logs(None));
+		}
+
+		let to_block = match filter.to_block {
+			Some(BlockNumberOrTag::U256(to_block)) =>
+				to_block.try_into().unwrap_or(latest_block).min(latest_block),
+			_ => latest_block,
+		};
+		let from_block = match filter.from_block {
+			Some(BlockNumberOrTag::U256(from_block)) =>
+				from_block.try_into().unwrap_or(installed.next_block).max(installed.next_block),
+			_ => installed.next_block,
+		};
+		if from_block > to_block {
+			return Ok(FilterChanges::Logs(None));
+		}
+
+		let filter = Filter {
+			from_block: Some(U256::from(from_block).into()),
+			to_block: Some(U256::from(to_block).into()),
+			..filter.clone()
+		};
+		installed.next_block = to_block.saturating_add(1);
+		Ok(FilterChanges::Logs(Some(filter)))
+	}
+
+	/// Notify the block filters of a new block.
+	pub async fn notify_new_block(&self, hash: H256) {
+		self.notify(hash, |kind| matches!(kind, FilterKind::Blocks)).await
+	}
+
+	/// Notify the pending transaction filters of a new transaction.
+	pub async fn notify_pending_transaction(&self, hash: H256) {
+		self.notify(hash, |kind| matches!(kind, FilterKind::PendingTransactions)).await
+	}
+
+	/// Buffer the given hash in the filters matching the predicate.
+	async fn notify(&self, hash: H256, predicate: impl Fn(&FilterKind) -> bool) {
+		let mut filters = self.filters.lock().await;
+		self.prune(&mut filters);
+		filters
+			.values_mut()
+			.filter(|installed| predicate(&installed.kind))
+			.for_each(|installed| installed.push_hash(hash));
+	}
+
+	/// Remove the filters that were not polled within the timeout.
+	fn prune(&self, filters: &mut HashMap<U256, InstalledFilter>) {
+		filters.retain(|_, installed| installed.last_poll.elapsed() < self.timeout);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pretty_assertions::assert_eq;
+
+	#[tokio::test]
+	async fn hash_filters_work() -> anyhow::Result<()> {
+		let provider = FilterProvider::default();
+		let blocks = provider.install(FilterKind::Blocks, 1).await?;
+		let txs = provider.install(FilterKind::PendingTransactions, 1).await?;
+		assert_ne!(blocks, txs);
+
+		provider.notify_new_block(H256::repeat_byte(1)).await;
+		provider.notify_new_block(H256::repeat_byte(2)).await;
+		provider.notify_pending_transaction(H256::repeat_byte(3)).await;
+
+		assert_eq!(
+			provider.poll(&blocks, 2).await?,
+			FilterChanges::Hashes(vec![H256::repeat_byte(1), H256::repeat_byte(2)])
+		);
+		assert_eq!(provider.poll(&blocks, 2).await?, FilterChanges::Hashes(vec![]));
+		assert_eq!(
+			provider.poll(&txs, 2).await?,
+			FilterChanges::Hashes(vec![H256::repeat_byte(3)])
+		);
+
+		assert!(provider.uninstall(&blocks).await);
+		assert!(!provider.uninstall(&blocks).await);
+		assert!(matches!(provider.poll(&blocks, 2).await, Err(ClientError::FilterNotFound)));
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn log_filters_work() -> anyhow::Result<()> {
+		let provider = FilterProvider::default();
+		let filter = Filter { to_block: Some(U256::from(5).into()), ..Default::default() };
+		let id = provider.install(FilterKind::Logs(filter.clone()), 1).await?;
+		assert_eq!(provider.log_filter(&id).await?, filter);
+
+		// No new blocks since the filter was installed.
+		assert_eq!(provider.poll(&id, 1).await?, FilterChanges::Logs(None));
+
+		let expected = |from: u64, to: u64| {
+			FilterChanges::Logs(Some(Filter {
+				from_block: Some(U256::from(from).into()),
+				to_block: Some(U256::from(to).into()),
+				..Default::default()
+			}))
+		};
+		assert_eq!(provider.poll(&id, 3).await?, expected(2, 3));
+		assert_eq!(provider.poll(&id, 3).await?, FilterChanges::Logs(None));
+
+		// The range is capped to the `to_block` of the filter.
+		assert_eq!(provider.poll(&id, 10).await?, expected(4, 5));
+		assert_eq!(provider.poll(&id, 11).await?, FilterChanges::Logs(None));
+
+		// Block filters are not log filters.
+		let blocks = provider.install(FilterKind::Blocks, 1).await?;
+		assert!(matches!(provider.log_filter(&blocks).await, Err(ClientError::FilterNotFound)));
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn filter_ids_are_not_sequential() -> anyhow::Result<()> {
+		let provider = FilterProvider::default();
+		let first = provider.install(FilterKind::Blocks, 1).await?;
+		let second = provider.install(FilterKind::Blocks, 1).await?;
+		assert_ne!(second, first.saturating_add(U256::one()));
+		assert!(first.bits() <= 128 && second.bits() <= 128);
+		Ok(())
+	}
+
+	#[tokio::test]
+	async fn filters_expire() -> anyhow::Result<()> {
+		let provider = FilterProvider::new(Duration::ZERO);
+		let id = provider.install(FilterKind::Blocks, 1).await?;
+		assert!(matches!(provider.poll(&id, 1).await, Err(ClientError::FilterNotFound)));
+		Ok(())
+	}
+}
@@ -44,6 +44,9 @@ pub use receipt_provider::*;
 mod fee_history_provider;
 pub use fee_history_provider::*;
 
+mod filter_provider;
+pub use filter_provider::*;
+
 mod receipt_extractor;
 pub use receipt_extractor::*;
 
@@ -98,6 +101,9 @@ pub enum EthRpcError {
 	/// Received an invalid transaction
 	#[error("Invalid transaction {0:?}")]
 	TransactionTypeNotSupported(Byte),
+	/// Received an invalid filter
+	#[error("Invalid filter: {0}")]
+	InvalidFilter(String),
 }
 
 // TODO use https://eips.ethereum.org/EIPS/eip-1474#error-codes
@@ -149,10 +155,18 @@ impl EthRpcServer for EthRpcServerImpl {
 		&self,
 		transaction: GenericTransaction,
 		block: Option<BlockNumberOrTag>,
+		state_overrides: Option<StateOverrideSet>,
+		block_overrides: Option<BlockOverrides>,
 	) -> RpcResult<U256> {
 		let hash = self.client.block_hash_for_tag(block.unwrap_or_default().into()).await?;
 		let runtime_api = self.client.runtime_api(hash);
-		let dry_run = runtime_api.dry_run(transaction).await?;
+		let dry_run = runtime_api
+			.dry_run_with_overrides(
+				transaction,
+				state_overrides.unwrap_or_default(),
+				block_overrides.unwrap_or_default(),
+			)
+			.await?;
 		Ok(dry_run.eth_gas)
 	}
 
@@ -160,10 +174,18 @@ impl EthRpcServer for EthRpcServerImpl {
 		&self,
 		transaction: GenericTransaction,
 		block: Option<BlockNumberOrTagOrHash>,
+		state_overrides: Option<StateOverrideSet>,
+		block_overrides: Option<BlockOverrides>,
 	) -> RpcResult<Bytes> {
 		let hash = self.client.block_hash_for_tag(block.unwrap_or_default()).await?;
 		let runtime_api = self.client.runtime_api(hash);
-		let dry_run = runtime_api.dry_run(transaction).await?;
+		let dry_run = runtime_api
+			.dry_run_with_overrides(
+				transaction,
+				state_overrides.unwrap_or_default(),
+				block_overrides.unwrap_or_default(),
+			)
+			.await?;
 		Ok(dry_run.data.into())
 	}
 
@@ -174,6 +196,7 @@ impl EthRpcServer for EthRpcServerImpl {
 			log::debug!(target: LOG_TARGET, "submit call failed: {err:?}");
 			err
 		})?;
+		self.client.notify_pending_transaction(hash).await;
 
 		log::debug!(target: LOG_TARGET, "send_raw_transaction hash: {hash:?}");
 		Ok(hash)
@@ -194,7 +217,7 @@ impl EthRpcServer for EthRpcServerImpl {
 			.ok_or(EthRpcError::AccountNotFound(from))?;
 
 		if transaction.gas.is_none() {
-			transaction.gas = Some(self.estimate_gas(transaction.clone(), None).await?);
+			transaction.gas = Some(self.estimate_gas(transaction.clone(), None, None, None).await?);
 		}
 
 		if transaction.gas_price.is_none() {
@@ -238,6 +261,17 @@ impl EthRpcServer for EthRpcServerImpl {
 		Ok(self.client.chain_id().into())
 	}
 
+	async fn create_access_list(
+		&self,
+		transaction: GenericTransaction,
+		block: Option<BlockNumberOrTag>,
+	) -> RpcResult<AccessListResult> {
+		let hash = self.client.block_hash_for_tag(block.unwrap_or_default().into()).await?;
+		let runtime_api = self.client.runtime_api(hash);
+		let result = runtime_api.create_access_list(transaction).await?;
+		Ok(result)
+	}
+
 	async fn gas_price(&self) -> RpcResult<U256> {
 		let hash = self.client.block_hash_for_tag(BlockTag::Latest.into()).await?;
 		let runtime_api = self.client.runtime_api(hash);
@@ -303,6 +337,43 @@ impl EthRpcServer for EthRpcServerImpl {
 		Ok(FilterResults::Logs(logs))
 	}
 
+	async fn new_filter(&self, filter: Filter) -> RpcResult<U256> {
+		if filter.topics.as_ref().is_some_and(|topics| topics.len() > 4) {
+			return Err(EthRpcError::InvalidFilter("exceed max topics".to_string()).into());
+		}
+		Ok(self.client.new_filter(FilterKind::Logs(filter)).await?)
+	}
+
+	async fn new_block_filter(&self) -> RpcResult<U256> {
+		Ok(self.client.new_filter(FilterKind::Blocks).await?)
+	}
+
+	async fn new_pending_transaction_filter(&self) -> RpcResult<U256> {
+		Ok(self.client.new_filter(FilterKind::PendingTransactions).await?)
+	}
+
+	async fn get_filter_changes(&self, filter_id: U256) -> RpcResult<FilterResults> {
+		Ok(self.client.filter_changes(&filter_id).await?)
+	}
+
+	async fn get_filter_logs(&self, filter_id: U256) -> RpcResult<FilterResults> {
+		let logs = self.client.filter_logs(&filter_id).await?;
+		Ok(FilterResults::Logs(logs))
+	}
+
+	async fn uninstall_filter(&self, filter_id: U256) -> RpcResult<bool> {
+		Ok(self.client.uninstall_filter(&filter_id).await)
+	}
+
+	async fn get_proof(
+		&self,
+		address: H160,
+		storage_keys: Vec<U256>,
+		block: BlockNumberOrTagOrHash,
+	) -> RpcResult<AccountProof> {
+		Ok(self.client.get_proof(address, storage_keys, block).await?)
+	}
+
 	async fn get_storage_at(
 		&self,
 		address: H160,
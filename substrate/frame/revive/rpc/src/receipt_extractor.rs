@@ -18,7 +18,10 @@ use crate::{
 	client::{SubstrateBlock, SubstrateBlockNumber},
 	subxt_client::{
 		self,
-		revive::{calls::types::EthTransact, events::ContractEmitted},
+		revive::{
+			calls::types::EthTransact,
+			events::{ContractEmitted, SetCodeCallFailed},
+		},
 		system::events::ExtrinsicSuccess,
 		transaction_payment::events::TransactionFeePaid,
 		SrcChainConfig,
@@ -106,6 +109,9 @@ impl ReceiptExtractor {
 		let success = events.has::<ExtrinsicSuccess>().inspect_err(|err| {
 		log::debug!(target: LOG_TARGET, "Failed to lookup for ExtrinsicSuccess event in block {block_number}: {err:?}")
 	})?;
+		// The dispatch of a set-code transaction succeeds even if its call fails, so that the
+		// authorizations remain applied.
+		let success = success && !events.has::<SetCodeCallFailed>()?;
 		let tx_fees = events
 		.find_first::<TransactionFeePaid>()?
 		.ok_or(ClientError::TxFeeNotFound)
@@ -21,12 +21,39 @@ use crate::{
 };
 use pallet_revive::evm::{Filter, Log, ReceiptInfo, TransactionSigned};
 use sp_core::{H256, U256};
-use sqlx::{query, QueryBuilder, Row, Sqlite, SqlitePool};
-use std::{
-	collections::{BTreeMap, HashMap},
-	sync::Arc,
-};
-use tokio::sync::Mutex;
+use sqlx::{query, sqlite::SqliteRow, QueryBuilder, Row, Sqlite, SqlitePool};
+use std::collections::{BTreeSet, HashMap};
+
+/// The default maximum number of logs returned by a log query.
+pub const DEFAULT_MAX_LOGS: usize = 10_000;
+
+/// The maximum number of blocks, selected by their logs bloom, queried at once.
+const MAX_BLOCKS_PER_QUERY: usize = 1024;
+
+/// The limits of the log queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogsQueryLimits {
+	/// The maximum number of logs returned by a query.
+	pub max_results: usize,
+	/// The maximum number of blocks covered by a query, if any.
+	pub max_block_range: Option<u64>,
+}
+
+impl Default for LogsQueryLimits {
+	fn default() -> Self {
+		Self { max_results: DEFAULT_MAX_LOGS, max_block_range: None }
+	}
+}
+
+/// The blocks a log query is restricted to.
+enum BlockSelection<'a> {
+	/// The block with the given hash.
+	Hash(H256),
+	/// The blocks in the given inclusive range of block numbers.
+	Range(u64, u64),
+	/// The blocks with the given numbers.
+	Numbers(&'a [i64]),
+}
 
 /// ReceiptProvider stores transaction receipts and logs in a SQLite database.
 #[derive(Clone)]
@@ -37,10 +64,10 @@ pub struct ReceiptProvider<B: BlockInfoProvider = SubxtBlockInfoProvider> {
 	block_provider: B,
 	/// A means to extract receipts from extrinsics.
 	receipt_extractor: ReceiptExtractor,
-	/// When `Some`, old blocks will be pruned.
+	/// When `Some`, only the latest n blocks are kept, older blocks are pruned.
 	keep_latest_n_blocks: Option<usize>,
-	/// A Map of the latest block numbers to block hashes.
-	block_number_to_hash: Arc<Mutex<BTreeMap<SubstrateBlockNumber, H256>>>,
+	/// The limits of the log queries.
+	logs_limits: LogsQueryLimits,
 }
 
 /// Provides information about a block,
@@ -69,16 +96,82 @@ impl<B: BlockInfoProvider> ReceiptProvider<B> {
 		block_provider: B,
 		receipt_extractor: ReceiptExtractor,
 		keep_latest_n_blocks: Option<usize>,
-	) -> Result<Self, sqlx::Error> {
+	) -> Result<Self, ClientError> {
 		let pool = SqlitePool::connect(database_url).await?;
-		sqlx::migrate!().run(&pool).await?;
-		Ok(Self {
+		sqlx::migrate!().run(&pool).await.map_err(sqlx::Error::from)?;
+		let provider = Self {
 			pool,
 			block_provider,
 			receipt_extractor,
 			keep_latest_n_blocks,
-			block_number_to_hash: Default::default(),
-		})
+			logs_limits: Default::default(),
+		};
+		provider.backfill_blocks().await?;
+		Ok(provider)
+	}
+
+	/// Add the blocks listed by the `blocks_backfill` migration to the `blocks` table, with their
+	/// number fetched from the node.
+	async fn backfill_blocks(&self) -> Result<(), ClientError> {
+		let block_hashes =
+			sqlx::query_scalar::<_, Vec<u8>>("SELECT block_hash FROM blocks_backfill")
+				.fetch_all(&self.pool)
+				.await?;
+		if block_hashes.is_empty() {
+			return Ok(());
+		}
+
+		log::info!(target: LOG_TARGET, "🗄️ Backfilling {} indexed blocks", block_hashes.len());
+		for block_hash in block_hashes {
+			let block_hash = H256::from_slice(&block_hash);
+			let block = self.block_provider.block_by_hash(&block_hash).await?;
+			self.backfill_block(block_hash, block.map(|block| block.number())).await?;
+		}
+		Ok(())
+	}
+
+	/// Backfill the number of a block indexed before the `blocks` table was created.
+	///
+	/// A block unknown to the node was replaced by a fork, and its entries are removed.
+	async fn backfill_block(
+		&self,
+		block_hash: H256,
+		block_number: Option<SubstrateBlockNumber>,
+	) -> Result<(), ClientError> {
+		match block_number {
+			Some(block_number) => {
+				sqlx::query("INSERT OR IGNORE INTO blocks (block_hash, block_number) VALUES (?, ?)")
+					.bind(block_hash.as_ref())
+					.bind(block_number as i64)
+					.execute(&self.pool)
+					.await?;
+			},
+			None => {
+				log::debug!(target: LOG_TARGET, "Removing unknown block {block_hash:?}");
+				self.remove(&[block_hash]).await?;
+			},
+		}
+		sqlx::query("DELETE FROM blocks_backfill WHERE block_hash = ?")
+			.bind(block_hash.as_ref())
+			.execute(&self.pool)
+			.await?;
+		Ok(())
+	}
+
+	/// The oldest block kept by the retention policy once the block `latest` is indexed, if
+	/// receipts are pruned.
+	pub fn oldest_retained_block(
+		&self,
+		latest: SubstrateBlockNumber,
+	) -> Option<SubstrateBlockNumber> {
+		self.keep_latest_n_blocks
+			.map(|n| latest.saturating_sub(n as SubstrateBlockNumber).saturating_add(1))
+	}
+
+	/// Set the limits of the log queries.
+	pub fn with_logs_limits(mut self, logs_limits: LogsQueryLimits) -> Self {
+		self.logs_limits = logs_limits;
+		self
 	}
 
 	async fn fetch_row(&self, transaction_hash: &H256) -> Option<(H256, usize)> {
@@ -114,14 +207,19 @@ impl<B: BlockInfoProvider> ReceiptProvider<B> {
 		let sql = format!("DELETE FROM logs WHERE block_hash in ({placeholders})");
 		let mut delete_logs_query = sqlx::query(&sql);
 
+		let sql = format!("DELETE FROM blocks WHERE block_hash in ({placeholders})");
+		let mut delete_blocks_query = sqlx::query(&sql);
+
 		for block_hash in block_hashes {
 			delete_tx_query = delete_tx_query.bind(block_hash.as_ref());
 			delete_logs_query = delete_logs_query.bind(block_hash.as_ref());
+			delete_blocks_query = delete_blocks_query.bind(block_hash.as_ref());
 		}
 
 		let delete_transaction_hashes = delete_tx_query.execute(&self.pool);
 		let delete_logs = delete_logs_query.execute(&self.pool);
-		tokio::try_join!(delete_transaction_hashes, delete_logs)?;
+		let delete_blocks = delete_blocks_query.execute(&self.pool);
+		tokio::try_join!(delete_transaction_hashes, delete_logs, delete_blocks)?;
 		Ok(())
 	}
 
@@ -152,6 +250,33 @@ impl<B: BlockInfoProvider> ReceiptProvider<B> {
 		Ok(receipts)
 	}
 
+	/// Extract the receipts from the given block, and replace the receipts and logs indexed for
+	/// its block number.
+	pub async fn reindex_block_receipts(&self, block: &SubstrateBlock) -> Result<(), ClientError> {
+		let receipts = self.receipts_from_block(block).await?;
+		self.reindex(block, &receipts).await
+	}
+
+	/// Replace the receipts and logs indexed for the number of the given block.
+	async fn reindex(
+		&self,
+		block: &impl BlockInfo,
+		receipts: &[(TransactionSigned, ReceiptInfo)],
+	) -> Result<(), ClientError> {
+		let mut to_remove = sqlx::query_scalar::<_, Vec<u8>>(
+			"SELECT block_hash FROM blocks WHERE block_number = ?",
+		)
+		.bind(block.number() as i64)
+		.fetch_all(&self.pool)
+		.await?
+		.into_iter()
+		.map(|hash| H256::from_slice(&hash))
+		.collect::<Vec<_>>();
+		to_remove.push(block.hash());
+		self.remove(&to_remove).await?;
+		self.insert(block, receipts).await
+	}
+
 	/// Insert receipts into the provider.
 	///
 	/// Note: Can be merged into `insert_block_receipts` once <https://github.com/paritytech/subxt/issues/1883> is fixed and subxt let
@@ -180,30 +305,38 @@ impl<B: BlockInfoProvider> ReceiptProvider<B> {
 			return Ok(());
 		}
 
-		// Keep track of the latest block hashes, so we can prune older blocks.
+		// Prune the blocks older than the latest n blocks, and the blocks replaced by a fork.
 		if let Some(keep_latest_n_blocks) = self.keep_latest_n_blocks {
-			let latest = block.number();
-			let mut block_number_to_hash = self.block_number_to_hash.lock().await;
-
-			let oldest_block = latest.saturating_sub(keep_latest_n_blocks as _);
-			let mut to_remove = block_number_to_hash
-				.iter()
-				.take_while(|(n, _)| **n <= oldest_block)
-				.map(|(_, hash)| *hash)
-				.collect::<Vec<_>>();
-
-			block_number_to_hash.retain(|&n, _| n > oldest_block);
-			match block_number_to_hash.insert(block.number(), block_hash) {
-				Some(old_hash) if old_hash != block_hash => {
-					to_remove.push(old_hash);
-				},
-				_ => {},
+			let oldest_block = block.number().saturating_sub(keep_latest_n_blocks as _) as i64;
+			let to_remove = sqlx::query_scalar::<_, Vec<u8>>(
+				"SELECT block_hash FROM blocks WHERE block_number <= ? OR (block_number = ? AND block_hash != ?)",
+			)
+			.bind(oldest_block)
+			.bind(block_number)
+			.bind(block_hash_ref)
+			.fetch_all(&self.pool)
+			.await?
+			.into_iter()
+			.map(|hash| H256::from_slice(&hash))
+			.collect::<Vec<_>>();
+
+			if !to_remove.is_empty() {
+				log::trace!(target: LOG_TARGET, "Pruning old blocks: {to_remove:?}");
+				self.remove(&to_remove).await?;
 			}
-
-			log::trace!(target: LOG_TARGET, "Pruning old blocks: {to_remove:?}");
-			self.remove(&to_remove).await?;
 		}
 
+		let logs_bloom =
+			ReceiptInfo::logs_bloom(receipts.iter().flat_map(|(_, receipt)| &receipt.logs));
+		sqlx::query(
+			"INSERT OR REPLACE INTO blocks (block_hash, block_number, logs_bloom) VALUES (?, ?, ?)",
+		)
+		.bind(block_hash_ref)
+		.bind(block_number)
+		.bind(&logs_bloom.0[..])
+		.execute(&self.pool)
+		.await?;
+
 		for (_, receipt) in receipts {
 			let transaction_hash: &[u8] = receipt.transaction_hash.as_ref();
 			let transaction_index = receipt.transaction_index.as_u32() as i32;
@@ -263,9 +396,14 @@ impl<B: BlockInfoProvider> ReceiptProvider<B> {
 	}
 
 	/// Get logs that match the given filter.
-	pub async fn logs(&self, filter: Option<Filter>) -> anyhow::Result<Vec<Log>> {
-		let mut qb = QueryBuilder::<Sqlite>::new("SELECT logs.* FROM logs WHERE 1=1");
+	///
+	/// When the filter has address or topic criteria, the blocks whose logs bloom can't match the
+	/// filter are skipped. An error is returned if the query exceeds the [`LogsQueryLimits`].
+	pub async fn logs(&self, filter: Option<Filter>) -> Result<Vec<Log>, ClientError> {
 		let filter = filter.unwrap_or_default();
+		if filter.topics.as_ref().is_some_and(|topics| topics.len() > 4) {
+			return Err(anyhow::anyhow!("exceed max topics").into());
+		}
 
 		let latest_block = U256::from(self.block_provider.latest_block_number().await);
 
@@ -273,47 +411,127 @@ impl<B: BlockInfoProvider> ReceiptProvider<B> {
 			None => Ok(None),
 			Some(BlockNumberOrTag::U256(v)) => Ok(Some(v)),
 			Some(BlockNumberOrTag::BlockTag(BlockTag::Latest)) => Ok(Some(latest_block)),
-			Some(BlockNumberOrTag::BlockTag(tag)) => anyhow::bail!("Unsupported tag: {tag:?}"),
+			Some(BlockNumberOrTag::BlockTag(tag)) =>
+				Err(anyhow::anyhow!("Unsupported tag: {tag:?}")),
 		};
 
-		let from_block = as_block_number(filter.from_block)?;
-		let to_block = as_block_number(filter.to_block)?;
+		let from_block = as_block_number(filter.from_block.clone())?;
+		let to_block = as_block_number(filter.to_block.clone())?;
 
-		match (from_block, to_block, filter.block_hash) {
+		let (from_block, to_block) = match (from_block, to_block, filter.block_hash) {
 			(Some(_), _, Some(_)) | (_, Some(_), Some(_)) => {
-				anyhow::bail!("block number and block hash cannot be used together");
+				return Err(
+					anyhow::anyhow!("block number and block hash cannot be used together").into()
+				);
 			},
-
 			(Some(block), _, _) | (_, Some(block), _) if block > latest_block => {
-				anyhow::bail!("block number exceeds latest block");
+				return Err(anyhow::anyhow!("block number exceeds latest block").into());
 			},
 			(Some(from_block), Some(to_block), None) if from_block > to_block => {
-				anyhow::bail!("invalid block range params");
+				return Err(anyhow::anyhow!("invalid block range params").into());
 			},
-			(Some(from_block), Some(to_block), None) if from_block == to_block => {
-				qb.push(" AND block_number = ").push_bind(from_block.as_u64() as i64);
+			(None, None, Some(hash)) => {
+				let max_results = self.logs_limits.max_results;
+				return self.query_logs(BlockSelection::Hash(hash), &filter, max_results).await;
 			},
-			(Some(from_block), Some(to_block), None) => {
-				qb.push(" AND block_number BETWEEN ")
-					.push_bind(from_block.as_u64() as i64)
-					.push(" AND ")
-					.push_bind(to_block.as_u64() as i64);
+			(from_block, to_block, None) => {
+				// Without `fromBlock`, the range starts at genesis if `toBlock` is set, and at the
+				// latest block otherwise.
+				let from = from_block.unwrap_or(if to_block.is_some() {
+					U256::zero()
+				} else {
+					latest_block
+				});
+				(from.as_u64(), to_block.unwrap_or(latest_block).as_u64())
 			},
-			(Some(from_block), None, None) => {
-				qb.push(" AND block_number >= ").push_bind(from_block.as_u64() as i64);
+		};
+
+		if let Some(max_block_range) = self.logs_limits.max_block_range {
+			if to_block - from_block >= max_block_range {
+				return Err(ClientError::LogsBlockRangeTooLarge(max_block_range));
+			}
+		}
+
+		if filter.address.is_none() && filter.topics.is_none() {
+			let max_results = self.logs_limits.max_results;
+			return self
+				.query_logs(BlockSelection::Range(from_block, to_block), &filter, max_results)
+				.await;
+		}
+
+		// Only query the blocks whose logs bloom may match the filter. The bloom of the blocks
+		// indexed before it was stored is unknown, these blocks are always queried.
+		let candidates = sqlx::query(
+			"SELECT block_number, logs_bloom FROM blocks WHERE block_number BETWEEN ? AND ?",
+		)
+		.bind(from_block as i64)
+		.bind(to_block as i64)
+		.try_map(|row: SqliteRow| {
+			let block_number: i64 = row.try_get("block_number")?;
+			let logs_bloom: Option<Vec<u8>> = row.try_get("logs_bloom")?;
+			Ok((block_number, logs_bloom))
+		})
+		.fetch_all(&self.pool)
+		.await?
+		.into_iter()
+		.filter(|(_, logs_bloom)| {
+			logs_bloom
+				.as_deref()
+				.and_then(|bloom| <[u8; 256]>::try_from(bloom).ok())
+				.map_or(true, |bloom| filter.matches_bloom(&bloom.into()))
+		})
+		.map(|(block_number, _)| block_number)
+		.collect::<BTreeSet<_>>()
+		.into_iter()
+		.collect::<Vec<_>>();
+
+		let mut logs = Vec::new();
+		for block_numbers in candidates.chunks(MAX_BLOCKS_PER_QUERY) {
+			let max_results = self.logs_limits.max_results - logs.len();
+			logs.extend(
+				self.query_logs(BlockSelection::Numbers(block_numbers), &filter, max_results)
+					.await?,
+			);
+		}
+		Ok(logs)
+	}
+
+	/// Query the logs of the selected blocks that match the address and topic criteria of the
+	/// filter.
+	///
+	/// An error is returned if more than `max_results` logs match.
+	async fn query_logs(
+		&self,
+		blocks: BlockSelection<'_>,
+		filter: &Filter,
+		max_results: usize,
+	) -> Result<Vec<Log>, ClientError> {
+		let mut qb = QueryBuilder::<Sqlite>::new("SELECT logs.* FROM logs WHERE 1=1");
+
+		match blocks {
+			BlockSelection::Hash(hash) => {
+				qb.push(" AND block_hash = ").push_bind(hash.0.to_vec());
 			},
-			(None, Some(to_block), None) => {
-				qb.push(" AND block_number <= ").push_bind(to_block.as_u64() as i64);
+			BlockSelection::Range(from_block, to_block) if from_block == to_block => {
+				qb.push(" AND block_number = ").push_bind(from_block as i64);
 			},
-			(None, None, Some(hash)) => {
-				qb.push(" AND block_hash = ").push_bind(hash.0.to_vec());
+			BlockSelection::Range(from_block, to_block) => {
+				qb.push(" AND block_number BETWEEN ")
+					.push_bind(from_block as i64)
+					.push(" AND ")
+					.push_bind(to_block as i64);
 			},
-			(None, None, None) => {
-				qb.push(" AND block_number = ").push_bind(latest_block.as_u64() as i64);
+			BlockSelection::Numbers(block_numbers) => {
+				qb.push(" AND block_number IN (");
+				let mut separated = qb.separated(", ");
+				for block_number in block_numbers {
+					separated.push_bind(*block_number);
+				}
+				separated.push_unseparated(")");
 			},
 		}
 
-		if let Some(addresses) = filter.address {
+		if let Some(addresses) = &filter.address {
 			match addresses {
 				AddressOrAddresses::Address(addr) => {
 					qb.push(" AND address = ").push_bind(addr.0.to_vec());
@@ -329,12 +547,8 @@ impl<B: BlockInfoProvider> ReceiptProvider<B> {
 			}
 		}
 
-		if let Some(topics) = filter.topics {
-			if topics.len() > 4 {
-				return Err(anyhow::anyhow!("exceed max topics"));
-			}
-
-			for (i, topic) in topics.into_iter().enumerate() {
+		if let Some(topics) = &filter.topics {
+			for (i, topic) in topics.iter().enumerate() {
 				match topic {
 					FilterTopic::Single(hash) => {
 						qb.push(format_args!(" AND topic_{i} = ")).push_bind(hash.0.to_vec());
@@ -351,42 +565,14 @@ impl<B: BlockInfoProvider> ReceiptProvider<B> {
 			}
 		}
 
-		qb.push(" LIMIT 10000");
-
-		let logs = qb
-			.build()
-			.try_map(|row| {
-				let block_hash: Vec<u8> = row.try_get("block_hash")?;
-				let transaction_index: i64 = row.try_get("transaction_index")?;
-				let log_index: i64 = row.try_get("log_index")?;
-				let address: Vec<u8> = row.try_get("address")?;
-				let block_number: i64 = row.try_get("block_number")?;
-				let transaction_hash: Vec<u8> = row.try_get("transaction_hash")?;
-				let topic_0: Option<Vec<u8>> = row.try_get("topic_0")?;
-				let topic_1: Option<Vec<u8>> = row.try_get("topic_1")?;
-				let topic_2: Option<Vec<u8>> = row.try_get("topic_2")?;
-				let topic_3: Option<Vec<u8>> = row.try_get("topic_3")?;
-				let data: Option<Vec<u8>> = row.try_get("data")?;
-
-				let topics = [topic_0, topic_1, topic_2, topic_3]
-					.iter()
-					.filter_map(|t| t.as_ref().map(|t| H256::from_slice(t)))
-					.collect::<Vec<_>>();
-
-				Ok(Log {
-					address: Address::from_slice(&address),
-					block_hash: H256::from_slice(&block_hash),
-					block_number: U256::from(block_number as u64),
-					data: data.map(Bytes::from),
-					log_index: U256::from(log_index as u64),
-					topics,
-					transaction_hash: H256::from_slice(&transaction_hash),
-					transaction_index: U256::from(transaction_index as u64),
-					removed: None,
-				})
-			})
-			.fetch_all(&self.pool)
-			.await?;
+		// Fetch one more log than allowed, to detect that the limit is exceeded.
+		qb.push(" ORDER BY block_number, transaction_index, log_index LIMIT ")
+			.push_bind(max_results.saturating_add(1) as i64);
+
+		let logs = qb.build().try_map(log_from_row).fetch_all(&self.pool).await?;
+		if logs.len() > max_results {
+			return Err(ClientError::LogsLimitExceeded(self.logs_limits.max_results));
+		}
 
 		Ok(logs)
 	}
@@ -492,6 +678,38 @@ impl<B: BlockInfoProvider> ReceiptProvider<B> {
 	}
 }
 
+/// Decode a log from a row of the `logs` table.
+fn log_from_row(row: SqliteRow) -> Result<Log, sqlx::Error> {
+	let block_hash: Vec<u8> = row.try_get("block_hash")?;
+	let transaction_index: i64 = row.try_get("transaction_index")?;
+	let log_index: i64 = row.try_get("log_index")?;
+	let address: Vec<u8> = row.try_get("address")?;
+	let block_number: i64 = row.try_get("block_number")?;
+	let transaction_hash: Vec<u8> = row.try_get("transaction_hash")?;
+	let topic_0: Option<Vec<u8>> = row.try_get("topic_0")?;
+	let topic_1: Option<Vec<u8>> = row.try_get("topic_1")?;
+	let topic_2: Option<Vec<u8>> = row.try_get("topic_2")?;
+	let topic_3: Option<Vec<u8>> = row.try_get("topic_3")?;
+	let data: Option<Vec<u8>> = row.try_get("data")?;
+
+	let topics = [topic_0, topic_1, topic_2, topic_3]
+		.iter()
+		.filter_map(|t| t.as_ref().map(|t| H256::from_slice(t)))
+		.collect::<Vec<_>>();
+
+	Ok(Log {
+		address: Address::from_slice(&address),
+		block_hash: H256::from_slice(&block_hash),
+		block_number: U256::from(block_number as u64),
+		data: data.map(Bytes::from),
+		log_index: U256::from(log_index as u64),
+		topics,
+		transaction_hash: H256::from_slice(&transaction_hash),
+		transaction_index: U256::from(transaction_index as u64),
+		removed: None,
+	})
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -524,7 +742,7 @@ mod tests {
 			block_provider: MockBlockInfoProvider {},
 			receipt_extractor: ReceiptExtractor::new_mock(),
 			keep_latest_n_blocks: Some(10),
-			block_number_to_hash: Default::default(),
+			logs_limits: Default::default(),
 		}
 	}
 
@@ -547,6 +765,7 @@ mod tests {
 		provider.remove(&[block.hash()]).await?;
 		assert_eq!(count(&provider.pool, "transaction_hashes", Some(block.hash())).await, 0);
 		assert_eq!(count(&provider.pool, "logs", Some(block.hash())).await, 0);
+		assert_eq!(count(&provider.pool, "blocks", Some(block.hash())).await, 0);
 		Ok(())
 	}
 
@@ -574,11 +793,62 @@ mod tests {
 		}
 		assert_eq!(count(&provider.pool, "transaction_hashes", None).await, n);
 		assert_eq!(count(&provider.pool, "logs", None).await, n);
-		assert_eq!(provider.block_number_to_hash.lock().await.len(), n);
+		assert_eq!(count(&provider.pool, "blocks", None).await, n);
+		assert_eq!(provider.oldest_retained_block(2 * n as u32 - 1), Some(n as u32));
 
 		return Ok(());
 	}
 
+	#[sqlx::test]
+	async fn test_backfill_blocks(pool: SqlitePool) -> anyhow::Result<()> {
+		let provider = setup_sqlite_provider(pool).await;
+
+		// Blocks without logs, indexed before the `blocks` table was created.
+		let known_block_hash = H256::from([1u8; 32]);
+		let unknown_block_hash = H256::from([2u8; 32]);
+		for block_hash in [known_block_hash, unknown_block_hash] {
+			sqlx::query(
+				"INSERT INTO transaction_hashes (transaction_hash, transaction_index, block_hash) \
+				VALUES (?, 0, ?)",
+			)
+			.bind(block_hash.as_ref())
+			.bind(block_hash.as_ref())
+			.execute(&provider.pool)
+			.await?;
+			sqlx::query("INSERT INTO blocks_backfill (block_hash) VALUES (?)")
+				.bind(block_hash.as_ref())
+				.execute(&provider.pool)
+				.await?;
+		}
+
+		// The block known to the node is added with its number.
+		provider.backfill_block(known_block_hash, Some(5)).await?;
+		assert_eq!(count(&provider.pool, "blocks", Some(known_block_hash)).await, 1);
+		assert_eq!(count(&provider.pool, "transaction_hashes", Some(known_block_hash)).await, 1);
+
+		// The block unknown to the node is removed.
+		provider.backfill_blocks().await?;
+		assert_eq!(count(&provider.pool, "blocks", Some(unknown_block_hash)).await, 0);
+		assert_eq!(count(&provider.pool, "transaction_hashes", Some(unknown_block_hash)).await, 0);
+		assert_eq!(count(&provider.pool, "blocks_backfill", None).await, 0);
+
+		// The backfilled block is kept until it is older than the latest n blocks.
+		let receipts = |transaction_hash: H256| {
+			vec![(
+				TransactionSigned::default(),
+				ReceiptInfo { transaction_hash, ..Default::default() },
+			)]
+		};
+		let block = MockBlockInfo { hash: H256::from([3u8; 32]), number: 14 };
+		provider.insert(&block, &receipts(block.hash)).await?;
+		assert_eq!(count(&provider.pool, "transaction_hashes", Some(known_block_hash)).await, 1);
+		let block = MockBlockInfo { hash: H256::from([4u8; 32]), number: 15 };
+		provider.insert(&block, &receipts(block.hash)).await?;
+		assert_eq!(count(&provider.pool, "transaction_hashes", Some(known_block_hash)).await, 0);
+		assert_eq!(count(&provider.pool, "blocks", None).await, 2);
+		Ok(())
+	}
+
 	#[sqlx::test]
 	async fn test_fork(pool: SqlitePool) -> anyhow::Result<()> {
 		let provider = setup_sqlite_provider(pool).await;
@@ -603,10 +873,11 @@ mod tests {
 		assert_eq!(count(&provider.pool, "transaction_hashes", None).await, 1);
 		assert_eq!(count(&provider.pool, "logs", None).await, 1);
 		assert_eq!(
-			provider.block_number_to_hash.lock().await.clone(),
-			[(1, H256::from([2u8; 32]))].into(),
+			count(&provider.pool, "blocks", Some(H256::from([2u8; 32]))).await,
+			1,
 			"New receipt for block #1 should replace the old one"
 		);
+		assert_eq!(count(&provider.pool, "blocks", None).await, 1);
 
 		return Ok(());
 	}
@@ -780,6 +1051,96 @@ mod tests {
 			}))
 			.await?;
 		assert_eq!(logs, vec![log1.clone(), log2.clone()]);
+
+		// No match
+		let logs = provider
+			.logs(Some(Filter {
+				from_block: Some(U256::from(0).into()),
+				topics: Some(vec![FilterTopic::Single(H256::from([4u8; 32]))]),
+				..Default::default()
+			}))
+			.await?;
+		assert_eq!(logs, vec![]);
+
+		// Blocks indexed without a logs bloom are always queried
+		sqlx::query("UPDATE blocks SET logs_bloom = NULL")
+			.execute(&provider.pool)
+			.await?;
+		let logs = provider
+			.logs(Some(Filter {
+				from_block: Some(U256::from(0).into()),
+				address: Some(log2.address.into()),
+				..Default::default()
+			}))
+			.await?;
+		assert_eq!(logs, vec![log2.clone()]);
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn test_logs_limits(pool: SqlitePool) -> anyhow::Result<()> {
+		let provider = setup_sqlite_provider(pool)
+			.await
+			.with_logs_limits(LogsQueryLimits { max_results: 2, max_block_range: Some(2) });
+		let block = MockBlockInfo { hash: H256::from([1u8; 32]), number: 1 };
+		let log = |log_index: u32| Log {
+			block_hash: block.hash,
+			block_number: block.number.into(),
+			log_index: log_index.into(),
+			..Default::default()
+		};
+		let receipts = vec![(
+			TransactionSigned::default(),
+			ReceiptInfo { logs: vec![log(0), log(1), log(2)], ..Default::default() },
+		)];
+		provider.insert(&block, &receipts).await?;
+
+		let result = provider
+			.logs(Some(Filter { block_hash: Some(block.hash), ..Default::default() }))
+			.await;
+		assert!(matches!(result, Err(ClientError::LogsLimitExceeded(2))));
+
+		let result = provider
+			.logs(Some(Filter {
+				from_block: Some(U256::from(0).into()),
+				to_block: Some(U256::from(2).into()),
+				..Default::default()
+			}))
+			.await;
+		assert!(matches!(result, Err(ClientError::LogsBlockRangeTooLarge(2))));
+		Ok(())
+	}
+
+	#[sqlx::test]
+	async fn test_reindex(pool: SqlitePool) -> anyhow::Result<()> {
+		let provider = setup_sqlite_provider(pool).await;
+		let block = MockBlockInfo { hash: H256::from([1u8; 32]), number: 1 };
+		let receipts = |transaction_hash: H256| {
+			vec![(
+				TransactionSigned::default(),
+				ReceiptInfo {
+					transaction_hash,
+					logs: vec![Log {
+						block_hash: block.hash,
+						block_number: block.number.into(),
+						transaction_hash,
+						..Default::default()
+					}],
+					..Default::default()
+				},
+			)]
+		};
+
+		// Re-indexing replaces the entries of the block, and of the blocks with the same number.
+		let fork = MockBlockInfo { hash: H256::from([2u8; 32]), number: 1 };
+		provider.insert(&fork, &receipts(H256::from([2u8; 32]))).await?;
+		provider.insert(&block, &receipts(H256::from([1u8; 32]))).await?;
+		provider.reindex(&block, &receipts(H256::from([3u8; 32]))).await?;
+
+		assert_eq!(count(&provider.pool, "blocks", None).await, 1);
+		assert_eq!(count(&provider.pool, "transaction_hashes", None).await, 1);
+		assert_eq!(count(&provider.pool, "logs", None).await, 1);
+		assert_eq!(provider.fetch_row(&H256::from([3u8; 32])).await, Some((block.hash, 0)));
 		Ok(())
 	}
 }
@@ -77,6 +77,36 @@ macro_rules! build_runtime(
 	};
 );
 
+/// Encode a BLS12-381 base field element as expected by the EIP-2537 precompiles.
+fn bls12_encode_fp(fp: &ark_bls12_381::Fq, output: &mut Vec<u8>) {
+	use ark_ff::{BigInteger, PrimeField};
+	output.extend_from_slice(&[0u8; 16]);
+	output.extend_from_slice(&fp.into_bigint().to_bytes_be());
+}
+
+/// Returns the encoded multiple of the BLS12-381 G1 generator by `scalar`.
+fn bls12_g1_point(scalar: u64) -> Vec<u8> {
+	use ark_ec::{AffineRepr, CurveGroup};
+	let point =
+		(ark_bls12_381::G1Affine::generator() * ark_bls12_381::Fr::from(scalar)).into_affine();
+	let mut output = Vec::new();
+	bls12_encode_fp(&point.x, &mut output);
+	bls12_encode_fp(&point.y, &mut output);
+	output
+}
+
+/// Returns the encoded multiple of the BLS12-381 G2 generator by `scalar`.
+fn bls12_g2_point(scalar: u64) -> Vec<u8> {
+	use ark_ec::{AffineRepr, CurveGroup};
+	let point =
+		(ark_bls12_381::G2Affine::generator() * ark_bls12_381::Fr::from(scalar)).into_affine();
+	let mut output = Vec::new();
+	for fp in [point.x.c0, point.x.c1, point.y.c0, point.y.c1] {
+		bls12_encode_fp(&fp, &mut output);
+	}
+	output
+}
+
 #[benchmarks(
 	where
 		BalanceOf<T>: Into<U256> + TryFrom<U256>,
@@ -397,6 +427,40 @@ mod benchmarks {
 		_(origin, Box::new(dispatchable));
 	}
 
+	// Worst case: the authorization is valid, so that the delegation is written.
+	#[benchmark(pov_mode = Measured)]
+	fn apply_authorization() {
+		let key_type = sp_core::crypto::KeyTypeId(*b"code");
+		let pub_key = sp_io::crypto::ecdsa_generate(key_type, None);
+		let mut authorization = evm::AuthorizationListEntry {
+			chain_id: T::ChainId::get().into(),
+			address: H160::repeat_byte(0x42),
+			..Default::default()
+		};
+		let hash = sp_io::hashing::keccak_256(&authorization.signing_payload());
+		let signature = sp_io::crypto::ecdsa_sign_prehashed(key_type, &pub_key, &hash)
+			.expect("Generates signature");
+		let signature = AsRef::<[u8; 65]>::as_ref(&signature);
+		authorization.r = U256::from_big_endian(&signature[..32]);
+		authorization.s = U256::from_big_endian(&signature[32..64]);
+		authorization.y_parity = signature[64].into();
+
+		let authority = authorization.recover_authority().expect("Signature is valid");
+		T::Currency::set_balance(
+			&T::AddressMapper::to_account_id(&authority),
+			caller_funding::<T>(),
+		);
+
+		let applied;
+		#[block]
+		{
+			applied = evm::apply_authorizations::<T>(&[authorization]);
+		}
+
+		assert_eq!(applied, 1);
+		assert_eq!(DelegationOf::<T>::get(&authority), Some(H160::repeat_byte(0x42)));
+	}
+
 	#[benchmark(pov_mode = Measured)]
 	fn noop_host_fn(r: Linear<0, API_BENCHMARK_RUNS>) {
 		let mut setup = CallSetup::<T>::new(WasmModule::noop());
@@ -2029,6 +2093,178 @@ mod benchmarks {
 		assert_ok!(result);
 	}
 
+	#[benchmark(pov_mode = Measured)]
+	fn p256_verify() {
+		use hex_literal::hex;
+		let input = hex!("621106f37a68d3debd80f280bb41f95ac84d1dfcb871acd3ac696ed24c87c1d41dcc86fca5b9a870a3530e3c641d82822c033afe46d4f1f415ec65c1330a386743bcb674332beef191c117cb1f0f2fcac4aa8254a0444537f58a9c93e000d84a088bb9ff22ab291a74c86fc677ba897baadee370cc6129b82d170ba3fc26415c442da9a716067956d91eaa02b93ad409490e87cd5e758ea6a331a1deb75ba846").to_vec();
+		let expected = hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let mut call_setup = CallSetup::<T>::default();
+		let (mut ext, _) = call_setup.ext();
+
+		let result;
+		#[block]
+		{
+			result = run_builtin_precompile(
+				&mut ext,
+				H160::from_low_u64_be(0x100).as_fixed_bytes(),
+				input,
+			);
+		}
+
+		assert_eq!(result.unwrap().data, expected);
+	}
+
+	#[benchmark(pov_mode = Measured)]
+	fn bls12_g1_add() {
+		let input = [bls12_g1_point(1), bls12_g1_point(2)].concat();
+		let mut call_setup = CallSetup::<T>::default();
+		let (mut ext, _) = call_setup.ext();
+
+		let result;
+		#[block]
+		{
+			result = run_builtin_precompile(
+				&mut ext,
+				H160::from_low_u64_be(0x0b).as_fixed_bytes(),
+				input,
+			);
+		}
+
+		assert_eq!(result.unwrap().data, bls12_g1_point(3));
+	}
+
+	// `n`: pairs of point and scalar to multiply
+	#[benchmark(pov_mode = Measured)]
+	fn bls12_g1_msm(n: Linear<1, 128>) {
+		let input = (0..n)
+			.flat_map(|i| {
+				let scalar = sp_io::hashing::keccak_256(&i.to_le_bytes());
+				[bls12_g1_point(i as u64 + 1), scalar.to_vec()].concat()
+			})
+			.collect::<Vec<_>>();
+		let mut call_setup = CallSetup::<T>::default();
+		let (mut ext, _) = call_setup.ext();
+
+		let result;
+		#[block]
+		{
+			result = run_builtin_precompile(
+				&mut ext,
+				H160::from_low_u64_be(0x0c).as_fixed_bytes(),
+				input,
+			);
+		}
+		assert_ok!(result);
+	}
+
+	#[benchmark(pov_mode = Measured)]
+	fn bls12_g2_add() {
+		let input = [bls12_g2_point(1), bls12_g2_point(2)].concat();
+		let mut call_setup = CallSetup::<T>::default();
+		let (mut ext, _) = call_setup.ext();
+
+		let result;
+		#[block]
+		{
+			result = run_builtin_precompile(
+				&mut ext,
+				H160::from_low_u64_be(0x0d).as_fixed_bytes(),
+				input,
+			);
+		}
+
+		assert_eq!(result.unwrap().data, bls12_g2_point(3));
+	}
+
+	// `n`: pairs of point and scalar to multiply
+	#[benchmark(pov_mode = Measured)]
+	fn bls12_g2_msm(n: Linear<1, 128>) {
+		let input = (0..n)
+			.flat_map(|i| {
+				let scalar = sp_io::hashing::keccak_256(&i.to_le_bytes());
+				[bls12_g2_point(i as u64 + 1), scalar.to_vec()].concat()
+			})
+			.collect::<Vec<_>>();
+		let mut call_setup = CallSetup::<T>::default();
+		let (mut ext, _) = call_setup.ext();
+
+		let result;
+		#[block]
+		{
+			result = run_builtin_precompile(
+				&mut ext,
+				H160::from_low_u64_be(0x0e).as_fixed_bytes(),
+				input,
+			);
+		}
+		assert_ok!(result);
+	}
+
+	// `n`: pairings to perform
+	#[benchmark(pov_mode = Measured)]
+	fn bls12_pairing_check(n: Linear<1, 20>) {
+		let input = (0..n)
+			.flat_map(|i| [bls12_g1_point(i as u64 + 1), bls12_g2_point(i as u64 + 1)].concat())
+			.collect::<Vec<_>>();
+		let mut call_setup = CallSetup::<T>::default();
+		let (mut ext, _) = call_setup.ext();
+
+		let result;
+		#[block]
+		{
+			result = run_builtin_precompile(
+				&mut ext,
+				H160::from_low_u64_be(0x0f).as_fixed_bytes(),
+				input,
+			);
+		}
+		assert_ok!(result);
+	}
+
+	#[benchmark(pov_mode = Measured)]
+	fn bls12_map_fp_to_g1() {
+		use ark_ff::PrimeField;
+		let fp = ark_bls12_381::Fq::from_be_bytes_mod_order(&sp_io::hashing::keccak_256(b"fp"));
+		let mut input = Vec::new();
+		bls12_encode_fp(&fp, &mut input);
+		let mut call_setup = CallSetup::<T>::default();
+		let (mut ext, _) = call_setup.ext();
+
+		let result;
+		#[block]
+		{
+			result = run_builtin_precompile(
+				&mut ext,
+				H160::from_low_u64_be(0x10).as_fixed_bytes(),
+				input,
+			);
+		}
+		assert_ok!(result);
+	}
+
+	#[benchmark(pov_mode = Measured)]
+	fn bls12_map_fp2_to_g2() {
+		use ark_ff::PrimeField;
+		let mut input = Vec::new();
+		for seed in [b"c0", b"c1"] {
+			let fp = ark_bls12_381::Fq::from_be_bytes_mod_order(&sp_io::hashing::keccak_256(seed));
+			bls12_encode_fp(&fp, &mut input);
+		}
+		let mut call_setup = CallSetup::<T>::default();
+		let (mut ext, _) = call_setup.ext();
+
+		let result;
+		#[block]
+		{
+			result = run_builtin_precompile(
+				&mut ext,
+				H160::from_low_u64_be(0x11).as_fixed_bytes(),
+				input,
+			);
+		}
+		assert_ok!(result);
+	}
+
 	// Only calling the function itself for the list of
 	// generated different ECDSA keys.
 	// This is a slow call: We reduce the number of runs.
@@ -23,5 +23,10 @@ mod tracing;
 pub use tracing::*;
 mod gas_encoder;
 pub use gas_encoder::*;
+mod delegation;
+pub use delegation::*;
+mod access_list;
+pub use access_list::*;
+pub(crate) mod overrides;
 pub mod runtime;
 pub use alloy_core::sol_types::decode_revert_reason;
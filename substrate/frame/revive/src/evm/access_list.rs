@@ -0,0 +1,114 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! The bounded access list of EIP-2930 transactions.
+//!
+//! See <https://eips.ethereum.org/EIPS/eip-2930>
+use crate::{
+	evm::{AccessList, AccessListEntry},
+	limits,
+};
+use alloc::vec::Vec;
+use codec::{Compact, Decode, DecodeWithMemTracking, Encode, Input};
+use core::ops::Deref;
+use scale_info::TypeInfo;
+use sp_core::{H160, H256};
+
+/// An access list with at most [`limits::ACCESS_LIST_ENTRIES`] addresses and storage keys.
+///
+/// It has the same encoding as an [`AccessList`], but decoding fails as soon as the bound is
+/// exceeded.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Encode, TypeInfo)]
+pub struct BoundedAccessList(AccessList);
+
+impl BoundedAccessList {
+	/// Returns the number of declared addresses and storage keys.
+	pub fn num_entries(&self) -> (u32, u32) {
+		let storage_keys = self.0.iter().map(|entry| entry.storage_keys.len()).sum::<usize>();
+		(self.0.len() as u32, storage_keys as u32)
+	}
+}
+
+impl Deref for BoundedAccessList {
+	type Target = AccessList;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl TryFrom<AccessList> for BoundedAccessList {
+	type Error = AccessList;
+
+	fn try_from(access_list: AccessList) -> Result<Self, Self::Error> {
+		let entries = access_list.iter().fold(access_list.len(), |entries, entry| {
+			entries.saturating_add(entry.storage_keys.len())
+		});
+		if entries > limits::ACCESS_LIST_ENTRIES as usize {
+			return Err(access_list);
+		}
+		Ok(Self(access_list))
+	}
+}
+
+impl Decode for BoundedAccessList {
+	fn decode<I: Input>(input: &mut I) -> Result<Self, codec::Error> {
+		let mut remaining = limits::ACCESS_LIST_ENTRIES;
+		let mut reserve = |entries: u32| {
+			remaining = remaining.checked_sub(entries).ok_or("Too many access list entries")?;
+			Ok::<_, codec::Error>(entries)
+		};
+
+		let addresses = reserve(<Compact<u32>>::decode(input)?.0)?;
+		let mut access_list = Vec::with_capacity(addresses as usize);
+		for _ in 0..addresses {
+			let address = H160::decode(input)?;
+			let storage_keys = reserve(<Compact<u32>>::decode(input)?.0)?;
+			let storage_keys =
+				(0..storage_keys).map(|_| H256::decode(input)).collect::<Result<_, _>>()?;
+			access_list.push(AccessListEntry { address, storage_keys });
+		}
+		Ok(Self(access_list))
+	}
+}
+
+impl DecodeWithMemTracking for BoundedAccessList {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn access_list_is_bounded() {
+		let entry = |storage_keys: usize| AccessListEntry {
+			address: H160::repeat_byte(1),
+			storage_keys: vec![H256::repeat_byte(2); storage_keys],
+		};
+		let max = limits::ACCESS_LIST_ENTRIES as usize;
+
+		// The encoding is the one of an access list.
+		let access_list = vec![entry(max - 2), entry(0)];
+		let bounded = BoundedAccessList::try_from(access_list.clone()).unwrap();
+		assert_eq!(bounded.encode(), access_list.encode());
+		assert_eq!(BoundedAccessList::decode(&mut &access_list.encode()[..]), Ok(bounded));
+
+		// Both the addresses and the storage keys count towards the bound.
+		for access_list in [vec![entry(max)], vec![entry(0); max + 1]] {
+			assert!(BoundedAccessList::try_from(access_list.clone()).is_err());
+			assert!(BoundedAccessList::decode(&mut &access_list.encode()[..]).is_err());
+		}
+	}
+}
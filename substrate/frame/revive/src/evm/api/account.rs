@@ -16,8 +16,8 @@
 // limitations under the License.
 //! Utilities for working with Ethereum accounts.
 use crate::{
-	evm::{TransactionSigned, TransactionUnsigned},
-	H160,
+	evm::{AuthorizationListEntry, TransactionSigned, TransactionUnsigned},
+	H160, U256,
 };
 use sp_runtime::AccountId32;
 
@@ -61,6 +61,22 @@ impl Account {
 		let signature = self.0.sign(&payload).0;
 		tx.with_signature(signature)
 	}
+
+	/// Sign an EIP-7702 authorization delegating the account to the code of `address`.
+	pub fn sign_authorization(
+		&self,
+		chain_id: U256,
+		address: H160,
+		nonce: U256,
+	) -> AuthorizationListEntry {
+		let mut authorization =
+			AuthorizationListEntry { chain_id, address, nonce, ..Default::default() };
+		let signature = self.0.sign(&authorization.signing_payload()).0;
+		authorization.r = U256::from_big_endian(&signature[..32]);
+		authorization.s = U256::from_big_endian(&signature[32..64]);
+		authorization.y_parity = U256::from(signature[64]);
+		authorization
+	}
 }
 
 #[test]
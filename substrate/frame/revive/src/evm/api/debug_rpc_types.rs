@@ -16,7 +16,7 @@
 // limitations under the License.
 
 use crate::evm::Bytes;
-use alloc::{string::String, vec::Vec};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use codec::{Decode, Encode};
 use derive_more::From;
 use scale_info::TypeInfo;
@@ -24,12 +24,20 @@ use serde::{Deserialize, Serialize};
 use sp_core::{H160, H256, U256};
 
 /// The type of tracer to use.
-/// Only "callTracer" is supported for now.
+/// Only "callTracer", "prestateTracer" and the default struct logger are supported for now.
 #[derive(TypeInfo, Debug, Clone, Encode, Decode, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "tracer", content = "tracerConfig", rename_all = "camelCase")]
 pub enum TracerType {
 	/// A tracer that traces calls.
 	CallTracer(Option<CallTracerConfig>),
+
+	/// A tracer that traces the prestate.
+	PrestateTracer(Option<PrestateTracerConfig>),
+
+	/// A tracer that traces every executed instruction.
+	///
+	/// This is the tracer used when no tracer is specified.
+	StructLogger(Option<StructLoggerConfig>),
 }
 
 impl From<CallTracerConfig> for TracerType {
@@ -38,6 +46,18 @@ impl From<CallTracerConfig> for TracerType {
 	}
 }
 
+impl From<PrestateTracerConfig> for TracerType {
+	fn from(config: PrestateTracerConfig) -> Self {
+		TracerType::PrestateTracer(Some(config))
+	}
+}
+
+impl From<StructLoggerConfig> for TracerType {
+	fn from(config: StructLoggerConfig) -> Self {
+		TracerType::StructLogger(Some(config))
+	}
+}
+
 impl Default for TracerType {
 	fn default() -> Self {
 		TracerType::CallTracer(Some(CallTracerConfig::default()))
@@ -46,7 +66,11 @@ impl Default for TracerType {
 
 /// Tracer configuration used to trace calls.
 #[derive(TypeInfo, Debug, Clone, Default, PartialEq)]
-#[cfg_attr(feature = "std", derive(Deserialize, Serialize), serde(rename_all = "camelCase"))]
+#[cfg_attr(
+	feature = "std",
+	derive(Deserialize, Serialize),
+	serde(rename_all = "camelCase", try_from = "TracerConfigHelper")
+)]
 pub struct TracerConfig {
 	/// The tracer type.
 	#[cfg_attr(feature = "std", serde(flatten, default))]
@@ -57,6 +81,46 @@ pub struct TracerConfig {
 	pub timeout: Option<core::time::Duration>,
 }
 
+/// Deserialization helper for [`TracerConfig`].
+///
+/// When no tracer is specified, the options of the struct logger are expected at the top
+/// level, as done by geth. An unknown tracer is rejected rather than silently falling back to
+/// the struct logger.
+#[cfg(feature = "std")]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TracerConfigHelper {
+	Tracer {
+		#[serde(flatten)]
+		config: TracerType,
+		#[serde(with = "humantime_serde", default)]
+		timeout: Option<core::time::Duration>,
+	},
+	StructLogger {
+		#[serde(default)]
+		tracer: Option<String>,
+		#[serde(flatten)]
+		config: StructLoggerConfig,
+		#[serde(with = "humantime_serde", default)]
+		timeout: Option<core::time::Duration>,
+	},
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<TracerConfigHelper> for TracerConfig {
+	type Error = String;
+
+	fn try_from(helper: TracerConfigHelper) -> Result<Self, Self::Error> {
+		match helper {
+			TracerConfigHelper::Tracer { config, timeout } => Ok(TracerConfig { config, timeout }),
+			TracerConfigHelper::StructLogger { tracer: None, config, timeout } =>
+				Ok(TracerConfig { config: config.into(), timeout }),
+			TracerConfigHelper::StructLogger { tracer: Some(tracer), .. } =>
+				Err(alloc::format!("unsupported tracer or invalid tracer config: {tracer}")),
+		}
+	}
+}
+
 /// The configuration for the call tracer.
 #[derive(Clone, Debug, Decode, Serialize, Deserialize, Encode, PartialEq, TypeInfo)]
 #[serde(default, rename_all = "camelCase")]
@@ -74,6 +138,38 @@ impl Default for CallTracerConfig {
 	}
 }
 
+/// The configuration for the prestate tracer.
+#[derive(Clone, Debug, Default, Decode, Serialize, Deserialize, Encode, PartialEq, TypeInfo)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PrestateTracerConfig {
+	/// Whether to include the state after the execution, only reporting the entries that
+	/// changed.
+	pub diff_mode: bool,
+
+	/// Whether to omit the storage entries from the trace.
+	pub disable_storage: bool,
+
+	/// Whether to omit the contract code from the trace.
+	pub disable_code: bool,
+}
+
+/// The configuration for the struct logger.
+#[derive(Clone, Debug, Default, Decode, Serialize, Deserialize, Encode, PartialEq, TypeInfo)]
+#[serde(default, rename_all = "camelCase")]
+pub struct StructLoggerConfig {
+	/// Whether to omit the registers, reported as the stack, from the trace.
+	pub disable_stack: bool,
+
+	/// Whether to omit the memory of the current stack frame from the trace.
+	pub disable_memory: bool,
+
+	/// Whether to omit the storage entries from the trace.
+	pub disable_storage: bool,
+
+	/// The maximum number of instructions to trace, or `0` for no limit.
+	pub limit: u64,
+}
+
 /// Serialization should support the following JSON format:
 ///
 /// ```json
@@ -83,6 +179,14 @@ impl Default for CallTracerConfig {
 /// ```json
 /// { "tracer": "callTracer" }
 /// ```
+///
+/// ```json
+/// { "tracer": "prestateTracer", "tracerConfig": { "diffMode": true } }
+/// ```
+///
+/// ```json
+/// { "disableStack": true, "disableMemory": true }
+/// ```
 #[test]
 fn test_tracer_config_serialization() {
 	let tracers = vec![
@@ -111,6 +215,35 @@ fn test_tracer_config_serialization() {
 				timeout: Some(core::time::Duration::from_millis(10)),
 			},
 		),
+		(
+			r#"{"tracer": "prestateTracer"}"#,
+			TracerConfig { config: TracerType::PrestateTracer(None), timeout: None },
+		),
+		(
+			r#"{"tracer": "prestateTracer", "tracerConfig": { "diffMode": true, "disableCode": true }}"#,
+			TracerConfig {
+				config: PrestateTracerConfig {
+					diff_mode: true,
+					disable_storage: false,
+					disable_code: true,
+				}
+				.into(),
+				timeout: None,
+			},
+		),
+		(r#"{}"#, TracerConfig { config: StructLoggerConfig::default().into(), timeout: None }),
+		(
+			r#"{"disableMemory": true, "limit": 100, "timeout": "10ms"}"#,
+			TracerConfig {
+				config: StructLoggerConfig {
+					disable_memory: true,
+					limit: 100,
+					..Default::default()
+				}
+				.into(),
+				timeout: Some(core::time::Duration::from_millis(10)),
+			},
+		),
 	];
 
 	for (json_data, expected) in tracers {
@@ -118,6 +251,15 @@ fn test_tracer_config_serialization() {
 			serde_json::from_str(json_data).expect("Deserialization should succeed");
 		assert_eq!(result, expected);
 	}
+
+	// Unknown tracers do not fall back to the struct logger.
+	for json_data in [
+		r#"{"tracer": "calltracer"}"#,
+		r#"{"tracer": "4byteTracer", "disableMemory": true}"#,
+		r#"{"tracer": "callTracer", "tracerConfig": { "withLogs": 1 }}"#,
+	] {
+		assert!(serde_json::from_str::<TracerConfig>(json_data).is_err(), "{json_data}");
+	}
 }
 
 /// The type of call that was executed.
@@ -141,6 +283,58 @@ pub enum CallType {
 pub enum Trace {
 	/// A call trace.
 	Call(CallTrace),
+	/// A prestate trace.
+	Prestate(PrestateTrace),
+	/// A struct logger trace.
+	StructLog(StructLogTrace),
+}
+
+/// A prestate Trace
+#[derive(TypeInfo, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum PrestateTrace {
+	/// The state of the accounts touched by the transaction, before it was executed.
+	Prestate(BTreeMap<H160, PrestateTraceInfo>),
+
+	/// The state of the accounts touched by the transaction, before and after it was
+	/// executed. Only the entries that changed are reported.
+	DiffMode {
+		/// The state before the execution.
+		pre: BTreeMap<H160, PrestateTraceInfo>,
+		/// The state after the execution.
+		post: BTreeMap<H160, PrestateTraceInfo>,
+	},
+}
+
+impl PrestateTrace {
+	/// Returns `true` if no account state was recorded.
+	pub fn is_empty(&self) -> bool {
+		match self {
+			PrestateTrace::Prestate(pre) => pre.is_empty(),
+			PrestateTrace::DiffMode { pre, post } => pre.is_empty() && post.is_empty(),
+		}
+	}
+}
+
+/// The state of an account recorded by the prestate tracer.
+#[derive(
+	TypeInfo, Default, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq,
+)]
+pub struct PrestateTraceInfo {
+	/// The balance of the account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub balance: Option<U256>,
+	/// The nonce of the account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub nonce: Option<u32>,
+	/// The code of the contract.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub code: Option<Bytes>,
+	/// The storage entries accessed during the execution.
+	///
+	/// A value of `None` means that the entry does not exist.
+	#[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+	pub storage: BTreeMap<Bytes, Option<Bytes>>,
 }
 
 /// A smart contract execution call trace.
@@ -200,6 +394,56 @@ pub struct CallLog {
 	pub position: u32,
 }
 
+/// The trace of every instruction executed by a transaction, as reported by the struct logger.
+#[derive(
+	TypeInfo, Default, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct StructLogTrace<Gas = u64> {
+	/// Amount of gas used.
+	pub gas: Gas,
+	/// Whether the transaction failed.
+	pub failed: bool,
+	/// Return data of the transaction.
+	pub return_value: Bytes,
+	/// The instructions executed by the transaction.
+	pub struct_logs: Vec<StructLog<Gas>>,
+}
+
+/// An instruction executed during a transaction.
+#[derive(
+	TypeInfo, Default, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct StructLog<Gas = u64> {
+	/// The offset of the instruction in the program.
+	pub pc: u64,
+	/// The name of the instruction.
+	///
+	/// Syscalls with an EVM equivalent are reported with the name of the EVM opcode.
+	pub op: String,
+	/// Amount of gas left before executing the instruction.
+	pub gas: Gas,
+	/// Amount of gas used by the instruction, including the gas used by nested calls.
+	pub gas_cost: Gas,
+	/// The depth of the call stack, starting at 1.
+	pub depth: u32,
+	/// The values of the registers.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub stack: Option<Vec<U256>>,
+	/// The memory of the current stack frame, in 32 bytes words.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub memory: Option<Vec<Bytes>>,
+	/// The storage entries of the current contract accessed so far.
+	///
+	/// Only reported for instructions that access the storage.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub storage: Option<BTreeMap<Bytes, Option<Bytes>>>,
+	/// The error message if the instruction failed.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+}
+
 /// A transaction trace
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct TransactionTrace {
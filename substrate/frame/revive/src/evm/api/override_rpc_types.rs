@@ -0,0 +1,125 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! The state and block overrides of `eth_call` and `eth_estimateGas`.
+//!
+//! See <https://geth.ethereum.org/docs/interacting-with-geth/rpc/ns-eth#eth-call>
+use crate::evm::Bytes;
+use alloc::collections::BTreeMap;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_core::{H160, H256, U256};
+
+/// The state overrides, by address.
+pub type StateOverrideSet = BTreeMap<H160, AccountOverride>;
+
+/// The overrides of the state of an account.
+#[derive(
+	TypeInfo, Default, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountOverride {
+	/// The balance of the account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub balance: Option<U256>,
+	/// The nonce of the account.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub nonce: Option<U256>,
+	/// The code of the account.
+	///
+	/// An empty code removes the code of the account, and a delegation designator delegates
+	/// the account, see [`crate::evm::delegation_designator`].
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub code: Option<Bytes>,
+	/// The storage of the account, replacing all of its current storage.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub state: Option<BTreeMap<H256, H256>>,
+	/// The storage entries of the account to override, leaving the other entries unchanged.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub state_diff: Option<BTreeMap<H256, H256>>,
+}
+
+/// The overrides of the block context.
+///
+/// Only the block number, the timestamp and the fee recipient can be overridden, the other
+/// fields of the geth block overrides are ignored.
+#[derive(
+	TypeInfo, Default, Encode, Decode, Serialize, Deserialize, Clone, Debug, Eq, PartialEq,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockOverrides {
+	/// The block number.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub number: Option<U256>,
+	/// The block timestamp, in seconds.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub time: Option<U256>,
+	/// The address of the block author.
+	#[serde(alias = "coinbase", skip_serializing_if = "Option::is_none")]
+	pub fee_recipient: Option<H160>,
+}
+
+impl BlockOverrides {
+	/// Returns `true` if no field is overridden.
+	pub fn is_empty(&self) -> bool {
+		self == &Self::default()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn deserialize_state_override_set_works() {
+		let json = r#"{
+			"0x0101010101010101010101010101010101010101": {
+				"balance": "0x100",
+				"stateDiff": {
+					"0x0000000000000000000000000000000000000000000000000000000000000001":
+					"0x0000000000000000000000000000000000000000000000000000000000000002"
+				}
+			}
+		}"#;
+		let overrides: StateOverrideSet = serde_json::from_str(json).unwrap();
+		assert_eq!(
+			overrides,
+			[(
+				H160::repeat_byte(1),
+				AccountOverride {
+					balance: Some(256.into()),
+					state_diff: Some([(H256::from_low_u64_be(1), H256::from_low_u64_be(2))].into()),
+					..Default::default()
+				}
+			)]
+			.into()
+		);
+
+		let block: BlockOverrides = serde_json::from_str(
+			r#"{"coinbase": "0x0202020202020202020202020202020202020202", "time": "0x10"}"#,
+		)
+		.unwrap();
+		assert_eq!(
+			block,
+			BlockOverrides {
+				time: Some(16.into()),
+				fee_recipient: Some(H160::repeat_byte(2)),
+				..Default::default()
+			}
+		);
+	}
+}
@@ -21,6 +21,9 @@ use super::*;
 use alloc::vec::Vec;
 use rlp::{Decodable, Encodable};
 
+/// The prefix of the payload signed by the authority of an EIP-7702 authorization.
+const AUTHORIZATION_MAGIC: u8 = 0x05;
+
 impl TransactionUnsigned {
 	/// Return the bytes to be signed by the private key.
 	pub fn unsigned_payload(&self) -> Vec<u8> {
@@ -39,6 +42,10 @@ impl TransactionUnsigned {
 				s.append(&tx.r#type.value());
 				s.append(tx);
 			},
+			Transaction7702Unsigned(ref tx) => {
+				s.append(&tx.r#type.value());
+				s.append(tx);
+			},
 			TransactionLegacyUnsigned(ref tx) => {
 				s.append(tx);
 			},
@@ -57,6 +64,7 @@ impl TransactionSigned {
 			Transaction2930Signed(tx) => Transaction2930Unsigned(tx.transaction_2930_unsigned),
 			Transaction1559Signed(tx) => Transaction1559Unsigned(tx.transaction_1559_unsigned),
 			Transaction4844Signed(tx) => Transaction4844Unsigned(tx.transaction_4844_unsigned),
+			Transaction7702Signed(tx) => Transaction7702Unsigned(tx.transaction_7702_unsigned),
 			TransactionLegacySigned(tx) =>
 				TransactionLegacyUnsigned(tx.transaction_legacy_unsigned),
 		}
@@ -79,6 +87,10 @@ impl TransactionSigned {
 				s.append(&tx.transaction_4844_unsigned.r#type.value());
 				s.append(tx);
 			},
+			Transaction7702Signed(ref tx) => {
+				s.append(&tx.transaction_7702_unsigned.r#type.value());
+				s.append(tx);
+			},
 			TransactionLegacySigned(ref tx) => {
 				s.append(tx);
 			},
@@ -96,6 +108,7 @@ impl TransactionSigned {
 			TYPE_EIP2930 => rlp::decode::<Transaction2930Signed>(&data[1..]).map(Into::into),
 			TYPE_EIP1559 => rlp::decode::<Transaction1559Signed>(&data[1..]).map(Into::into),
 			TYPE_EIP4844 => rlp::decode::<Transaction4844Signed>(&data[1..]).map(Into::into),
+			TYPE_EIP7702 => rlp::decode::<Transaction7702Signed>(&data[1..]).map(Into::into),
 			_ => rlp::decode::<TransactionLegacySigned>(data).map(Into::into),
 		}
 	}
@@ -410,6 +423,107 @@ impl Decodable for Transaction4844Signed {
 	}
 }
 
+impl AuthorizationListEntry {
+	/// Return the bytes signed by the authority: `0x05 || rlp([chain_id, address, nonce])`.
+	pub fn signing_payload(&self) -> Vec<u8> {
+		let mut s = rlp::RlpStream::new_list(3);
+		s.append(&self.chain_id);
+		s.append(&self.address);
+		s.append(&self.nonce);
+		[&[AUTHORIZATION_MAGIC][..], &s.out()[..]].concat()
+	}
+}
+
+/// See <https://eips.ethereum.org/EIPS/eip-7702>
+impl Encodable for AuthorizationListEntry {
+	fn rlp_append(&self, s: &mut rlp::RlpStream) {
+		s.begin_list(6);
+		s.append(&self.chain_id);
+		s.append(&self.address);
+		s.append(&self.nonce);
+		s.append(&self.y_parity);
+		s.append(&self.r);
+		s.append(&self.s);
+	}
+}
+
+/// See <https://eips.ethereum.org/EIPS/eip-7702>
+impl Decodable for AuthorizationListEntry {
+	fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+		Ok(AuthorizationListEntry {
+			chain_id: rlp.val_at(0)?,
+			address: rlp.val_at(1)?,
+			nonce: rlp.val_at(2)?,
+			y_parity: rlp.val_at(3)?,
+			r: rlp.val_at(4)?,
+			s: rlp.val_at(5)?,
+		})
+	}
+}
+
+/// See <https://eips.ethereum.org/EIPS/eip-7702>
+impl Encodable for Transaction7702Unsigned {
+	fn rlp_append(&self, s: &mut rlp::RlpStream) {
+		s.begin_list(10);
+		s.append(&self.chain_id);
+		s.append(&self.nonce);
+		s.append(&self.max_priority_fee_per_gas);
+		s.append(&self.max_fee_per_gas);
+		s.append(&self.gas);
+		s.append(&self.to);
+		s.append(&self.value);
+		s.append(&self.input.0);
+		s.append_list(&self.access_list);
+		s.append_list(&self.authorization_list);
+	}
+}
+
+/// See <https://eips.ethereum.org/EIPS/eip-7702>
+impl Encodable for Transaction7702Signed {
+	fn rlp_append(&self, s: &mut rlp::RlpStream) {
+		let tx = &self.transaction_7702_unsigned;
+		s.begin_list(13);
+		s.append(&tx.chain_id);
+		s.append(&tx.nonce);
+		s.append(&tx.max_priority_fee_per_gas);
+		s.append(&tx.max_fee_per_gas);
+		s.append(&tx.gas);
+		s.append(&tx.to);
+		s.append(&tx.value);
+		s.append(&tx.input.0);
+		s.append_list(&tx.access_list);
+		s.append_list(&tx.authorization_list);
+		s.append(&self.y_parity);
+		s.append(&self.r);
+		s.append(&self.s);
+	}
+}
+
+impl Decodable for Transaction7702Signed {
+	fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
+		Ok(Transaction7702Signed {
+			transaction_7702_unsigned: {
+				Transaction7702Unsigned {
+					chain_id: rlp.val_at(0)?,
+					nonce: rlp.val_at(1)?,
+					max_priority_fee_per_gas: rlp.val_at(2)?,
+					max_fee_per_gas: rlp.val_at(3)?,
+					gas: rlp.val_at(4)?,
+					to: rlp.val_at(5)?,
+					value: rlp.val_at(6)?,
+					input: Bytes(rlp.val_at(7)?),
+					access_list: rlp.list_at(8)?,
+					authorization_list: rlp.list_at(9)?,
+					..Default::default()
+				}
+			},
+			y_parity: rlp.val_at(10)?,
+			r: rlp.val_at(11)?,
+			s: rlp.val_at(12)?,
+		})
+	}
+}
+
 /// See <https://eips.ethereum.org/EIPS/eip-155>
 impl Decodable for TransactionLegacySigned {
 	fn decode(rlp: &rlp::Rlp) -> Result<Self, rlp::DecoderError> {
@@ -557,6 +671,43 @@ mod test {
 					"yParity": "0x0"
 				}
 				"#
+			),
+			// type 4: EIP7702
+			(
+				"04f8fa018080018301e24194095e7baea6a6c7c4c2dfeb977efac326af552d878080f838f7940000000000000000000000000000000000000001e1a00000000000000000000000000000000000000000000000000000000000000000f85cf85a019400000000000000000000000000000000000000028001a0fe38ca4e44a30002ac54af7cf922a6ac2ba11b7d22f548e8ecb3f51f41cb31b0a06de6a5cbae13c0c856e33acf021b51819636cfc009d39eafb9f606d546e305a880a0fe38ca4e44a30002ac54af7cf922a6ac2ba11b7d22f548e8ecb3f51f41cb31b0a06de6a5cbae13c0c856e33acf021b51819636cfc009d39eafb9f606d546e305a8",
+				r#"
+				{
+					"accessList": [
+						{
+						"address": "0x0000000000000000000000000000000000000001",
+						"storageKeys": ["0x0000000000000000000000000000000000000000000000000000000000000000"]
+						}
+					],
+					"authorizationList": [
+						{
+						"chainId": "0x1",
+						"address": "0x0000000000000000000000000000000000000002",
+						"nonce": "0x0",
+						"yParity": "0x1",
+						"r": "0xfe38ca4e44a30002ac54af7cf922a6ac2ba11b7d22f548e8ecb3f51f41cb31b0",
+						"s": "0x6de6a5cbae13c0c856e33acf021b51819636cfc009d39eafb9f606d546e305a8"
+						}
+					],
+					"chainId": "0x1",
+					"gas": "0x1e241",
+					"gasPrice": "0x0",
+					"input": "0x",
+					"maxFeePerGas": "0x1",
+					"maxPriorityFeePerGas": "0x0",
+					"nonce": "0x0",
+					"to": "0x095e7baea6a6c7c4c2dfeb977efac326af552d87",
+					"type": "0x4",
+					"value": "0x0",
+					"r": "0xfe38ca4e44a30002ac54af7cf922a6ac2ba11b7d22f548e8ecb3f51f41cb31b0",
+					"s": "0x6de6a5cbae13c0c856e33acf021b51819636cfc009d39eafb9f606d546e305a8",
+					"yParity": "0x0"
+				}
+				"#
 			)
 		];
 
@@ -17,6 +17,8 @@
 //! Utility impl for the RPC types.
 use super::*;
 use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
 use sp_core::{H160, U256};
 
 impl From<BlockNumberOrTag> for BlockNumberOrTagOrHash {
@@ -32,6 +34,7 @@ impl From<TransactionSigned> for TransactionUnsigned {
 	fn from(tx: TransactionSigned) -> Self {
 		use TransactionSigned::*;
 		match tx {
+			Transaction7702Signed(tx) => tx.transaction_7702_unsigned.into(),
 			Transaction4844Signed(tx) => tx.transaction_4844_unsigned.into(),
 			Transaction1559Signed(tx) => tx.transaction_1559_unsigned.into(),
 			Transaction2930Signed(tx) => tx.transaction_2930_unsigned.into(),
@@ -94,8 +97,8 @@ impl ReceiptInfo {
 		self.status.map_or(false, |status| status == U256::one())
 	}
 
-	/// Calculate receipt logs bloom.
-	fn logs_bloom(logs: &[Log]) -> Bytes256 {
+	/// Calculate the bloom of the given logs.
+	pub fn logs_bloom<'a>(logs: impl IntoIterator<Item = &'a Log>) -> Bytes256 {
 		let mut bloom = [0u8; 256];
 		for log in logs {
 			m3_2048(&mut bloom, &log.address.as_ref());
@@ -106,6 +109,39 @@ impl ReceiptInfo {
 		bloom.into()
 	}
 }
+
+impl Filter {
+	/// Returns `true` if a block or a receipt with the given logs bloom may contain logs matching
+	/// the address and topics criteria of the filter.
+	pub fn matches_bloom(&self, bloom: &Bytes256) -> bool {
+		let contains = |bytes: &[u8]| m3_2048_contains(&bloom.0, bytes);
+
+		let address_matches = match &self.address {
+			None => true,
+			Some(AddressOrAddresses::Address(address)) => contains(address.as_ref()),
+			Some(AddressOrAddresses::Addresses(addresses)) =>
+				addresses.is_empty() || addresses.iter().any(|address| contains(address.as_ref())),
+		};
+
+		let topics_match = self.topics.iter().flatten().all(|topic| match topic {
+			FilterTopic::Single(topic) => contains(topic.as_ref()),
+			FilterTopic::Multiple(topics) =>
+				topics.is_empty() || topics.iter().any(|topic| contains(topic.as_ref())),
+		});
+
+		address_matches && topics_match
+	}
+}
+
+/// Returns the positions of the three bits set by [`m3_2048`], as byte indexes and masks.
+fn m3_2048_bits(bytes: &[u8]) -> [(usize, u8); 3] {
+	let hash = sp_core::keccak_256(bytes);
+	[0, 2, 4].map(|i| {
+		let bit = (hash[i + 1] as usize + ((hash[i] as usize) << 8)) & 0x7FF;
+		(256 - 1 - bit / 8, 1 << (bit % 8))
+	})
+}
+
 /// Specialised Bloom filter that sets three bits out of 2048, given an
 /// arbitrary byte sequence.
 ///
@@ -113,13 +149,16 @@ impl ReceiptInfo {
 ///
 /// [ref]: https://ethereum.github.io/yellowpaper/paper.pdf
 fn m3_2048(bloom: &mut [u8; 256], bytes: &[u8]) {
-	let hash = sp_core::keccak_256(bytes);
-	for i in [0, 2, 4] {
-		let bit = (hash[i + 1] as usize + ((hash[i] as usize) << 8)) & 0x7FF;
-		bloom[256 - 1 - bit / 8] |= 1 << (bit % 8);
+	for (index, mask) in m3_2048_bits(bytes) {
+		bloom[index] |= mask;
 	}
 }
 
+/// Returns `true` if the three bits of `bytes` are set in `bloom`, see [`m3_2048`].
+fn m3_2048_contains(bloom: &[u8; 256], bytes: &[u8]) -> bool {
+	m3_2048_bits(bytes).iter().all(|(index, mask)| bloom[*index] & mask != 0)
+}
+
 #[test]
 fn can_deserialize_input_or_data_field_from_generic_transaction() {
 	let cases = [
@@ -200,6 +239,17 @@ fn logs_bloom_works() {
 	)
 	.unwrap();
 	assert_eq!(receipt.logs_bloom, ReceiptInfo::logs_bloom(&receipt.logs));
+
+	let log = &receipt.logs[0];
+	let filter = Filter { address: Some(log.address.into()), ..Default::default() };
+	assert!(filter.matches_bloom(&receipt.logs_bloom));
+	let filter = Filter {
+		topics: Some(vec![FilterTopic::Multiple(vec![H256::zero(), log.topics[0]])]),
+		..Default::default()
+	};
+	assert!(filter.matches_bloom(&receipt.logs_bloom));
+	let filter = Filter { address: Some(H160::repeat_byte(0xff).into()), ..Default::default() };
+	assert!(!filter.matches_bloom(&receipt.logs_bloom));
 }
 
 impl GenericTransaction {
@@ -228,6 +278,26 @@ impl GenericTransaction {
 				gas_price: Some(tx.gas_price),
 				..Default::default()
 			},
+			Transaction7702Unsigned(tx) => GenericTransaction {
+				from,
+				r#type: Some(tx.r#type.as_byte()),
+				chain_id: Some(tx.chain_id),
+				input: tx.input.into(),
+				nonce: Some(tx.nonce),
+				value: Some(tx.value),
+				to: Some(tx.to),
+				gas: Some(tx.gas),
+				gas_price: Some(
+					base_gas_price
+						.saturating_add(tx.max_priority_fee_per_gas)
+						.min(tx.max_fee_per_gas),
+				),
+				access_list: Some(tx.access_list),
+				authorization_list: tx.authorization_list,
+				max_fee_per_gas: Some(tx.max_fee_per_gas),
+				max_priority_fee_per_gas: Some(tx.max_priority_fee_per_gas),
+				..Default::default()
+			},
 			Transaction4844Unsigned(tx) => GenericTransaction {
 				from,
 				r#type: Some(tx.r#type.as_byte()),
@@ -339,11 +409,86 @@ impl GenericTransaction {
 				blob_versioned_hashes: self.blob_versioned_hashes,
 			}
 			.into()),
+			TYPE_EIP7702 => Ok(Transaction7702Unsigned {
+				r#type: TypeEip7702 {},
+				chain_id: self.chain_id.unwrap_or_default(),
+				input: self.input.to_bytes(),
+				nonce: self.nonce.unwrap_or_default(),
+				value: self.value.unwrap_or_default(),
+				to: self.to.unwrap_or_default(),
+				gas: self.gas.unwrap_or_default(),
+				gas_price: self.max_fee_per_gas.unwrap_or_default(),
+				max_fee_per_gas: self.max_fee_per_gas.unwrap_or_default(),
+				max_priority_fee_per_gas: self.max_priority_fee_per_gas.unwrap_or_default(),
+				access_list: self.access_list.unwrap_or_default(),
+				authorization_list: self.authorization_list,
+			}
+			.into()),
 			_ => Err(()),
 		}
 	}
 }
 
+/// A [`GenericTransaction`] as encoded in version 1 of the `ReviveApi`, before the EIP-7702
+/// authorization list was added.
+///
+/// The fields are those of [`GenericTransaction`], in the same order.
+#[allow(missing_docs)]
+#[derive(Debug, Default, Clone, Encode, Decode, TypeInfo, Eq, PartialEq)]
+pub struct GenericTransactionV1 {
+	pub access_list: Option<AccessList>,
+	pub blob_versioned_hashes: Vec<H256>,
+	pub blobs: Vec<Bytes>,
+	pub chain_id: Option<U256>,
+	pub from: Option<Address>,
+	pub gas: Option<U256>,
+	pub gas_price: Option<U256>,
+	pub input: InputOrData,
+	pub max_fee_per_blob_gas: Option<U256>,
+	pub max_fee_per_gas: Option<U256>,
+	pub max_priority_fee_per_gas: Option<U256>,
+	pub nonce: Option<U256>,
+	pub to: Option<Address>,
+	pub r#type: Option<Byte>,
+	pub value: Option<U256>,
+}
+
+impl From<GenericTransaction> for GenericTransactionV1 {
+	fn from(tx: GenericTransaction) -> Self {
+		Self {
+			access_list: tx.access_list,
+			blob_versioned_hashes: tx.blob_versioned_hashes,
+			blobs: tx.blobs,
+			chain_id: tx.chain_id,
+			from: tx.from,
+			gas: tx.gas,
+			gas_price: tx.gas_price,
+			input: tx.input,
+			max_fee_per_blob_gas: tx.max_fee_per_blob_gas,
+			max_fee_per_gas: tx.max_fee_per_gas,
+			max_priority_fee_per_gas: tx.max_priority_fee_per_gas,
+			nonce: tx.nonce,
+			to: tx.to,
+			r#type: tx.r#type,
+			value: tx.value,
+		}
+	}
+}
+
+#[test]
+fn generic_transaction_v1_encoding_is_a_prefix() {
+	let tx = GenericTransaction {
+		chain_id: Some(U256::from(1)),
+		input: Bytes::from(vec![1u8]).into(),
+		nonce: Some(U256::from(2)),
+		to: Some(H160::repeat_byte(1)),
+		..Default::default()
+	};
+	let encoded = tx.encode();
+	// The empty authorization list is encoded as a single byte.
+	assert_eq!(GenericTransactionV1::from(tx).encode(), encoded[..encoded.len() - 1]);
+}
+
 #[test]
 fn from_unsigned_works_for_legacy() {
 	let base_gas_price = U256::from(10);
@@ -17,9 +17,9 @@
 //! Generated JSON-RPC types.
 #![allow(missing_docs)]
 
-use super::{byte::*, TypeEip1559, TypeEip2930, TypeEip4844, TypeLegacy};
-use alloc::vec::Vec;
-use codec::{Decode, Encode};
+use super::{byte::*, TypeEip1559, TypeEip2930, TypeEip4844, TypeEip7702, TypeLegacy};
+use alloc::{string::String, vec::Vec};
+use codec::{Decode, DecodeWithMemTracking, Encode};
 use derive_more::{From, TryInto};
 pub use ethereum_types::*;
 use scale_info::TypeInfo;
@@ -311,6 +311,13 @@ pub struct GenericTransaction {
 	/// value
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub value: Option<U256>,
+	/// authorizationList
+	/// EIP-7702 authorization list
+	///
+	/// Added last, the other fields are encoded as in version 1 of the `ReviveApi`, see
+	/// [`super::GenericTransactionV1`].
+	#[serde(rename = "authorizationList", default, skip_serializing_if = "Vec::is_empty")]
+	pub authorization_list: AuthorizationList,
 }
 
 /// Receipt information
@@ -420,6 +427,7 @@ pub struct TransactionInfo {
 #[derive(Debug, Clone, Serialize, Deserialize, From, TryInto, Eq, PartialEq)]
 #[serde(untagged)]
 pub enum TransactionUnsigned {
+	Transaction7702Unsigned(Transaction7702Unsigned),
 	Transaction4844Unsigned(Transaction4844Unsigned),
 	Transaction1559Unsigned(Transaction1559Unsigned),
 	Transaction2930Unsigned(Transaction2930Unsigned),
@@ -434,6 +442,22 @@ impl Default for TransactionUnsigned {
 /// Access list
 pub type AccessList = Vec<AccessListEntry>;
 
+/// Access list result
+#[derive(
+	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
+)]
+pub struct AccessListResult {
+	#[serde(rename = "accessList")]
+	pub access_list: AccessList,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub error: Option<String>,
+	#[serde(rename = "gasUsed")]
+	pub gas_used: U256,
+}
+
+/// Authorization list
+pub type AuthorizationList = Vec<AuthorizationListEntry>;
+
 /// Address(es)
 #[derive(Debug, Clone, Serialize, Deserialize, From, TryInto, Eq, PartialEq)]
 #[serde(untagged)]
@@ -650,6 +674,50 @@ pub struct Transaction4844Unsigned {
 	pub value: U256,
 }
 
+/// EIP-7702 transaction.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Transaction7702Unsigned {
+	/// accessList
+	/// EIP-2930 access list
+	#[serde(rename = "accessList")]
+	pub access_list: AccessList,
+	/// authorizationList
+	/// EIP-7702 authorization list
+	#[serde(rename = "authorizationList")]
+	pub authorization_list: AuthorizationList,
+	/// chainId
+	/// Chain ID that this transaction is valid on.
+	#[serde(rename = "chainId")]
+	pub chain_id: U256,
+	/// gas limit
+	pub gas: U256,
+	/// gas price
+	/// The effective gas price paid by the sender in wei. For transactions not yet included in a
+	/// block, this value should be set equal to the max fee per gas. This field is DEPRECATED,
+	/// please transition to using effectiveGasPrice in the receipt object going forward.
+	#[serde(rename = "gasPrice")]
+	pub gas_price: U256,
+	/// input data
+	pub input: Bytes,
+	/// max fee per gas
+	/// The maximum total fee per gas the sender is willing to pay (includes the network / base fee
+	/// and miner / priority fee) in wei
+	#[serde(rename = "maxFeePerGas")]
+	pub max_fee_per_gas: U256,
+	/// max priority fee per gas
+	/// Maximum fee per gas the sender is willing to pay to miners in wei
+	#[serde(rename = "maxPriorityFeePerGas")]
+	pub max_priority_fee_per_gas: U256,
+	/// nonce
+	pub nonce: U256,
+	/// to address
+	pub to: Address,
+	/// type
+	pub r#type: TypeEip7702,
+	/// value
+	pub value: U256,
+}
+
 /// Legacy transaction.
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub struct TransactionLegacyUnsigned {
@@ -678,6 +746,7 @@ pub struct TransactionLegacyUnsigned {
 #[derive(Debug, Clone, Serialize, Deserialize, From, TryInto, Eq, PartialEq)]
 #[serde(untagged)]
 pub enum TransactionSigned {
+	Transaction7702Signed(Transaction7702Signed),
 	Transaction4844Signed(Transaction4844Signed),
 	Transaction1559Signed(Transaction1559Signed),
 	Transaction2930Signed(Transaction2930Signed),
@@ -705,7 +774,17 @@ pub struct Withdrawal {
 
 /// Access list entry
 #[derive(
-	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
+	Debug,
+	Default,
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+	Eq,
+	PartialEq,
 )]
 pub struct AccessListEntry {
 	pub address: Address,
@@ -713,6 +792,40 @@ pub struct AccessListEntry {
 	pub storage_keys: Vec<H256>,
 }
 
+/// Authorization list entry
+#[derive(
+	Debug,
+	Default,
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+	Eq,
+	PartialEq,
+)]
+pub struct AuthorizationListEntry {
+	/// chainId
+	/// Chain ID that this authorization is valid on, or zero for all chains.
+	#[serde(rename = "chainId")]
+	pub chain_id: U256,
+	/// The address of the account whose code the authority delegates to.
+	pub address: Address,
+	/// nonce
+	/// The nonce of the authority at the time the authorization is applied.
+	pub nonce: U256,
+	/// yParity
+	/// The parity (0 for even, 1 for odd) of the y-value of the secp256k1 signature.
+	#[serde(rename = "yParity")]
+	pub y_parity: U256,
+	/// r
+	pub r: U256,
+	/// s
+	pub s: U256,
+}
+
 /// Filter Topic List Entry
 #[derive(Debug, Clone, Serialize, Deserialize, From, TryInto, Eq, PartialEq)]
 #[serde(untagged)]
@@ -783,6 +896,21 @@ pub struct Transaction4844Signed {
 	pub y_parity: U256,
 }
 
+/// Signed 7702 Transaction
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Transaction7702Signed {
+	#[serde(flatten)]
+	pub transaction_7702_unsigned: Transaction7702Unsigned,
+	/// r
+	pub r: U256,
+	/// s
+	pub s: U256,
+	/// yParity
+	/// The parity (0 for even, 1 for odd) of the y-value of the secp256k1 signature.
+	#[serde(rename = "yParity")]
+	pub y_parity: U256,
+}
+
 /// Signed Legacy Transaction
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub struct TransactionLegacySigned {
@@ -821,3 +949,36 @@ pub struct FeeHistoryResult {
 	#[serde(default, skip_serializing_if = "Vec::is_empty")]
 	pub reward: Vec<Vec<U256>>,
 }
+
+/// Account proof
+///
+/// The proofs are substrate trie proofs, see [`crate::proof`] for how to verify them.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountProof {
+	/// The address of the account.
+	pub address: Address,
+	/// The nodes of the proof of the account entries in the state trie.
+	pub account_proof: Vec<Bytes>,
+	/// The balance of the account.
+	pub balance: U256,
+	/// The keccak-256 hash of the code of the account.
+	pub code_hash: H256,
+	/// The nonce of the account.
+	pub nonce: U256,
+	/// The root of the contract storage child trie, or zero if the account has no storage.
+	pub storage_hash: H256,
+	/// The proofs of the requested storage slots.
+	pub storage_proof: Vec<StorageProof>,
+}
+
+/// Storage proof
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct StorageProof {
+	/// The storage slot.
+	pub key: U256,
+	/// The value of the storage slot.
+	pub value: U256,
+	/// The nodes of the proof of the storage slot in the contract storage child trie.
+	pub proof: Vec<Bytes>,
+}
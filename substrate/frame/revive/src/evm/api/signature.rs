@@ -42,6 +42,8 @@ impl TransactionUnsigned {
 		match tx {
 			TransactionSigned::TransactionLegacySigned(signed) =>
 				Self::TransactionLegacyUnsigned(signed.transaction_legacy_unsigned),
+			TransactionSigned::Transaction7702Signed(signed) =>
+				Self::Transaction7702Unsigned(signed.transaction_7702_unsigned),
 			TransactionSigned::Transaction4844Signed(signed) =>
 				Self::Transaction4844Unsigned(signed.transaction_4844_unsigned),
 			TransactionSigned::Transaction1559Signed(signed) =>
@@ -77,6 +79,15 @@ impl TransactionUnsigned {
 				}
 				.into(),
 
+			TransactionUnsigned::Transaction7702Unsigned(transaction_7702_unsigned) =>
+				Transaction7702Signed {
+					transaction_7702_unsigned,
+					r,
+					s,
+					y_parity: U256::from(recovery_id),
+				}
+				.into(),
+
 			TransactionUnsigned::Transaction4844Unsigned(transaction_4844_unsigned) =>
 				Transaction4844Signed {
 					transaction_4844_unsigned,
@@ -108,6 +119,7 @@ impl TransactionSigned {
 		use TransactionSigned::*;
 		let (r, s, v) = match self {
 			TransactionLegacySigned(tx) => (tx.r, tx.s, tx.extract_recovery_id().ok_or(())?),
+			Transaction7702Signed(tx) => (tx.r, tx.s, tx.y_parity.try_into().map_err(|_| ())?),
 			Transaction4844Signed(tx) => (tx.r, tx.s, tx.y_parity.try_into().map_err(|_| ())?),
 			Transaction1559Signed(tx) => (tx.r, tx.s, tx.y_parity.try_into().map_err(|_| ())?),
 			Transaction2930Signed(tx) => (tx.r, tx.s, tx.y_parity.try_into().map_err(|_| ())?),
@@ -129,6 +141,11 @@ impl TransactionSigned {
 				let tx = &tx.transaction_legacy_unsigned;
 				s.append(tx);
 			},
+			Transaction7702Signed(tx) => {
+				let tx = &tx.transaction_7702_unsigned;
+				s.append(&tx.r#type.value());
+				s.append(tx);
+			},
 			Transaction4844Signed(tx) => {
 				let tx = &tx.transaction_4844_unsigned;
 				s.append(&tx.r#type.value());
@@ -147,15 +164,41 @@ impl TransactionSigned {
 		}
 		let bytes = s.out().to_vec();
 		let signature = self.raw_signature()?;
+		recover_address(&signature, &bytes)
+	}
+}
 
-		let hash = keccak_256(&bytes);
-		let mut addr = H160::default();
-		let pk = secp256k1_ecdsa_recover(&signature, &hash).map_err(|_| ())?;
-		addr.assign_from_slice(&keccak_256(&pk[..])[12..]);
-		Ok(addr)
+impl AuthorizationListEntry {
+	/// Recover the address of the authority that signed the authorization.
+	///
+	/// Fails if the signature is invalid, or is malleable, i.e. `s` is greater than
+	/// `secp256k1n / 2` or `y_parity` is not 0 or 1, as required by EIP-7702.
+	/// See <https://eips.ethereum.org/EIPS/eip-7702>
+	pub fn recover_authority(&self) -> Result<H160, ()> {
+		/// Half of the order of the secp256k1 curve.
+		const SECP256K1N_HALF: U256 =
+			U256([0xdfe92f46681b20a0, 0x5d576e7357a4501d, 0xffffffffffffffff, 0x7fffffffffffffff]);
+
+		if self.s > SECP256K1N_HALF || self.y_parity > U256::one() {
+			return Err(());
+		}
+		let mut signature = [0u8; 65];
+		self.r.write_as_big_endian(signature[0..32].as_mut());
+		self.s.write_as_big_endian(signature[32..64].as_mut());
+		signature[64] = self.y_parity.low_u32() as u8;
+		recover_address(&signature, &self.signing_payload())
 	}
 }
 
+/// Recover the Ethereum address that signed the keccak hash of the given payload.
+fn recover_address(signature: &[u8; 65], payload: &[u8]) -> Result<H160, ()> {
+	let hash = keccak_256(payload);
+	let mut addr = H160::default();
+	let pk = secp256k1_ecdsa_recover(signature, &hash).map_err(|_| ())?;
+	addr.assign_from_slice(&keccak_256(&pk[..])[12..]);
+	Ok(addr)
+}
+
 #[test]
 fn sign_and_recover_work() {
 	use crate::evm::TransactionUnsigned;
@@ -185,3 +228,21 @@ fn sign_and_recover_work() {
 		assert_eq!(tx, signed);
 	}
 }
+
+#[test]
+fn sign_and_recover_authorization_work() {
+	let account = Account::default();
+	let authorization = account.sign_authorization(1.into(), H160::repeat_byte(0x42), 7.into());
+	assert_eq!(authorization.address, H160::repeat_byte(0x42));
+	assert_eq!(authorization.recover_authority(), Ok(account.address()));
+
+	// Tampering with the authorization changes the recovered authority.
+	let tampered = AuthorizationListEntry { nonce: 8.into(), ..authorization.clone() };
+	assert_ne!(tampered.recover_authority(), Ok(account.address()));
+
+	// Malleable signatures are rejected.
+	let malleable = AuthorizationListEntry { s: U256::MAX, ..authorization.clone() };
+	assert_eq!(malleable.recover_authority(), Err(()));
+	let invalid_parity = AuthorizationListEntry { y_parity: 27.into(), ..authorization };
+	assert_eq!(invalid_parity.recover_authority(), Err(()));
+}
@@ -0,0 +1,109 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Support for the code delegations of EIP-7702 set-code transactions.
+//!
+//! An authority delegates its account to the code of another account by signing an
+//! [`AuthorizationListEntry`]. Calls to a delegated account execute the code of the delegation
+//! target in the context of the delegated account, and the code of the delegated account is
+//! reported as the delegation designator `0xef0100 || target`.
+//!
+//! See <https://eips.ethereum.org/EIPS/eip-7702>
+use crate::{
+	evm::AuthorizationListEntry, limits, tracing::if_tracing, AddressMapper, Config,
+	ContractInfoOf, DelegationOf, LOG_TARGET,
+};
+use alloc::vec::Vec;
+use frame_support::{traits::ConstU32, BoundedVec};
+use sp_core::{Get, H160, H256, U256};
+use sp_runtime::SaturatedConversion;
+
+/// The authorization list of a set-code transaction, bounded by [`limits::AUTHORIZATIONS`].
+pub type BoundedAuthorizationList =
+	BoundedVec<AuthorizationListEntry, ConstU32<{ limits::AUTHORIZATIONS }>>;
+
+/// The prefix of a delegation designator.
+pub const DELEGATION_PREFIX: [u8; 3] = [0xef, 0x01, 0x00];
+
+/// Returns the delegation designator of an account delegated to `target`.
+pub fn delegation_designator(target: &H160) -> Vec<u8> {
+	[&DELEGATION_PREFIX[..], target.as_bytes()].concat()
+}
+
+/// Returns the delegation designator of `address`, if the account is delegated.
+pub(crate) fn delegation_designator_of<T: Config>(address: &H160) -> Option<Vec<u8>> {
+	<DelegationOf<T>>::get(address).map(|target| delegation_designator(&target))
+}
+
+/// Apply the authorizations of a set-code transaction, in order.
+///
+/// Invalid authorizations are skipped, as required by EIP-7702. Returns the number of applied
+/// authorizations.
+pub(crate) fn apply_authorizations<T: Config>(
+	authorization_list: &[AuthorizationListEntry],
+) -> u32 {
+	let mut applied = 0;
+	for authorization in authorization_list {
+		match apply_authorization::<T>(authorization) {
+			Ok(()) => applied += 1,
+			Err(reason) => {
+				log::debug!(target: LOG_TARGET, "Skipping authorization {authorization:?}: {reason}")
+			},
+		}
+	}
+	applied
+}
+
+/// Apply a single authorization.
+///
+/// Unlike Ethereum, the authority must already exist, as creating an account requires the
+/// existential deposit.
+fn apply_authorization<T: Config>(
+	authorization: &AuthorizationListEntry,
+) -> Result<(), &'static str> {
+	let chain_id: U256 = T::ChainId::get().into();
+	if !authorization.chain_id.is_zero() && authorization.chain_id != chain_id {
+		return Err("invalid chain id");
+	}
+	if authorization.nonce >= U256::from(u64::MAX) {
+		return Err("invalid nonce");
+	}
+
+	let authority = authorization.recover_authority().map_err(|_| "invalid signature")?;
+	if_tracing(|tracer| tracer.watch_address(&authority));
+
+	// Delegated accounts only have a contract info, with no code, to hold their storage.
+	if <ContractInfoOf<T>>::get(&authority).is_some_and(|info| info.code_hash != H256::zero()) {
+		return Err("authority is a contract");
+	}
+
+	let account_id = T::AddressMapper::to_account_id(&authority);
+	if !frame_system::Pallet::<T>::account_exists(&account_id) {
+		return Err("authority does not exist");
+	}
+	let nonce: u64 = frame_system::Pallet::<T>::account_nonce(&account_id).saturated_into();
+	if authorization.nonce != U256::from(nonce) {
+		return Err("nonce mismatch");
+	}
+
+	if authorization.address.is_zero() {
+		<DelegationOf<T>>::remove(&authority);
+	} else {
+		<DelegationOf<T>>::insert(&authority, authorization.address);
+	}
+	frame_system::Pallet::<T>::inc_account_nonce(&account_id);
+	Ok(())
+}
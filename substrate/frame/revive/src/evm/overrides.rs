@@ -0,0 +1,209 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Support for the state and block overrides of `eth_call` and `eth_estimateGas`.
+//!
+//! The state overrides are written to the state before the dry run. As the runtime API calls are
+//! executed on top of an overlay, they are discarded along with the changes of the dry run.
+//! The block overrides are applied to the call stacks created during the dry run.
+use crate::{
+	evm::{AccountOverride, BlockOverrides, StateOverrideSet, DELEGATION_PREFIX},
+	exec::{Executable, Key, MomentOf},
+	storage::ContractInfo,
+	wasm::{CodeInfo, WasmBlob},
+	AddressMapper, BalanceOf, Config, ContractInfoOf, ConversionPrecision, DelegationOf,
+	EthTransactError, Pallet,
+};
+use alloc::{format, string::String, vec::Vec};
+use environmental::environmental;
+use frame_support::{
+	storage::child,
+	traits::{
+		fungible::{Inspect, Mutate},
+		IsType,
+	},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_core::{H160, H256, U256};
+use sp_runtime::{
+	traits::{Bounded, Saturating},
+	SaturatedConversion,
+};
+
+environmental!(block_overrides: BlockOverrides);
+
+/// Run the given closure with the given block overrides.
+pub(crate) fn with_block_overrides<R>(overrides: &mut BlockOverrides, f: impl FnOnce() -> R) -> R {
+	block_overrides::using_once(overrides, f)
+}
+
+/// Returns the block number, or its override.
+pub(crate) fn block_number<T: Config>() -> BlockNumberFor<T> {
+	block_overrides::with(|overrides| overrides.number)
+		.flatten()
+		.and_then(|number| number.try_into().ok())
+		.unwrap_or_else(frame_system::Pallet::<T>::block_number)
+}
+
+/// Returns the timestamp of the block in milliseconds, or its override.
+pub(crate) fn timestamp<T: Config>() -> MomentOf<T> {
+	use frame_support::traits::Time;
+	block_overrides::with(|overrides| overrides.time)
+		.flatten()
+		.and_then(|time| u64::try_from(time.saturating_mul(1000u32.into())).ok())
+		.map(|time| time.saturated_into())
+		.unwrap_or_else(T::Time::now)
+}
+
+/// Returns the overridden block author, if any.
+pub(crate) fn block_author<T: Config>() -> Option<T::AccountId> {
+	block_overrides::with(|overrides| overrides.fee_recipient)
+		.flatten()
+		.map(|address| T::AddressMapper::to_account_id(&address))
+}
+
+/// Write the given state overrides to the state.
+pub(crate) fn apply_state_overrides<T: Config>(
+	overrides: StateOverrideSet,
+) -> Result<(), EthTransactError>
+where
+	BalanceOf<T>: Into<U256> + TryFrom<U256> + Bounded,
+	MomentOf<T>: Into<U256>,
+	T::Hash: IsType<H256>,
+{
+	for (address, account_override) in overrides {
+		apply_account_override::<T>(&address, account_override).map_err(|reason| {
+			EthTransactError::Message(format!("Failed to override account {address:?}: {reason}"))
+		})?;
+	}
+	Ok(())
+}
+
+/// Apply the overrides of a single account.
+fn apply_account_override<T: Config>(
+	address: &H160,
+	account_override: AccountOverride,
+) -> Result<(), String>
+where
+	BalanceOf<T>: Into<U256> + TryFrom<U256> + Bounded,
+	MomentOf<T>: Into<U256>,
+	T::Hash: IsType<H256>,
+{
+	let AccountOverride { balance, nonce, code, state, state_diff } = account_override;
+	let account_id = T::AddressMapper::to_account_id(address);
+	let min_balance = T::Currency::minimum_balance();
+
+	// The existential deposit is added to the balance, so that the balance reported by
+	// `eth_getBalance` matches the override.
+	let balance = match balance {
+		Some(balance) => Some(
+			Pallet::<T>::convert_evm_to_native(balance, ConversionPrecision::RoundUp)
+				.map_err(|_| "invalid balance")?
+				.saturating_add(min_balance),
+		),
+		None if !frame_system::Pallet::<T>::account_exists(&account_id) => Some(min_balance),
+		None => None,
+	};
+	if let Some(balance) = balance {
+		T::Currency::set_balance(&account_id, balance);
+	}
+
+	if let Some(nonce) = nonce {
+		let nonce: u64 = nonce.try_into().map_err(|_| "invalid nonce")?;
+		frame_system::Account::<T>::mutate(&account_id, |account| {
+			account.nonce = nonce.saturated_into()
+		});
+	}
+
+	if let Some(code) = code {
+		set_code::<T>(address, &account_id, code.0)?;
+	}
+
+	let (entries, clear) = match (state, state_diff) {
+		(Some(_), Some(_)) => return Err("both state and stateDiff are overridden".into()),
+		(Some(state), None) => (state, true),
+		(None, Some(state_diff)) => (state_diff, false),
+		(None, None) => return Ok(()),
+	};
+	let info = match <ContractInfoOf<T>>::get(address) {
+		Some(info) => info,
+		// The storage of a delegated account is created on first use.
+		None if <DelegationOf<T>>::contains_key(address) => {
+			let info = ContractInfo::<T>::new(address, 0u32.into(), H256::zero())
+				.map_err(|err| format!("{err:?}"))?;
+			<ContractInfoOf<T>>::insert(address, &info);
+			info
+		},
+		None => return Err("the storage of an account without code can't be overridden".into()),
+	};
+	if clear {
+		let _ = child::clear_storage(&info.child_trie_info(), None, None);
+	}
+	for (key, value) in entries {
+		// Like `set_storage_or_clear`, a zero value removes the entry.
+		let value = (!value.is_zero()).then(|| value.0.to_vec());
+		info.write(&Key::from_fixed(key.0), value, None, false)
+			.map_err(|err| format!("{err:?}"))?;
+	}
+	Ok(())
+}
+
+/// Replace the code of an account.
+///
+/// The code is stored without collecting a deposit.
+fn set_code<T: Config>(
+	address: &H160,
+	account_id: &T::AccountId,
+	code: Vec<u8>,
+) -> Result<(), String>
+where
+	BalanceOf<T>: Into<U256> + TryFrom<U256>,
+{
+	<DelegationOf<T>>::remove(address);
+	if let Some(info) = <ContractInfoOf<T>>::get(address) {
+		if info.code_hash != H256::zero() {
+			let _ = CodeInfo::<T>::decrement_refcount(info.code_hash);
+		}
+	}
+
+	if code.is_empty() {
+		<ContractInfoOf<T>>::remove(address);
+		return Ok(());
+	}
+
+	if code.len() == DELEGATION_PREFIX.len() + 20 && code.starts_with(&DELEGATION_PREFIX) {
+		<ContractInfoOf<T>>::remove(address);
+		<DelegationOf<T>>::insert(address, H160::from_slice(&code[DELEGATION_PREFIX.len()..]));
+		return Ok(());
+	}
+
+	let mut module =
+		WasmBlob::<T>::from_code(code, account_id.clone()).map_err(|err| format!("{err:?}"))?;
+	module.store_code(true).map_err(|err| format!("{err:?}"))?;
+	let code_hash = *module.code_hash();
+	CodeInfo::<T>::increment_refcount(code_hash).map_err(|err| format!("{err:?}"))?;
+
+	let mut info = match <ContractInfoOf<T>>::get(address) {
+		Some(info) => info,
+		None => {
+			let nonce = frame_system::Pallet::<T>::account_nonce(account_id);
+			ContractInfo::new(address, nonce, code_hash).map_err(|err| format!("{err:?}"))?
+		},
+	};
+	info.code_hash = code_hash;
+	<ContractInfoOf<T>>::insert(address, info);
+	Ok(())
+}
@@ -17,8 +17,8 @@
 //! Runtime types for integrating `pallet-revive` with the EVM.
 use crate::{
 	evm::{
-		api::{GenericTransaction, TransactionSigned},
-		GasEncoder,
+		api::{GenericTransaction, TransactionSigned, TYPE_EIP7702},
+		BoundedAccessList, BoundedAuthorizationList, GasEncoder,
 	},
 	AccountIdOf, AddressMapper, BalanceOf, Config, ConversionPrecision, MomentOf, Pallet,
 	LOG_TARGET,
@@ -301,9 +301,42 @@ pub trait EthExtra {
 			InvalidTransaction::BadProof
 		})?;
 
+		// Let tracers record the sender and destination before the transaction extensions bump
+		// the nonce and withdraw the fees.
+		let signer_addr = signer;
 		let signer = <Self::Config as Config>::AddressMapper::to_fallback_account_id(&signer);
-		let GenericTransaction { nonce, chain_id, to, value, input, gas, gas_price, .. } =
-			GenericTransaction::from_signed(tx, crate::GAS_PRICE.into(), None);
+		let GenericTransaction {
+			nonce,
+			chain_id,
+			to,
+			value,
+			input,
+			gas,
+			gas_price,
+			access_list,
+			authorization_list,
+			r#type,
+			..
+		} = GenericTransaction::from_signed(tx, crate::GAS_PRICE.into(), None);
+
+		crate::tracing::if_tracing(|tracer| {
+			tracer.watch_address(&signer_addr);
+			if let Some(to) = &to {
+				tracer.watch_address(to);
+			}
+		});
+
+		// See <https://eips.ethereum.org/EIPS/eip-7702>
+		if r#type == Some(TYPE_EIP7702.into()) && authorization_list.is_empty() {
+			log::debug!(target: LOG_TARGET, "Empty authorization list");
+			return Err(InvalidTransaction::Call);
+		}
+
+		let authorization_list: BoundedAuthorizationList =
+			authorization_list.try_into().map_err(|_| {
+				log::debug!(target: LOG_TARGET, "Too many authorizations");
+				InvalidTransaction::Call
+			})?;
 
 		let Some(gas) = gas else {
 			log::debug!(target: LOG_TARGET, "No gas provided");
@@ -332,15 +365,40 @@ pub trait EthExtra {
 				InvalidTransaction::Call
 			})?;
 
+		let access_list: BoundedAccessList =
+			access_list.unwrap_or_default().try_into().map_err(|_| {
+				log::debug!(target: LOG_TARGET, "Too many access list entries");
+				InvalidTransaction::Call
+			})?;
 		let call = if let Some(dest) = to {
-			crate::Call::call::<Self::Config> {
-				dest,
-				value,
-				gas_limit,
-				storage_deposit_limit,
-				data,
+			match (access_list.is_empty(), authorization_list.is_empty()) {
+				(true, true) => crate::Call::call::<Self::Config> {
+					dest,
+					value,
+					gas_limit,
+					storage_deposit_limit,
+					data,
+				},
+				(false, true) => crate::Call::call_with_access_list::<Self::Config> {
+					dest,
+					value,
+					gas_limit,
+					storage_deposit_limit,
+					data,
+					access_list,
+				},
+				(_, false) => crate::Call::call_with_authorizations::<Self::Config> {
+					dest,
+					value,
+					gas_limit,
+					storage_deposit_limit,
+					data,
+					access_list,
+					authorization_list,
+				},
 			}
 		} else {
+			// The access list of a contract deployment is ignored.
 			let blob = match polkavm::ProgramBlob::blob_length(&data) {
 				Some(blob_len) =>
 					blob_len.try_into().ok().and_then(|blob_len| (data.split_at_checked(blob_len))),
@@ -607,6 +665,91 @@ mod test {
 		);
 	}
 
+	#[test]
+	fn check_eth_transact_set_code_works() {
+		let authorization = Account::default().sign_authorization(
+			<Test as Config>::ChainId::get().into(),
+			H160::from([2u8; 20]),
+			1u32.into(),
+		);
+		let mut builder = UncheckedExtrinsicBuilder::call_with(H160::from([1u8; 20]));
+		builder.tx.r#type = Some(TYPE_EIP7702.into());
+		builder.tx.authorization_list = vec![authorization.clone()];
+		builder.tx.max_fee_per_gas = Some(GAS_PRICE.into());
+		let (call, _, tx) = builder.check().unwrap();
+		let (gas_limit, storage_deposit_limit) =
+			<<Test as Config>::EthGasEncoder as GasEncoder<_>>::decode(tx.gas.unwrap()).unwrap();
+
+		assert_eq!(
+			call,
+			crate::Call::call_with_authorizations::<Test> {
+				dest: tx.to.unwrap(),
+				value: tx.value.unwrap_or_default().as_u64(),
+				data: tx.input.to_vec(),
+				gas_limit,
+				storage_deposit_limit,
+				access_list: Default::default(),
+				authorization_list: vec![authorization.clone()].try_into().unwrap(),
+			}
+			.into()
+		);
+
+		// The number of authorizations is bounded.
+		let mut builder = UncheckedExtrinsicBuilder::call_with(H160::from([1u8; 20]));
+		builder.tx.r#type = Some(TYPE_EIP7702.into());
+		builder.tx.authorization_list =
+			vec![authorization; crate::limits::AUTHORIZATIONS as usize + 1];
+		assert_eq!(
+			builder.check(),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+		);
+
+		// Set-code transactions must have at least one authorization.
+		let mut builder = UncheckedExtrinsicBuilder::call_with(H160::from([1u8; 20]));
+		builder.tx.r#type = Some(TYPE_EIP7702.into());
+		assert_eq!(
+			builder.check(),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+		);
+	}
+
+	#[test]
+	fn check_eth_transact_access_list_works() {
+		let access_list = vec![AccessListEntry {
+			address: H160::from([2u8; 20]),
+			storage_keys: vec![H256::repeat_byte(1)],
+		}];
+		let mut builder = UncheckedExtrinsicBuilder::call_with(H160::from([1u8; 20]));
+		builder.tx.r#type = Some(TYPE_EIP2930.into());
+		builder.tx.access_list = Some(access_list.clone());
+		let (call, _, tx) = builder.check().unwrap();
+		let (gas_limit, storage_deposit_limit) =
+			<<Test as Config>::EthGasEncoder as GasEncoder<_>>::decode(tx.gas.unwrap()).unwrap();
+
+		assert_eq!(
+			call,
+			crate::Call::call_with_access_list::<Test> {
+				dest: tx.to.unwrap(),
+				value: tx.value.unwrap_or_default().as_u64(),
+				data: tx.input.to_vec(),
+				gas_limit,
+				storage_deposit_limit,
+				access_list: access_list.try_into().unwrap(),
+			}
+			.into()
+		);
+
+		// The number of entries is bounded.
+		let mut builder = UncheckedExtrinsicBuilder::call_with(H160::from([1u8; 20]));
+		builder.tx.r#type = Some(TYPE_EIP2930.into());
+		builder.tx.access_list =
+			Some(vec![AccessListEntry::default(); crate::limits::ACCESS_LIST_ENTRIES as usize + 1]);
+		assert_eq!(
+			builder.check(),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Call))
+		);
+	}
+
 	#[test]
 	fn check_eth_transact_nonce_works() {
 		let builder = UncheckedExtrinsicBuilder::call_with(H160::from([1u8; 20]));
@@ -692,4 +835,46 @@ mod test {
 		let expected_tip = crate::Pallet::<Test>::evm_gas_to_fee(tx.gas.unwrap(), diff).unwrap();
 		assert_eq!(extra.1.tip(), expected_tip);
 	}
+
+	#[test]
+	fn prestate_is_traced_before_fees_and_nonce() {
+		use crate::tracing::trace;
+		use sp_runtime::traits::Applyable;
+
+		let mut builder = UncheckedExtrinsicBuilder::call_with(H160::from([1u8; 20]));
+		ExtBuilder::default().build().execute_with(|| builder.estimate_gas());
+		ExtBuilder::default().build().execute_with(|| {
+			let account = Account::default();
+			let _ = <Test as Config>::Currency::set_balance(
+				&account.substrate_account(),
+				100_000_000_000_000,
+			);
+			let balance = crate::Pallet::<Test>::evm_balance(&account.address());
+
+			let payload = account
+				.sign_transaction(builder.tx.clone().try_into_unsigned().unwrap())
+				.signed_payload();
+			let call = RuntimeCall::Contracts(crate::Call::eth_transact { payload });
+			let encoded_len = call.encoded_size();
+			let uxt: Ex = generic::UncheckedExtrinsic::new_bare(call).into();
+
+			// Check and apply the extrinsic, as the executive does when tracing a block.
+			let mut tracer = PrestateTracer::<Test>::new(Default::default());
+			trace(&mut tracer, || {
+				let checked = uxt.check(&TestContext {}).unwrap();
+				let info = checked.function.get_dispatch_info();
+				checked.apply::<Test>(&info, encoded_len).unwrap().unwrap();
+			});
+
+			assert_ne!(crate::Pallet::<Test>::evm_balance(&account.address()), balance);
+			assert_eq!(frame_system::Pallet::<Test>::account_nonce(account.substrate_account()), 1);
+			let Some(PrestateTrace::Prestate(pre)) = tracer.collect_trace() else {
+				panic!("Expected a prestate trace")
+			};
+			assert_eq!(
+				pre.get(&account.address()),
+				Some(&PrestateTraceInfo { balance: Some(balance), ..Default::default() })
+			);
+		});
+	}
 }
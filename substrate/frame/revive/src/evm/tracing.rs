@@ -15,27 +15,49 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use crate::{
-	evm::{CallTrace, Trace},
+	evm::{CallTrace, StructLogTrace, Trace},
 	tracing::Tracing,
-	Weight,
+	BalanceOf, Config, MomentOf, Weight,
 };
-use sp_core::U256;
+use frame_support::traits::IsType;
+use sp_core::{H256, U256};
+use sp_runtime::traits::Bounded;
+
+mod access_list_tracing;
+pub use access_list_tracing::*;
 
 mod call_tracing;
 pub use call_tracing::*;
 
+mod prestate_tracing;
+pub use prestate_tracing::*;
+
+mod struct_logging;
+pub use struct_logging::*;
+
 /// A composite tracer.
-#[derive(derive_more::From, Debug)]
-pub enum Tracer {
+#[derive(derive_more::From, frame_support::RuntimeDebugNoBound)]
+pub enum Tracer<T> {
 	/// A tracer that traces calls.
 	CallTracer(CallTracer<U256, fn(Weight) -> U256>),
+	/// A tracer that traces the prestate.
+	PrestateTracer(PrestateTracer<T>),
+	/// A tracer that traces every executed instruction.
+	StructLogger(StructLogger<u64, fn(Weight) -> u64>),
 }
 
-impl Tracer {
+impl<T: Config> Tracer<T>
+where
+	BalanceOf<T>: Into<U256> + TryFrom<U256> + Bounded,
+	MomentOf<T>: Into<U256>,
+	T::Hash: IsType<H256>,
+{
 	/// Returns an empty trace.
 	pub fn empty_trace(&self) -> Trace {
 		match self {
 			Tracer::CallTracer(_) => CallTrace::default().into(),
+			Tracer::PrestateTracer(inner) => inner.empty_trace().into(),
+			Tracer::StructLogger(_) => StructLogTrace::default().into(),
 		}
 	}
 
@@ -43,6 +65,8 @@ impl Tracer {
 	pub fn as_tracing(&mut self) -> &mut (dyn Tracing + 'static) {
 		match self {
 			Tracer::CallTracer(inner) => inner as &mut dyn Tracing,
+			Tracer::PrestateTracer(inner) => inner as &mut dyn Tracing,
+			Tracer::StructLogger(inner) => inner as &mut dyn Tracing,
 		}
 	}
 
@@ -50,6 +74,8 @@ impl Tracer {
 	pub fn collect_trace(&mut self) -> Option<Trace> {
 		match self {
 			Tracer::CallTracer(inner) => inner.collect_trace().map(Trace::Call),
+			Tracer::PrestateTracer(inner) => inner.collect_trace().map(Trace::Prestate),
+			Tracer::StructLogger(inner) => inner.collect_trace().map(Trace::StructLog),
 		}
 	}
 }
@@ -0,0 +1,80 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{
+	evm::{AccessList, AccessListEntry},
+	tracing::Tracing,
+	Key,
+};
+use alloc::collections::{BTreeMap, BTreeSet};
+use sp_core::{H160, H256};
+
+/// A tracer that collects the accounts and storage entries accessed by a transaction, to build
+/// its access list.
+///
+/// Like geth, the excluded addresses (the sender, the recipient and the precompiles) are only
+/// included in the access list if their storage is accessed. Storage entries with a variable
+/// sized key can't be declared in an access list and are not reported.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct AccessListTracer {
+	/// The addresses that are only included if their storage is accessed.
+	excluded: BTreeSet<H160>,
+	/// The accessed storage keys, by address.
+	accessed: BTreeMap<H160, BTreeSet<H256>>,
+}
+
+impl AccessListTracer {
+	/// Create a new [`AccessListTracer`], starting with the entries of `access_list`.
+	pub fn new(excluded: impl IntoIterator<Item = H160>, access_list: &AccessList) -> Self {
+		let mut tracer = Self { excluded: excluded.into_iter().collect(), ..Default::default() };
+		for entry in access_list {
+			tracer.add_address(&entry.address);
+			for key in &entry.storage_keys {
+				tracer.accessed.entry(entry.address).or_default().insert(*key);
+			}
+		}
+		tracer
+	}
+
+	/// Returns the access list of the accessed accounts and storage entries.
+	pub fn access_list(&self) -> AccessList {
+		self.accessed
+			.iter()
+			.map(|(address, keys)| AccessListEntry {
+				address: *address,
+				storage_keys: keys.iter().copied().collect(),
+			})
+			.collect()
+	}
+
+	fn add_address(&mut self, address: &H160) {
+		if !self.excluded.contains(address) {
+			self.accessed.entry(*address).or_default();
+		}
+	}
+}
+
+impl Tracing for AccessListTracer {
+	fn access_address(&mut self, address: &H160) {
+		self.add_address(address);
+	}
+
+	fn access_storage(&mut self, address: &H160, key: &Key) {
+		if let Key::Fix(key) = key {
+			self.accessed.entry(*address).or_default().insert(H256(*key));
+		}
+	}
+}
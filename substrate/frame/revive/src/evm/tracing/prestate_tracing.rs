@@ -0,0 +1,237 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{
+	evm::{Bytes, PrestateTrace, PrestateTraceInfo, PrestateTracerConfig},
+	tracing::Tracing,
+	AddressMapper, BalanceOf, Config, ContractInfoOf, DispatchError, ExecReturnValue, Key,
+	MomentOf, Pallet, Weight,
+};
+use alloc::{
+	collections::{BTreeMap, BTreeSet},
+	vec::Vec,
+};
+use core::marker::PhantomData;
+use frame_support::traits::IsType;
+use sp_core::{H160, H256, U256};
+use sp_runtime::{traits::Bounded, SaturatedConversion};
+
+/// A tracer that reports the state of the accounts touched by a transaction.
+///
+/// The state of an account is recorded the first time it is touched. When `diff_mode` is
+/// enabled, the state is read again when the trace is collected, and only the entries that
+/// changed are reported.
+#[derive(frame_support::RuntimeDebugNoBound)]
+pub struct PrestateTracer<T> {
+	/// The tracer configuration.
+	config: PrestateTracerConfig,
+	/// Stack of the addresses of the active call frames.
+	calls: Vec<H160>,
+	/// The state of the touched accounts before the execution.
+	pre: BTreeMap<H160, PrestateTraceInfo>,
+	/// The storage keys accessed for each account, used to read back their final values.
+	keys: BTreeMap<H160, BTreeMap<Bytes, Key>>,
+	_phantom: PhantomData<T>,
+}
+
+impl<T: Config> PrestateTracer<T>
+where
+	BalanceOf<T>: Into<U256> + TryFrom<U256> + Bounded,
+	MomentOf<T>: Into<U256>,
+	T::Hash: IsType<H256>,
+{
+	/// Create a new [`PrestateTracer`] instance.
+	pub fn new(config: PrestateTracerConfig) -> Self {
+		Self {
+			config,
+			calls: Vec::new(),
+			pre: BTreeMap::new(),
+			keys: BTreeMap::new(),
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Returns an empty trace.
+	pub fn empty_trace(&self) -> PrestateTrace {
+		if self.config.diff_mode {
+			PrestateTrace::DiffMode { pre: Default::default(), post: Default::default() }
+		} else {
+			PrestateTrace::Prestate(Default::default())
+		}
+	}
+
+	/// Collect the traces and return them.
+	///
+	/// Returns `None` if no account was touched since the last collection.
+	pub fn collect_trace(&mut self) -> Option<PrestateTrace> {
+		self.calls.clear();
+		let mut pre = core::mem::take(&mut self.pre);
+		let keys = core::mem::take(&mut self.keys);
+
+		if pre.is_empty() {
+			return None
+		}
+
+		if !self.config.diff_mode {
+			return Some(PrestateTrace::Prestate(pre))
+		}
+
+		let mut post = BTreeMap::new();
+		pre.retain(|address, pre_info| {
+			let mut post_info = self.account_info(address);
+			if let Some(keys) = keys.get(address) {
+				post_info.storage = keys
+					.iter()
+					.map(|(raw_key, key)| (raw_key.clone(), Self::read_storage(address, key)))
+					.collect();
+			}
+
+			// Only report the entries that changed, omitting the ones that are empty.
+			let changed: BTreeSet<Bytes> = post_info
+				.storage
+				.iter()
+				.filter(|(k, v)| pre_info.storage.get(*k) != Some(*v))
+				.map(|(k, _)| k.clone())
+				.collect();
+			pre_info.storage.retain(|k, v| v.is_some() && changed.contains(k));
+			post_info.storage.retain(|k, v| v.is_some() && changed.contains(k));
+
+			let mut modified = !changed.is_empty();
+			if post_info.balance == pre_info.balance {
+				post_info.balance = None;
+			} else {
+				modified = true;
+			}
+			if post_info.nonce == pre_info.nonce {
+				post_info.nonce = None;
+			} else {
+				modified = true;
+			}
+			if post_info.code == pre_info.code {
+				post_info.code = None;
+			} else {
+				modified = true;
+			}
+
+			if !modified {
+				return false
+			}
+			post.insert(*address, post_info);
+
+			// Accounts that did not exist before the execution are only part of the post state.
+			pre_info.balance.map_or(false, |b| !b.is_zero()) ||
+				pre_info.nonce.is_some() ||
+				pre_info.code.is_some()
+		});
+
+		Some(PrestateTrace::DiffMode { pre, post })
+	}
+
+	/// Read the current state of the given account, without its storage.
+	fn account_info(&self, address: &H160) -> PrestateTraceInfo {
+		let account = T::AddressMapper::to_account_id(address);
+		let nonce: u32 = frame_system::Pallet::<T>::account_nonce(account).saturated_into();
+		let code = if self.config.disable_code { Vec::new() } else { Pallet::<T>::code(address) };
+
+		PrestateTraceInfo {
+			balance: Some(Pallet::<T>::evm_balance(address)),
+			nonce: (nonce != 0).then_some(nonce),
+			code: (!code.is_empty()).then(|| code.into()),
+			storage: Default::default(),
+		}
+	}
+
+	/// Read the current value of the given storage entry of the given contract.
+	fn read_storage(address: &H160, key: &Key) -> Option<Bytes> {
+		ContractInfoOf::<T>::get(address).and_then(|info| info.read(key)).map(Bytes)
+	}
+
+	/// Record the state of the given account if it was not touched before.
+	fn touch(&mut self, address: &H160) {
+		if !self.pre.contains_key(address) {
+			let info = self.account_info(address);
+			self.pre.insert(*address, info);
+		}
+	}
+
+	/// Record the value of a storage entry of the current contract, if it was not accessed
+	/// before.
+	fn record_storage(&mut self, key: &Key, value: Option<&[u8]>) {
+		if self.config.disable_storage {
+			return
+		}
+
+		let Some(address) = self.calls.last().copied() else { return };
+		self.touch(&address);
+
+		let raw_key = Bytes(match key {
+			Key::Fix(k) => k.to_vec(),
+			Key::Var(k) => k.to_vec(),
+		});
+
+		let keys = self.keys.entry(address).or_default();
+		if keys.contains_key(&raw_key) {
+			return
+		}
+		keys.insert(raw_key.clone(), key.clone());
+
+		if let Some(info) = self.pre.get_mut(&address) {
+			info.storage.insert(raw_key, value.map(|v| Bytes(v.to_vec())));
+		}
+	}
+}
+
+impl<T: Config> Tracing for PrestateTracer<T>
+where
+	BalanceOf<T>: Into<U256> + TryFrom<U256> + Bounded,
+	MomentOf<T>: Into<U256>,
+	T::Hash: IsType<H256>,
+{
+	fn watch_address(&mut self, address: &H160) {
+		self.touch(address);
+	}
+
+	fn enter_child_span(
+		&mut self,
+		from: H160,
+		to: H160,
+		_is_delegate_call: bool,
+		_is_read_only: bool,
+		_value: U256,
+		_input: &[u8],
+		_gas: Weight,
+	) {
+		self.touch(&from);
+		self.touch(&to);
+		self.calls.push(to);
+	}
+
+	fn storage_read(&mut self, key: &Key, value: Option<&[u8]>) {
+		self.record_storage(key, value);
+	}
+
+	fn storage_write(&mut self, key: &Key, old_value: Option<Vec<u8>>, _new_value: Option<&[u8]>) {
+		self.record_storage(key, old_value.as_deref());
+	}
+
+	fn exit_child_span(&mut self, _output: &ExecReturnValue, _gas_used: Weight) {
+		self.calls.pop();
+	}
+
+	fn exit_child_span_with_error(&mut self, _error: DispatchError, _gas_used: Weight) {
+		self.calls.pop();
+	}
+}
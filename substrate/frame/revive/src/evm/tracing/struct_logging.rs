@@ -0,0 +1,191 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{
+	evm::{Bytes, StructLog, StructLogTrace, StructLoggerConfig},
+	primitives::ExecReturnValue,
+	tracing::{ExecutionState, Tracing},
+	DispatchError, Key, Weight,
+};
+use alloc::{collections::BTreeMap, format, string::ToString, vec::Vec};
+use sp_core::{H160, U256};
+
+/// A tracer that reports every instruction executed by a transaction, similar to the default
+/// struct logger of geth.
+///
+/// PolkaVM registers are reported as the stack, and the memory of the current stack frame is
+/// reported as the memory.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct StructLogger<Gas, GasMapper> {
+	/// Map Weight to Gas equivalent.
+	gas_mapper: GasMapper,
+	/// The tracer configuration.
+	config: StructLoggerConfig,
+	/// The instructions traced so far.
+	logs: Vec<StructLog<Gas>>,
+	/// Stack of the active call frames, with the address of the contract and the index of
+	/// the last instruction it executed.
+	frames: Vec<(H160, Option<usize>)>,
+	/// The storage entries accessed so far, for each contract.
+	storage: BTreeMap<H160, BTreeMap<Bytes, Option<Bytes>>>,
+	/// The result of the outermost call, once it returned.
+	result: Option<(Gas, bool, Bytes)>,
+}
+
+impl<Gas, GasMapper> StructLogger<Gas, GasMapper> {
+	/// Create a new [`StructLogger`] instance.
+	pub fn new(config: StructLoggerConfig, gas_mapper: GasMapper) -> Self {
+		Self {
+			gas_mapper,
+			config,
+			logs: Vec::new(),
+			frames: Vec::new(),
+			storage: BTreeMap::new(),
+			result: None,
+		}
+	}
+
+	/// Collect the traces and return them.
+	///
+	/// Returns `None` if no call was executed since the last collection.
+	pub fn collect_trace(&mut self) -> Option<StructLogTrace<Gas>> {
+		self.frames.clear();
+		self.storage.clear();
+		let struct_logs = core::mem::take(&mut self.logs);
+		let (gas, failed, return_value) = self.result.take()?;
+		Some(StructLogTrace { gas, failed, return_value, struct_logs })
+	}
+
+	/// Returns `true` if no more instructions should be traced.
+	fn limit_reached(&self) -> bool {
+		self.config.limit != 0 && self.logs.len() as u64 >= self.config.limit
+	}
+
+	/// Attach the storage entries of the current contract to its last instruction, after
+	/// recording the accessed entry.
+	fn record_storage(&mut self, key: &Key, value: Option<&[u8]>) {
+		if self.config.disable_storage {
+			return
+		}
+		let Some((address, Some(index))) = self.frames.last().copied() else { return };
+
+		let raw_key = Bytes(match key {
+			Key::Fix(k) => k.to_vec(),
+			Key::Var(k) => k.to_vec(),
+		});
+		let storage = self.storage.entry(address).or_default();
+		storage.insert(raw_key, value.map(|v| Bytes(v.to_vec())));
+
+		if let Some(log) = self.logs.get_mut(index) {
+			log.storage = Some(storage.clone());
+		}
+	}
+}
+
+impl<Gas, GasMapper> Tracing for StructLogger<Gas, GasMapper>
+where
+	Gas: Default + Copy + core::ops::Sub<Output = Gas> + PartialOrd,
+	GasMapper: Fn(Weight) -> Gas,
+{
+	fn is_execution_tracer(&self) -> bool {
+		true
+	}
+
+	fn enter_instruction(
+		&mut self,
+		pc: u64,
+		opcode: &str,
+		gas_left: Weight,
+		state: &dyn ExecutionState,
+	) {
+		if self.limit_reached() {
+			return
+		}
+		let Some((_, last_index)) = self.frames.last_mut() else { return };
+
+		let gas = (self.gas_mapper)(gas_left);
+		if let Some(last) = last_index.and_then(|index| self.logs.get_mut(index)) {
+			if last.gas > gas {
+				last.gas_cost = last.gas - gas;
+			}
+		}
+		*last_index = Some(self.logs.len());
+
+		let stack = (!self.config.disable_stack)
+			.then(|| state.registers().into_iter().map(U256::from).collect());
+		let memory = (!self.config.disable_memory)
+			.then(|| state.stack_memory().chunks(32).map(|word| Bytes(word.to_vec())).collect());
+
+		self.logs.push(StructLog {
+			pc,
+			op: opcode.to_string(),
+			gas,
+			gas_cost: Default::default(),
+			depth: self.frames.len() as u32,
+			stack,
+			memory,
+			storage: None,
+			error: None,
+		});
+	}
+
+	fn enter_child_span(
+		&mut self,
+		_from: H160,
+		to: H160,
+		_is_delegate_call: bool,
+		_is_read_only: bool,
+		_value: U256,
+		_input: &[u8],
+		_gas: Weight,
+	) {
+		self.frames.push((to, None));
+	}
+
+	fn storage_read(&mut self, key: &Key, value: Option<&[u8]>) {
+		self.record_storage(key, value);
+	}
+
+	fn storage_write(&mut self, key: &Key, _old_value: Option<Vec<u8>>, new_value: Option<&[u8]>) {
+		self.record_storage(key, new_value);
+	}
+
+	fn exit_child_span(&mut self, output: &ExecReturnValue, gas_used: Weight) {
+		self.frames.pop();
+		if self.frames.is_empty() {
+			self.result = Some((
+				(self.gas_mapper)(gas_used),
+				output.did_revert(),
+				output.data.clone().into(),
+			));
+		}
+	}
+
+	fn exit_child_span_with_error(&mut self, error: DispatchError, gas_used: Weight) {
+		if let Some((_, Some(index))) = self.frames.pop() {
+			if let Some(log) = self.logs.get_mut(index) {
+				log.error = Some(match error {
+					DispatchError::Module(sp_runtime::ModuleError { message, .. }) =>
+						message.unwrap_or_default().to_string(),
+					_ => format!("{:?}", error),
+				});
+			}
+		}
+		if self.frames.is_empty() {
+			self.result = Some(((self.gas_mapper)(gas_used), true, Default::default()));
+		}
+	}
+}
@@ -17,7 +17,8 @@
 
 use crate::{
 	address::{self, AddressMapper},
-	gas::GasMeter,
+	evm::{delegation_designator_of, overrides},
+	gas::{AccessTracker, GasMeter},
 	limits,
 	precompiles::{All as AllPrecompiles, Instance as PrecompileInstance, Precompiles},
 	primitives::{ExecReturnValue, StorageDeposit},
@@ -26,7 +27,7 @@ use crate::{
 	tracing::if_tracing,
 	transient_storage::TransientStorage,
 	BalanceOf, CodeInfo, CodeInfoOf, Config, ContractInfo, ContractInfoOf, ConversionPrecision,
-	Error, Event, ImmutableData, ImmutableDataOf, Pallet as Contracts,
+	DelegationOf, Error, Event, ImmutableData, ImmutableDataOf, Pallet as Contracts,
 };
 use alloc::vec::Vec;
 use core::{fmt::Debug, marker::PhantomData, mem};
@@ -51,7 +52,10 @@ use sp_core::{
 	sr25519::{Public as SR25519Public, Signature as SR25519Signature},
 	ConstU32, H160, H256, U256,
 };
-use sp_io::{crypto::secp256k1_ecdsa_recover_compressed, hashing::blake2_256};
+use sp_io::{
+	crypto::secp256k1_ecdsa_recover_compressed,
+	hashing::{blake2_256, keccak_256},
+};
 use sp_runtime::{
 	traits::{BadOrigin, Bounded, Convert, Saturating, Zero},
 	DispatchError, SaturatedConversion,
@@ -74,6 +78,7 @@ pub const EMPTY_CODE_HASH: H256 =
 	H256(sp_core::hex2array!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"));
 
 /// Combined key type for both fixed and variable sized storage keys.
+#[derive(Clone, Debug)]
 pub enum Key {
 	/// Variant for fixed sized keys.
 	Fix([u8; 32]),
@@ -229,6 +234,16 @@ pub trait Ext: PrecompileWithInfoExt {
 	///
 	/// Note: Requires &mut self to access the contract info.
 	fn set_immutable_data(&mut self, data: ImmutableData) -> Result<(), DispatchError>;
+
+	/// Record an access to the storage entry `key` of the executing account.
+	///
+	/// Returns `true` if this is the first access to the entry in this transaction.
+	fn access_storage(&mut self, key: &Key) -> bool;
+
+	/// Record an access to the state of `address`.
+	///
+	/// Returns `true` if this is the first access to the account in this transaction.
+	fn access_address(&mut self, address: &H160) -> bool;
 }
 
 /// Environment functions which are available to pre-compiles with `HAS_CONTRACT_INFO = true`.
@@ -514,6 +529,8 @@ pub struct Stack<'a, T: Config, E> {
 	first_frame: Frame<T>,
 	/// Transient storage used to store data, which is kept for the duration of a transaction.
 	transient_storage: TransientStorage<T>,
+	/// The accounts and storage entries accessed during the transaction.
+	access_tracker: AccessTracker,
 	/// Whether or not actual transfer of funds should be performed.
 	/// This is set to `true` exclusively when we simulate a call through eth_transact.
 	skip_transfer: bool,
@@ -593,6 +610,23 @@ impl<T: Config, E: Executable<T>, Env> ExecutableOrPrecompile<T, E, Env> {
 	}
 }
 
+/// Returns the hash of the code to execute when calling `address`, given the code hash of its
+/// contract info.
+///
+/// Accounts delegated with an EIP-7702 authorization only have a contract info to hold their
+/// storage. Its code hash is zero and the code of the delegation target is executed instead.
+/// Returns `None` if there is no code to execute, e.g. when the delegation was revoked, or the
+/// delegation target has no code.
+fn code_hash_to_execute<T: Config>(address: &H160, code_hash: Option<H256>) -> Option<H256> {
+	match code_hash {
+		Some(code_hash) if code_hash != H256::zero() => Some(code_hash),
+		_ => <DelegationOf<T>>::get(address)
+			.and_then(|target| <ContractInfoOf<T>>::get(&target))
+			.map(|info| info.code_hash)
+			.filter(|code_hash| *code_hash != H256::zero()),
+	}
+}
+
 /// Parameter passed in when creating a new `Frame`.
 ///
 /// It determines whether the new frame is for a call or an instantiate.
@@ -774,7 +808,6 @@ where
 		)? {
 			stack.run(executable, input_data).map(|_| stack.first_frame.last_frame_output)
 		} else {
-			let result = Self::transfer_from_origin(&origin, &origin, &dest, value, storage_meter);
 			if_tracing(|t| {
 				t.enter_child_span(
 					origin.account_id().map(T::AddressMapper::to_address).unwrap_or_default(),
@@ -785,10 +818,11 @@ where
 					&input_data,
 					Weight::zero(),
 				);
-				match result {
-					Ok(ref output) => t.exit_child_span(&output, Weight::zero()),
-					Err(e) => t.exit_child_span_with_error(e.error.into(), Weight::zero()),
-				}
+			});
+			let result = Self::transfer_from_origin(&origin, &origin, &dest, value, storage_meter);
+			if_tracing(|t| match result {
+				Ok(ref output) => t.exit_child_span(&output, Weight::zero()),
+				Err(e) => t.exit_child_span_with_error(e.error.into(), Weight::zero()),
 			});
 
 			result
@@ -868,6 +902,8 @@ where
 		skip_transfer: bool,
 	) -> Result<Option<(Self, ExecutableOrPrecompile<T, E, Self>)>, ExecError> {
 		origin.ensure_mapped()?;
+
+		let mut access_tracker = AccessTracker::new();
 		let Some((first_frame, executable)) = Self::new_frame(
 			args,
 			value,
@@ -882,15 +918,22 @@ where
 			return Ok(None);
 		};
 
+		// The origin and the destination of the transaction are always warm.
+		if let Ok(origin) = origin.account_id() {
+			access_tracker.access_address(&T::AddressMapper::to_address(origin));
+		}
+		access_tracker.access_address(&T::AddressMapper::to_address(&first_frame.account_id));
+
 		let stack = Self {
 			origin,
 			gas_meter,
 			storage_meter,
-			timestamp: T::Time::now(),
-			block_number: <frame_system::Pallet<T>>::block_number(),
+			timestamp: overrides::timestamp::<T>(),
+			block_number: overrides::block_number::<T>(),
 			first_frame,
 			frames: Default::default(),
 			transient_storage: TransientStorage::new(limits::TRANSIENT_STORAGE_BYTES),
+			access_tracker,
 			skip_transfer,
 			_phantom: Default::default(),
 		};
@@ -924,6 +967,10 @@ where
 					(None, None) =>
 						if let Some(info) = <ContractInfoOf<T>>::get(&address) {
 							CachedContract::Cached(info)
+						} else if <DelegationOf<T>>::contains_key(&address) {
+							// The storage of a delegated account is created on first use.
+							let info = ContractInfo::new(&address, 0u32.into(), H256::zero())?;
+							CachedContract::Cached(info)
 						} else {
 							return Ok(None)
 						},
@@ -948,10 +995,14 @@ where
 							_phantom: Default::default(),
 						}
 					} else {
-						let Some(info) = ContractInfoOf::<T>::get(&delegated_call.callee) else {
+						let code_hash = ContractInfoOf::<T>::get(&delegated_call.callee)
+							.map(|info| info.code_hash);
+						let Some(code_hash) =
+							code_hash_to_execute::<T>(&delegated_call.callee, code_hash)
+						else {
 							return Ok(None);
 						};
-						let executable = E::from_storage(info.code_hash, gas_meter)?;
+						let executable = E::from_storage(code_hash, gas_meter)?;
 						ExecutableOrPrecompile::Executable(executable)
 					}
 				} else {
@@ -961,13 +1012,15 @@ where
 							_phantom: Default::default(),
 						}
 					} else {
-						let executable = E::from_storage(
-							contract
-								.as_contract()
-								.expect("When not a precompile the contract was loaded above; qed")
-								.code_hash,
-							gas_meter,
-						)?;
+						let code_hash = contract
+							.as_contract()
+							.expect("When not a precompile the contract was loaded above; qed")
+							.code_hash;
+						let Some(code_hash) = code_hash_to_execute::<T>(&address, Some(code_hash))
+						else {
+							return Ok(None);
+						};
+						let executable = E::from_storage(code_hash, gas_meter)?;
 						ExecutableOrPrecompile::Executable(executable)
 					}
 				};
@@ -1106,6 +1159,7 @@ where
 		}
 
 		self.transient_storage.start_transaction();
+		self.access_tracker.start_transaction();
 
 		let do_transaction = || -> ExecResult {
 			let caller = self.caller();
@@ -1266,8 +1320,10 @@ where
 
 		if success {
 			self.transient_storage.commit_transaction();
+			self.access_tracker.commit_transaction();
 		} else {
 			self.transient_storage.rollback_transaction();
+			self.access_tracker.rollback_transaction();
 		}
 
 		self.pop_frame(success);
@@ -1537,6 +1593,7 @@ where
 			return Err(Error::<T>::TerminatedInConstructor.into());
 		}
 		let info = frame.terminate();
+		if_tracing(|tracer| tracer.watch_address(beneficiary));
 		let beneficiary_account = T::AddressMapper::to_account_id(beneficiary);
 		frame.nested_storage.terminate(&info, beneficiary_account);
 
@@ -1544,7 +1601,10 @@ where
 		let account_address = T::AddressMapper::to_address(&frame.account_id);
 		ContractInfoOf::<T>::remove(&account_address);
 		ImmutableDataOf::<T>::remove(&account_address);
-		<CodeInfo<T>>::decrement_refcount(info.code_hash)?;
+		// The contract info of a delegated account has no code.
+		if info.code_hash != H256::zero() {
+			<CodeInfo<T>>::decrement_refcount(info.code_hash)?;
+		}
 
 		Ok(())
 	}
@@ -1586,7 +1646,10 @@ where
 		frame.nested_storage.charge_deposit(frame.account_id.clone(), deposit);
 
 		<CodeInfo<T>>::increment_refcount(hash)?;
-		<CodeInfo<T>>::decrement_refcount(prev_hash)?;
+		// The contract info of a delegated account has no code.
+		if prev_hash != H256::zero() {
+			<CodeInfo<T>>::decrement_refcount(prev_hash)?;
+		}
 		Ok(())
 	}
 
@@ -1618,6 +1681,17 @@ where
 		<ImmutableDataOf<T>>::insert(T::AddressMapper::to_address(&frame.account_id), &data);
 		Ok(())
 	}
+
+	fn access_storage(&mut self, key: &Key) -> bool {
+		let address = self.address();
+		if_tracing(|tracer| tracer.access_storage(&address, key));
+		self.access_tracker.access_storage(&address, key)
+	}
+
+	fn access_address(&mut self, address: &H160) -> bool {
+		if_tracing(|tracer| tracer.access_address(address));
+		self.access_tracker.access_address(address)
+	}
 }
 
 impl<'a, T, E> PrecompileWithInfoExt for Stack<'a, T, E>
@@ -1629,7 +1703,9 @@ where
 	T::Hash: frame_support::traits::IsType<H256>,
 {
 	fn get_storage(&mut self, key: &Key) -> Option<Vec<u8>> {
-		self.top_frame_mut().contract_info().read(key)
+		let value = self.top_frame_mut().contract_info().read(key);
+		if_tracing(|tracer| tracer.storage_read(key, value.as_deref()));
+		value
 	}
 
 	fn get_storage_size(&mut self, key: &Key) -> Option<u32> {
@@ -1643,6 +1719,10 @@ where
 		take_old: bool,
 	) -> Result<WriteOutcome, DispatchError> {
 		let frame = self.top_frame_mut();
+		if_tracing(|tracer| {
+			let old_value = frame.contract_info.get(&frame.account_id).read(key);
+			tracer.storage_write(key, old_value, value.as_deref());
+		});
 		frame.contract_info.get(&frame.account_id).write(
 			key.into(),
 			value,
@@ -1683,6 +1763,8 @@ where
 			self.is_read_only(),
 		)?;
 		let address = T::AddressMapper::to_address(&self.top_frame().account_id);
+		// The address of a new contract is warm, even if the instantiation fails.
+		self.access_tracker.access_address(&address);
 		self.run(executable.expect(FRAME_ALWAYS_EXISTS_ON_INSTANTIATE), input_data)
 			.map(|_| address)
 	}
@@ -1754,6 +1836,17 @@ where
 			)? {
 				self.run(executable, input_data)
 			} else {
+				if_tracing(|t| {
+					t.enter_child_span(
+						T::AddressMapper::to_address(self.account_id()),
+						T::AddressMapper::to_address(&dest),
+						false,
+						is_read_only,
+						value,
+						&input_data,
+						Weight::zero(),
+					);
+				});
 				let result = if is_read_only && value.is_zero() {
 					Ok(Default::default())
 				} else if is_read_only {
@@ -1770,20 +1863,9 @@ where
 					)
 				};
 
-				if_tracing(|t| {
-					t.enter_child_span(
-						T::AddressMapper::to_address(self.account_id()),
-						T::AddressMapper::to_address(&dest),
-						false,
-						is_read_only,
-						value,
-						&input_data,
-						Weight::zero(),
-					);
-					match result {
-						Ok(ref output) => t.exit_child_span(&output, Weight::zero()),
-						Err(e) => t.exit_child_span_with_error(e.error.into(), Weight::zero()),
-					}
+				if_tracing(|t| match result {
+					Ok(ref output) => t.exit_child_span(&output, Weight::zero()),
+					Err(e) => t.exit_child_span_with_error(e.error.into(), Weight::zero()),
 				});
 				result.map(|_| ())
 			}
@@ -1846,8 +1928,13 @@ where
 	}
 
 	fn code_hash(&self, address: &H160) -> H256 {
+		if let Some(designator) = delegation_designator_of::<T>(address) {
+			return H256(keccak_256(&designator));
+		}
 		<ContractInfoOf<T>>::get(&address)
 			.map(|contract| contract.code_hash)
+			// The contract info left behind by a revoked delegation has no code.
+			.filter(|code_hash| *code_hash != H256::zero())
 			.unwrap_or_else(|| {
 				if System::<T>::account_exists(&T::AddressMapper::to_account_id(address)) {
 					return EMPTY_CODE_HASH;
@@ -1857,6 +1944,9 @@ where
 	}
 
 	fn code_size(&self, address: &H160) -> u64 {
+		if let Some(designator) = delegation_designator_of::<T>(address) {
+			return designator.len() as u64;
+		}
 		<ContractInfoOf<T>>::get(&address)
 			.and_then(|contract| CodeInfoOf::<T>::get(contract.code_hash))
 			.map(|info| info.code_len())
@@ -1877,6 +1967,7 @@ where
 	}
 
 	fn balance_of(&self, address: &H160) -> U256 {
+		if_tracing(|tracer| tracer.watch_address(address));
 		self.account_balance(&<Self::T as Config>::AddressMapper::to_account_id(address))
 	}
 
@@ -1909,6 +2000,9 @@ where
 	}
 
 	fn block_author(&self) -> Option<AccountIdOf<Self::T>> {
+		if let Some(author) = overrides::block_author::<T>() {
+			return Some(author);
+		}
 		let digest = <frame_system::Pallet<T>>::digest();
 		let pre_runtime_digests = digest.logs.iter().filter_map(|d| d.as_pre_runtime());
 
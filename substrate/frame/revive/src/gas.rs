@@ -15,13 +15,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{exec::ExecError, weights::WeightInfo, Config, Error};
+use crate::{
+	evm::{AccessList, BoundedAccessList},
+	exec::{ExecError, Key},
+	weights::WeightInfo,
+	Config, Error,
+};
+use alloc::{collections::BTreeSet, vec::Vec};
 use core::marker::PhantomData;
+use environmental::environmental;
 use frame_support::{
 	dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo, PostDispatchInfo},
 	weights::Weight,
 	DefaultNoBound,
 };
+use sp_core::H160;
 use sp_runtime::DispatchError;
 
 #[cfg(test)]
@@ -280,6 +288,19 @@ impl<T: Config> GasMeter<T> {
 		self.gas_left
 	}
 
+	/// Returns how much gas is left from the initial budget, including the fuel consumed by the
+	/// executor since the last sync.
+	///
+	/// This does not update the meter and is only meant to be used for tracing.
+	pub fn gas_left_with_engine_fuel(&self, engine_fuel: polkavm::Gas) -> Weight {
+		let consumed = self
+			.engine_meter
+			.fuel
+			.saturating_sub(engine_fuel.try_into().unwrap_or_default())
+			.saturating_mul(EngineMeter::<T>::ref_time_per_fuel());
+		self.gas_left.saturating_sub(Weight::from_parts(consumed, 0))
+	}
+
 	/// The amount of gas in terms of engine gas.
 	pub fn engine_fuel_left(&self) -> Result<polkavm::Gas, DispatchError> {
 		self.engine_meter.fuel.try_into().map_err(|_| <Error<T>>::OutOfGas.into())
@@ -314,10 +335,172 @@ impl<T: Config> GasMeter<T> {
 	}
 }
 
+environmental!(declared_accesses: AccessTracker);
+
+/// Run the given closure with the entries of `access_list` declared as accessed.
+///
+/// The call stacks created by the closure start with these entries warm, see [`AccessTracker`].
+pub fn with_access_list<R>(access_list: &AccessList, f: impl FnOnce() -> R) -> R {
+	let mut declared = AccessTracker::default();
+	for entry in access_list {
+		declared.access_address(&entry.address);
+		for key in &entry.storage_keys {
+			declared.access_storage(&entry.address, &Key::from_fixed(key.0));
+		}
+	}
+	declared_accesses::using_once(&mut declared, f)
+}
+
+/// The overhead of the first access to an account or a storage entry within a transaction.
+///
+/// It follows the cold and warm access costs of
+/// [EIP-2929](https://eips.ethereum.org/EIPS/eip-2929). The benchmarked costs of the host
+/// functions accessing the state include reading it from the state trie, which only the first
+/// (cold) access pays. Subsequent (warm) accesses are charged without this overhead, see
+/// [`WarmAccess`].
+///
+/// The entries declared in the access list of a transaction pay the overhead upfront, as part of
+/// the weight of the dispatchable, so that their first access is warm. See
+/// [`access_list_weight`].
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+#[derive(Copy, Clone)]
+pub enum AccessCost {
+	/// Reading the state of an account.
+	Account,
+	/// Reading a storage entry.
+	StorageRead,
+	/// Writing a storage entry.
+	StorageWrite,
+	/// Reading the entries declared in the access list of a transaction.
+	AccessList { addresses: u32, storage_keys: u32 },
+}
+
+impl<T: Config> Token<T> for AccessCost {
+	fn weight(&self) -> Weight {
+		match *self {
+			AccessCost::Account =>
+				T::WeightInfo::seal_balance_of().saturating_sub(T::WeightInfo::seal_balance()),
+			AccessCost::StorageRead =>
+				T::WeightInfo::get_storage_full().saturating_sub(T::WeightInfo::get_storage_empty()),
+			AccessCost::StorageWrite =>
+				T::WeightInfo::set_storage_full().saturating_sub(T::WeightInfo::set_storage_empty()),
+			AccessCost::AccessList { addresses, storage_keys } =>
+				<Self as Token<T>>::weight(&AccessCost::Account)
+					.saturating_mul(addresses.into())
+					.saturating_add(
+						<Self as Token<T>>::weight(&AccessCost::StorageRead)
+							.saturating_mul(storage_keys.into()),
+					),
+		}
+	}
+}
+
+/// The weight of declaring the entries of `access_list`, charged upfront by the dispatchables.
+///
+/// It covers decoding the entries and the overhead of their cold access.
+pub fn access_list_weight<T: Config>(access_list: &BoundedAccessList) -> Weight {
+	let (addresses, storage_keys) = access_list.num_entries();
+	<AccessCost as Token<T>>::weight(&AccessCost::AccessList { addresses, storage_keys })
+}
+
+/// The `costs` of a warm access, which are charged without the `overhead` of a cold access.
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+#[derive(Copy, Clone)]
+pub struct WarmAccess<Tok> {
+	pub costs: Tok,
+	pub overhead: AccessCost,
+}
+
+impl<T: Config, Tok: Token<T>> Token<T> for WarmAccess<Tok> {
+	fn weight(&self) -> Weight {
+		self.costs
+			.weight()
+			.saturating_sub(<AccessCost as Token<T>>::weight(&self.overhead))
+	}
+
+	fn influence_lowest_gas_limit(&self) -> bool {
+		self.costs.influence_lowest_gas_limit()
+	}
+}
+
+/// An entry recorded by the [`AccessTracker`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Access {
+	Address(H160),
+	Storage(H160, Vec<u8>),
+}
+
+/// Tracks the accounts and storage entries accessed by a transaction.
+///
+/// Accesses recorded by a call frame that is rolled back are forgotten, so that they are cold
+/// again for the caller, as in Ethereum.
+#[derive(Default, Debug, Clone)]
+pub struct AccessTracker {
+	/// The entries accessed so far.
+	accessed: BTreeSet<Access>,
+	/// The entries first accessed within each open transaction.
+	journal: Vec<Vec<Access>>,
+}
+
+impl AccessTracker {
+	/// Create the tracker of a new call stack.
+	///
+	/// It contains the entries declared with [`with_access_list`], if any.
+	pub fn new() -> Self {
+		declared_accesses::with(|declared| declared.clone()).unwrap_or_default()
+	}
+
+	/// Record an access to the state of `address`.
+	///
+	/// Returns `true` if the access is cold.
+	pub fn access_address(&mut self, address: &H160) -> bool {
+		self.insert(Access::Address(*address))
+	}
+
+	/// Record an access to the storage entry `key` of `address`.
+	///
+	/// Returns `true` if the access is cold.
+	pub fn access_storage(&mut self, address: &H160, key: &Key) -> bool {
+		self.insert(Access::Storage(*address, key.hash()))
+	}
+
+	/// Start a new nested transaction.
+	pub fn start_transaction(&mut self) {
+		self.journal.push(Vec::new());
+	}
+
+	/// Keep the accesses of the current transaction.
+	pub fn commit_transaction(&mut self) {
+		let accesses = self.journal.pop().expect("No open transaction that can be committed.");
+		if let Some(parent) = self.journal.last_mut() {
+			parent.extend(accesses);
+		}
+	}
+
+	/// Forget the accesses of the current transaction.
+	pub fn rollback_transaction(&mut self) {
+		let accesses = self.journal.pop().expect("No open transaction that can be rolled back.");
+		for access in accesses {
+			self.accessed.remove(&access);
+		}
+	}
+
+	fn insert(&mut self, access: Access) -> bool {
+		if self.accessed.contains(&access) {
+			return false
+		}
+		if let Some(accesses) = self.journal.last_mut() {
+			accesses.push(access.clone());
+		}
+		self.accessed.insert(access)
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use super::{GasMeter, Token, Weight};
-	use crate::tests::Test;
+	use super::{AccessTracker, GasMeter, Token, Weight};
+	use crate::{exec::Key, tests::Test};
+	use sp_core::H160;
 
 	/// A simple utility macro that helps to match against a
 	/// list of tokens.
@@ -450,4 +633,27 @@ mod tests {
 		let mut gas_meter = GasMeter::<Test>::new(Weight::from_parts(25, 0));
 		assert!(!gas_meter.charge(SimpleToken(25)).is_err());
 	}
+
+	#[test]
+	fn access_tracker_rolls_back() {
+		let (a, b) = (H160::repeat_byte(1), H160::repeat_byte(2));
+		let key = Key::from_fixed([1u8; 32]);
+		let mut tracker = AccessTracker::default();
+
+		assert!(tracker.access_address(&a));
+		assert!(!tracker.access_address(&a));
+
+		tracker.start_transaction();
+		assert!(tracker.access_storage(&a, &key));
+		tracker.start_transaction();
+		assert!(tracker.access_address(&b));
+		assert!(!tracker.access_storage(&a, &key));
+		tracker.rollback_transaction();
+		tracker.commit_transaction();
+
+		// Only the accesses of the rolled back transaction are forgotten.
+		assert!(tracker.access_address(&b));
+		assert!(!tracker.access_storage(&a, &key));
+		assert!(tracker.access_storage(&b, &key));
+	}
 }
@@ -38,16 +38,18 @@ mod wasm;
 
 pub mod evm;
 pub mod precompiles;
+pub mod proof;
 pub mod test_utils;
 pub mod tracing;
 pub mod weights;
 
 use crate::{
 	evm::{
-		runtime::GAS_PRICE, CallTracer, GasEncoder, GenericTransaction, Trace, Tracer, TracerType,
-		TYPE_EIP1559,
+		runtime::GAS_PRICE, AccessListResult, BlockOverrides, CallTracer, GasEncoder,
+		GenericTransaction, PrestateTracer, StateOverrideSet, StructLogger, Trace, Tracer,
+		TracerType, TYPE_EIP1559,
 	},
-	exec::{AccountIdOf, ExecError, Executable, Key, Stack as ExecStack},
+	exec::{AccountIdOf, ExecError, Executable, Stack as ExecStack},
 	gas::GasMeter,
 	storage::{meter::Meter as StorageMeter, ContractInfo, DeletionQueueManager},
 	wasm::{CodeInfo, RuntimeCosts, WasmBlob},
@@ -62,6 +64,7 @@ use frame_support::{
 	},
 	ensure,
 	pallet_prelude::DispatchClass,
+	storage::{with_transaction, TransactionOutcome},
 	traits::{
 		fungible::{Inspect, Mutate, MutateHold},
 		tokens::{Fortitude::Polite, Preservation::Preserve},
@@ -78,14 +81,14 @@ use frame_system::{
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{BadOrigin, Bounded, Convert, Dispatchable, Saturating, Zero},
-	AccountId32, DispatchError,
+	AccountId32, DispatchError, SaturatedConversion,
 };
 
 pub use crate::{
 	address::{
 		create1, create2, is_eth_derived, AccountId32Mapper, AddressMapper, TestAccountMapper,
 	},
-	exec::{MomentOf, Origin},
+	exec::{Key, MomentOf, Origin},
 	pallet::*,
 };
 pub use codec;
@@ -358,6 +361,14 @@ pub mod pallet {
 			/// Number of topics is capped by [`limits::NUM_EVENT_TOPICS`].
 			topics: Vec<H256>,
 		},
+
+		/// The call of a [`Call::call_with_authorizations`] failed or reverted.
+		///
+		/// The authorizations remain applied, and the changes made by the call are reverted.
+		SetCodeCallFailed {
+			/// The error the call failed with.
+			error: DispatchError,
+		},
 	}
 
 	#[pallet::error]
@@ -519,6 +530,13 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type OriginalAccount<T: Config> = StorageMap<_, Identity, H160, AccountId32>;
 
+	/// The account whose code is executed when calling an account delegated with an EIP-7702
+	/// authorization.
+	///
+	/// See [`crate::evm::AuthorizationListEntry`].
+	#[pallet::storage]
+	pub(crate) type DelegationOf<T: Config> = StorageMap<_, Identity, H160, H160>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -956,6 +974,133 @@ pub mod pallet {
 				T::AddressMapper::to_fallback_account_id(&T::AddressMapper::to_address(&origin));
 			call.dispatch(RawOrigin::Signed(unmapped_account).into())
 		}
+
+		/// Makes a call to an account after applying a list of EIP-7702 authorizations.
+		///
+		/// This is the dispatchable an Ethereum set-code transaction is converted to. It is
+		/// identical to [`Self::call`], except that the authorizations are applied before the
+		/// call is executed.
+		///
+		/// # Parameters
+		///
+		/// * `access_list`: The accounts and storage entries declared as accessed by the call, see
+		///   [`Self::call_with_access_list`].
+		/// * `authorization_list`: The authorizations delegating the code of their authority to the
+		///   code of another account. Invalid authorizations are skipped.
+		///
+		/// See [`Self::call`] for the other parameters.
+		///
+		/// # Note
+		///
+		/// As required by EIP-7702, the authorizations remain applied if the call fails. The
+		/// dispatch then succeeds and the failure is reported by [`Event::SetCodeCallFailed`].
+		#[pallet::call_index(10)]
+		#[pallet::weight(
+			T::WeightInfo::call()
+				.saturating_add(gas::access_list_weight::<T>(access_list))
+				.saturating_add(
+					T::WeightInfo::apply_authorization()
+						.saturating_mul(authorization_list.len() as u64)
+				)
+				.saturating_add(*gas_limit)
+		)]
+		pub fn call_with_authorizations(
+			origin: OriginFor<T>,
+			dest: H160,
+			#[pallet::compact] value: BalanceOf<T>,
+			gas_limit: Weight,
+			#[pallet::compact] storage_deposit_limit: BalanceOf<T>,
+			data: Vec<u8>,
+			access_list: evm::BoundedAccessList,
+			authorization_list: evm::BoundedAuthorizationList,
+		) -> DispatchResultWithPostInfo {
+			let base_weight = T::WeightInfo::call()
+				.saturating_add(gas::access_list_weight::<T>(&access_list))
+				.saturating_add(
+					T::WeightInfo::apply_authorization()
+						.saturating_mul(authorization_list.len() as u64),
+				);
+			evm::apply_authorizations::<T>(&authorization_list);
+
+			// The call runs in its own storage layer, so that only its changes are reverted if
+			// it fails.
+			let output = with_transaction(|| {
+				let mut output = gas::with_access_list(&access_list, || {
+					Self::bare_call(
+						origin,
+						dest,
+						value,
+						gas_limit,
+						DepositLimit::Balance(storage_deposit_limit),
+						data,
+					)
+				});
+
+				if let Ok(return_value) = &output.result {
+					if return_value.did_revert() {
+						output.result = Err(<Error<T>>::ContractReverted.into());
+					}
+				}
+				if output.result.is_ok() {
+					TransactionOutcome::Commit(Ok::<_, DispatchError>(output))
+				} else {
+					TransactionOutcome::Rollback(Ok(output))
+				}
+			})?;
+
+			if let Err(error) = output.result {
+				Self::deposit_event(Event::SetCodeCallFailed { error });
+			}
+			dispatch_result(Ok(()), output.gas_consumed, base_weight)
+		}
+
+		/// Makes a call to an account with an EIP-2930 access list.
+		///
+		/// This is the dispatchable an Ethereum transaction with a non-empty access list is
+		/// converted to. It is identical to [`Self::call`], except that the accounts and storage
+		/// entries of the access list are warm from the start of the call.
+		///
+		/// # Parameters
+		///
+		/// * `access_list`: The accounts and storage entries declared as accessed by the call.
+		///   Their cold access cost is charged upfront by the weight of the dispatchable.
+		///
+		/// See [`Self::call`] for the other parameters.
+		#[pallet::call_index(11)]
+		#[pallet::weight(
+			T::WeightInfo::call()
+				.saturating_add(gas::access_list_weight::<T>(access_list))
+				.saturating_add(*gas_limit)
+		)]
+		pub fn call_with_access_list(
+			origin: OriginFor<T>,
+			dest: H160,
+			#[pallet::compact] value: BalanceOf<T>,
+			gas_limit: Weight,
+			#[pallet::compact] storage_deposit_limit: BalanceOf<T>,
+			data: Vec<u8>,
+			access_list: evm::BoundedAccessList,
+		) -> DispatchResultWithPostInfo {
+			let base_weight =
+				T::WeightInfo::call().saturating_add(gas::access_list_weight::<T>(&access_list));
+			let mut output = gas::with_access_list(&access_list, || {
+				Self::bare_call(
+					origin,
+					dest,
+					value,
+					gas_limit,
+					DepositLimit::Balance(storage_deposit_limit),
+					data,
+				)
+			});
+
+			if let Ok(return_value) = &output.result {
+				if return_value.did_revert() {
+					output.result = Err(<Error<T>>::ContractReverted.into());
+				}
+			}
+			dispatch_result(output.result, output.gas_consumed, base_weight)
+		}
 	}
 }
 
@@ -1198,15 +1343,28 @@ where
 		let (mut result, dispatch_info) = match tx.to {
 			// A contract call.
 			Some(dest) => {
+				// The authorizations of a set-code transaction are applied before the call.
+				let authorization_list: evm::BoundedAuthorizationList =
+					tx.authorization_list.clone().try_into().map_err(|_| {
+						EthTransactError::Message("Too many authorizations".into())
+					})?;
+				evm::apply_authorizations::<T>(&authorization_list);
+
 				// Dry run the call.
-				let result = crate::Pallet::<T>::bare_call(
-					T::RuntimeOrigin::signed(origin),
-					dest,
-					native_value,
-					gas_limit,
-					storage_deposit_limit,
-					input.clone(),
-				);
+				let access_list: evm::BoundedAccessList =
+					tx.access_list.clone().unwrap_or_default().try_into().map_err(|_| {
+						EthTransactError::Message("Too many access list entries".into())
+					})?;
+				let result = gas::with_access_list(&access_list, || {
+					crate::Pallet::<T>::bare_call(
+						T::RuntimeOrigin::signed(origin),
+						dest,
+						native_value,
+						gas_limit,
+						storage_deposit_limit,
+						input.clone(),
+					)
+				});
 
 				let data = match result.result {
 					Ok(return_value) => {
@@ -1232,16 +1390,43 @@ where
 					result.gas_required,
 					result.storage_deposit,
 				);
-				let dispatch_call: <T as Config>::RuntimeCall = crate::Call::<T>::call {
-					dest,
-					value: native_value,
-					gas_limit,
-					storage_deposit_limit,
-					data: input.clone(),
-				}
-				.into();
+				let dispatch_call: <T as Config>::RuntimeCall =
+					match (access_list.is_empty(), tx.authorization_list.is_empty()) {
+						(true, true) => crate::Call::<T>::call {
+							dest,
+							value: native_value,
+							gas_limit,
+							storage_deposit_limit,
+							data: input.clone(),
+						}
+						.into(),
+						(false, true) => crate::Call::<T>::call_with_access_list {
+							dest,
+							value: native_value,
+							gas_limit,
+							storage_deposit_limit,
+							data: input.clone(),
+							access_list,
+						}
+						.into(),
+						(_, false) => crate::Call::<T>::call_with_authorizations {
+							dest,
+							value: native_value,
+							gas_limit,
+							storage_deposit_limit,
+							data: input.clone(),
+							access_list,
+							authorization_list,
+						}
+						.into(),
+					};
 				(result, dispatch_call.get_dispatch_info())
 			},
+			// A set-code transaction cannot deploy a contract.
+			None if !tx.authorization_list.is_empty() =>
+				return Err(EthTransactError::Message(
+					"Set-code transactions cannot create contracts".into(),
+				)),
 			// A contract deployment
 			None => {
 				// Extract code and data from the input.
@@ -1323,6 +1508,80 @@ where
 		Ok(result)
 	}
 
+	/// Create the access list of the given transaction.
+	///
+	/// The transaction is dry run with `dry_run`, see [`Self::dry_run_eth_transact`]. As the
+	/// declared access list changes the gas available to the transaction, and thus possibly its
+	/// execution, it is dry run again with the access list of the previous run until the list
+	/// doesn't change anymore. The state changes of the runs are discarded.
+	///
+	/// An execution error of the transaction is reported in [`AccessListResult::error`], along
+	/// with the access list of the failed run.
+	pub fn create_access_list(
+		mut tx: GenericTransaction,
+		dry_run: impl Fn(GenericTransaction) -> Result<EthTransactInfo<BalanceOf<T>>, EthTransactError>,
+	) -> Result<AccessListResult, EthTransactError> {
+		use frame_support::storage::{with_transaction, TransactionOutcome};
+
+		/// The maximum number of dry runs, in case the access list doesn't converge.
+		const MAX_RUNS: usize = 8;
+
+		let excluded: Vec<H160> = tx.from.into_iter().chain(tx.to).collect();
+		let mut access_list = tx.access_list.take().unwrap_or_default();
+
+		let mut runs = 0;
+		loop {
+			runs += 1;
+			let mut tracer = evm::AccessListTracer::new(excluded.iter().copied(), &access_list);
+			tx.access_list = Some(access_list.clone());
+			let result = tracing::trace(&mut tracer, || {
+				with_transaction(|| {
+					TransactionOutcome::Rollback(Ok::<_, DispatchError>(dry_run(tx.clone())))
+				})
+			})
+			.map_err(|err| EthTransactError::Message(format!("Failed to dry run: {err:?}")))?;
+
+			let new_access_list = tracer.access_list();
+			let info = match result {
+				Ok(info) => info,
+				Err(err) => {
+					let error = match err {
+						EthTransactError::Data(_) => "execution reverted".into(),
+						EthTransactError::Message(message) => message,
+					};
+					return Ok(AccessListResult {
+						access_list: new_access_list,
+						error: Some(error),
+						gas_used: Default::default(),
+					})
+				},
+			};
+
+			if new_access_list == access_list || runs >= MAX_RUNS {
+				return Ok(AccessListResult {
+					access_list: new_access_list,
+					error: None,
+					gas_used: info.eth_gas,
+				})
+			}
+			access_list = new_access_list;
+		}
+	}
+
+	/// Run `f`, usually a dry run of an Ethereum transaction, with the given overrides.
+	///
+	/// The state overrides are written to the state before running `f`, and the block overrides
+	/// are applied to the call stacks created by `f`. This must only be called from a runtime
+	/// API, as the state overrides are not reverted.
+	pub fn with_overrides<R>(
+		state_overrides: StateOverrideSet,
+		mut block_overrides: BlockOverrides,
+		f: impl FnOnce() -> Result<R, EthTransactError>,
+	) -> Result<R, EthTransactError> {
+		evm::overrides::apply_state_overrides::<T>(state_overrides)?;
+		evm::overrides::with_block_overrides(&mut block_overrides, f)
+	}
+
 	/// Get the balance with EVM decimals of the given `address`.
 	pub fn evm_balance(address: &H160) -> U256 {
 		let account = T::AddressMapper::to_account_id(&address);
@@ -1354,6 +1613,11 @@ where
 		Self::evm_fee_to_gas(fee)
 	}
 
+	/// Convert a weight to a gas value, saturating at `u64::MAX`.
+	fn evm_gas_from_weight_u64(weight: Weight) -> u64 {
+		Self::evm_gas_from_weight(weight).saturated_into()
+	}
+
 	/// Get the block gas limit.
 	pub fn evm_block_gas_limit() -> U256 {
 		let max_block_weight = T::BlockWeights::get()
@@ -1370,13 +1634,20 @@ where
 	}
 
 	/// Build an EVM tracer from the given tracer type.
-	pub fn evm_tracer(tracer_type: TracerType) -> Tracer {
+	pub fn evm_tracer(tracer_type: TracerType) -> Tracer<T> {
 		match tracer_type {
 			TracerType::CallTracer(config) => CallTracer::new(
 				config.unwrap_or_default(),
 				Self::evm_gas_from_weight as fn(Weight) -> U256,
 			)
 			.into(),
+			TracerType::PrestateTracer(config) =>
+				PrestateTracer::new(config.unwrap_or_default()).into(),
+			TracerType::StructLogger(config) => StructLogger::new(
+				config.unwrap_or_default(),
+				Self::evm_gas_from_weight_u64 as fn(Weight) -> u64,
+			)
+			.into(),
 		}
 	}
 
@@ -1393,6 +1664,20 @@ where
 		Ok(CodeUploadReturnValue { code_hash: *module.code_hash(), deposit })
 	}
 
+	/// Get the code of the contract at the given `address`.
+	///
+	/// Returns the delegation designator if the account is delegated with an EIP-7702
+	/// authorization, and an empty vector if no contract exists at the given `address`.
+	pub fn code(address: &H160) -> Vec<u8> {
+		if let Some(designator) = evm::delegation_designator_of::<T>(address) {
+			return designator;
+		}
+		ContractInfoOf::<T>::get(address)
+			.and_then(|info| PristineCode::<T>::get(info.code_hash))
+			.map(|code| code.into_inner())
+			.unwrap_or_default()
+	}
+
 	/// Query storage of a specified contract under a specified key.
 	pub fn get_storage(address: H160, key: [u8; 32]) -> GetStorageResult {
 		let contract_info =
@@ -1415,6 +1700,30 @@ where
 		Ok(maybe_value)
 	}
 
+	/// Get the storage keys needed to prove the state of `address` and of the given storage
+	/// slots.
+	///
+	/// The storage keys are empty if the account is not a contract.
+	pub fn account_proof_keys(address: H160, storage_keys: Vec<[u8; 32]>) -> AccountProofKeys {
+		let account_id = T::AddressMapper::to_account_id(&address);
+		let mut account_keys = vec![
+			frame_system::Account::<T>::hashed_key_for(&account_id),
+			ContractInfoOf::<T>::hashed_key_for(&address),
+		];
+
+		let Some(contract_info) = ContractInfoOf::<T>::get(&address) else {
+			return AccountProofKeys { account_keys, ..Default::default() }
+		};
+
+		let child_trie_key = contract_info.child_trie_info().prefixed_storage_key().into_inner();
+		account_keys.push(child_trie_key.clone());
+		AccountProofKeys {
+			account_keys,
+			child_trie_key: Some(child_trie_key),
+			storage_keys: storage_keys.into_iter().map(|key| Key::from_fixed(key).hash()).collect(),
+		}
+	}
+
 	/// Uploads new code and returns the Wasm blob and deposit amount collected.
 	fn try_upload_code(
 		origin: T::AccountId,
@@ -1490,7 +1799,7 @@ environmental!(executing_contract: bool);
 
 sp_api::decl_runtime_apis! {
 	/// The API used to dry-run contract interactions.
-	#[api_version(1)]
+	#[api_version(2)]
 	pub trait ReviveApi<AccountId, Balance, Nonce, BlockNumber> where
 		AccountId: Codec,
 		Balance: Codec,
@@ -1535,11 +1844,35 @@ sp_api::decl_runtime_apis! {
 		) -> ContractResult<InstantiateReturnValue, Balance>;
 
 
+		/// Perform an Ethereum call.
+		#[changed_in(2)]
+		fn eth_transact(
+			tx: crate::evm::GenericTransactionV1,
+		) -> Result<EthTransactInfo<Balance>, EthTransactError>;
+
 		/// Perform an Ethereum call.
 		///
 		/// See [`crate::Pallet::dry_run_eth_transact`]
 		fn eth_transact(tx: GenericTransaction) -> Result<EthTransactInfo<Balance>, EthTransactError>;
 
+		/// Perform an Ethereum call with the given state and block overrides.
+		///
+		/// See [`crate::Pallet::with_overrides`]
+		#[api_version(2)]
+		fn eth_transact_with_overrides(
+			tx: GenericTransaction,
+			state_overrides: StateOverrideSet,
+			block_overrides: BlockOverrides,
+		) -> Result<EthTransactInfo<Balance>, EthTransactError>;
+
+		/// Dry run an Ethereum transaction and return its access list.
+		///
+		/// See [`crate::Pallet::create_access_list`]
+		#[api_version(2)]
+		fn eth_create_access_list(
+			tx: GenericTransaction,
+		) -> Result<AccessListResult, EthTransactError>;
+
 		/// Upload new code without instantiating a contract from it.
 		///
 		/// See [`crate::Pallet::bare_upload_code`].
@@ -1569,6 +1902,13 @@ sp_api::decl_runtime_apis! {
 			key: Vec<u8>,
 		) -> GetStorageResult;
 
+		/// Returns the storage keys needed to prove the state of the given `[H160]` address, and
+		/// of the given storage slots.
+		///
+		/// See eth-rpc `eth_getProof` for usage, and [`crate::proof`] for the verification.
+		#[api_version(2)]
+		fn account_proof_keys(address: H160, storage_keys: Vec<[u8; 32]>) -> AccountProofKeys;
+
 		/// Traces the execution of an entire block and returns call traces.
 		///
 		/// This is intended to be called through `state_call` to replay the block from the
@@ -1592,6 +1932,13 @@ sp_api::decl_runtime_apis! {
 			config: TracerType
 		) -> Option<Trace>;
 
+		/// Dry run and return the trace of the given call.
+		#[changed_in(2)]
+		fn trace_call(
+			tx: crate::evm::GenericTransactionV1,
+			config: TracerType,
+		) -> Result<Trace, EthTransactError>;
+
 		/// Dry run and return the trace of the given call.
 		///
 		/// See eth-rpc `debug_traceCall` for usage.
@@ -1663,6 +2010,22 @@ macro_rules! impl_runtime_apis_plus_revive {
 					$crate::Pallet::<Self>::dry_run_eth_transact(tx, blockweights.max_block, tx_fee)
 				}
 
+				fn eth_transact_with_overrides(
+					tx: $crate::evm::GenericTransaction,
+					state_overrides: $crate::evm::StateOverrideSet,
+					block_overrides: $crate::evm::BlockOverrides,
+				) -> Result<$crate::EthTransactInfo<Balance>, $crate::EthTransactError> {
+					$crate::Pallet::<Self>::with_overrides(state_overrides, block_overrides, || {
+						Self::eth_transact(tx)
+					})
+				}
+
+				fn eth_create_access_list(
+					tx: $crate::evm::GenericTransaction,
+				) -> Result<$crate::evm::AccessListResult, $crate::EthTransactError> {
+					$crate::Pallet::<Self>::create_access_list(tx, |tx| Self::eth_transact(tx))
+				}
+
 				fn call(
 					origin: AccountId,
 					dest: $crate::H160,
@@ -1736,6 +2099,13 @@ macro_rules! impl_runtime_apis_plus_revive {
 					$crate::Pallet::<Self>::get_storage(address, key)
 				}
 
+				fn account_proof_keys(
+					address: $crate::H160,
+					storage_keys: Vec<[u8; 32]>,
+				) -> $crate::AccountProofKeys {
+					$crate::Pallet::<Self>::account_proof_keys(address, storage_keys)
+				}
+
 				fn trace_block(
 					block: Block,
 					tracer_type: $crate::evm::TracerType,
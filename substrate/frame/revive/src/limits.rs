@@ -46,6 +46,18 @@ pub const NUM_EVENT_TOPICS: u32 = 4;
 /// Maximum size of events (including topics) and storage values.
 pub const PAYLOAD_BYTES: u32 = 416;
 
+/// The maximum number of authorizations of an EIP-7702 set-code transaction.
+///
+/// Ethereum only bounds the authorization list by the gas limit of the transaction. We need a
+/// static bound because all the authorizations are charged upfront by the dispatchable weight.
+pub const AUTHORIZATIONS: u32 = 64;
+
+/// The maximum number of addresses and storage keys of an EIP-2930 access list.
+///
+/// Ethereum only bounds the access list by the gas limit of the transaction. We need a static
+/// bound because all the entries are charged upfront by the dispatchable weight.
+pub const ACCESS_LIST_ENTRIES: u32 = 256;
+
 /// The maximum size of the transient storage in bytes.
 ///
 /// This includes keys, values, and previous entries used for storage rollback.
@@ -16,10 +16,12 @@
 // limitations under the License.
 
 mod blake2f;
+mod bls12;
 mod bn128;
 mod ecrecover;
 mod identity;
 mod modexp;
+mod p256_verify;
 mod point_eval;
 mod ripemd160;
 mod sha256;
@@ -53,6 +55,14 @@ type Production<T> = (
 	bn128::Bn128Pairing<T>,
 	blake2f::Blake2F<T>,
 	point_eval::PointEval<T>,
+	bls12::Bls12G1Add<T>,
+	bls12::Bls12G1Msm<T>,
+	bls12::Bls12G2Add<T>,
+	bls12::Bls12G2Msm<T>,
+	bls12::Bls12PairingCheck<T>,
+	bls12::Bls12MapFpToG1<T>,
+	bls12::Bls12MapFp2ToG2<T>,
+	p256_verify::P256Verify<T>,
 );
 
 #[cfg(feature = "runtime-benchmarks")]
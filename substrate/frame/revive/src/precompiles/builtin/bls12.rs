@@ -0,0 +1,423 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The BLS12-381 precompiles of EIP-2537.
+//!
+//! See <https://eips.ethereum.org/EIPS/eip-2537>
+
+use crate::{
+	precompiles::{BuiltinAddressMatcher, Error, Ext, PrimitivePrecompile},
+	wasm::RuntimeCosts,
+	Config,
+};
+use alloc::vec::Vec;
+use ark_bls12_381::{g1, g2, Bls12_381, Fq, Fq2};
+use ark_ec::{
+	hashing::{
+		curve_maps::wb::{WBConfig, WBMap},
+		map_to_curve_hasher::MapToCurve,
+	},
+	pairing::Pairing,
+	short_weierstrass::{Affine, Projective, SWCurveConfig},
+	AffineRepr, CurveGroup, VariableBaseMSM,
+};
+use ark_ff::{BigInt, BigInteger, One, PrimeField, Zero};
+use core::{marker::PhantomData, num::NonZero};
+use sp_core::U256;
+use sp_runtime::DispatchError;
+
+/// The length of an encoded base field element, padded to 64 bytes.
+const FP_LENGTH: usize = 64;
+/// The number of zero bytes padding an encoded base field element.
+const FP_PADDING: usize = 16;
+/// The length of an encoded scalar.
+const SCALAR_LENGTH: usize = 32;
+/// The length of an encoded G1 point.
+const G1_LENGTH: usize = 2 * FP_LENGTH;
+/// The length of an encoded G2 point.
+const G2_LENGTH: usize = 4 * FP_LENGTH;
+
+pub struct Bls12G1Add<T>(PhantomData<T>);
+
+impl<T: Config> PrimitivePrecompile for Bls12G1Add<T> {
+	type T = T;
+	const MATCHER: BuiltinAddressMatcher =
+		BuiltinAddressMatcher::Fixed(NonZero::new(0x0b).unwrap());
+	const HAS_CONTRACT_INFO: bool = false;
+
+	fn call(
+		_address: &[u8; 20],
+		input: Vec<u8>,
+		env: &mut impl Ext<T = Self::T>,
+	) -> Result<Vec<u8>, Error> {
+		if input.len() != 2 * G1_LENGTH {
+			Err(DispatchError::from("invalid input length"))?;
+		}
+		env.gas_meter_mut().charge(RuntimeCosts::Bls12G1Add)?;
+
+		Ok(add::<g1::Config>(&input)?)
+	}
+}
+
+pub struct Bls12G1Msm<T>(PhantomData<T>);
+
+impl<T: Config> PrimitivePrecompile for Bls12G1Msm<T> {
+	type T = T;
+	const MATCHER: BuiltinAddressMatcher =
+		BuiltinAddressMatcher::Fixed(NonZero::new(0x0c).unwrap());
+	const HAS_CONTRACT_INFO: bool = false;
+
+	fn call(
+		_address: &[u8; 20],
+		input: Vec<u8>,
+		env: &mut impl Ext<T = Self::T>,
+	) -> Result<Vec<u8>, Error> {
+		let pairs = count_pairs(&input, G1_LENGTH + SCALAR_LENGTH)?;
+		env.gas_meter_mut().charge(RuntimeCosts::Bls12G1Msm(pairs))?;
+
+		Ok(msm::<g1::Config>(&input)?)
+	}
+}
+
+pub struct Bls12G2Add<T>(PhantomData<T>);
+
+impl<T: Config> PrimitivePrecompile for Bls12G2Add<T> {
+	type T = T;
+	const MATCHER: BuiltinAddressMatcher =
+		BuiltinAddressMatcher::Fixed(NonZero::new(0x0d).unwrap());
+	const HAS_CONTRACT_INFO: bool = false;
+
+	fn call(
+		_address: &[u8; 20],
+		input: Vec<u8>,
+		env: &mut impl Ext<T = Self::T>,
+	) -> Result<Vec<u8>, Error> {
+		if input.len() != 2 * G2_LENGTH {
+			Err(DispatchError::from("invalid input length"))?;
+		}
+		env.gas_meter_mut().charge(RuntimeCosts::Bls12G2Add)?;
+
+		Ok(add::<g2::Config>(&input)?)
+	}
+}
+
+pub struct Bls12G2Msm<T>(PhantomData<T>);
+
+impl<T: Config> PrimitivePrecompile for Bls12G2Msm<T> {
+	type T = T;
+	const MATCHER: BuiltinAddressMatcher =
+		BuiltinAddressMatcher::Fixed(NonZero::new(0x0e).unwrap());
+	const HAS_CONTRACT_INFO: bool = false;
+
+	fn call(
+		_address: &[u8; 20],
+		input: Vec<u8>,
+		env: &mut impl Ext<T = Self::T>,
+	) -> Result<Vec<u8>, Error> {
+		let pairs = count_pairs(&input, G2_LENGTH + SCALAR_LENGTH)?;
+		env.gas_meter_mut().charge(RuntimeCosts::Bls12G2Msm(pairs))?;
+
+		Ok(msm::<g2::Config>(&input)?)
+	}
+}
+
+pub struct Bls12PairingCheck<T>(PhantomData<T>);
+
+impl<T: Config> PrimitivePrecompile for Bls12PairingCheck<T> {
+	type T = T;
+	const MATCHER: BuiltinAddressMatcher =
+		BuiltinAddressMatcher::Fixed(NonZero::new(0x0f).unwrap());
+	const HAS_CONTRACT_INFO: bool = false;
+
+	fn call(
+		_address: &[u8; 20],
+		input: Vec<u8>,
+		env: &mut impl Ext<T = Self::T>,
+	) -> Result<Vec<u8>, Error> {
+		let pairs = count_pairs(&input, G1_LENGTH + G2_LENGTH)?;
+		env.gas_meter_mut().charge(RuntimeCosts::Bls12PairingCheck(pairs))?;
+
+		let (a, b): (Vec<_>, Vec<_>) = input
+			.chunks_exact(G1_LENGTH + G2_LENGTH)
+			.map(|pair| {
+				let a = decode_point::<g1::Config>(&pair[..G1_LENGTH], true)?;
+				let b = decode_point::<g2::Config>(&pair[G1_LENGTH..], true)?;
+				Ok((a, b))
+			})
+			.collect::<Result<Vec<_>, DispatchError>>()?
+			.into_iter()
+			.unzip();
+
+		let ret_val =
+			if Bls12_381::multi_pairing(a, b).0.is_one() { U256::one() } else { U256::zero() };
+
+		Ok(ret_val.to_big_endian().to_vec())
+	}
+}
+
+pub struct Bls12MapFpToG1<T>(PhantomData<T>);
+
+impl<T: Config> PrimitivePrecompile for Bls12MapFpToG1<T> {
+	type T = T;
+	const MATCHER: BuiltinAddressMatcher =
+		BuiltinAddressMatcher::Fixed(NonZero::new(0x10).unwrap());
+	const HAS_CONTRACT_INFO: bool = false;
+
+	fn call(
+		_address: &[u8; 20],
+		input: Vec<u8>,
+		env: &mut impl Ext<T = Self::T>,
+	) -> Result<Vec<u8>, Error> {
+		if input.len() != FP_LENGTH {
+			Err(DispatchError::from("invalid input length"))?;
+		}
+		env.gas_meter_mut().charge(RuntimeCosts::Bls12MapFpToG1)?;
+
+		Ok(map_to_curve::<g1::Config>(&input)?)
+	}
+}
+
+pub struct Bls12MapFp2ToG2<T>(PhantomData<T>);
+
+impl<T: Config> PrimitivePrecompile for Bls12MapFp2ToG2<T> {
+	type T = T;
+	const MATCHER: BuiltinAddressMatcher =
+		BuiltinAddressMatcher::Fixed(NonZero::new(0x11).unwrap());
+	const HAS_CONTRACT_INFO: bool = false;
+
+	fn call(
+		_address: &[u8; 20],
+		input: Vec<u8>,
+		env: &mut impl Ext<T = Self::T>,
+	) -> Result<Vec<u8>, Error> {
+		if input.len() != 2 * FP_LENGTH {
+			Err(DispatchError::from("invalid input length"))?;
+		}
+		env.gas_meter_mut().charge(RuntimeCosts::Bls12MapFp2ToG2)?;
+
+		Ok(map_to_curve::<g2::Config>(&input)?)
+	}
+}
+
+/// The encoding of the base field elements of the curves.
+trait Encoding: Sized {
+	/// The length of an encoded element.
+	const LENGTH: usize;
+
+	/// Decode an element, rejecting non canonical encodings.
+	fn decode(input: &[u8]) -> Result<Self, DispatchError>;
+
+	/// Append the encoded element to `output`.
+	fn encode(&self, output: &mut Vec<u8>);
+}
+
+impl Encoding for Fq {
+	const LENGTH: usize = FP_LENGTH;
+
+	fn decode(input: &[u8]) -> Result<Self, DispatchError> {
+		let (padding, bytes) = input.split_at(FP_PADDING);
+		if padding.iter().any(|byte| *byte != 0) {
+			return Err("invalid field element".into());
+		}
+
+		let mut limbs = [0u64; 6];
+		for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks_exact(8)) {
+			*limb = u64::from_be_bytes(chunk.try_into().expect("chunks are 8 bytes long; qed"));
+		}
+
+		// Values greater than or equal to the modulus are rejected.
+		Fq::from_bigint(BigInt(limbs)).ok_or_else(|| "invalid field element".into())
+	}
+
+	fn encode(&self, output: &mut Vec<u8>) {
+		output.extend_from_slice(&[0u8; FP_PADDING]);
+		output.extend_from_slice(&self.into_bigint().to_bytes_be());
+	}
+}
+
+impl Encoding for Fq2 {
+	const LENGTH: usize = 2 * FP_LENGTH;
+
+	fn decode(input: &[u8]) -> Result<Self, DispatchError> {
+		let c0 = Fq::decode(&input[..FP_LENGTH])?;
+		let c1 = Fq::decode(&input[FP_LENGTH..])?;
+		Ok(Fq2::new(c0, c1))
+	}
+
+	fn encode(&self, output: &mut Vec<u8>) {
+		self.c0.encode(output);
+		self.c1.encode(output);
+	}
+}
+
+/// Returns the number of pairs of the given length in `input`, which must not be empty.
+fn count_pairs(input: &[u8], pair_length: usize) -> Result<u32, DispatchError> {
+	if input.is_empty() || input.len() % pair_length != 0 {
+		return Err("invalid input length".into());
+	}
+	Ok((input.len() / pair_length) as u32)
+}
+
+/// Decode a point, checking that it is on the curve, and optionally in the subgroup.
+///
+/// The point at infinity is encoded with zero coordinates.
+fn decode_point<P: SWCurveConfig>(
+	input: &[u8],
+	subgroup_check: bool,
+) -> Result<Affine<P>, DispatchError>
+where
+	P::BaseField: Encoding,
+{
+	let (x, y) = input.split_at(P::BaseField::LENGTH);
+	let x = P::BaseField::decode(x)?;
+	let y = P::BaseField::decode(y)?;
+	if x.is_zero() && y.is_zero() {
+		return Ok(Affine::identity());
+	}
+
+	let point = Affine::new_unchecked(x, y);
+	if !point.is_on_curve() {
+		return Err("point is not on curve".into());
+	}
+	if subgroup_check && !point.is_in_correct_subgroup_assuming_on_curve() {
+		return Err("point is not in the subgroup".into());
+	}
+	Ok(point)
+}
+
+/// Encode a point, the point at infinity being encoded with zero coordinates.
+fn encode_point<P: SWCurveConfig>(point: &Affine<P>) -> Vec<u8>
+where
+	P::BaseField: Encoding,
+{
+	let (x, y) = if point.infinity { (Zero::zero(), Zero::zero()) } else { (point.x, point.y) };
+	let mut output = Vec::with_capacity(2 * P::BaseField::LENGTH);
+	x.encode(&mut output);
+	y.encode(&mut output);
+	output
+}
+
+/// Add two points, which are not required to be in the subgroup.
+fn add<P: SWCurveConfig>(input: &[u8]) -> Result<Vec<u8>, DispatchError>
+where
+	P::BaseField: Encoding,
+{
+	let (a, b) = input.split_at(input.len() / 2);
+	let a = decode_point::<P>(a, false)?;
+	let b = decode_point::<P>(b, false)?;
+	Ok(encode_point(&(a.into_group() + b).into_affine()))
+}
+
+/// Multi-scalar multiplication of pairs of points and scalars.
+///
+/// The scalars are not required to be lower than the order of the subgroup.
+fn msm<P: SWCurveConfig>(input: &[u8]) -> Result<Vec<u8>, DispatchError>
+where
+	P::BaseField: Encoding,
+{
+	let point_length = 2 * P::BaseField::LENGTH;
+	let (bases, scalars): (Vec<_>, Vec<_>) = input
+		.chunks_exact(point_length + SCALAR_LENGTH)
+		.map(|pair| {
+			let point = decode_point::<P>(&pair[..point_length], true)?;
+			let scalar = P::ScalarField::from_be_bytes_mod_order(&pair[point_length..]);
+			Ok((point, scalar))
+		})
+		.collect::<Result<Vec<_>, DispatchError>>()?
+		.into_iter()
+		.unzip();
+
+	Ok(encode_point(&Projective::<P>::msm_unchecked(&bases, &scalars).into_affine()))
+}
+
+/// Map a base field element to a point of the subgroup, using the simplified SWU map followed
+/// by the clearing of the cofactor.
+fn map_to_curve<P: WBConfig>(input: &[u8]) -> Result<Vec<u8>, DispatchError>
+where
+	P::BaseField: Encoding,
+{
+	let element = P::BaseField::decode(input)?;
+	let point = WBMap::<P>::new()
+		.and_then(|map| map.map_to_curve(element))
+		.map_err(|_| DispatchError::from("failed to map to curve"))?;
+	Ok(encode_point(&point.clear_cofactor()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		precompiles::tests::{run_failure_test_vectors, run_test_vectors},
+		tests::Test,
+	};
+
+	#[test]
+	fn test_bls12_g1_add() {
+		run_test_vectors::<Bls12G1Add<Test>>(include_str!("./testdata/11-blsG1Add.json"));
+		run_failure_test_vectors::<Bls12G1Add<Test>>(include_str!(
+			"./testdata/11-blsG1Add-failure.json"
+		));
+	}
+
+	#[test]
+	fn test_bls12_g1_msm() {
+		run_test_vectors::<Bls12G1Msm<Test>>(include_str!("./testdata/12-blsG1MultiExp.json"));
+		run_failure_test_vectors::<Bls12G1Msm<Test>>(include_str!(
+			"./testdata/12-blsG1MultiExp-failure.json"
+		));
+	}
+
+	#[test]
+	fn test_bls12_g2_add() {
+		run_test_vectors::<Bls12G2Add<Test>>(include_str!("./testdata/13-blsG2Add.json"));
+		run_failure_test_vectors::<Bls12G2Add<Test>>(include_str!(
+			"./testdata/13-blsG2Add-failure.json"
+		));
+	}
+
+	#[test]
+	fn test_bls12_g2_msm() {
+		run_test_vectors::<Bls12G2Msm<Test>>(include_str!("./testdata/14-blsG2MultiExp.json"));
+		run_failure_test_vectors::<Bls12G2Msm<Test>>(include_str!(
+			"./testdata/14-blsG2MultiExp-failure.json"
+		));
+	}
+
+	#[test]
+	fn test_bls12_pairing_check() {
+		run_test_vectors::<Bls12PairingCheck<Test>>(include_str!("./testdata/15-blsPairing.json"));
+		run_failure_test_vectors::<Bls12PairingCheck<Test>>(include_str!(
+			"./testdata/15-blsPairing-failure.json"
+		));
+	}
+
+	#[test]
+	fn test_bls12_map_fp_to_g1() {
+		run_test_vectors::<Bls12MapFpToG1<Test>>(include_str!("./testdata/16-blsMapG1.json"));
+		run_failure_test_vectors::<Bls12MapFpToG1<Test>>(include_str!(
+			"./testdata/16-blsMapG1-failure.json"
+		));
+	}
+
+	#[test]
+	fn test_bls12_map_fp2_to_g2() {
+		run_test_vectors::<Bls12MapFp2ToG2<Test>>(include_str!("./testdata/17-blsMapG2.json"));
+		run_failure_test_vectors::<Bls12MapFp2ToG2<Test>>(include_str!(
+			"./testdata/17-blsMapG2-failure.json"
+		));
+	}
+}
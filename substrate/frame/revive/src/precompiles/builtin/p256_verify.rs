@@ -0,0 +1,87 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+	precompiles::{BuiltinAddressMatcher, Error, Ext, PrimitivePrecompile},
+	wasm::RuntimeCosts,
+	Config,
+};
+use alloc::vec::Vec;
+use core::{marker::PhantomData, num::NonZero};
+use p256::{
+	ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey},
+	EncodedPoint, FieldBytes,
+};
+use sp_core::U256;
+
+/// The `P256VERIFY` precompile of RIP-7212, verifying secp256r1 signatures.
+///
+/// See <https://github.com/ethereum/RIPs/blob/master/RIPS/rip-7212.md>
+pub struct P256Verify<T>(PhantomData<T>);
+
+impl<T: Config> PrimitivePrecompile for P256Verify<T> {
+	type T = T;
+	const MATCHER: BuiltinAddressMatcher =
+		BuiltinAddressMatcher::Fixed(NonZero::new(0x100).unwrap());
+	const HAS_CONTRACT_INFO: bool = false;
+
+	fn call(
+		_address: &[u8; 20],
+		input: Vec<u8>,
+		env: &mut impl Ext<T = Self::T>,
+	) -> Result<Vec<u8>, Error> {
+		env.gas_meter_mut().charge(RuntimeCosts::P256Verify)?;
+
+		// An invalid input or signature returns an empty output instead of failing the call.
+		if verify(&input) {
+			Ok(U256::one().to_big_endian().to_vec())
+		} else {
+			Ok(Vec::new())
+		}
+	}
+}
+
+/// Verify the signature of a message hash.
+///
+/// The input is the 32 bytes message hash, followed by the `r` and `s` components of the
+/// signature and the `x` and `y` coordinates of the public key, each 32 bytes long.
+fn verify(input: &[u8]) -> bool {
+	if input.len() != 160 {
+		return false;
+	}
+
+	let Ok(signature) = Signature::from_slice(&input[32..96]) else { return false };
+	let public_key = EncodedPoint::from_affine_coordinates(
+		FieldBytes::from_slice(&input[96..128]),
+		FieldBytes::from_slice(&input[128..160]),
+		false,
+	);
+	let Ok(verifying_key) = VerifyingKey::from_encoded_point(&public_key) else { return false };
+
+	verifying_key.verify_prehash(&input[..32], &signature).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{precompiles::tests::run_test_vectors, tests::Test};
+
+	#[test]
+	fn test_p256_verify() {
+		run_test_vectors::<P256Verify<Test>>(include_str!("./testdata/256-p256Verify.json"));
+	}
+}
@@ -131,6 +131,21 @@ pub enum ContractAccessError {
 	KeyDecodingFailed,
 }
 
+/// The storage keys needed to prove the state of an account and of some of its storage slots.
+///
+/// See [`crate::proof`] for how the resulting proofs are verified.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
+pub struct AccountProofKeys {
+	/// The keys of the account entries in the main trie.
+	///
+	/// This includes the key of the child trie root when the account is a contract.
+	pub account_keys: Vec<Vec<u8>>,
+	/// The prefixed storage key of the contract child trie, if the account is a contract.
+	pub child_trie_key: Option<Vec<u8>>,
+	/// The keys of the requested storage slots in the child trie, in the order of the request.
+	pub storage_keys: Vec<Vec<u8>>,
+}
+
 /// Output of a contract call or instantiation which ran to completion.
 #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
 pub struct ExecReturnValue {
@@ -0,0 +1,125 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verification of the account and storage proofs returned by the `eth_getProof` RPC.
+//!
+//! The proofs are plain substrate trie proofs of the state at a given block:
+//!
+//! - The account proof covers the `frame_system::Account` entry of the account, its
+//!   [`ContractInfoOf`] entry and, for contracts, the root of the contract's child trie.
+//! - The storage proofs cover the requested slots in the contract's child trie.
+//!
+//! The nodes of all the proofs are merged into a single [`StorageProof`] and checked against the
+//! state root of the block header with [`verify_account_proof`].
+
+use crate::{storage::ContractInfo, Config, ContractInfoOf, Key, H160};
+use alloc::vec::Vec;
+use codec::Decode;
+use frame_system::AccountInfo;
+use sp_trie::{LayoutV1, StorageProof};
+
+/// The account state of type `T` proven by a proof.
+pub type ProvenAccountInfo<T> =
+	AccountInfo<<T as frame_system::Config>::Nonce, <T as frame_system::Config>::AccountData>;
+
+/// The state of an account, verified against a state root.
+#[derive(frame_support::RuntimeDebugNoBound)]
+pub struct VerifiedAccount<T: Config> {
+	/// The account info stored by `frame_system`, if the account exists.
+	pub account: Option<ProvenAccountInfo<T>>,
+	/// The hash of the code of the account, if it is a contract.
+	pub code_hash: Option<sp_core::H256>,
+	/// The value of each requested storage slot, in the order of the request.
+	pub storage: Vec<([u8; 32], Option<Vec<u8>>)>,
+}
+
+/// The errors that can happen while verifying a proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofError {
+	/// A trie node needed to read one of the entries is missing from the proof.
+	IncompleteProof,
+	/// A proven value could not be decoded.
+	InvalidValue,
+}
+
+/// Verify a proof of the state of an account and of the given storage slots.
+///
+/// `state_root` is the state root of the block the proof was generated at, `account_id` is the
+/// account `address` is mapped to, and `proof` holds the merged nodes of the account proof and
+/// of the storage proofs.
+///
+/// Returns the proven state on success. Entries that are absent from the state are proven to be
+/// absent and reported as `None`. An error is returned if any of the entries can't be read
+/// from the proof, which means the proof is incomplete or was not generated for `state_root`.
+///
+/// # Example
+///
+/// ```ignore
+/// let proof = StorageProof::new(
+/// 	response.account_proof.into_iter().chain(
+/// 		response.storage_proof.into_iter().flat_map(|entry| entry.proof)
+/// 	).map(|node| node.0),
+/// );
+/// let verified =
+/// 	verify_account_proof::<Runtime>(&header.state_root, &account_id, &address, &keys, proof)?;
+/// ```
+pub fn verify_account_proof<T: Config>(
+	state_root: &T::Hash,
+	account_id: &T::AccountId,
+	address: &H160,
+	storage_keys: &[[u8; 32]],
+	proof: StorageProof,
+) -> Result<VerifiedAccount<T>, ProofError> {
+	let db = proof.into_memory_db::<T::Hashing>();
+	let read = |root: &T::Hash, key: &[u8]| {
+		sp_trie::read_trie_value::<LayoutV1<T::Hashing>, _>(&db, root, key, None, None)
+			.map_err(|_| ProofError::IncompleteProof)
+	};
+	fn decode<V: Decode>(value: Option<Vec<u8>>) -> Result<Option<V>, ProofError> {
+		value
+			.map(|value| V::decode(&mut &value[..]))
+			.transpose()
+			.map_err(|_| ProofError::InvalidValue)
+	}
+
+	let account: Option<ProvenAccountInfo<T>> =
+		decode(read(state_root, &frame_system::Account::<T>::hashed_key_for(account_id))?)?;
+	let contract: Option<ContractInfo<T>> =
+		decode(read(state_root, &ContractInfoOf::<T>::hashed_key_for(address))?)?;
+
+	// The root of the child trie is absent when the contract has no storage.
+	let child_root: Option<T::Hash> = match &contract {
+		Some(contract) => {
+			let child_info = contract.child_trie_info();
+			decode(read(state_root, child_info.prefixed_storage_key().as_slice())?)?
+		},
+		None => None,
+	};
+
+	let storage = storage_keys
+		.iter()
+		.map(|key| {
+			let value = match &child_root {
+				Some(root) => read(root, &Key::from_fixed(*key).hash())?,
+				None => None,
+			};
+			Ok((*key, value))
+		})
+		.collect::<Result<_, _>>()?;
+
+	Ok(VerifiedAccount { account, code_hash: contract.map(|contract| contract.code_hash), storage })
+}
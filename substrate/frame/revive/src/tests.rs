@@ -31,8 +31,8 @@ use crate::{
 	tracing::trace,
 	weights::WeightInfo,
 	AccountId32Mapper, BalanceOf, Code, CodeInfoOf, Config, ContractInfo, ContractInfoOf,
-	DeletionQueueCounter, DepositLimit, Error, EthTransactError, HoldReason, Origin, Pallet,
-	PristineCode, H160,
+	DelegationOf, DeletionQueueCounter, DepositLimit, Error, EthTransactError, HoldReason, Origin,
+	Pallet, PristineCode, H160,
 };
 
 use crate::test_utils::builder::Contract;
@@ -256,6 +256,7 @@ impl pallet_proxy::Config for Test {
 	type ProxyType = ();
 	type ProxyDepositBase = ConstU64<1>;
 	type ProxyDepositFactor = ConstU64<1>;
+	type LimitedProxyDepositFactor = ConstU64<1>;
 	type MaxProxies = ConstU32<32>;
 	type WeightInfo = ();
 	type MaxPending = ConstU32<32>;
@@ -4215,6 +4216,601 @@ fn tracing_works() {
 	});
 }
 
+#[test]
+fn prestate_tracing_works() {
+	use crate::evm::*;
+	use alloc::collections::BTreeMap;
+	let (code, _) = compile_module("store_call").unwrap();
+
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 100_000_000);
+		let Contract { addr, account_id } =
+			builder::bare_instantiate(Code::Upload(code.clone())).build_and_unwrap_contract();
+
+		let mut key = [0u8; 32];
+		key[0] = 1;
+		let key = Bytes(key.to_vec());
+		let alice_nonce = System::account_nonce(&ALICE) as u32;
+		let alice_info = |balance| PrestateTraceInfo {
+			balance: Some(balance),
+			nonce: (alice_nonce != 0).then_some(alice_nonce),
+			..Default::default()
+		};
+		let contract_nonce = System::account_nonce(&account_id) as u32;
+		let contract_info = |storage: Option<Vec<u8>>| PrestateTraceInfo {
+			balance: Some(Pallet::<Test>::evm_balance(&addr)),
+			nonce: (contract_nonce != 0).then_some(contract_nonce),
+			code: Some(code.clone().into()),
+			storage: BTreeMap::from([(key.clone(), storage.map(Bytes))]),
+		};
+
+		// The prestate reports the accounts touched, before the call is executed.
+		let alice_balance = Pallet::<Test>::evm_balance(&ALICE_ADDR);
+		let mut tracer = PrestateTracer::<Test>::new(Default::default());
+		trace(&mut tracer, || {
+			builder::bare_call(addr)
+				.data(8u32.to_le_bytes().to_vec())
+				.build_and_unwrap_result();
+		});
+		assert_eq!(
+			tracer.collect_trace(),
+			Some(PrestateTrace::Prestate(BTreeMap::from([
+				(ALICE_ADDR, alice_info(alice_balance)),
+				(addr, contract_info(None)),
+			])))
+		);
+
+		// The diff mode only reports the entries that changed.
+		let alice_balance = Pallet::<Test>::evm_balance(&ALICE_ADDR);
+		let mut tracer = PrestateTracer::<Test>::new(PrestateTracerConfig {
+			diff_mode: true,
+			..Default::default()
+		});
+		trace(&mut tracer, || {
+			builder::bare_call(addr)
+				.data(16u32.to_le_bytes().to_vec())
+				.build_and_unwrap_result();
+		});
+		let new_alice_balance = Pallet::<Test>::evm_balance(&ALICE_ADDR);
+		assert_ne!(alice_balance, new_alice_balance);
+		assert_eq!(
+			tracer.collect_trace(),
+			Some(PrestateTrace::DiffMode {
+				pre: BTreeMap::from([
+					(ALICE_ADDR, alice_info(alice_balance)),
+					(addr, contract_info(Some(vec![0u8; 8]))),
+				]),
+				post: BTreeMap::from([
+					(
+						ALICE_ADDR,
+						PrestateTraceInfo {
+							balance: Some(new_alice_balance),
+							..Default::default()
+						}
+					),
+					(
+						addr,
+						PrestateTraceInfo {
+							storage: BTreeMap::from([(key.clone(), Some(Bytes(vec![0u8; 16])))]),
+							..Default::default()
+						}
+					),
+				]),
+			})
+		);
+
+		// Nothing is reported once the trace is collected.
+		assert_eq!(tracer.collect_trace(), None);
+	});
+}
+
+#[test]
+fn struct_logger_works() {
+	use crate::evm::*;
+	let (code, _) = compile_module("store_call").unwrap();
+
+	ExtBuilder::default().existential_deposit(200).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 100_000_000);
+		let Contract { addr, .. } =
+			builder::bare_instantiate(Code::Upload(code)).build_and_unwrap_contract();
+
+		let mut key = [0u8; 32];
+		key[0] = 1;
+
+		let mut tracer = StructLogger::new(Default::default(), |w: Weight| w.ref_time());
+		trace(&mut tracer, || {
+			builder::bare_call(addr)
+				.data(8u32.to_le_bytes().to_vec())
+				.build_and_unwrap_result();
+		});
+		let logs = tracer.collect_trace().unwrap();
+		assert!(!logs.failed);
+		assert!(logs.struct_logs.iter().all(|log| log.depth == 1));
+		assert!(logs.struct_logs.iter().all(|log| log.stack.is_some() && log.memory.is_some()));
+
+		// The storage write is reported with the storage entries of the contract.
+		let sstore = logs.struct_logs.iter().find(|log| log.op == "SSTORE").unwrap();
+		assert_eq!(
+			sstore.storage,
+			Some([(Bytes(key.to_vec()), Some(Bytes(vec![0u8; 8])))].into_iter().collect())
+		);
+		assert!(logs
+			.struct_logs
+			.iter()
+			.filter(|log| log.op != "SSTORE")
+			.all(|log| log.storage.is_none()));
+
+		// The options are respected.
+		let config = StructLoggerConfig {
+			disable_stack: true,
+			disable_memory: true,
+			disable_storage: true,
+			limit: 2,
+		};
+		let mut tracer = StructLogger::new(config, |w: Weight| w.ref_time());
+		trace(&mut tracer, || {
+			builder::bare_call(addr)
+				.data(8u32.to_le_bytes().to_vec())
+				.build_and_unwrap_result();
+		});
+		let logs = tracer.collect_trace().unwrap();
+		assert_eq!(logs.struct_logs.len(), 2);
+		assert!(logs
+			.struct_logs
+			.iter()
+			.all(|log| log.stack.is_none() && log.memory.is_none() && log.storage.is_none()));
+	});
+}
+
+#[test]
+fn account_proof_works() {
+	use crate::proof::{verify_account_proof, ProofError};
+	let (code, _) = compile_module("dummy").unwrap();
+
+	let mut ext = ExtBuilder::default().existential_deposit(1).build();
+	let (addr, account_id, code_hash) = ext.execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let Contract { addr, account_id } =
+			builder::bare_instantiate(Code::Upload(code)).build_and_unwrap_contract();
+
+		let info = get_contract(&addr);
+		info.write(&Key::Fix([1u8; 32]), Some(vec![42u8]), None, false).unwrap();
+		(addr, account_id, info.code_hash)
+	});
+	ext.commit_all().unwrap();
+	let state_root = *ext.backend.root();
+
+	let storage_keys = [[1u8; 32], [2u8; 32]];
+
+	// Record the proof of the entries returned by the runtime API.
+	let ((), proof) = ext.execute_and_prove(|| {
+		let keys = Pallet::<Test>::account_proof_keys(addr, storage_keys.to_vec());
+		assert_eq!(keys.account_keys.len(), 3);
+		for key in &keys.account_keys {
+			sp_io::storage::get(key);
+		}
+		let child_info = get_contract(&addr).child_trie_info();
+		for key in &keys.storage_keys {
+			child::get_raw(&child_info, key);
+		}
+	});
+
+	let verified =
+		verify_account_proof::<Test>(&state_root, &account_id, &addr, &storage_keys, proof.clone())
+			.unwrap();
+	assert!(verified.account.is_some());
+	assert_eq!(verified.code_hash, Some(code_hash));
+	assert_eq!(verified.storage, vec![([1u8; 32], Some(vec![42u8])), ([2u8; 32], None)]);
+
+	// The proof is only valid for the state it was generated at.
+	assert_eq!(
+		verify_account_proof::<Test>(&H256::zero(), &account_id, &addr, &storage_keys, proof)
+			.map(|_| ()),
+		Err(ProofError::IncompleteProof)
+	);
+}
+
+#[test]
+fn set_code_delegation_works() {
+	use crate::evm::{delegation_designator, Account};
+	let (code, _) = compile_module("store_call").unwrap();
+
+	ExtBuilder::default().existential_deposit(1).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let Contract { addr, .. } =
+			builder::bare_instantiate(Code::Upload(code)).build_and_unwrap_contract();
+
+		let signer = Account::default();
+		let authority = signer.address();
+		let authority_id = signer.substrate_account();
+		let _ = <Test as Config>::Currency::set_balance(&authority_id, 1_000_000);
+		let chain_id = U256::from(<Test as Config>::ChainId::get());
+
+		// Authorizations with a wrong chain id or a stale nonce are skipped.
+		let invalid = vec![
+			signer.sign_authorization(chain_id + 1, addr, 0.into()),
+			signer.sign_authorization(chain_id, addr, 1.into()),
+		];
+		assert_eq!(crate::evm::apply_authorizations::<Test>(&invalid), 0);
+		assert_eq!(DelegationOf::<Test>::get(&authority), None);
+
+		// Calling the delegated account runs the code of the contract in its context.
+		let authorization = signer.sign_authorization(chain_id, addr, 0.into());
+		assert_ok!(Contracts::call_with_authorizations(
+			RuntimeOrigin::signed(ALICE),
+			authority,
+			0,
+			GAS_LIMIT,
+			deposit_limit::<Test>(),
+			7u32.encode(),
+			Default::default(),
+			vec![authorization].try_into().unwrap(),
+		));
+		assert_eq!(DelegationOf::<Test>::get(&authority), Some(addr));
+		assert_eq!(Pallet::<Test>::code(&authority), delegation_designator(&addr));
+		assert_eq!(System::account_nonce(&authority_id), 1);
+
+		let mut key = [0u8; 32];
+		key[0] = 1;
+		assert_eq!(
+			Pallet::<Test>::get_storage_var_key(authority, key.to_vec()),
+			Ok(Some(vec![0u8; 7]))
+		);
+		assert_eq!(Pallet::<Test>::get_storage_var_key(addr, key.to_vec()), Ok(None));
+
+		// Delegating to the zero address clears the delegation.
+		let revocation = signer.sign_authorization(chain_id, H160::zero(), 1.into());
+		assert_eq!(crate::evm::apply_authorizations::<Test>(&[revocation]), 1);
+		assert_eq!(DelegationOf::<Test>::get(&authority), None);
+		assert!(Pallet::<Test>::code(&authority).is_empty());
+		assert_eq!(System::account_nonce(&authority_id), 2);
+	});
+}
+
+#[test]
+fn set_code_delegated_accounts_have_no_code() {
+	use crate::evm::Account;
+	let (store_code, _) = compile_module("store_call").unwrap();
+	let (terminate_code, _) = compile_module("terminate_and_send_to_argument").unwrap();
+	let (code_hash_code, _) = compile_module("code_hash").unwrap();
+
+	ExtBuilder::default().existential_deposit(1).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let Contract { addr: store_addr, .. } =
+			builder::bare_instantiate(Code::Upload(store_code)).build_and_unwrap_contract();
+		let Contract { addr: terminate_addr, .. } =
+			builder::bare_instantiate(Code::Upload(terminate_code)).build_and_unwrap_contract();
+		let Contract { addr: code_hash_addr, .. } =
+			builder::bare_instantiate(Code::Upload(code_hash_code)).build_and_unwrap_contract();
+
+		let signer = Account::default();
+		let authority = signer.address();
+		let _ = <Test as Config>::Currency::set_balance(&signer.substrate_account(), 1_000_000);
+		let chain_id = U256::from(<Test as Config>::ChainId::get());
+
+		// Applies the authorization, and checks that the call succeeded.
+		let call = |dest: H160, data: Vec<u8>, target: H160, nonce: u32| {
+			System::reset_events();
+			let authorization = signer.sign_authorization(chain_id, target, nonce.into());
+			assert_ok!(Contracts::call_with_authorizations(
+				RuntimeOrigin::signed(ALICE),
+				dest,
+				0,
+				GAS_LIMIT,
+				deposit_limit::<Test>(),
+				data,
+				Default::default(),
+				vec![authorization].try_into().unwrap(),
+			));
+			assert!(!System::events().iter().any(|record| matches!(
+				record.event,
+				RuntimeEvent::Contracts(crate::Event::SetCodeCallFailed { .. })
+			)));
+		};
+
+		// The storage of the delegated account is held by a contract info without code.
+		call(authority, 7u32.encode(), store_addr, 0);
+		assert_eq!(
+			ContractInfoOf::<Test>::get(&authority).map(|info| info.code_hash),
+			Some(H256::zero())
+		);
+
+		// Once the delegation is revoked, the account has the code hash of an account without
+		// code.
+		call(code_hash_addr, (authority, crate::exec::EMPTY_CODE_HASH).encode(), H160::zero(), 1);
+		assert_eq!(DelegationOf::<Test>::get(&authority), None);
+
+		// A delegated account can be terminated.
+		call(authority, BOB_ADDR.encode(), terminate_addr, 2);
+		assert_eq!(DelegationOf::<Test>::get(&authority), Some(terminate_addr));
+		assert!(ContractInfoOf::<Test>::get(&authority).is_none());
+	});
+}
+
+#[test]
+fn set_code_dry_run_bumps_authority_nonce() {
+	use crate::evm::Account;
+	let (code, _) = compile_module("store_call").unwrap();
+
+	ExtBuilder::default().existential_deposit(1).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let Contract { addr, .. } =
+			builder::bare_instantiate(Code::Upload(code)).build_and_unwrap_contract();
+
+		let signer = Account::default();
+		let authority = signer.address();
+		let authority_id = signer.substrate_account();
+		let _ = <Test as Config>::Currency::set_balance(&authority_id, 1_000_000);
+		let chain_id = U256::from(<Test as Config>::ChainId::get());
+
+		// The sender is the authority, so its nonce is bumped for the transaction before the
+		// authorization is applied, as done by the transaction extensions.
+		let authorization = signer.sign_authorization(chain_id, addr, 1.into());
+		assert_ok!(Pallet::<Test>::dry_run_eth_transact(
+			GenericTransaction {
+				from: Some(authority),
+				to: Some(authority),
+				input: 7u32.encode().into(),
+				authorization_list: vec![authorization],
+				..Default::default()
+			},
+			Weight::MAX,
+			|_, _| 0u64,
+		));
+		assert_eq!(DelegationOf::<Test>::get(&authority), Some(addr));
+		assert_eq!(System::account_nonce(&authority_id), 2);
+
+		let mut key = [0u8; 32];
+		key[0] = 1;
+		assert_eq!(
+			Pallet::<Test>::get_storage_var_key(authority, key.to_vec()),
+			Ok(Some(vec![0u8; 7]))
+		);
+	});
+}
+
+#[test]
+fn set_code_authorizations_persist_when_call_fails() {
+	use crate::evm::Account;
+	let (code, _) = compile_module("ok_trap_revert").unwrap();
+
+	ExtBuilder::default().existential_deposit(1).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let Contract { addr, .. } =
+			builder::bare_instantiate(Code::Upload(code)).build_and_unwrap_contract();
+
+		let signer = Account::default();
+		let authority = signer.address();
+		let authority_id = signer.substrate_account();
+		let _ = <Test as Config>::Currency::set_balance(&authority_id, 1_000_000);
+		let chain_id = U256::from(<Test as Config>::ChainId::get());
+
+		// The call reverts, but the dispatch succeeds and the delegation is kept.
+		initialize_block(2);
+		let authorization = signer.sign_authorization(chain_id, addr, 0.into());
+		assert_ok!(Contracts::call_with_authorizations(
+			RuntimeOrigin::signed(ALICE),
+			authority,
+			0,
+			GAS_LIMIT,
+			deposit_limit::<Test>(),
+			vec![1u8, 0, 0, 0],
+			Default::default(),
+			vec![authorization].try_into().unwrap(),
+		));
+		assert_eq!(DelegationOf::<Test>::get(&authority), Some(addr));
+		assert_eq!(System::account_nonce(&authority_id), 1);
+		assert_eq!(
+			System::events(),
+			vec![EventRecord {
+				phase: Phase::Initialization,
+				event: RuntimeEvent::Contracts(crate::Event::SetCodeCallFailed {
+					error: Error::<Test>::ContractReverted.into(),
+				}),
+				topics: vec![],
+			}]
+		);
+
+		// A trapped call is reported the same way.
+		initialize_block(3);
+		let revocation = signer.sign_authorization(chain_id, H160::zero(), 1.into());
+		assert_ok!(Contracts::call_with_authorizations(
+			RuntimeOrigin::signed(ALICE),
+			addr,
+			0,
+			GAS_LIMIT,
+			deposit_limit::<Test>(),
+			vec![2u8, 0, 0, 0],
+			Default::default(),
+			vec![revocation].try_into().unwrap(),
+		));
+		assert_eq!(DelegationOf::<Test>::get(&authority), None);
+		assert_eq!(System::account_nonce(&authority_id), 2);
+		assert_eq!(
+			System::events(),
+			vec![EventRecord {
+				phase: Phase::Initialization,
+				event: RuntimeEvent::Contracts(crate::Event::SetCodeCallFailed {
+					error: Error::<Test>::ContractTrapped.into(),
+				}),
+				topics: vec![],
+			}]
+		);
+	});
+}
+
+#[test]
+fn access_list_makes_declared_entries_warm() {
+	use crate::{
+		evm::{AccessListEntry, BoundedAccessList},
+		gas::{with_access_list, AccessCost, Token},
+	};
+	use frame_support::dispatch::GetDispatchInfo;
+	let (store_fixed_key, _) = compile_module("store_fixed_key").unwrap();
+	let (balance_of, _) = compile_module("balance_of").unwrap();
+	let weight = |cost| <AccessCost as Token<Test>>::weight(&cost);
+
+	// The gas consumed by a call of `code` with `input`, with the given access list.
+	let gas_consumed = |code: &Vec<u8>, input: Vec<u8>, access_list: Vec<AccessListEntry>| {
+		ExtBuilder::default().existential_deposit(1).build().execute_with(|| {
+			let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+			let _ = <Test as Config>::Currency::set_balance(&BOB, 1_000_000);
+			let Contract { addr, .. } =
+				builder::bare_instantiate(Code::Upload(code.clone())).build_and_unwrap_contract();
+
+			let access_list: Vec<_> = access_list
+				.into_iter()
+				.map(|entry| AccessListEntry {
+					address: if entry.address.is_zero() { addr } else { entry.address },
+					..entry
+				})
+				.collect();
+			let result =
+				with_access_list(&access_list, || builder::bare_call(addr).data(input).build());
+			assert_ok!(result.result);
+			result.gas_consumed
+		})
+	};
+
+	// The first write to a declared storage entry is warm.
+	let mut key = [0u8; 32];
+	key[0] = 1;
+	let declared_key = AccessListEntry { storage_keys: vec![H256(key)], ..Default::default() };
+	let input = 7u32.encode();
+	let cold = gas_consumed(&store_fixed_key, input.clone(), vec![]);
+	let warm = gas_consumed(&store_fixed_key, input, vec![declared_key.clone()]);
+	assert_eq!(warm + weight(AccessCost::StorageWrite), cold);
+
+	// The balance of a declared account is read without the overhead of a cold access.
+	let input = BOB_ADDR.0.to_vec();
+	let cold = gas_consumed(&balance_of, input.clone(), vec![]);
+	let warm = gas_consumed(
+		&balance_of,
+		input,
+		vec![AccessListEntry { address: BOB_ADDR, ..Default::default() }],
+	);
+	assert_eq!(warm + weight(AccessCost::Account), cold);
+
+	// Instead, the declared entries are charged upfront by the weight of the dispatchable.
+	let access_list: BoundedAccessList =
+		vec![AccessListEntry { address: BOB_ADDR, ..declared_key }].try_into().unwrap();
+	let call = crate::Call::<Test>::call_with_access_list {
+		dest: BOB_ADDR,
+		value: 0,
+		gas_limit: GAS_LIMIT,
+		storage_deposit_limit: 0,
+		data: vec![],
+		access_list,
+	};
+	assert_eq!(
+		call.get_dispatch_info().call_weight,
+		<Test as Config>::WeightInfo::call() +
+			weight(AccessCost::AccessList { addresses: 1, storage_keys: 1 }) +
+			GAS_LIMIT,
+	);
+}
+
+#[test]
+fn create_access_list_works() {
+	use crate::evm::{AccessListEntry, AccessListResult};
+	let (code_caller, _) = compile_module("call").unwrap();
+	let (code, _) = compile_module("store_fixed_key").unwrap();
+
+	ExtBuilder::default().existential_deposit(1).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let Contract { addr: callee, .. } =
+			builder::bare_instantiate(Code::Upload(code)).build_and_unwrap_contract();
+		let Contract { addr: caller, .. } =
+			builder::bare_instantiate(Code::Upload(code_caller)).build_and_unwrap_contract();
+
+		let tx = GenericTransaction {
+			from: Some(ALICE_ADDR),
+			to: Some(caller),
+			input: (7u32, callee).encode().into(),
+			..Default::default()
+		};
+		let dry_run = |tx| Pallet::<Test>::dry_run_eth_transact(tx, Weight::MAX, |_, _| 0u64);
+
+		let mut key = [0u8; 32];
+		key[0] = 1;
+		let AccessListResult { access_list, error, .. } =
+			Pallet::<Test>::create_access_list(tx.clone(), dry_run).unwrap();
+		assert_eq!(error, None);
+		assert_eq!(
+			access_list,
+			vec![AccessListEntry { address: callee, storage_keys: vec![H256(key)] }]
+		);
+
+		// The state changes of the dry runs are discarded.
+		assert_eq!(Pallet::<Test>::get_storage(callee, key), Ok(None));
+
+		// Execution errors are reported in the result.
+		let tx = GenericTransaction { input: 7u32.encode().into(), ..tx };
+		let result = Pallet::<Test>::create_access_list(tx, dry_run).unwrap();
+		assert!(result.error.is_some());
+	});
+}
+
+#[test]
+fn state_and_block_overrides_work() {
+	use crate::evm::{AccountOverride, BlockOverrides, Bytes};
+	let (code, _) = compile_module("block_author").unwrap();
+
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let addr = H160::repeat_byte(0x42);
+		let balance = Pallet::<Test>::convert_native_to_evm(1_000u64);
+		let key = H256::from_low_u64_be(1);
+		let state_overrides = [(
+			addr,
+			AccountOverride {
+				balance: Some(balance),
+				nonce: Some(7.into()),
+				code: Some(Bytes(code)),
+				state_diff: Some([(key, H256::repeat_byte(2))].into()),
+				..Default::default()
+			},
+		)]
+		.into();
+		let block_overrides =
+			BlockOverrides { fee_recipient: Some(BOB_ADDR), ..Default::default() };
+
+		assert_ok!(Pallet::<Test>::with_overrides(state_overrides, block_overrides, || {
+			assert_eq!(Pallet::<Test>::evm_balance(&addr), balance);
+			assert_eq!(
+				System::account_nonce(&<Test as Config>::AddressMapper::to_account_id(&addr)),
+				7
+			);
+			assert_eq!(Pallet::<Test>::get_storage(addr, key.0), Ok(Some(vec![2; 32])));
+
+			// The fixture asserts the input to match the overridden block author.
+			let result = builder::bare_call(addr).data(BOB_ADDR.encode()).build_and_unwrap_result();
+			assert!(!result.did_revert());
+			Ok(())
+		}));
+
+		// The block overrides only apply within `with_overrides`.
+		assert_err!(
+			builder::bare_call(addr).data(BOB_ADDR.encode()).build().result,
+			Error::<Test>::ContractTrapped
+		);
+
+		let state_overrides = [(
+			addr,
+			AccountOverride {
+				state: Some(Default::default()),
+				state_diff: Some(Default::default()),
+				..Default::default()
+			},
+		)]
+		.into();
+		assert!(matches!(
+			Pallet::<Test>::with_overrides(state_overrides, Default::default(), || Ok(())),
+			Err(EthTransactError::Message(_))
+		));
+	});
+}
+
 #[test]
 fn unknown_precompiles_revert() {
 	let (code, _code_hash) = compile_module("read_only_call").unwrap();
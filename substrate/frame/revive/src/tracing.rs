@@ -15,7 +15,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{primitives::ExecReturnValue, DispatchError, Weight};
+use crate::{primitives::ExecReturnValue, DispatchError, Key, Weight};
+use alloc::vec::Vec;
 use environmental::environmental;
 use sp_core::{H160, H256, U256};
 
@@ -39,8 +40,58 @@ pub(crate) fn if_tracing<F: FnOnce(&mut (dyn Tracing + 'static))>(f: F) {
 	tracer::with(f);
 }
 
+/// Returns `true` if the active tracer traces the execution of every instruction.
+///
+/// This always returns `false` for on-chain code as tracing will never be activated there.
+pub(crate) fn is_execution_tracing() -> bool {
+	tracer::with(|tracer| tracer.is_execution_tracer()).unwrap_or(false)
+}
+
+/// Read access to the state of the execution engine, passed to the tracer for every
+/// instruction.
+pub trait ExecutionState {
+	/// Returns the values of all registers.
+	fn registers(&self) -> Vec<u64>;
+
+	/// Returns the memory of the current stack frame, from the stack pointer up to the top of
+	/// the stack.
+	fn stack_memory(&self) -> Vec<u8>;
+}
+
 /// Defines methods to trace contract interactions.
 pub trait Tracing {
+	/// Returns `true` if [`Self::enter_instruction`] should be called for every instruction.
+	///
+	/// Enabling it makes the execution engine interrupt the execution after every
+	/// instruction. Hence it should only be enabled by tracers that make use of it.
+	fn is_execution_tracer(&self) -> bool {
+		false
+	}
+
+	/// Called before an instruction is executed.
+	///
+	/// This is only called if [`Self::is_execution_tracer`] returns `true`.
+	fn enter_instruction(
+		&mut self,
+		_pc: u64,
+		_opcode: &str,
+		_gas_left: Weight,
+		_state: &dyn ExecutionState,
+	) {
+	}
+
+	/// Register an address whose state is accessed outside of a call frame.
+	///
+	/// This is used for accounts that are not entered through a child span, like the
+	/// beneficiary of a terminated contract or an account whose balance is queried.
+	fn watch_address(&mut self, _address: &H160) {}
+
+	/// Called when the state of an account is accessed by a contract.
+	fn access_address(&mut self, _address: &H160) {}
+
+	/// Called when a storage entry of `address` is accessed by a contract.
+	fn access_storage(&mut self, _address: &H160, _key: &Key) {}
+
 	/// Called before a contract call is executed
 	fn enter_child_span(
 		&mut self,
@@ -57,6 +108,20 @@ pub trait Tracing {
 	/// Record a log event
 	fn log_event(&mut self, _event: H160, _topics: &[H256], _data: &[u8]) {}
 
+	/// Called when a storage entry of the current contract is read.
+	fn storage_read(&mut self, _key: &Key, _value: Option<&[u8]>) {}
+
+	/// Called before a storage entry of the current contract is written.
+	///
+	/// A `new_value` of `None` means that the entry is removed.
+	fn storage_write(
+		&mut self,
+		_key: &Key,
+		_old_value: Option<Vec<u8>>,
+		_new_value: Option<&[u8]>,
+	) {
+	}
+
 	/// Called after a contract call is executed
 	fn exit_child_span(&mut self, _output: &ExecReturnValue, _gas_left: Weight) {}
 
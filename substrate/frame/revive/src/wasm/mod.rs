@@ -33,17 +33,24 @@ use crate::{
 	gas::{GasMeter, Token},
 	limits,
 	storage::meter::Diff,
+	tracing::{if_tracing, is_execution_tracing, ExecutionState},
 	weights::WeightInfo,
 	AccountIdOf, BadOrigin, BalanceOf, CodeInfoOf, CodeVec, Config, Error, ExecError, HoldReason,
 	PristineCode, Weight, LOG_TARGET,
 };
-use alloc::vec::Vec;
+use alloc::{
+	collections::BTreeMap,
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	traits::{fungible::MutateHold, tokens::Precision::BestEffort},
 };
+use polkavm::program::{Instruction, ISA64_V1 as ISA};
 use sp_core::{Get, H256, U256};
 use sp_runtime::DispatchError;
 
@@ -288,6 +295,10 @@ pub struct PreparedCall<'a, E: Ext> {
 	module: polkavm::Module,
 	instance: polkavm::RawInstance,
 	runtime: Runtime<'a, E, polkavm::RawInstance>,
+	/// The instructions of the program indexed by their offset.
+	///
+	/// Only populated when an execution tracer is active.
+	instructions: Option<BTreeMap<u32, Instruction>>,
 }
 
 impl<'a, E: Ext> PreparedCall<'a, E>
@@ -298,6 +309,9 @@ where
 	pub fn call(mut self) -> ExecResult {
 		let exec_result = loop {
 			let interrupt = self.instance.run();
+			if let Ok(polkavm::InterruptKind::Step) = interrupt {
+				self.trace_instruction();
+			}
 			if let Some(exec_result) =
 				self.runtime.handle_interrupt(interrupt, &self.module, &mut self.instance)
 			{
@@ -308,6 +322,29 @@ where
 		exec_result
 	}
 
+	/// Report the instruction that is about to be executed to the tracer.
+	fn trace_instruction(&mut self) {
+		let Some(instructions) = &self.instructions else { return };
+		let Some(pc) = self.instance.program_counter() else { return };
+		let opcode = match instructions.get(&pc.0) {
+			Some(Instruction::ecalli(idx)) => self
+				.module
+				.imports()
+				.get(*idx)
+				.map(|symbol| match syscall_opcode(symbol.as_bytes()) {
+					Some(opcode) => opcode.to_string(),
+					None => String::from_utf8_lossy(symbol.as_bytes()).into_owned(),
+				})
+				.unwrap_or_else(|| "ecalli".to_string()),
+			Some(instruction) => format!("{:?}", instruction.opcode()),
+			None => return,
+		};
+		let gas_left =
+			self.runtime.ext().gas_meter().gas_left_with_engine_fuel(self.instance.gas());
+		let state = InstanceState { module: &self.module, instance: &self.instance };
+		if_tracing(|tracer| tracer.enter_instruction(pc.0.into(), &opcode, gas_left, &state));
+	}
+
 	/// The guest memory address at which the aux data is located.
 	#[cfg(feature = "runtime-benchmarks")]
 	pub fn aux_data_base(&self) -> u32 {
@@ -333,6 +370,65 @@ where
 	}
 }
 
+/// Exposes the state of a running instance to the tracer.
+struct InstanceState<'a> {
+	module: &'a polkavm::Module,
+	instance: &'a polkavm::RawInstance,
+}
+
+impl<'a> ExecutionState for InstanceState<'a> {
+	fn registers(&self) -> Vec<u64> {
+		polkavm::Reg::ALL.iter().map(|reg| self.instance.reg(*reg)).collect()
+	}
+
+	fn stack_memory(&self) -> Vec<u8> {
+		let stack_pointer = self.instance.reg(polkavm::Reg::SP) as u32;
+		let stack_top = self.module.memory_map().stack_address_high();
+		self.instance
+			.read_memory(stack_pointer, stack_top.saturating_sub(stack_pointer))
+			.unwrap_or_default()
+	}
+}
+
+/// Map a syscall to the EVM opcode with the same semantics, if there is one.
+fn syscall_opcode(symbol: &[u8]) -> Option<&'static str> {
+	let opcode = match symbol {
+		b"get_storage" | b"get_storage_or_zero" => "SLOAD",
+		b"set_storage" | b"set_storage_or_clear" | b"clear_storage" | b"take_storage" => "SSTORE",
+		b"call" => "CALL",
+		b"delegate_call" => "DELEGATECALL",
+		b"instantiate" => "CREATE2",
+		b"seal_return" => "RETURN",
+		b"terminate" => "SELFDESTRUCT",
+		b"deposit_event" => "LOG",
+		b"call_data_load" => "CALLDATALOAD",
+		b"call_data_size" => "CALLDATASIZE",
+		b"call_data_copy" => "CALLDATACOPY",
+		b"return_data_size" => "RETURNDATASIZE",
+		b"return_data_copy" => "RETURNDATACOPY",
+		b"caller" => "CALLER",
+		b"origin" => "ORIGIN",
+		b"address" => "ADDRESS",
+		b"balance" => "SELFBALANCE",
+		b"balance_of" => "BALANCE",
+		b"value_transferred" => "CALLVALUE",
+		b"code_hash" => "EXTCODEHASH",
+		b"code_size" => "EXTCODESIZE",
+		b"chain_id" => "CHAINID",
+		b"gas_limit" => "GASLIMIT",
+		b"gas_price" => "GASPRICE",
+		b"base_fee" => "BASEFEE",
+		b"now" => "TIMESTAMP",
+		b"block_number" => "NUMBER",
+		b"block_hash" => "BLOCKHASH",
+		b"block_author" => "COINBASE",
+		b"ref_time_left" => "GAS",
+		b"hash_keccak_256" => "KECCAK256",
+		_ => return None,
+	};
+	Some(opcode)
+}
+
 impl<T: Config> WasmBlob<T> {
 	/// Compile and instantiate contract.
 	///
@@ -363,6 +459,17 @@ impl<T: Config> WasmBlob<T> {
 		module_config.set_gas_metering(Some(polkavm::GasMeteringKind::Sync));
 		module_config.set_allow_sbrk(false);
 		module_config.set_aux_data_size(aux_data_size);
+
+		// Step tracing is only enabled off-chain, when a tracer wants to see every instruction.
+		let instructions = if is_execution_tracing() {
+			module_config.set_step_tracing(true);
+			polkavm::ProgramBlob::parse(self.code.as_slice().into()).ok().map(|program| {
+				program.instructions(ISA).map(|inst| (inst.offset.0, inst.kind)).collect()
+			})
+		} else {
+			None
+		};
+
 		let module = polkavm::Module::new(&engine, &module_config, self.code.into_inner().into())
 			.map_err(|err| {
 			log::debug!(target: LOG_TARGET, "failed to create polkavm module: {err:?}");
@@ -385,7 +492,7 @@ impl<T: Config> WasmBlob<T> {
 		instance.set_gas(gas_limit_polkavm);
 		instance.prepare_call_untyped(entry_program_counter, &[]);
 
-		Ok(PreparedCall { module, instance, runtime })
+		Ok(PreparedCall { module, instance, runtime, instructions })
 	}
 }
 
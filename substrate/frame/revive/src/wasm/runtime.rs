@@ -21,7 +21,7 @@ use crate::{
 	address::AddressMapper,
 	evm::runtime::GAS_PRICE,
 	exec::{ExecError, ExecResult, Ext, Key},
-	gas::{ChargedAmount, Token},
+	gas::{AccessCost, ChargedAmount, Token, WarmAccess},
 	limits,
 	precompiles::{All as AllPrecompiles, Precompiles},
 	primitives::ExecReturnValue,
@@ -384,12 +384,31 @@ pub enum RuntimeCosts {
 	Blake2F(u32),
 	/// Weight of calling `Modexp` precompile
 	Modexp(u64),
+	/// Weight of calling `P256Verify` precompile
+	P256Verify,
+	/// Weight of calling `Bls12G1Add` precompile
+	Bls12G1Add,
+	/// Weight of calling `Bls12G1Msm` precompile for the given number of input pairs.
+	Bls12G1Msm(u32),
+	/// Weight of calling `Bls12G2Add` precompile
+	Bls12G2Add,
+	/// Weight of calling `Bls12G2Msm` precompile for the given number of input pairs.
+	Bls12G2Msm(u32),
+	/// Weight of calling `Bls12PairingCheck` precompile for the given number of input pairs.
+	Bls12PairingCheck(u32),
+	/// Weight of calling `Bls12MapFpToG1` precompile
+	Bls12MapFpToG1,
+	/// Weight of calling `Bls12MapFp2ToG2` precompile
+	Bls12MapFp2ToG2,
 }
 
 /// For functions that modify storage, benchmarks are performed with one item in the
 /// storage. To account for the worst-case scenario, the weight of the overhead of
 /// writing to or reading from full storage is included. For transient storage writes,
 /// the rollback weight is added to reflect the worst-case scenario for this operation.
+///
+/// For persistent storage, the overhead of a full storage is refunded to warm accesses, see
+/// [`WarmAccess`].
 macro_rules! cost_storage {
     (write_transient, $name:ident $(, $arg:expr )*) => {
         T::WeightInfo::$name($( $arg ),*)
@@ -532,6 +551,14 @@ impl<T: Config> Token<T> for RuntimeCosts {
 				const WEIGHT_PER_GAS: u64 = WEIGHT_REF_TIME_PER_SECOND / GAS_PER_SECOND;
 				Weight::from_parts(gas.saturating_mul(WEIGHT_PER_GAS), 0)
 			},
+			P256Verify => T::WeightInfo::p256_verify(),
+			Bls12G1Add => T::WeightInfo::bls12_g1_add(),
+			Bls12G1Msm(pairs) => T::WeightInfo::bls12_g1_msm(pairs),
+			Bls12G2Add => T::WeightInfo::bls12_g2_add(),
+			Bls12G2Msm(pairs) => T::WeightInfo::bls12_g2_msm(pairs),
+			Bls12PairingCheck(pairs) => T::WeightInfo::bls12_pairing_check(pairs),
+			Bls12MapFpToG1 => T::WeightInfo::bls12_map_fp_to_g1(),
+			Bls12MapFp2ToG2 => T::WeightInfo::bls12_map_fp2_to_g2(),
 		}
 	}
 }
@@ -808,6 +835,47 @@ impl<'a, E: Ext, M: ?Sized + Memory<E::T>> Runtime<'a, E, M> {
 		}
 	}
 
+	/// Record an access to the storage entry `key` of the current contract.
+	///
+	/// Returns the `overhead` that is not charged if the access is warm. Transient storage is not
+	/// tracked.
+	fn access_storage(
+		&mut self,
+		key: &Key,
+		transient: bool,
+		overhead: AccessCost,
+	) -> Option<AccessCost> {
+		(!transient && !self.ext.access_storage(key)).then_some(overhead)
+	}
+
+	/// Record an access to the state of `address` by a host function that was charged `costs`.
+	///
+	/// Warm accesses are refunded the overhead of reading the account. Accesses to precompiles
+	/// are not tracked.
+	fn access_account(&mut self, charged: ChargedAmount, costs: RuntimeCosts, address: &H160) {
+		if <AllPrecompiles<E::T>>::get::<E>(address.as_fixed_bytes()).is_none() &&
+			!self.ext.access_address(address)
+		{
+			self.adjust_access_gas(charged, costs, Some(AccessCost::Account));
+		}
+	}
+
+	/// Adjust a previously charged amount down to the actual `costs` of an access to the state.
+	///
+	/// Warm accesses are charged without the `overhead` of a cold access, see [`WarmAccess`].
+	fn adjust_access_gas(
+		&mut self,
+		charged: ChargedAmount,
+		costs: RuntimeCosts,
+		warm: Option<AccessCost>,
+	) {
+		match warm {
+			Some(overhead) =>
+				self.ext.gas_meter_mut().adjust_gas(charged, WarmAccess { costs, overhead }),
+			None => self.adjust_gas(charged, costs),
+		}
+	}
+
 	fn decode_key(&self, memory: &M, key_ptr: u32, key_len: u32) -> Result<Key, TrapReason> {
 		let res = match key_len {
 			SENTINEL => {
@@ -860,6 +928,7 @@ impl<'a, E: Ext, M: ?Sized + Memory<E::T>> Runtime<'a, E, M> {
 		}
 
 		let key = self.decode_key(memory, key_ptr, key_len)?;
+		let warm = self.access_storage(&key, transient, AccessCost::StorageWrite);
 
 		let value = match value {
 			StorageValue::Memory { ptr, len } => Some(memory.read(ptr, len)?),
@@ -872,7 +941,7 @@ impl<'a, E: Ext, M: ?Sized + Memory<E::T>> Runtime<'a, E, M> {
 			self.ext.set_storage(&key, value, false)?
 		};
 
-		self.adjust_gas(charged, costs(value_len, write_outcome.old_len()));
+		self.adjust_access_gas(charged, costs(value_len, write_outcome.old_len()), warm);
 		Ok(write_outcome.old_len_with_sentinel())
 	}
 
@@ -893,12 +962,13 @@ impl<'a, E: Ext, M: ?Sized + Memory<E::T>> Runtime<'a, E, M> {
 		};
 		let charged = self.charge_gas(costs(self.ext.max_value_size()))?;
 		let key = self.decode_key(memory, key_ptr, key_len)?;
+		let warm = self.access_storage(&key, transient, AccessCost::StorageWrite);
 		let outcome = if transient {
 			self.ext.set_transient_storage(&key, None, false)?
 		} else {
 			self.ext.set_storage(&key, None, false)?
 		};
-		self.adjust_gas(charged, costs(outcome.old_len()));
+		self.adjust_access_gas(charged, costs(outcome.old_len()), warm);
 		Ok(outcome.old_len_with_sentinel())
 	}
 
@@ -921,6 +991,7 @@ impl<'a, E: Ext, M: ?Sized + Memory<E::T>> Runtime<'a, E, M> {
 		};
 		let charged = self.charge_gas(costs(self.ext.max_value_size()))?;
 		let key = self.decode_key(memory, key_ptr, key_len)?;
+		let warm = self.access_storage(&key, transient, AccessCost::StorageRead);
 		let outcome = if transient {
 			self.ext.get_transient_storage(&key)
 		} else {
@@ -928,7 +999,7 @@ impl<'a, E: Ext, M: ?Sized + Memory<E::T>> Runtime<'a, E, M> {
 		};
 
 		if let Some(value) = outcome {
-			self.adjust_gas(charged, costs(value.len() as u32));
+			self.adjust_access_gas(charged, costs(value.len() as u32), warm);
 
 			match read_mode {
 				StorageReadMode::FixedOutput32 => {
@@ -958,7 +1029,7 @@ impl<'a, E: Ext, M: ?Sized + Memory<E::T>> Runtime<'a, E, M> {
 				},
 			}
 		} else {
-			self.adjust_gas(charged, costs(0));
+			self.adjust_access_gas(charged, costs(0), warm);
 
 			match read_mode {
 				StorageReadMode::FixedOutput32 => {
@@ -993,12 +1064,13 @@ impl<'a, E: Ext, M: ?Sized + Memory<E::T>> Runtime<'a, E, M> {
 		};
 		let charged = self.charge_gas(costs(self.ext.max_value_size()))?;
 		let key = self.decode_key(memory, key_ptr, key_len)?;
+		let warm = self.access_storage(&key, transient, AccessCost::StorageRead);
 		let outcome = if transient {
 			self.ext.get_transient_storage_size(&key)
 		} else {
 			self.ext.get_storage_size(&key)
 		};
-		self.adjust_gas(charged, costs(outcome.unwrap_or(0)));
+		self.adjust_access_gas(charged, costs(outcome.unwrap_or(0)), warm);
 		Ok(outcome.unwrap_or(SENTINEL))
 	}
 
@@ -1021,6 +1093,7 @@ impl<'a, E: Ext, M: ?Sized + Memory<E::T>> Runtime<'a, E, M> {
 		};
 		let charged = self.charge_gas(costs(self.ext.max_value_size()))?;
 		let key = self.decode_key(memory, key_ptr, key_len)?;
+		let warm = self.access_storage(&key, transient, AccessCost::StorageWrite);
 		let outcome = if transient {
 			self.ext.set_transient_storage(&key, None, true)?
 		} else {
@@ -1028,7 +1101,7 @@ impl<'a, E: Ext, M: ?Sized + Memory<E::T>> Runtime<'a, E, M> {
 		};
 
 		if let crate::storage::WriteOutcome::Taken(value) = outcome {
-			self.adjust_gas(charged, costs(value.len() as u32));
+			self.adjust_access_gas(charged, costs(value.len() as u32), warm);
 			self.write_sandbox_output(
 				memory,
 				out_ptr,
@@ -1039,7 +1112,7 @@ impl<'a, E: Ext, M: ?Sized + Memory<E::T>> Runtime<'a, E, M> {
 			)?;
 			Ok(ReturnErrorCode::Success)
 		} else {
-			self.adjust_gas(charged, costs(0));
+			self.adjust_access_gas(charged, costs(0), warm);
 			Ok(ReturnErrorCode::KeyNotFound)
 		}
 	}
@@ -1059,12 +1132,14 @@ impl<'a, E: Ext, M: ?Sized + Memory<E::T>> Runtime<'a, E, M> {
 	) -> Result<ReturnErrorCode, TrapReason> {
 		let callee = memory.read_h160(callee_ptr)?;
 		let precompile = <AllPrecompiles<E::T>>::get::<E>(&callee.as_fixed_bytes());
-		match &precompile {
+		let costs = match &precompile {
 			Some(precompile) if precompile.has_contract_info() =>
-				self.charge_gas(RuntimeCosts::PrecompileWithInfoBase)?,
-			Some(_) => self.charge_gas(RuntimeCosts::PrecompileBase)?,
-			None => self.charge_gas(call_type.cost())?,
+				RuntimeCosts::PrecompileWithInfoBase,
+			Some(_) => RuntimeCosts::PrecompileBase,
+			None => call_type.cost(),
 		};
+		let charged = self.charge_gas(costs)?;
+		self.access_account(charged, costs, &callee);
 
 		let deposit_limit = memory.read_u256(deposit_ptr)?;
 
@@ -1537,8 +1612,9 @@ pub mod env {
 	/// See [`pallet_revive_uapi::HostFn::code_hash`].
 	#[stable]
 	fn code_hash(&mut self, memory: &mut M, addr_ptr: u32, out_ptr: u32) -> Result<(), TrapReason> {
-		self.charge_gas(RuntimeCosts::CodeHash)?;
+		let charged = self.charge_gas(RuntimeCosts::CodeHash)?;
 		let address = memory.read_h160(addr_ptr)?;
+		self.access_account(charged, RuntimeCosts::CodeHash, &address);
 		Ok(self.write_fixed_sandbox_output(
 			memory,
 			out_ptr,
@@ -1552,8 +1628,9 @@ pub mod env {
 	/// See [`pallet_revive_uapi::HostFn::code_size`].
 	#[stable]
 	fn code_size(&mut self, memory: &mut M, addr_ptr: u32) -> Result<u64, TrapReason> {
-		self.charge_gas(RuntimeCosts::CodeSize)?;
+		let charged = self.charge_gas(RuntimeCosts::CodeSize)?;
 		let address = memory.read_h160(addr_ptr)?;
+		self.access_account(charged, RuntimeCosts::CodeSize, &address);
 		Ok(self.ext.code_size(&address))
 	}
 
@@ -1647,8 +1724,9 @@ pub mod env {
 		addr_ptr: u32,
 		out_ptr: u32,
 	) -> Result<(), TrapReason> {
-		self.charge_gas(RuntimeCosts::BalanceOf)?;
+		let charged = self.charge_gas(RuntimeCosts::BalanceOf)?;
 		let address = memory.read_h160(addr_ptr)?;
+		self.access_account(charged, RuntimeCosts::BalanceOf, &address);
 		Ok(self.write_fixed_sandbox_output(
 			memory,
 			out_ptr,
@@ -1981,8 +2059,9 @@ pub mod env {
 	/// Checks whether a specified address belongs to a contract.
 	/// See [`pallet_revive_uapi::HostFn::is_contract`].
 	fn is_contract(&mut self, memory: &mut M, account_ptr: u32) -> Result<u32, TrapReason> {
-		self.charge_gas(RuntimeCosts::IsContract)?;
+		let charged = self.charge_gas(RuntimeCosts::IsContract)?;
 		let address = memory.read_h160(account_ptr)?;
+		self.access_account(charged, RuntimeCosts::IsContract, &address);
 		Ok(self.ext.is_contract(&address) as u32)
 	}
 
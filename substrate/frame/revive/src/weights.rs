@@ -84,6 +84,7 @@ pub trait WeightInfo {
 	fn map_account() -> Weight;
 	fn unmap_account() -> Weight;
 	fn dispatch_as_fallback_account() -> Weight;
+	fn apply_authorization() -> Weight;
 	fn noop_host_fn(r: u32, ) -> Weight;
 	fn seal_caller() -> Weight;
 	fn seal_origin() -> Weight;
@@ -154,6 +155,14 @@ pub trait WeightInfo {
 	fn bn128_mul() -> Weight;
 	fn bn128_pairing(n: u32, ) -> Weight;
 	fn blake2f(n: u32, ) -> Weight;
+	fn p256_verify() -> Weight;
+	fn bls12_g1_add() -> Weight;
+	fn bls12_g1_msm(n: u32, ) -> Weight;
+	fn bls12_g2_add() -> Weight;
+	fn bls12_g2_msm(n: u32, ) -> Weight;
+	fn bls12_pairing_check(n: u32, ) -> Weight;
+	fn bls12_map_fp_to_g1() -> Weight;
+	fn bls12_map_fp2_to_g2() -> Weight;
 	fn seal_ecdsa_to_eth_address() -> Weight;
 	fn seal_set_code_hash() -> Weight;
 	fn instr(r: u32, ) -> Weight;
@@ -399,6 +408,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(13_240_000, 3610)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:0)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	/// Storage: `Revive::OriginalAccount` (r:1 w:0)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Revive::DelegationOf` (r:0 w:1)
+	/// Proof: `Revive::DelegationOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `Measured`)
+	fn apply_authorization() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `595`
+		//  Estimated: `4060`
+		// Minimum execution time: 168_068_000 picoseconds.
+		Weight::from_parts(185_460_000, 4060)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 	/// The range of component `r` is `[0, 1600]`.
 	fn noop_host_fn(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -443,15 +469,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(9_672_000, 4003)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	/// Storage: `Revive::DelegationOf` (r:1 w:0)
+	/// Proof: `Revive::DelegationOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `Measured`)
 	/// Storage: `Revive::ContractInfoOf` (r:1 w:0)
 	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
 	fn seal_code_hash() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `402`
 		//  Estimated: `3867`
-		// Minimum execution time: 9_183_000 picoseconds.
-		Weight::from_parts(9_609_000, 3867)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		// Minimum execution time: 18_441_000 picoseconds.
+		Weight::from_parts(20_109_000, 3867)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
 	fn seal_own_code_hash() -> Weight {
 		// Proof Size summary in bytes:
@@ -460,6 +488,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 131_000 picoseconds.
 		Weight::from_parts(176_000, 0)
 	}
+	/// Storage: `Revive::DelegationOf` (r:1 w:0)
+	/// Proof: `Revive::DelegationOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `Measured`)
 	/// Storage: `Revive::ContractInfoOf` (r:1 w:0)
 	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
 	/// Storage: `Revive::CodeInfoOf` (r:1 w:0)
@@ -468,9 +498,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `472`
 		//  Estimated: `3937`
-		// Minimum execution time: 12_217_000 picoseconds.
-		Weight::from_parts(13_005_000, 3937)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		// Minimum execution time: 28_092_000 picoseconds.
+		Weight::from_parts(46_361_000, 3937)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 	}
 	fn seal_caller_is_origin() -> Weight {
 		// Proof Size summary in bytes:
@@ -1108,6 +1138,71 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 6
 			.saturating_add(Weight::from_parts(22_679, 0).saturating_mul(n.into()))
 	}
+	fn p256_verify() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_839_563_000 picoseconds.
+		Weight::from_parts(1_965_381_000, 0)
+	}
+	fn bls12_g1_add() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 46_730_000 picoseconds.
+		Weight::from_parts(62_132_000, 0)
+	}
+	/// The range of component `n` is `[1, 128]`.
+	fn bls12_g1_msm(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_231_753_000 picoseconds.
+		Weight::from_parts(11_993_561_305, 0)
+			// Standard Error: 13_832_400
+			.saturating_add(Weight::from_parts(1_675_045_281, 0).saturating_mul(n.into()))
+	}
+	fn bls12_g2_add() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 118_183_000 picoseconds.
+		Weight::from_parts(130_023_000, 0)
+	}
+	/// The range of component `n` is `[1, 128]`.
+	fn bls12_g2_msm(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_478_940_000 picoseconds.
+		Weight::from_parts(58_354_766_567, 0)
+			// Standard Error: 58_978_709
+			.saturating_add(Weight::from_parts(2_823_106_634, 0).saturating_mul(n.into()))
+	}
+	/// The range of component `n` is `[1, 20]`.
+	fn bls12_pairing_check(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_152_541_000 picoseconds.
+		Weight::from_parts(10_381_293_316, 0)
+			// Standard Error: 175_253_649
+			.saturating_add(Weight::from_parts(7_111_642_497, 0).saturating_mul(n.into()))
+	}
+	fn bls12_map_fp_to_g1() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_629_566_000 picoseconds.
+		Weight::from_parts(2_676_489_000, 0)
+	}
+	fn bls12_map_fp2_to_g2() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_800_084_000 picoseconds.
+		Weight::from_parts(6_547_976_000, 0)
+	}
 	fn seal_ecdsa_to_eth_address() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
@@ -1386,6 +1481,23 @@ impl WeightInfo for () {
 		Weight::from_parts(13_240_000, 3610)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
+	/// Storage: `Revive::ContractInfoOf` (r:1 w:0)
+	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
+	/// Storage: `Revive::OriginalAccount` (r:1 w:0)
+	/// Proof: `Revive::OriginalAccount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Revive::DelegationOf` (r:0 w:1)
+	/// Proof: `Revive::DelegationOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `Measured`)
+	fn apply_authorization() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `595`
+		//  Estimated: `4060`
+		// Minimum execution time: 168_068_000 picoseconds.
+		Weight::from_parts(185_460_000, 4060)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 	/// The range of component `r` is `[0, 1600]`.
 	fn noop_host_fn(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -1430,15 +1542,17 @@ impl WeightInfo for () {
 		Weight::from_parts(9_672_000, 4003)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
+	/// Storage: `Revive::DelegationOf` (r:1 w:0)
+	/// Proof: `Revive::DelegationOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `Measured`)
 	/// Storage: `Revive::ContractInfoOf` (r:1 w:0)
 	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
 	fn seal_code_hash() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `402`
 		//  Estimated: `3867`
-		// Minimum execution time: 9_183_000 picoseconds.
-		Weight::from_parts(9_609_000, 3867)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		// Minimum execution time: 18_441_000 picoseconds.
+		Weight::from_parts(20_109_000, 3867)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
 	fn seal_own_code_hash() -> Weight {
 		// Proof Size summary in bytes:
@@ -1447,6 +1561,8 @@ impl WeightInfo for () {
 		// Minimum execution time: 131_000 picoseconds.
 		Weight::from_parts(176_000, 0)
 	}
+	/// Storage: `Revive::DelegationOf` (r:1 w:0)
+	/// Proof: `Revive::DelegationOf` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `Measured`)
 	/// Storage: `Revive::ContractInfoOf` (r:1 w:0)
 	/// Proof: `Revive::ContractInfoOf` (`max_values`: None, `max_size`: Some(242), added: 2717, mode: `Measured`)
 	/// Storage: `Revive::CodeInfoOf` (r:1 w:0)
@@ -1455,9 +1571,9 @@ impl WeightInfo for () {
 		// Proof Size summary in bytes:
 		//  Measured:  `472`
 		//  Estimated: `3937`
-		// Minimum execution time: 12_217_000 picoseconds.
-		Weight::from_parts(13_005_000, 3937)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		// Minimum execution time: 28_092_000 picoseconds.
+		Weight::from_parts(46_361_000, 3937)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 	}
 	fn seal_caller_is_origin() -> Weight {
 		// Proof Size summary in bytes:
@@ -2095,6 +2211,71 @@ impl WeightInfo for () {
 			// Standard Error: 6
 			.saturating_add(Weight::from_parts(22_679, 0).saturating_mul(n.into()))
 	}
+	fn p256_verify() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_839_563_000 picoseconds.
+		Weight::from_parts(1_965_381_000, 0)
+	}
+	fn bls12_g1_add() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 46_730_000 picoseconds.
+		Weight::from_parts(62_132_000, 0)
+	}
+	/// The range of component `n` is `[1, 128]`.
+	fn bls12_g1_msm(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_231_753_000 picoseconds.
+		Weight::from_parts(11_993_561_305, 0)
+			// Standard Error: 13_832_400
+			.saturating_add(Weight::from_parts(1_675_045_281, 0).saturating_mul(n.into()))
+	}
+	fn bls12_g2_add() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 118_183_000 picoseconds.
+		Weight::from_parts(130_023_000, 0)
+	}
+	/// The range of component `n` is `[1, 128]`.
+	fn bls12_g2_msm(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_478_940_000 picoseconds.
+		Weight::from_parts(58_354_766_567, 0)
+			// Standard Error: 58_978_709
+			.saturating_add(Weight::from_parts(2_823_106_634, 0).saturating_mul(n.into()))
+	}
+	/// The range of component `n` is `[1, 20]`.
+	fn bls12_pairing_check(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_152_541_000 picoseconds.
+		Weight::from_parts(10_381_293_316, 0)
+			// Standard Error: 175_253_649
+			.saturating_add(Weight::from_parts(7_111_642_497, 0).saturating_mul(n.into()))
+	}
+	fn bls12_map_fp_to_g1() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 1_629_566_000 picoseconds.
+		Weight::from_parts(2_676_489_000, 0)
+	}
+	fn bls12_map_fp2_to_g2() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_800_084_000 picoseconds.
+		Weight::from_parts(6_547_976_000, 0)
+	}
 	fn seal_ecdsa_to_eth_address() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
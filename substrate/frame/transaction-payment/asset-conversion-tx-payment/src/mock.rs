@@ -270,6 +270,8 @@ impl pallet_asset_conversion::Config for Runtime {
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type MaxSwapPathLength = MaxSwapPathLength;
 	type MintMinLiquidity = ConstU64<100>; // 100 is good enough when the main currency has 12 decimals.
+	type PriceObservationPeriod = ConstU64<10>;
+	type MaxPriceObservations = ConstU32<10>;
 	type WeightInfo = ();
 	pallet_asset_conversion::runtime_benchmarks_enabled! {
 		type BenchmarkHelper = ();
@@ -94,6 +94,7 @@ impl pallet_proxy::Config for Test {
 	type ProxyType = ProxyType;
 	type ProxyDepositBase = ConstU64<1>;
 	type ProxyDepositFactor = ConstU64<1>;
+	type LimitedProxyDepositFactor = ConstU64<1>;
 	type MaxProxies = ConstU32<4>;
 	type WeightInfo = ();
 	type CallHasher = BlakeTwo256;